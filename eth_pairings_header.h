@@ -46,6 +46,20 @@
 
 #define MNT6PAIR 10
 
+#define EDWARDSADD 13
+
+#define EDWARDSMUL 14
+
+#define EDWARDSMULTIEXP 15
+
+#define G1DECOMPRESS 16
+
+#define G2DECOMPRESS 17
+
+#define BW6PAIR 18
+
+#define BLS24PAIR 19
+
 uint32_t c_perform_operation(char op,
                              const char *i,
                              uint32_t i_len,
@@ -61,4 +75,14 @@ uint32_t c_meter_operation(char op,
                              char *err,
                              uint32_t *char_len);
 
+uint32_t c_execute_with_gas_limit(char op,
+                             const char *i,
+                             uint32_t i_len,
+                             uint64_t gas_limit,
+                             char *o,
+                             uint32_t *o_len,
+                             uint64_t *gas_used,
+                             char *err,
+                             uint32_t *char_len);
+
 #endif /* eth_pairings_bindings_h */
\ No newline at end of file
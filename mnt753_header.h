@@ -0,0 +1,37 @@
+#ifndef mnt753_bindings_h
+#define mnt753_bindings_h
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MNT_753_PREALLOCATE_FOR_ERROR_BYTES 256
+
+#define MNT_753_PREALLOCATE_FOR_RESULT_BYTES 576
+
+#define MNT_G1ADD_OPERATION_RAW_VALUE 1
+#define MNT_G1MUL_OPERATION_RAW_VALUE 2
+#define MNT_G1MULTIEXP_OPERATION_RAW_VALUE 3
+#define MNT_G2ADD_OPERATION_RAW_VALUE 4
+#define MNT_G2MUL_OPERATION_RAW_VALUE 5
+#define MNT_G2MULTIEXP_OPERATION_RAW_VALUE 6
+#define MNT_PAIR_OPERATION_RAW_VALUE 7
+
+uint32_t mnt4_753_perform_operation(char op,
+                                    const char *i,
+                                    uint32_t i_len,
+                                    char *o,
+                                    uint32_t *o_len,
+                                    char *err,
+                                    uint32_t *char_len);
+
+uint32_t mnt6_753_perform_operation(char op,
+                                    const char *i,
+                                    uint32_t i_len,
+                                    char *o,
+                                    uint32_t *o_len,
+                                    char *err,
+                                    uint32_t *char_len);
+
+#endif /* mnt753_bindings_h */
@@ -1,6 +1,9 @@
 #![recursion_limit = "1024"]
 
 extern crate byteorder;
+extern crate num_bigint;
+extern crate num_integer;
+extern crate num_traits;
 
 extern crate proc_macro;
 extern crate proc_macro2;
@@ -11,6 +14,10 @@ extern crate quote;
 use quote::TokenStreamExt;
 use std::str::FromStr;
 
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{Num, One, ToPrimitive, Zero};
+
 #[proc_macro_derive(ElementRepresentation, attributes(NumberOfLimbs))]
 pub fn element_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // Parse the type definition
@@ -33,6 +40,140 @@ pub fn element_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     gen.into()
 }
 
+#[proc_macro_derive(SizedPrimeField, attributes(PrimeFieldModulus, PrimeFieldRepr, NumberOfLimbs))]
+pub fn prime_field(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    // Parse the type definition
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+
+    match ast.data {
+        syn::Data::Struct(ref variant_data) => match variant_data.fields {
+            syn::Fields::Unit => {},
+            _ => panic!("SizedPrimeField derive only operates over unit structs"),
+        },
+        _ => panic!("SizedPrimeField derive only operates over unit structs"),
+    }
+
+    let modulus_str = fetch_attr("PrimeFieldModulus", &ast.attrs)
+        .expect("Please supply a modulus");
+    let modulus = parse_biguint(&modulus_str).expect("Modulus must be a decimal or 0x-prefixed hex number");
+
+    let repr_str = fetch_attr("PrimeFieldRepr", &ast.attrs)
+        .expect("Please supply a representation type");
+    let repr: syn::Type = syn::parse_str(&repr_str).expect("Representation must be a type");
+
+    let limbs_str = fetch_attr("NumberOfLimbs", &ast.attrs)
+        .expect("Please supply a representation length in terms of 64 bit limbs");
+    let limbs = usize::from_str(&limbs_str).expect("Number of limbs must be a number");
+
+    let mut gen = proc_macro2::TokenStream::new();
+
+    gen.extend(prime_field_constants_impl(&ast.ident, &repr, &modulus, limbs));
+
+    // Return the generated impl
+    gen.into()
+}
+
+fn parse_biguint(value: &str) -> Option<BigUint> {
+    let value = value.trim();
+    if value.starts_with("0x") || value.starts_with("0X") {
+        BigUint::from_str_radix(&value[2..], 16).ok()
+    } else {
+        BigUint::from_str_radix(value, 10).ok()
+    }
+}
+
+/// Converts a number into exactly `limbs` little-endian 64 bit limbs
+fn biguint_to_limbs(value: &BigUint, limbs: usize) -> Vec<u64> {
+    let mut result = vec![0u64; limbs];
+    let mut value = value.clone();
+    let limb_modulus = BigUint::one() << 64;
+    for limb in result.iter_mut() {
+        let (quotient, remainder) = value.div_rem(&limb_modulus);
+        *limb = remainder.to_u64().unwrap();
+        value = quotient;
+    }
+    assert!(value.is_zero(), "number does not fit into {} limbs", limbs);
+
+    result
+}
+
+fn limbs_into_repr(repr: &syn::Type, value: &BigUint, limbs: usize) -> proc_macro2::TokenStream {
+    let limbs = biguint_to_limbs(value, limbs);
+
+    quote!{ #repr([#(#limbs),*]) }
+}
+
+// Implement SizedPrimeField for a zero-sized `name` with a modulus fixed at compile time
+fn prime_field_constants_impl(
+    name: &syn::Ident,
+    repr: &syn::Type,
+    modulus: &BigUint,
+    limbs: usize
+) -> proc_macro2::TokenStream {
+    assert!(modulus.is_odd(), "modulus must be odd");
+    assert!(*modulus > BigUint::one(), "modulus must be larger than one");
+    assert!(modulus.bits() <= limbs * 64, "modulus does not fit into {} limbs", limbs);
+
+    let mont_power = (limbs * 64) as u64;
+    let modulus_bits = modulus.bits() as u64;
+
+    // R = 2**(64 * limbs) mod m
+    let r = (BigUint::one() << (limbs * 64)) % modulus;
+    // R**2 mod m
+    let r2 = (&r * &r) % modulus;
+
+    // -(m^-1 mod 2^64) mod 2^64
+    let modulus_low = biguint_to_limbs(&(modulus % (BigUint::one() << 64)), 1)[0];
+    let mut inv = 1u64;
+    for _ in 0..63 {
+        inv = inv.wrapping_mul(inv);
+        inv = inv.wrapping_mul(modulus_low);
+    }
+    let mont_inv = inv.wrapping_neg();
+
+    let modulus_repr = limbs_into_repr(repr, modulus, limbs);
+    let r_repr = limbs_into_repr(repr, &r, limbs);
+    let r2_repr = limbs_into_repr(repr, &r2, limbs);
+
+    quote! {
+        impl #name {
+            pub const MONT_POWER: u64 = #mont_power;
+            pub const MODULUS_BITS: u64 = #modulus_bits;
+            pub const MODULUS: #repr = #modulus_repr;
+            pub const MONT_R: #repr = #r_repr;
+            pub const MONT_R2: #repr = #r2_repr;
+            pub const MONT_INV: u64 = #mont_inv;
+        }
+
+        impl crate::field::SizedPrimeField for #name {
+            type Repr = #repr;
+
+            #[inline(always)]
+            fn mont_power(&self) -> u64 { Self::MONT_POWER }
+
+            #[inline(always)]
+            fn modulus_bits(&self) -> u64 { Self::MODULUS_BITS }
+
+            #[inline(always)]
+            fn modulus(&self) -> &Self::Repr { &Self::MODULUS }
+
+            #[inline(always)]
+            fn mont_r(&self) -> &Self::Repr { &Self::MONT_R }
+
+            #[inline(always)]
+            fn mont_r2(&self) -> &Self::Repr { &Self::MONT_R2 }
+
+            #[inline(always)]
+            fn mont_inv(&self) -> u64 { Self::MONT_INV }
+
+            #[inline(always)]
+            fn is_valid_repr(&self, repr: &Self::Repr) -> bool {
+                repr < &Self::MODULUS
+            }
+        }
+    }
+}
+
 /// Fetches the ident being wrapped by the type we're deriving.
 fn fetch_wrapped_ident(body: &syn::Data) -> Option<syn::Ident> {
     match body {
@@ -244,7 +385,8 @@ fn prime_field_repr_impl(repr: &syn::Ident, limbs: usize) -> proc_macro2::TokenS
         gen
     }
 
-    fn mul_impl(
+    // Schoolbook product into the temporaries r0..r{2*limbs - 1}
+    fn product_impl(
         a: proc_macro2::TokenStream,
         b: proc_macro2::TokenStream,
         limbs: usize,
@@ -277,6 +419,16 @@ fn prime_field_repr_impl(repr: &syn::Ident, limbs: usize) -> proc_macro2::TokenS
             });
         }
 
+        gen
+    }
+
+    fn mul_impl(
+        a: proc_macro2::TokenStream,
+        b: proc_macro2::TokenStream,
+        limbs: usize,
+    ) -> proc_macro2::TokenStream {
+        let mut gen = product_impl(a, b, limbs);
+
         let mut mont_calling = proc_macro2::TokenStream::new();
         mont_calling.append_separated(
             (0..(limbs * 2)).map(|i| get_temp(i)),
@@ -290,10 +442,192 @@ fn prime_field_repr_impl(repr: &syn::Ident, limbs: usize) -> proc_macro2::TokenS
         gen
     }
 
+    fn mul_wide_impl(
+        a: proc_macro2::TokenStream,
+        b: proc_macro2::TokenStream,
+        limbs: usize,
+    ) -> proc_macro2::TokenStream {
+        let mut gen = product_impl(a, b, limbs);
+
+        let mut low = proc_macro2::TokenStream::new();
+        low.append_separated(
+            (0..limbs).map(|i| get_temp(i)),
+            proc_macro2::Punct::new(',', proc_macro2::Spacing::Alone),
+        );
+        let mut high = proc_macro2::TokenStream::new();
+        high.append_separated(
+            (limbs..(limbs * 2)).map(|i| get_temp(i)),
+            proc_macro2::Punct::new(',', proc_macro2::Spacing::Alone),
+        );
+
+        gen.extend(quote!{
+            (Self([#low]), Self([#high]))
+        });
+
+        gen
+    }
+
+    // "No-carry" CIOS multiplication, see
+    // <https://hackmd.io/@gnark/modular_multiplication>. Valid if the highest
+    // limb of the modulus is below 2^63 - 1, so every row fits into `limbs` words
+    // and the last carry can be added without overflow.
+    fn mul_no_carry_impl(
+        a: proc_macro2::TokenStream,
+        b: proc_macro2::TokenStream,
+        limbs: usize,
+    ) -> proc_macro2::TokenStream {
+        let mut gen = proc_macro2::TokenStream::new();
+
+        for i in 0..limbs {
+            gen.extend(quote!{
+                let mut carry_a = 0u64;
+                let mut carry_c = 0u64;
+            });
+
+            let temp0 = get_temp(0);
+            if i == 0 {
+                gen.extend(quote!{
+                    let #temp0 = crate::arithmetics::mac_with_carry(0, #a.0[0], #b.0[#i], &mut carry_a);
+                });
+            } else {
+                gen.extend(quote!{
+                    let #temp0 = crate::arithmetics::mac_with_carry(#temp0, #a.0[0], #b.0[#i], &mut carry_a);
+                });
+            }
+
+            gen.extend(quote!{
+                let m = #temp0.wrapping_mul(mont_inv);
+                crate::arithmetics::mac_with_carry(#temp0, m, modulus.0[0], &mut carry_c);
+            });
+
+            for j in 1..limbs {
+                let temp = get_temp(j);
+                let temp_prev = get_temp(j - 1);
+
+                if i == 0 {
+                    gen.extend(quote!{
+                        let #temp = crate::arithmetics::mac_with_carry(0, #a.0[#j], #b.0[#i], &mut carry_a);
+                    });
+                } else {
+                    gen.extend(quote!{
+                        let #temp = crate::arithmetics::mac_with_carry(#temp, #a.0[#j], #b.0[#i], &mut carry_a);
+                    });
+                }
+
+                gen.extend(quote!{
+                    let #temp_prev = crate::arithmetics::mac_with_carry(#temp, m, modulus.0[#j], &mut carry_c);
+                });
+            }
+
+            let temp_last = get_temp(limbs - 1);
+            gen.extend(quote!{
+                let #temp_last = carry_c + carry_a;
+            });
+        }
+
+        for i in 0..limbs {
+            let temp = get_temp(i);
+            gen.extend(quote!{
+                self.0[#i] = #temp;
+            });
+        }
+
+        gen
+    }
+
+    // Squaring in the same interleaved form: row `i` adds a_i^2 and 2*a_i*a_j
+    // for j > i and is immediately followed by one reduction step. Doubled products
+    // are accumulated with a u128 carry (below 2^66), and an extra word holds the
+    // top of the accumulator. Valid if the highest limb of the modulus is below
+    // 2^62, so the accumulator stays below 3p and fits into `limbs + 1` words.
+    fn sqr_no_carry_impl(
+        a: proc_macro2::TokenStream,
+        limbs: usize,
+    ) -> proc_macro2::TokenStream {
+        let mut gen = proc_macro2::TokenStream::new();
+
+        let top = get_temp(limbs);
+
+        for i in 0..limbs {
+            let temp = get_temp(i);
+            gen.extend(quote!{
+                let mut #temp = 0u64;
+            });
+        }
+
+        for i in 0..limbs {
+            let temp_i = get_temp(i);
+            gen.extend(quote!{
+                let tmp = u128::from(#temp_i) + u128::from(#a.0[#i]) * u128::from(#a.0[#i]);
+                #temp_i = tmp as u64;
+            });
+
+            if i == limbs - 1 {
+                gen.extend(quote!{
+                    let carry = tmp >> 64;
+                });
+            } else {
+                gen.extend(quote!{
+                    let mut carry = tmp >> 64;
+                });
+            }
+
+            for j in (i + 1)..limbs {
+                let temp_j = get_temp(j);
+                gen.extend(quote!{
+                    let product = u128::from(#a.0[#i]) * u128::from(#a.0[#j]);
+                    let tmp = u128::from(#temp_j) + (u128::from(product as u64) << 1) + carry;
+                    #temp_j = tmp as u64;
+                    carry = (tmp >> 64) + ((product >> 64) << 1);
+                });
+            }
+
+            let temp0 = get_temp(0);
+            gen.extend(quote!{
+                let #top = carry as u64;
+
+                let m = #temp0.wrapping_mul(mont_inv);
+                let mut carry = 0u64;
+                crate::arithmetics::mac_with_carry(#temp0, m, modulus.0[0], &mut carry);
+            });
+
+            for j in 1..limbs {
+                let temp = get_temp(j);
+                let temp_prev = get_temp(j - 1);
+                gen.extend(quote!{
+                    #temp_prev = crate::arithmetics::mac_with_carry(#temp, m, modulus.0[#j], &mut carry);
+                });
+            }
+
+            let temp_last = get_temp(limbs - 1);
+            gen.extend(quote!{
+                #temp_last = #top + carry;
+            });
+        }
+
+        for i in 0..limbs {
+            let temp = get_temp(i);
+            gen.extend(quote!{
+                self.0[#i] = #temp;
+            });
+        }
+
+        gen
+    }
+
     let squaring_impl = sqr_impl(quote!{self}, limbs);
     let multiply_impl = mul_impl(quote!{self}, quote!{other}, limbs);
+    let multiply_wide_impl = mul_wide_impl(quote!{self}, quote!{other}, limbs);
+    let multiply_no_carry_impl = mul_no_carry_impl(quote!{self}, quote!{other}, limbs);
+    let squaring_no_carry_impl = sqr_no_carry_impl(quote!{self}, limbs);
     let montgomery_impl = mont_impl(limbs);
 
+    let mut wide_reduce_calling = proc_macro2::TokenStream::new();
+    wide_reduce_calling.append_separated(
+        (0..limbs).map(|i| quote!{low.0[#i]}).chain((0..limbs).map(|i| quote!{high.0[#i]})),
+        proc_macro2::Punct::new(',', proc_macro2::Spacing::Alone),
+    );
+
     quote! {
 
         #[derive(Copy, Clone, PartialEq, Eq, Default)]
@@ -318,6 +652,47 @@ fn prime_field_repr_impl(repr: &syn::Ident, limbs: usize) -> proc_macro2::TokenS
             //     self.reduce(modulus);
             // }
 
+            /// Multiplication in "no-carry" form can be used if the highest limb of the modulus
+            /// is below 2^63 - 1.
+            #[inline(always)]
+            pub(crate) fn supports_no_carry_mul(modulus: &#repr) -> bool {
+                modulus.0[#limbs - 1] < 0x7fffffffffffffff
+            }
+
+            /// Dedicated squaring can be used if the highest limb of the modulus is below 2^62.
+            #[inline(always)]
+            pub(crate) fn supports_no_carry_square(modulus: &#repr) -> bool {
+                modulus.0[#limbs - 1] < 0x4000000000000000
+            }
+
+            #[inline(always)]
+            pub(crate) fn mont_mul_assign_with_carry(&mut self, other: &#repr, modulus: &#repr, mont_inv: u64)
+            {
+                #multiply_impl
+                self.reduce(modulus);
+            }
+
+            #[inline(always)]
+            pub(crate) fn mont_square_with_carry(&mut self, modulus: &#repr, mont_inv: u64)
+            {
+                #squaring_impl
+                self.reduce(modulus);
+            }
+
+            #[inline(always)]
+            pub(crate) fn mont_mul_assign_no_carry(&mut self, other: &#repr, modulus: &#repr, mont_inv: u64)
+            {
+                #multiply_no_carry_impl
+                self.reduce(modulus);
+            }
+
+            #[inline(always)]
+            pub(crate) fn mont_square_no_carry(&mut self, modulus: &#repr, mont_inv: u64)
+            {
+                #squaring_no_carry_impl
+                self.reduce(modulus);
+            }
+
             #[inline(always)]
             fn mont_partial_reduce(
                 &mut self,
@@ -563,15 +938,21 @@ fn prime_field_repr_impl(repr: &syn::Ident, limbs: usize) -> proc_macro2::TokenS
             #[inline]
             fn mont_mul_assign(&mut self, other: &#repr, modulus: &#repr, mont_inv: u64)
             {
-                #multiply_impl
-                self.reduce(modulus);
+                if Self::supports_no_carry_mul(modulus) {
+                    self.mont_mul_assign_no_carry(other, modulus, mont_inv);
+                } else {
+                    self.mont_mul_assign_with_carry(other, modulus, mont_inv);
+                }
             }
 
             #[inline]
             fn mont_square(&mut self, modulus: &#repr, mont_inv: u64)
             {
-                #squaring_impl
-                self.reduce(modulus);
+                if Self::supports_no_carry_square(modulus) {
+                    self.mont_square_no_carry(modulus, mont_inv);
+                } else {
+                    self.mont_square_with_carry(modulus, mont_inv);
+                }
             }
 
             #[inline]
@@ -586,6 +967,22 @@ fn prime_field_repr_impl(repr: &syn::Ident, limbs: usize) -> proc_macro2::TokenS
                 #squaring_impl
             }
 
+            #[inline]
+            fn mul_wide(&self, other: &#repr) -> (#repr, #repr)
+            {
+                #multiply_wide_impl
+            }
+
+            #[inline]
+            fn mont_reduce_wide(low: &#repr, high: &#repr, modulus: &#repr, mont_inv: u64) -> #repr
+            {
+                let mut r = #repr::default();
+                r.mont_partial_reduce(modulus, mont_inv, #wide_reduce_calling);
+                r.reduce(modulus);
+
+                r
+            }
+
             #[inline(always)]
             fn into_normal_repr(&self, modulus: &#repr, mont_inv: u64) -> #repr {
                 let mut r = *self;
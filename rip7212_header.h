@@ -0,0 +1,30 @@
+#ifndef rip7212_bindings_h
+#define rip7212_bindings_h
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define RIP7212_PREALLOCATE_FOR_ERROR_BYTES 256
+
+#define RIP7212_PREALLOCATE_FOR_RESULT_BYTES 32
+
+#define RIP7212_P256VERIFY_OPERATION_RAW_VALUE 1
+
+uint32_t rip7212_perform_operation(char op,
+                                   const char *i,
+                                   uint32_t i_len,
+                                   char *o,
+                                   uint32_t *o_len,
+                                   char *err,
+                                   uint32_t *char_len);
+
+uint32_t rip7212_meter_operation(char op,
+                                 const char *i,
+                                 uint32_t i_len,
+                                 uint64_t *o,
+                                 char *err,
+                                 uint32_t *char_len);
+
+#endif /* rip7212_bindings_h */
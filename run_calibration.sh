@@ -0,0 +1,2 @@
+#!/bin/sh
+cargo run --release --features=calibration,gas_metering_mode --bin calibrate_gas -- --samples 5 --repetitions 5 --output calibration
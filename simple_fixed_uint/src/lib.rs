@@ -32,6 +32,10 @@ pub use rand;
 #[doc(hidden)]
 pub use static_assertions;
 
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+
 #[cfg(feature = "unroll")]
 pub use crunchy::unroll;
 
@@ -1601,6 +1601,7 @@ macro_rules! construct_uint {
 		}
 
 		$crate::impl_std_for_uint!($name, $n_words);
+		$crate::impl_serde_for_uint!($name, $n_words);
 		// `$n_words * 8` because macro expects bytes and
 		// uints use 64 bit (8 byte) words
 		// $crate::impl_quickcheck_arbitrary_for_uint!($name, ($n_words * 8));
@@ -1642,6 +1643,65 @@ macro_rules! impl_std_for_uint {
 	($name: ident, $n_words: tt) => {};
 }
 
+/// Serializes as a `0x` prefixed hex string with no leading zeroes, same as `{:#x}`.
+/// Deserialization accepts the same format with at most `$n_words * 16` digits
+#[cfg(feature = "serde")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_serde_for_uint {
+	($name: ident, $n_words: tt) => {
+		impl $crate::serde::Serialize for $name {
+			fn serialize<S: $crate::serde::Serializer>(&self, serializer: S) -> $crate::core_::result::Result<S::Ok, S::Error> {
+				serializer.collect_str(&$crate::core_::format_args!("{:#x}", self))
+			}
+		}
+
+		impl<'de> $crate::serde::Deserialize<'de> for $name {
+			fn deserialize<D: $crate::serde::Deserializer<'de>>(deserializer: D) -> $crate::core_::result::Result<$name, D::Error> {
+				struct HexVisitor;
+
+				impl<'de> $crate::serde::de::Visitor<'de> for HexVisitor {
+					type Value = $name;
+
+					fn expecting(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+						$crate::core_::write!(f, "a 0x prefixed hex string of at most {} digits", $n_words * 16)
+					}
+
+					fn visit_str<E: $crate::serde::de::Error>(self, value: &str) -> $crate::core_::result::Result<$name, E> {
+						let digits = match value.strip_prefix("0x") {
+							Some(digits) => digits,
+							None => return Err(E::invalid_value($crate::serde::de::Unexpected::Str(value), &self)),
+						};
+						if digits.is_empty() || digits.len() > $n_words * 16 {
+							return Err(E::invalid_length(digits.len(), &self));
+						}
+
+						let mut words = [0u64; $n_words];
+						for (i, c) in digits.chars().rev().enumerate() {
+							let nibble = match c.to_digit(16) {
+								Some(nibble) => nibble as u64,
+								None => return Err(E::invalid_value($crate::serde::de::Unexpected::Str(value), &self)),
+							};
+							words[i / 16] |= nibble << ((i % 16) * 4);
+						}
+
+						Ok($name(words))
+					}
+				}
+
+				deserializer.deserialize_str(HexVisitor)
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_serde_for_uint {
+	($name: ident, $n_words: tt) => {};
+}
+
 // #[cfg(feature = "quickcheck")]
 // #[macro_export]
 // #[doc(hidden)]
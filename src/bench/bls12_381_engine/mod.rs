@@ -103,6 +103,26 @@ fn bench_bls12_381_engine_sugroup_g2_window_7(b: &mut Bencher) {
 }
 
 
+#[bench]
+fn bench_bls12_381_engine_pair_1(b: &mut Bencher) {
+    let g1s = vec![BLS12_381_G1_GENERATOR.clone()];
+    let g2s = vec![BLS12_381_G2_GENERATOR.clone()];
+    b.iter(|| {
+        assert!(BLS12_381_PAIRING_ENGINE.pair(&g1s, &g2s).is_some());
+    });
+}
+
+#[bench]
+fn bench_bls12_381_engine_pair_1_generic_final_exp(b: &mut Bencher) {
+    let g1s = vec![BLS12_381_G1_GENERATOR.clone()];
+    let g2s = vec![BLS12_381_G2_GENERATOR.clone()];
+    let mut engine = BLS12_381_PAIRING_ENGINE.clone();
+    engine.prefer_hht_final_exp = false;
+    b.iter(|| {
+        assert!(engine.pair(&g1s, &g2s).is_some());
+    });
+}
+
 #[bench]
 fn bench_bls12_381_engine_pair_2(b: &mut Bencher) {
     let g1_point = BLS12_381_G1_GENERATOR.clone();
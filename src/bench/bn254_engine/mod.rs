@@ -14,7 +14,7 @@ use crate::engines::bn254::*;
 
 #[bench]
 fn bench_bn254_engine_g1_double_and_add_worst_case(b: &mut Bencher) {
-    let point = &*BN254_G1_GENERATOR;
+    let point = &BN254_G1_GENERATOR;
     let worst_case_scalar = [std::u64::MAX; 4];
     b.iter(|| {
         assert!(!point.mul(&worst_case_scalar[..]).is_zero());
@@ -23,7 +23,7 @@ fn bench_bn254_engine_g1_double_and_add_worst_case(b: &mut Bencher) {
 
 #[bench]
 fn bench_bn254_engine_sugroup_g2_window_5(b: &mut Bencher) {
-    let point = &*BN254_G2_GENERATOR;
+    let point = &BN254_G2_GENERATOR;
     let subgroup = &BN254_SUBGROUP_ORDER[..];
     b.iter(|| {
         assert!(point.wnaf_mul_with_window_size(subgroup, 5).is_zero());
@@ -32,11 +32,11 @@ fn bench_bn254_engine_sugroup_g2_window_5(b: &mut Bencher) {
 
 #[bench]
 fn bench_bn254_engine_pair_2(b: &mut Bencher) {
-    let g1_point = (&*BN254_G1_GENERATOR).clone();
-    let g2_point = (&*BN254_G2_GENERATOR).clone();
+    let g1_point = BN254_G1_GENERATOR.clone();
+    let g2_point = BN254_G2_GENERATOR.clone();
     let g1s = vec![g1_point; 2];
     let g2s = vec![g2_point; 2];
-    let eng = &*BN254_PAIRING_ENGINE;
+    let eng = &BN254_PAIRING_ENGINE;
     b.iter(|| {
         assert!(eng.pair(&g1s, &g2s).is_some());
     });
@@ -44,12 +44,32 @@ fn bench_bn254_engine_pair_2(b: &mut Bencher) {
 
 #[bench]
 fn bench_bn254_engine_pair_4(b: &mut Bencher) {
-    let g1_point = (&*BN254_G1_GENERATOR).clone();
-    let g2_point = (&*BN254_G2_GENERATOR).clone();
+    let g1_point = BN254_G1_GENERATOR.clone();
+    let g2_point = BN254_G2_GENERATOR.clone();
     let g1s = vec![g1_point; 4];
     let g2s = vec![g2_point; 4];
-    let eng = &*BN254_PAIRING_ENGINE;
+    let eng = &BN254_PAIRING_ENGINE;
     b.iter(|| {
         assert!(eng.pair(&g1s, &g2s).is_some());
     });
-}
\ No newline at end of file
+}
+
+#[bench]
+fn bench_bn254_engine_pair_1(b: &mut Bencher) {
+    let g1s = vec![BN254_G1_GENERATOR.clone()];
+    let g2s = vec![BN254_G2_GENERATOR.clone()];
+    let eng = &BN254_PAIRING_ENGINE;
+    b.iter(|| {
+        assert!(eng.pair(&g1s, &g2s).is_some());
+    });
+}
+
+#[bench]
+fn bench_bn254_engine_pair_1_fuentes_final_exp(b: &mut Bencher) {
+    let g1s = vec![BN254_G1_GENERATOR.clone()];
+    let g2s = vec![BN254_G2_GENERATOR.clone()];
+    let eng = BN254_PAIRING_ENGINE.for_pairing_checks();
+    b.iter(|| {
+        assert!(eng.pair(&g1s, &g2s).is_some());
+    });
+}
@@ -0,0 +1,89 @@
+extern crate test as rust_test;
+
+use crate::field::SizedPrimeField;
+use crate::representation::ElementRepr;
+use crate::fp::Fp;
+use crate::traits::{FieldElement, ZeroAndOne};
+use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2};
+use crate::engines::bls12_381::BLS12_381_EXTENSION_12_FIELD;
+use crate::engines::bn254::{BN254_EXT12_FIELD, BN254_U};
+
+use rust_test::Bencher;
+
+const BLS12_381_X: [u64; 1] = [0xd201000000010000];
+
+// element of the cyclotomic subgroup, made by the easy part of the final exponentiation
+fn cyclotomic_element<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(
+    extension: &'a Extension2Over3Over2<'a, E, F>
+) -> Fp12<'a, E, F> {
+    let field = extension.field.field.field;
+    let mut f = Fp12::one(extension);
+    f.c0.c1.c0 = Fp::from_repr(field, E::from(3)).unwrap();
+    f.c1.c0.c1 = Fp::from_repr(field, E::from(7)).unwrap();
+    f.c1.c2.c0 = Fp::from_repr(field, E::from(11)).unwrap();
+
+    let mut el = f;
+    el.frobenius_map(6);
+    el.mul_assign(&f.inverse().unwrap());
+    let mut tmp = el;
+    tmp.frobenius_map(2);
+    el.mul_assign(&tmp);
+
+    el
+}
+
+#[bench]
+fn bench_bls12_381_cyclotomic_square(b: &mut Bencher) {
+    let mut el = cyclotomic_element(&BLS12_381_EXTENSION_12_FIELD);
+    b.iter(|| {
+        el.cyclotomic_square();
+    });
+}
+
+#[bench]
+fn bench_bls12_381_compressed_cyclotomic_square(b: &mut Bencher) {
+    let mut el = cyclotomic_element(&BLS12_381_EXTENSION_12_FIELD).compress();
+    b.iter(|| {
+        el.square();
+    });
+}
+
+#[bench]
+fn bench_bls12_381_decompress(b: &mut Bencher) {
+    let el = cyclotomic_element(&BLS12_381_EXTENSION_12_FIELD).compress();
+    b.iter(|| {
+        el.decompress()
+    });
+}
+
+#[bench]
+fn bench_bls12_381_cyclotomic_exp_by_x(b: &mut Bencher) {
+    let el = cyclotomic_element(&BLS12_381_EXTENSION_12_FIELD);
+    b.iter(|| {
+        el.cyclotomic_exp(&BLS12_381_X)
+    });
+}
+
+#[bench]
+fn bench_bls12_381_compressed_cyclotomic_exp_by_x(b: &mut Bencher) {
+    let el = cyclotomic_element(&BLS12_381_EXTENSION_12_FIELD);
+    b.iter(|| {
+        el.cyclotomic_exp_compressed(&BLS12_381_X)
+    });
+}
+
+#[bench]
+fn bench_bn254_cyclotomic_exp_by_u(b: &mut Bencher) {
+    let el = cyclotomic_element(&BN254_EXT12_FIELD);
+    b.iter(|| {
+        el.cyclotomic_exp(&[BN254_U])
+    });
+}
+
+#[bench]
+fn bench_bn254_compressed_cyclotomic_exp_by_u(b: &mut Bencher) {
+    let el = cyclotomic_element(&BN254_EXT12_FIELD);
+    b.iter(|| {
+        el.cyclotomic_exp_compressed(&[BN254_U])
+    });
+}
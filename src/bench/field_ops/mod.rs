@@ -1,2 +1,4 @@
 mod frobenius;
-mod sqrt;
\ No newline at end of file
+mod sqrt;
+mod montgomery;
+mod cyclotomic;
\ No newline at end of file
@@ -0,0 +1,117 @@
+extern crate test as rust_test;
+
+use crate::field::{U256Repr, U384Repr, SizedPrimeField};
+use crate::representation::ElementRepr;
+use crate::engines::bn254::BN254_BASE_FIELD;
+use crate::engines::bls12_381::BLS12_381_FIELD;
+
+use rust_test::Bencher;
+
+// some elements in Montgomery form, e.g. R^2 mod p and R^3 mod p
+fn bn254_elements() -> (U256Repr, U256Repr) {
+    let field = &BN254_BASE_FIELD;
+    let a = *field.mont_r2();
+    let mut b = a;
+    b.mont_mul_assign_with_carry(&a, field.modulus(), field.mont_inv());
+
+    (a, b)
+}
+
+fn bls12_381_elements() -> (U384Repr, U384Repr) {
+    let field = &BLS12_381_FIELD;
+    let a = *field.mont_r2();
+    let mut b = a;
+    b.mont_mul_assign_with_carry(&a, field.modulus(), field.mont_inv());
+
+    (a, b)
+}
+
+#[bench]
+fn bench_bn254_mont_mul_with_carry(b: &mut Bencher) {
+    let field = &BN254_BASE_FIELD;
+    let (mut x, y) = bn254_elements();
+    b.iter(|| {
+        x.mont_mul_assign_with_carry(&y, field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
+
+#[bench]
+fn bench_bn254_mont_mul_no_carry(b: &mut Bencher) {
+    let field = &BN254_BASE_FIELD;
+    let (mut x, y) = bn254_elements();
+    b.iter(|| {
+        x.mont_mul_assign_no_carry(&y, field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
+
+#[bench]
+fn bench_bn254_mont_square_with_carry(b: &mut Bencher) {
+    let field = &BN254_BASE_FIELD;
+    let (mut x, _) = bn254_elements();
+    b.iter(|| {
+        x.mont_square_with_carry(field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
+
+#[bench]
+fn bench_bn254_mont_square_no_carry(b: &mut Bencher) {
+    let field = &BN254_BASE_FIELD;
+    let (mut x, _) = bn254_elements();
+    b.iter(|| {
+        x.mont_square_no_carry(field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
+
+#[bench]
+fn bench_bls12_381_mont_mul_with_carry(b: &mut Bencher) {
+    let field = &BLS12_381_FIELD;
+    let (mut x, y) = bls12_381_elements();
+    b.iter(|| {
+        x.mont_mul_assign_with_carry(&y, field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
+
+#[bench]
+fn bench_bls12_381_mont_mul_no_carry(b: &mut Bencher) {
+    let field = &BLS12_381_FIELD;
+    let (mut x, y) = bls12_381_elements();
+    b.iter(|| {
+        x.mont_mul_assign_no_carry(&y, field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
+
+#[bench]
+fn bench_bls12_381_mont_square_with_carry(b: &mut Bencher) {
+    let field = &BLS12_381_FIELD;
+    let (mut x, _) = bls12_381_elements();
+    b.iter(|| {
+        x.mont_square_with_carry(field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
+
+#[bench]
+fn bench_bls12_381_mont_square_no_carry(b: &mut Bencher) {
+    let field = &BLS12_381_FIELD;
+    let (mut x, _) = bls12_381_elements();
+    b.iter(|| {
+        x.mont_square_no_carry(field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
+
+#[bench]
+fn bench_bls12_381_mont_mul_dispatched(b: &mut Bencher) {
+    let field = &BLS12_381_FIELD;
+    let (mut x, y) = bls12_381_elements();
+    b.iter(|| {
+        x.mont_mul_assign(&y, field.modulus(), field.mont_inv());
+        rust_test::black_box(x)
+    });
+}
@@ -0,0 +1,82 @@
+//! Calibrates gas models on this machine and writes them in the format of `src/gas_meter/*.json`
+//!
+//! Usage: calibrate_gas [--output DIR] [--samples N] [--repetitions N] [--seed N]
+//!                      [--gas-per-microsecond N] [--models FILE_NAME,FILE_NAME,...]
+
+use eth_pairings::calibration::{self, CalibrationConfig, CalibrationReport, DEFAULT_GAS_PER_MICROSECOND};
+use eth_pairings::gas_meter::{GasModel, GasSchedule};
+
+fn usage() -> ! {
+    let models: Vec<&str> = GasModel::ALL.iter().map(|model| model.file_name()).collect();
+    eprintln!("Usage: calibrate_gas [--output DIR] [--samples N] [--repetitions N] [--seed N] [--gas-per-microsecond N] [--models FILE_NAME,...]");
+    eprintln!("Default rate is {} gas per microsecond, known models are: {}", DEFAULT_GAS_PER_MICROSECOND, models.join(", "));
+    std::process::exit(1);
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> T {
+    match value.as_ref().map(|v| v.parse()) {
+        Some(Ok(value)) => value,
+        _ => {
+            eprintln!("Flag {} requires a numeric value", flag);
+            usage();
+        }
+    }
+}
+
+fn main() {
+    let mut config = CalibrationConfig::default();
+    let mut output = String::from("calibration");
+
+    let mut args = std::env::args().skip(1);
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--output" => output = args.next().unwrap_or_else(|| usage()),
+            "--samples" => config.samples = parse_number(&flag, args.next()),
+            "--repetitions" => config.repetitions = parse_number(&flag, args.next()),
+            "--seed" => config.seed = parse_number(&flag, args.next()),
+            "--gas-per-microsecond" => config.gas_per_microsecond = parse_number(&flag, args.next()),
+            "--models" => {
+                let names = args.next().unwrap_or_else(|| usage());
+                config.models = names.split(',').map(|name| {
+                    GasModel::from_file_name(name.trim()).unwrap_or_else(|| {
+                        eprintln!("Unknown model {}", name);
+                        usage();
+                    })
+                }).collect();
+            },
+            _ => usage(),
+        }
+    }
+
+    // models are calibrated one by one to report progress, every next one starts from
+    // the schedule with previously calibrated models
+    let mut schedule: GasSchedule = config.base_schedule.clone();
+    let mut report = CalibrationReport {
+        gas_per_microsecond: config.gas_per_microsecond,
+        models: vec![],
+    };
+    for (i, model) in config.models.iter().enumerate() {
+        println!("Calibrating {} ({}/{})", model.file_name(), i + 1, config.models.len());
+        let model_config = CalibrationConfig {
+            models: vec![*model],
+            seed: config.seed.wrapping_add(i as u64),
+            base_schedule: schedule,
+            ..config.clone()
+        };
+        let calibration = calibration::calibrate(&model_config).unwrap_or_else(|e| {
+            eprintln!("Calibration of {} failed: {}", model.file_name(), e);
+            std::process::exit(1);
+        });
+        schedule = calibration.schedule;
+        report.models.extend(calibration.report.models);
+    }
+
+    println!("{}", report);
+
+    let calibration = calibration::Calibration { schedule, report };
+    if let Err(e) = std::fs::create_dir_all(&output).map_err(|e| e.to_string()).and_then(|_| calibration.write_to_directory(&output).map_err(|e| e.to_string())) {
+        eprintln!("Failed to write calibration results to {}: {}", output, e);
+        std::process::exit(1);
+    }
+    println!("Models and report are written to {}", output);
+}
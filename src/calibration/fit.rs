@@ -0,0 +1,210 @@
+//! Least squares fitting of measured running times and reports of fit residuals
+
+use serde::Serialize;
+
+use crate::gas_meter::GasMeter;
+
+/// Ridge term that keeps rank deficient systems (e.g. too few samples) solvable,
+/// relative to columns that are scaled to the unit maximum
+const RIDGE: f64 = 1e-12;
+
+/// Least squares solution of `rows * x = targets` using Householder QR
+fn solve_least_squares(rows: &[Vec<f64>], targets: &[f64]) -> Vec<f64> {
+    let num_columns = rows[0].len();
+    let mut a: Vec<Vec<f64>> = rows.to_vec();
+    let mut b: Vec<f64> = targets.to_vec();
+    for j in 0..num_columns {
+        let mut ridge_row = vec![0f64; num_columns];
+        ridge_row[j] = RIDGE.sqrt();
+        a.push(ridge_row);
+        b.push(0f64);
+    }
+
+    for k in 0..num_columns {
+        let norm = a[k..].iter().map(|row| row[k] * row[k]).sum::<f64>().sqrt();
+        if norm == 0f64 {
+            continue;
+        }
+        let alpha = if a[k][k] > 0f64 { -norm } else { norm };
+        let mut v: Vec<f64> = a[k..].iter().map(|row| row[k]).collect();
+        v[0] -= alpha;
+        let v_norm_squared = v.iter().map(|x| x * x).sum::<f64>();
+        if v_norm_squared == 0f64 {
+            continue;
+        }
+
+        for j in k..num_columns {
+            let dot = v.iter().zip(a[k..].iter()).map(|(v, row)| v * row[j]).sum::<f64>();
+            let factor = 2f64 * dot / v_norm_squared;
+            for (v, row) in v.iter().zip(a[k..].iter_mut()) {
+                row[j] -= factor * v;
+            }
+        }
+        let dot = v.iter().zip(b[k..].iter()).map(|(v, b)| v * b).sum::<f64>();
+        let factor = 2f64 * dot / v_norm_squared;
+        for (v, b) in v.iter().zip(b[k..].iter_mut()) {
+            *b -= factor * v;
+        }
+    }
+
+    let mut solution = vec![0f64; num_columns];
+    for k in (0..num_columns).rev() {
+        let tail = (k + 1..num_columns).map(|j| a[k][j] * solution[j]).sum::<f64>();
+        solution[k] = if a[k][k].abs() < f64::EPSILON { 0f64 } else { (b[k] - tail) / a[k][k] };
+    }
+
+    solution
+}
+
+/// Least squares fit with non-negative coefficients. Columns are scaled for conditioning,
+/// and columns that get negative coefficients are removed from the system one by one
+/// (the most negative first) until all the coefficients are non-negative
+pub(crate) fn fit_non_negative(rows: &[Vec<f64>], targets: &[f64]) -> Vec<f64> {
+    assert!(!rows.is_empty());
+    assert_eq!(rows.len(), targets.len());
+    let num_columns = rows[0].len();
+
+    let scales: Vec<f64> = (0..num_columns).map(|j| {
+        let max = rows.iter().map(|row| row[j].abs()).fold(0f64, f64::max);
+        if max == 0f64 { 1f64 } else { max }
+    }).collect();
+
+    let mut active: Vec<usize> = (0..num_columns).collect();
+    let mut coefficients = vec![0f64; num_columns];
+    while !active.is_empty() {
+        let scaled_rows: Vec<Vec<f64>> = rows.iter().map(|row| active.iter().map(|j| row[*j] / scales[*j]).collect()).collect();
+        let solution = solve_least_squares(&scaled_rows, targets);
+
+        let (most_negative, min_value) = solution.iter().enumerate().fold((0, 0f64), |(index, min), (i, value)| {
+            if *value < min { (i, *value) } else { (index, min) }
+        });
+        if min_value < 0f64 {
+            active.remove(most_negative);
+            continue;
+        }
+
+        coefficients = vec![0f64; num_columns];
+        for (j, value) in active.iter().zip(solution.iter()) {
+            coefficients[*j] = value / scales[*j];
+        }
+        break;
+    }
+
+    coefficients
+}
+
+/// Value of the monomial `prod(features[variable]^power)`
+pub(crate) fn monomial(variables_and_powers: &[(usize, usize)], features: &[f64]) -> f64 {
+    variables_and_powers.iter().map(|(variable, power)| features[*variable].powi(*power as i32)).product()
+}
+
+/// Fit residuals of a single model. Residuals are differences between measured gas
+/// (running time multiplied by the gas per microsecond rate) and the gas that
+/// is metered using calibrated schedule for the same inputs
+#[derive(Clone, Debug, Serialize)]
+pub struct ModelFitReport {
+    pub model: String,
+    pub samples: usize,
+    pub rejected_samples: usize,
+    pub r_squared: f64,
+    pub rms_residual: f64,
+    pub max_relative_residual: f64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CalibrationReport {
+    pub gas_per_microsecond: u64,
+    pub models: Vec<ModelFitReport>,
+}
+
+impl std::fmt::Display for CalibrationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Calibrated for {} gas per microsecond", self.gas_per_microsecond)?;
+        writeln!(f, "{:<30} {:>8} {:>9} {:>8} {:>14} {:>14}", "model", "samples", "rejected", "R^2", "RMS residual", "max rel. res.")?;
+        for report in self.models.iter() {
+            writeln!(
+                f,
+                "{:<30} {:>8} {:>9} {:>8.4} {:>14.1} {:>14.4}",
+                report.model,
+                report.samples,
+                report.rejected_samples,
+                report.r_squared,
+                report.rms_residual,
+                report.max_relative_residual
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Measured inputs of a single model together with measured gas
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Measurements {
+    pub(crate) samples: Vec<(Vec<u8>, f64)>,
+    pub(crate) rejected: usize,
+}
+
+impl Measurements {
+    pub(crate) fn report(&self, model: &str, meter: &GasMeter) -> ModelFitReport {
+        let mut residuals = Vec::with_capacity(self.samples.len());
+        let mut max_relative_residual = 0f64;
+        for (input, measured) in self.samples.iter() {
+            let metered = meter.meter_input(input).map(|gas| gas as f64).unwrap_or(0f64);
+            let residual = measured - metered;
+            if *measured > 0f64 {
+                max_relative_residual = f64::max(max_relative_residual, residual.abs() / measured);
+            }
+            residuals.push(residual);
+        }
+
+        let num_samples = self.samples.len() as f64;
+        let mean = self.samples.iter().map(|(_, measured)| measured).sum::<f64>() / num_samples;
+        let total_sum_of_squares = self.samples.iter().map(|(_, measured)| (measured - mean) * (measured - mean)).sum::<f64>();
+        let residual_sum_of_squares = residuals.iter().map(|r| r * r).sum::<f64>();
+        let r_squared = if total_sum_of_squares > 0f64 {
+            1f64 - residual_sum_of_squares / total_sum_of_squares
+        } else if residual_sum_of_squares == 0f64 {
+            1f64
+        } else {
+            0f64
+        };
+
+        ModelFitReport {
+            model: model.to_owned(),
+            samples: self.samples.len(),
+            rejected_samples: self.rejected,
+            r_squared,
+            rms_residual: (residual_sum_of_squares / num_samples).sqrt(),
+            max_relative_residual,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_non_negative_fit() {
+        // y = 3 + 2 * x + 0.5 * x^2
+        let rows: Vec<Vec<f64>> = (1..20).map(|x| vec![1f64, x as f64, (x * x) as f64]).collect();
+        let targets: Vec<f64> = (1..20).map(|x| 3f64 + 2f64 * x as f64 + 0.5 * (x * x) as f64).collect();
+        let coefficients = fit_non_negative(&rows, &targets);
+        for (c, expected) in coefficients.iter().zip([3f64, 2f64, 0.5].iter()) {
+            assert!((c - expected).abs() < 1e-6, "{:?}", coefficients);
+        }
+
+        // y = 10 * x - 5 can not have negative intercept
+        let rows: Vec<Vec<f64>> = (1..20).map(|x| vec![1f64, x as f64]).collect();
+        let targets: Vec<f64> = (1..20).map(|x| 10f64 * x as f64 - 5f64).collect();
+        let coefficients = fit_non_negative(&rows, &targets);
+        assert_eq!(coefficients[0], 0f64);
+        assert!(coefficients[1] > 9f64 && coefficients[1] < 10f64);
+
+        // rank deficient system is still solved
+        let rows = vec![vec![1f64, 2f64], vec![2f64, 4f64]];
+        let coefficients = fit_non_negative(&rows, &[5f64, 10f64]);
+        assert!((coefficients[0] + 2f64 * coefficients[1] - 5f64).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,33 @@
+use std::time::Instant;
+
+use crate::public_interface::API;
+
+/// Median running time of an operation in microseconds over `repetitions` runs,
+/// or `None` if the operation is rejected
+pub(crate) fn time_operation(input: &[u8], repetitions: usize) -> Option<f64> {
+    debug_assert!(repetitions > 0);
+    // warm up caches and check that operation is accepted at all
+    API::run(input).ok()?;
+
+    let mut timings = Vec::with_capacity(repetitions);
+    for _ in 0..repetitions {
+        let now = Instant::now();
+        let result = API::run(input);
+        let elapsed = now.elapsed();
+        result.ok()?;
+        timings.push(elapsed.as_nanos() as f64 / 1000f64);
+    }
+
+    Some(median(&mut timings))
+}
+
+pub(crate) fn median(values: &mut [f64]) -> f64 {
+    debug_assert!(!values.is_empty());
+    values.sort_by(|a, b| a.partial_cmp(b).expect("timings are finite"));
+    let middle = values.len() / 2;
+    if values.len() % 2 == 1 {
+        values[middle]
+    } else {
+        (values[middle - 1] + values[middle]) / 2f64
+    }
+}
@@ -0,0 +1,630 @@
+//! Calibration of the gas schedule. A population of pseudo-curves of every supported size
+//! is generated from a seed, operations are timed on the local machine, running times are
+//! converted to gas at a fixed rate and models of the same structure as the shipped ones
+//! are fitted to these measurements. Result is a `GasSchedule` that can be written as
+//! model files in the format that `gas_meter` loads, together with a residuals report.
+//!
+//! Pseudo-curves are only accepted by the library built with `gas_metering_mode` feature,
+//! that skips on-curve, subgroup and non-residuosity checks.
+
+mod population;
+mod measure;
+mod fit;
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::errors::ApiError;
+use crate::gas_meter::{GasMeter, GasModel, GasSchedule};
+use crate::public_interface::constants::*;
+
+use self::fit::*;
+use self::population::*;
+
+pub use self::fit::{CalibrationReport, ModelFitReport};
+
+/// Rate that was used for the shipped schedule, 30 MGas per second
+pub const DEFAULT_GAS_PER_MICROSECOND: u64 = 30;
+
+/// Name of the residuals report written next to the model files
+pub const CALIBRATION_REPORT_FILE_NAME: &str = "calibration_report.json";
+
+/// Numbers of pairs at which multiexp is measured, discounts in between are interpolated
+const MULTIEXP_MEASURED_PAIRS: [usize; 14] = [1, 2, 3, 4, 6, 8, 12, 16, 24, 32, 48, 64, 96, 128];
+
+/// Numbers of pairs at which every pairing curve is measured to separate the
+/// per pair (Miller loop) and one-off (final exponentiation) costs
+const PAIRING_MEASURED_PAIRS: [usize; 3] = [2, 4, 6];
+
+#[derive(Clone, Debug)]
+pub struct CalibrationConfig {
+    /// Models to calibrate, other models of the result are taken from `base_schedule`
+    pub models: Vec<GasModel>,
+    /// Random curves measured per number of modulus limbs (and per number of
+    /// group order limbs for multiplication models)
+    pub samples: usize,
+    /// Runs of every input, the median running time is used
+    pub repetitions: usize,
+    pub seed: u64,
+    pub gas_per_microsecond: u64,
+    /// Provides the terms of pairing models, the multiexp table size and multiplier,
+    /// and the models that are not calibrated
+    pub base_schedule: GasSchedule,
+}
+
+impl Default for CalibrationConfig {
+    fn default() -> Self {
+        Self {
+            models: GasModel::ALL.to_vec(),
+            samples: 3,
+            repetitions: 3,
+            seed: 42,
+            gas_per_microsecond: DEFAULT_GAS_PER_MICROSECOND,
+            base_schedule: GasSchedule::default(),
+        }
+    }
+}
+
+impl CalibrationConfig {
+    fn validate(&self) -> Result<(), ApiError> {
+        if self.models.is_empty() {
+            return Err(ApiError::InputError("No models to calibrate".to_owned()));
+        }
+        if self.samples == 0 || self.repetitions == 0 {
+            return Err(ApiError::InputError("Calibration requires at least one sample and one repetition".to_owned()));
+        }
+        if self.gas_per_microsecond == 0 {
+            return Err(ApiError::InputError("Gas per microsecond rate must be positive".to_owned()));
+        }
+
+        self.base_schedule.validate()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Calibration {
+    pub schedule: GasSchedule,
+    pub report: CalibrationReport,
+}
+
+impl Calibration {
+    /// Writes all the model files of the calibrated schedule and the residuals report
+    pub fn write_to_directory<P: AsRef<Path>>(&self, path: P) -> Result<(), ApiError> {
+        self.schedule.write_to_directory(&path)?;
+        let report = serde_json::to_string_pretty(&self.report).map_err(|e| {
+            ApiError::InputError(format!("Failed to serialize calibration report: {}", e))
+        })?;
+        let report_path = path.as_ref().join(CALIBRATION_REPORT_FILE_NAME);
+        std::fs::write(&report_path, report).map_err(|e| {
+            ApiError::InputError(format!("Failed to write {}: {}", report_path.display(), e))
+        })
+    }
+}
+
+/// Calibrates the requested models by timing operations on this machine
+pub fn calibrate(config: &CalibrationConfig) -> Result<Calibration, ApiError> {
+    if !crate::features::in_gas_metering() {
+        return Err(ApiError::InputError("Calibration runs on pseudo-curves and requires the library built with `gas_metering_mode` feature".to_owned()));
+    }
+    let repetitions = config.repetitions;
+
+    calibrate_with_timer(config, |input| measure::time_operation(input, repetitions))
+}
+
+/// Calibrates the requested models using `timer` that returns running time of an
+/// input in microseconds or `None` if input is rejected
+pub(crate) fn calibrate_with_timer<F: FnMut(&[u8]) -> Option<f64>>(config: &CalibrationConfig, mut timer: F) -> Result<Calibration, ApiError> {
+    config.validate()?;
+
+    let mut rng = XorShiftRng::new(config.seed);
+    let mut calibrator = Calibrator {
+        rng: &mut rng,
+        timer: &mut timer,
+        gas_per_microsecond: config.gas_per_microsecond as f64,
+        samples: config.samples,
+    };
+
+    let mut schedule = config.base_schedule.clone();
+    let mut measurements = vec![];
+    for model in config.models.iter() {
+        let model_measurements = match model {
+            GasModel::G1Addition => calibrator.addition(1, &mut schedule.g1_addition.lookup_parameters)?,
+            GasModel::G2Ext2Addition => calibrator.addition(2, &mut schedule.g2_ext_2_addition.lookup_parameters)?,
+            GasModel::G2Ext3Addition => calibrator.addition(3, &mut schedule.g2_ext_3_addition.lookup_parameters)?,
+            GasModel::G1Multiplication => {
+                let params = &mut schedule.g1_multiplication;
+                calibrator.multiplication(1, &mut params.base, &mut params.per_limb)?
+            },
+            GasModel::G2Ext2Multiplication => {
+                let params = &mut schedule.g2_ext_2_multiplication;
+                calibrator.multiplication(2, &mut params.base, &mut params.per_limb)?
+            },
+            GasModel::G2Ext3Multiplication => {
+                let params = &mut schedule.g2_ext_3_multiplication;
+                calibrator.multiplication(3, &mut params.base, &mut params.per_limb)?
+            },
+            GasModel::MultiexpDiscounts => {
+                let params = &mut schedule.multiexp_discounts;
+                let (discounts, max_discount, model_measurements) = calibrator.multiexp_discounts(params.max_pairs, params.discount_multiplier)?;
+                params.discounts = discounts;
+                params.max_discount = max_discount;
+
+                model_measurements
+            },
+            GasModel::Mnt4Pairing => {
+                let params = &mut schedule.mnt4_pairing;
+                calibrator.pairing(
+                    PairingCurve::random_mnt4,
+                    params.multiplier,
+                    &mut params.one_off,
+                    &mut params.miller,
+                    &mut params.final_exp
+                )?
+            },
+            GasModel::Mnt6Pairing => {
+                let params = &mut schedule.mnt6_pairing;
+                calibrator.pairing(
+                    PairingCurve::random_mnt6,
+                    params.multiplier,
+                    &mut params.one_off,
+                    &mut params.miller,
+                    &mut params.final_exp
+                )?
+            },
+            GasModel::Bls12Pairing => {
+                let params = &mut schedule.bls12_pairing;
+                let mut model_measurements = calibrator.pairing(
+                    PairingCurve::random_bls12,
+                    params.multiplier,
+                    &mut params.one_off,
+                    &mut params.miller,
+                    &mut params.final_exp
+                )?;
+                params.family_one_off = calibrator.family_one_off(
+                    PairingCurve::family_bls12,
+                    params.multiplier,
+                    &params.one_off,
+                    &mut model_measurements
+                );
+
+                model_measurements
+            },
+            GasModel::BnPairing => {
+                let params = &mut schedule.bn_pairing;
+                let mut model_measurements = calibrator.pairing(
+                    PairingCurve::random_bn,
+                    params.multiplier,
+                    &mut params.one_off,
+                    &mut params.miller,
+                    &mut params.final_exp
+                )?;
+                params.family_one_off = calibrator.family_one_off(
+                    PairingCurve::family_bn,
+                    params.multiplier,
+                    &params.one_off,
+                    &mut model_measurements
+                );
+
+                model_measurements
+            },
+            GasModel::Bw6Pairing => {
+                let params = &mut schedule.bw6_pairing;
+                calibrator.pairing(
+                    PairingCurve::random_bw6,
+                    params.multiplier,
+                    &mut params.one_off,
+                    &mut params.miller,
+                    &mut params.final_exp
+                )?
+            },
+            GasModel::Bls24Pairing => {
+                let params = &mut schedule.bls24_pairing;
+                calibrator.pairing(
+                    PairingCurve::random_bls24,
+                    params.multiplier,
+                    &mut params.one_off,
+                    &mut params.miller,
+                    &mut params.final_exp
+                )?
+            },
+        };
+        measurements.push((*model, model_measurements));
+    }
+
+    let meter = GasMeter::new(schedule)?;
+    let models = measurements.iter().map(|(model, m)| m.report(model.file_name(), &meter)).collect();
+    let report = CalibrationReport {
+        gas_per_microsecond: config.gas_per_microsecond,
+        models,
+    };
+
+    Ok(Calibration {
+        schedule: meter.schedule().clone(),
+        report,
+    })
+}
+
+struct Calibrator<'a, F: FnMut(&[u8]) -> Option<f64>> {
+    rng: &'a mut XorShiftRng,
+    timer: &'a mut F,
+    gas_per_microsecond: f64,
+    samples: usize,
+}
+
+fn no_samples_error(model: &str, limbs: usize) -> ApiError {
+    ApiError::InputError(format!("All {} samples for {} modulus limbs were rejected", model, limbs))
+}
+
+impl<'a, F: FnMut(&[u8]) -> Option<f64>> Calibrator<'a, F> {
+    /// Measures gas for the input and records it, or counts the input as rejected
+    fn measure(&mut self, input: Vec<u8>, measurements: &mut Measurements) -> Option<f64> {
+        match (self.timer)(&input) {
+            Some(microseconds) => {
+                let gas = microseconds * self.gas_per_microsecond;
+                measurements.samples.push((input, gas));
+
+                Some(gas)
+            },
+            None => {
+                measurements.rejected += 1;
+
+                None
+            }
+        }
+    }
+
+    /// Addition price is a median measured gas over random curves of the given size
+    fn addition(&mut self, extension_degree: usize, lookup: &mut HashMap<usize, u64>) -> Result<Measurements, ApiError> {
+        let mut measurements = Measurements::default();
+        for limbs in NUM_LIMBS_MIN..=NUM_LIMBS_MAX {
+            let mut gas = vec![];
+            for _ in 0..self.samples {
+                let group_limbs = self.rng.range(NUM_GROUP_LIMBS_MIN, NUM_GROUP_LIMBS_MAX);
+                let curve = ArithmeticCurve::random(self.rng, limbs, group_limbs, extension_degree);
+                let input = curve.addition_input(self.rng);
+                gas.extend(self.measure(input, &mut measurements));
+            }
+            if gas.is_empty() {
+                return Err(no_samples_error("addition", limbs));
+            }
+            lookup.insert(limbs, measure::median(&mut gas).ceil() as u64);
+        }
+
+        Ok(measurements)
+    }
+
+    /// Multiplication is priced for the worst case scalar as `base + per_limb * group_limbs`
+    fn multiplication(
+        &mut self,
+        extension_degree: usize,
+        base: &mut HashMap<usize, u64>,
+        per_limb: &mut HashMap<usize, u64>
+    ) -> Result<Measurements, ApiError> {
+        let mut measurements = Measurements::default();
+        for limbs in NUM_LIMBS_MIN..=NUM_LIMBS_MAX {
+            let mut rows = vec![];
+            let mut targets = vec![];
+            for group_limbs in NUM_GROUP_LIMBS_MIN..=NUM_GROUP_LIMBS_MAX {
+                for _ in 0..self.samples {
+                    let curve = ArithmeticCurve::worst_case(self.rng, limbs, group_limbs, extension_degree);
+                    let input = curve.worst_case_multiplication_input(self.rng);
+                    if let Some(gas) = self.measure(input, &mut measurements) {
+                        rows.push(vec![1f64, group_limbs as f64]);
+                        targets.push(gas);
+                    }
+                }
+            }
+            if rows.is_empty() {
+                return Err(no_samples_error("multiplication", limbs));
+            }
+            let coefficients = fit_non_negative(&rows, &targets);
+            base.insert(limbs, coefficients[0].round() as u64);
+            per_limb.insert(limbs, coefficients[1].ceil() as u64);
+        }
+
+        Ok(measurements)
+    }
+
+    /// Discount for `k` pairs is a ratio of multiexp running time to `k` multiplications
+    /// with random scalars on the same curve. Discounts are medians over all curves,
+    /// linearly interpolated between measured numbers of pairs and made non-increasing
+    fn multiexp_discounts(&mut self, max_pairs: usize, discount_multiplier: u64) -> Result<(HashMap<usize, u64>, u64, Measurements), ApiError> {
+        let measured_pairs: Vec<usize> = MULTIEXP_MEASURED_PAIRS.iter().copied().filter(|k| *k < max_pairs).chain(std::iter::once(max_pairs)).collect();
+        let mut measurements = Measurements::default();
+        let mut ratios: Vec<Vec<f64>> = vec![vec![]; measured_pairs.len()];
+        for limbs in NUM_LIMBS_MIN..=NUM_LIMBS_MAX {
+            for _ in 0..self.samples {
+                let group_limbs = self.rng.range(NUM_GROUP_LIMBS_MIN, NUM_GROUP_LIMBS_MAX);
+                let curve = ArithmeticCurve::random(self.rng, limbs, group_limbs, 1);
+                // single multiplication is measured separately, it is not a part of the residuals
+                let multiplication = match (self.timer)(&curve.multiplication_input(self.rng)) {
+                    Some(microseconds) => microseconds,
+                    None => {
+                        measurements.rejected += 1;
+                        continue;
+                    }
+                };
+                for (ratios, num_pairs) in ratios.iter_mut().zip(measured_pairs.iter()) {
+                    let input = curve.multiexp_input(self.rng, *num_pairs);
+                    if let Some(gas) = self.measure(input, &mut measurements) {
+                        let microseconds = gas / self.gas_per_microsecond;
+                        ratios.push(microseconds / (multiplication * (*num_pairs as f64)));
+                    }
+                }
+            }
+        }
+
+        let mut measured_discounts = vec![];
+        for (ratios, num_pairs) in ratios.iter_mut().zip(measured_pairs.iter()) {
+            if ratios.is_empty() {
+                return Err(ApiError::InputError(format!("All multiexp samples for {} pairs were rejected", num_pairs)));
+            }
+            measured_discounts.push(measure::median(ratios) * discount_multiplier as f64);
+        }
+
+        let mut discounts = HashMap::new();
+        let mut previous = u64::MAX;
+        for num_pairs in 1..=max_pairs {
+            let upper = measured_pairs.iter().position(|k| *k >= num_pairs).expect("max pairs is always measured");
+            let discount = if upper == 0 || measured_pairs[upper] == num_pairs {
+                measured_discounts[upper]
+            } else {
+                let (k0, k1) = (measured_pairs[upper - 1] as f64, measured_pairs[upper] as f64);
+                let (d0, d1) = (measured_discounts[upper - 1], measured_discounts[upper]);
+                d0 + (d1 - d0) * (num_pairs as f64 - k0) / (k1 - k0)
+            };
+            let discount = u64::min(discount.ceil() as u64, previous).max(1);
+            discounts.insert(num_pairs, discount);
+            previous = discount;
+        }
+        let max_discount = discounts[&max_pairs];
+
+        Ok((discounts, max_discount, measurements))
+    }
+
+    /// Every curve is measured for a few numbers of pairs, slope of the running time is
+    /// a Miller loop cost and intercept is a final exponentiation and one-off cost. Miller
+    /// loop and final exponentiation polynomials keep the terms of the base schedule,
+    /// one-off costs are fitted per number of modulus limbs. Final exponentiation terms
+    /// that only depend on modulus limbs can not be distinguished from one-off costs,
+    /// so they are absorbed by the one-off table and get zero coefficients
+    fn pairing(
+        &mut self,
+        random_curve: fn(&mut XorShiftRng, usize) -> PairingCurve,
+        multiplier: u64,
+        one_off: &mut HashMap<usize, u64>,
+        miller: &mut [(u64, Vec<(usize, usize)>)],
+        final_exp: &mut [(u64, Vec<(usize, usize)>)]
+    ) -> Result<Measurements, ApiError> {
+        let mut measurements = Measurements::default();
+        let mut miller_rows = vec![];
+        let mut miller_targets = vec![];
+        let mut final_exp_rows = vec![];
+        let mut final_exp_targets = vec![];
+        let num_one_off_columns = NUM_LIMBS_MAX - NUM_LIMBS_MIN + 1;
+        // modulus limbs is always the last feature
+        let limbs_variable = final_exp.iter().flat_map(|(_, term)| term.iter().map(|(variable, _)| *variable)).max().unwrap_or(0);
+        let fitted_final_exp_terms: Vec<usize> = (0..final_exp.len()).filter(|i| {
+            final_exp[*i].1.iter().any(|(variable, _)| *variable != limbs_variable)
+        }).collect();
+        for limbs in NUM_LIMBS_MIN..=NUM_LIMBS_MAX {
+            let mut accepted = 0;
+            for _ in 0..self.samples {
+                let curve = random_curve(self.rng, limbs);
+                let mut points = vec![];
+                for num_pairs in PAIRING_MEASURED_PAIRS.iter() {
+                    let input = curve.pairing_input(self.rng, *num_pairs);
+                    if let Some(gas) = self.measure(input, &mut measurements) {
+                        points.push((*num_pairs as f64, gas));
+                    }
+                }
+                if points.len() != PAIRING_MEASURED_PAIRS.len() {
+                    continue;
+                }
+                accepted += 1;
+
+                let (slope, intercept) = fit_line(&points);
+                let limbs = limbs as f64;
+
+                let features: Vec<f64> = curve.miller_features.iter().copied().chain(std::iter::once(limbs)).collect();
+                miller_rows.push(miller.iter().map(|(_, term)| monomial(term, &features)).collect());
+                miller_targets.push(slope * multiplier as f64);
+
+                let features: Vec<f64> = curve.final_exp_features.iter().copied().chain(std::iter::once(limbs)).collect();
+                let mut row = vec![0f64; num_one_off_columns];
+                row[curve.limbs - NUM_LIMBS_MIN] = 1f64;
+                row.extend(fitted_final_exp_terms.iter().map(|i| monomial(&final_exp[*i].1, &features)));
+                final_exp_rows.push(row);
+                final_exp_targets.push(intercept * multiplier as f64);
+            }
+            if accepted == 0 {
+                return Err(no_samples_error("pairing", limbs));
+            }
+        }
+
+        let coefficients = fit_non_negative(&miller_rows, &miller_targets);
+        for ((coeff, _), fitted) in miller.iter_mut().zip(coefficients.iter()) {
+            *coeff = fitted.round() as u64;
+        }
+
+        let coefficients = fit_non_negative(&final_exp_rows, &final_exp_targets);
+        for (limbs, fitted) in (NUM_LIMBS_MIN..=NUM_LIMBS_MAX).zip(coefficients.iter()) {
+            one_off.insert(limbs, fitted.round() as u64);
+        }
+        for (coeff, _) in final_exp.iter_mut() {
+            *coeff = 0;
+        }
+        for (i, fitted) in fitted_final_exp_terms.iter().zip(coefficients[num_one_off_columns..].iter()) {
+            final_exp[*i].0 = fitted.round() as u64;
+        }
+
+        Ok(measurements)
+    }
+
+    /// Curves with the modulus given by the family polynomial have a shorter final exponentiation
+    /// chain. Every such curve is measured together with a curve of the same size and loop
+    /// parameter and a random modulus, so Miller loops of both cost the same and a mean
+    /// difference over the numbers of pairs is the saving over the generic one-off cost.
+    /// Sizes that family moduli can not have are skipped and use the generic cost
+    fn family_one_off(
+        &mut self,
+        family_curves: fn(&mut XorShiftRng, usize) -> Option<(PairingCurve, PairingCurve)>,
+        multiplier: u64,
+        one_off: &HashMap<usize, u64>,
+        measurements: &mut Measurements
+    ) -> HashMap<usize, u64> {
+        let mut family_one_off = HashMap::new();
+        for limbs in NUM_LIMBS_MIN..=NUM_LIMBS_MAX {
+            let mut savings = vec![];
+            for _ in 0..self.samples {
+                let (family_curve, generic_curve) = match family_curves(self.rng, limbs) {
+                    Some(curves) => curves,
+                    None => break,
+                };
+                let mut differences = vec![];
+                for num_pairs in PAIRING_MEASURED_PAIRS.iter() {
+                    let family_input = family_curve.pairing_input(self.rng, *num_pairs);
+                    let generic_input = generic_curve.pairing_input(self.rng, *num_pairs);
+                    let family_gas = self.measure(family_input, measurements);
+                    let generic_gas = self.measure(generic_input, measurements);
+                    if let (Some(family_gas), Some(generic_gas)) = (family_gas, generic_gas) {
+                        differences.push(generic_gas - family_gas);
+                    }
+                }
+                if differences.len() == PAIRING_MEASURED_PAIRS.len() {
+                    savings.push(differences.iter().sum::<f64>() / differences.len() as f64 * multiplier as f64);
+                }
+            }
+            if savings.is_empty() {
+                continue;
+            }
+            let generic_cost = one_off[&limbs];
+            let saving = measure::median(&mut savings).max(0f64).floor() as u64;
+            family_one_off.insert(limbs, generic_cost.saturating_sub(saving));
+        }
+
+        family_one_off
+    }
+}
+
+/// Ordinary least squares line `y = slope * x + intercept`
+fn fit_line(points: &[(f64, f64)]) -> (f64, f64) {
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let covariance = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum::<f64>();
+    let variance = points.iter().map(|(x, _)| (x - mean_x) * (x - mean_x)).sum::<f64>();
+    let slope = covariance / variance;
+
+    (slope, mean_y - slope * mean_x)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn oracle_timer(meter: &GasMeter, gas_per_microsecond: u64) -> impl FnMut(&[u8]) -> Option<f64> + '_ {
+        move |input| meter.meter_input(input).ok().map(|gas| gas as f64 / gas_per_microsecond as f64)
+    }
+
+    fn assert_close(calibrated: u64, expected: u64, tolerance: f64, what: &str) {
+        let difference = (calibrated as f64 - expected as f64).abs();
+        assert!(difference <= expected as f64 * tolerance + 2f64, "{}: calibrated {}, expected {}", what, calibrated, expected);
+    }
+
+    #[test]
+    fn test_loop_parameter_generation() {
+        let mut rng = XorShiftRng::new(1);
+        for (bits, hamming) in [(1usize, 1usize), (64, 1), (64, 64), (128, 17), (255, 100)].iter() {
+            let x = random_loop_parameter(&mut rng, *bits, *hamming);
+            assert_eq!(x.bits(), *bits);
+            assert_eq!(crate::pairings::calculate_hamming_weight(x.as_ref()) as usize, *hamming);
+        }
+    }
+
+    #[test]
+    fn test_calibration_recovers_schedule() {
+        let default_meter = GasMeter::default();
+        let config = CalibrationConfig {
+            samples: 2,
+            ..CalibrationConfig::default()
+        };
+        let calibration = calibrate_with_timer(&config, oracle_timer(&default_meter, config.gas_per_microsecond)).unwrap();
+        println!("{}", calibration.report);
+
+        assert_eq!(calibration.report.models.len(), GasModel::ALL.len());
+        for report in calibration.report.models.iter() {
+            assert_eq!(report.rejected_samples, 0, "{}", report.model);
+            assert!(report.r_squared > 0.99, "{}: R^2 = {}", report.model, report.r_squared);
+            assert!(report.max_relative_residual < 0.05, "{}: max relative residual = {}", report.model, report.max_relative_residual);
+        }
+
+        // fresh population is metered almost the same as by the original schedule
+        let calibrated_meter = GasMeter::new(calibration.schedule.clone()).unwrap();
+        let mut rng = XorShiftRng::new(7);
+        for limbs in NUM_LIMBS_MIN..=NUM_LIMBS_MAX {
+            let group_limbs = rng.range(NUM_GROUP_LIMBS_MIN, NUM_GROUP_LIMBS_MAX);
+            let curve = ArithmeticCurve::random(&mut rng, limbs, group_limbs, 2);
+            let num_pairs = rng.range(1, 10);
+            let inputs = [
+                curve.addition_input(&mut rng),
+                curve.multiplication_input(&mut rng),
+                curve.multiexp_input(&mut rng, num_pairs),
+                PairingCurve::random_bls12(&mut rng, limbs).pairing_input(&mut rng, 3),
+                PairingCurve::random_bn(&mut rng, limbs).pairing_input(&mut rng, 3),
+                PairingCurve::random_mnt4(&mut rng, limbs).pairing_input(&mut rng, 2),
+                PairingCurve::random_mnt6(&mut rng, limbs).pairing_input(&mut rng, 2),
+                PairingCurve::random_bw6(&mut rng, limbs).pairing_input(&mut rng, 2),
+                PairingCurve::random_bls24(&mut rng, limbs).pairing_input(&mut rng, 2),
+            ];
+            let family_inputs = [
+                PairingCurve::family_bls12(&mut rng, limbs).map(|(curve, _)| curve.pairing_input(&mut rng, 3)),
+                PairingCurve::family_bn(&mut rng, limbs).map(|(curve, _)| curve.pairing_input(&mut rng, 3)),
+            ];
+            for input in inputs.iter().chain(family_inputs.iter().flatten()) {
+                let expected = default_meter.meter_input(input).unwrap();
+                let calibrated = calibrated_meter.meter_input(input).unwrap();
+                assert_close(calibrated, expected, 0.02, &format!("operation {} for {} limbs", input[0], limbs));
+            }
+        }
+    }
+
+    #[test]
+    fn test_calibrated_models_are_loadable() {
+        let default_meter = GasMeter::default();
+        let config = CalibrationConfig {
+            models: vec![GasModel::G1Addition, GasModel::MultiexpDiscounts],
+            samples: 1,
+            ..CalibrationConfig::default()
+        };
+        let calibration = calibrate_with_timer(&config, oracle_timer(&default_meter, config.gas_per_microsecond)).unwrap();
+        assert_eq!(calibration.report.models.len(), 2);
+
+        for model in GasModel::ALL.iter() {
+            let json = calibration.schedule.model_json(*model);
+            let schedule = GasSchedule::default().with_model_json(*model, &json).unwrap();
+            schedule.validate().unwrap();
+            assert_eq!(schedule.model_json(*model), json);
+        }
+
+        let discounts = &calibration.schedule.multiexp_discounts;
+        for num_pairs in 2..=discounts.max_pairs {
+            assert!(discounts.discounts[&num_pairs] <= discounts.discounts[&(num_pairs - 1)]);
+        }
+    }
+
+    #[test]
+    fn test_calibration_rejects_invalid_config() {
+        let config = CalibrationConfig {
+            samples: 0,
+            ..CalibrationConfig::default()
+        };
+        assert!(calibrate_with_timer(&config, |_| Some(1f64)).is_err());
+
+        let config = CalibrationConfig {
+            models: vec![GasModel::G1Addition],
+            ..CalibrationConfig::default()
+        };
+        assert!(calibrate_with_timer(&config, |_| None).is_err());
+    }
+}
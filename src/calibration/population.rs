@@ -0,0 +1,603 @@
+//! Input population for calibration. Curves are "pseudo-curves": random odd moduli of
+//! the required number of limbs with random coefficients and points. Such inputs are only
+//! accepted when the library is built in `gas_metering_mode`, but they exercise exactly
+//! the same arithmetic as real curves of the same size, and metering only looks at sizes.
+
+use crate::public_interface::constants::*;
+use crate::public_interface::sane_limits::*;
+use crate::integers::{MaxFieldUint, MaxGroupSizeUint, MaxLoopParametersUint};
+use crate::pairings::{TwistType, calculate_hamming_weight};
+use crate::serialization::instances::*;
+
+/// Small deterministic xorshift generator, so population only depends on a seed
+#[derive(Clone, Debug)]
+pub(crate) struct XorShiftRng(u64);
+
+impl XorShiftRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        // state must never be zero
+        Self(seed ^ 0x9e37_79b9_7f4a_7c15 | 1)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+
+        x
+    }
+
+    /// Uniform value in `[low, high]`
+    pub(crate) fn range(&mut self, low: usize, high: usize) -> usize {
+        debug_assert!(low <= high);
+        low + (self.next_u64() % ((high - low + 1) as u64)) as usize
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next_u64() as u8).collect()
+    }
+
+    pub(crate) fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+}
+
+/// Big-endian modulus of exactly `limbs` limbs as metering counts them (`bits / 64 + 1`)
+fn random_modulus(rng: &mut XorShiftRng, limbs: usize) -> Vec<u8> {
+    let mut modulus = rng.bytes(limbs * 8);
+    modulus[0] = (modulus[0] & 0x3f) | 0x40;
+    let last = modulus.len() - 1;
+    modulus[last] |= 1;
+
+    modulus
+}
+
+/// Random element that is strictly smaller than the modulus
+fn random_field_element(rng: &mut XorShiftRng, modulus: &[u8]) -> Vec<u8> {
+    let mut element = rng.bytes(modulus.len());
+    element[0] %= modulus[0];
+
+    element
+}
+
+fn random_group_order(rng: &mut XorShiftRng, group_limbs: usize) -> Vec<u8> {
+    let mut order = rng.bytes(group_limbs * 8);
+    order[0] |= 0x80;
+    let last = order.len() - 1;
+    order[last] |= 1;
+
+    order
+}
+
+/// Curve for addition, multiplication and multiexp, encoded up to the operation specific data
+#[derive(Clone, Debug)]
+pub(crate) struct ArithmeticCurve {
+    encoding: Vec<u8>,
+    modulus: Vec<u8>,
+    extension_degree: usize,
+    pub(crate) order: Vec<u8>,
+}
+
+impl ArithmeticCurve {
+    /// `extension_degree` is 1 for G1, 2 or 3 for G2
+    pub(crate) fn random(rng: &mut XorShiftRng, limbs: usize, group_limbs: usize, extension_degree: usize) -> Self {
+        let order = random_group_order(rng, group_limbs);
+        Self::with_order(rng, limbs, order, extension_degree)
+    }
+
+    /// Curve with the group order of `group_limbs` limbs with all bits set,
+    /// that is the worst case for multiplication by a scalar below the order
+    pub(crate) fn worst_case(rng: &mut XorShiftRng, limbs: usize, group_limbs: usize, extension_degree: usize) -> Self {
+        Self::with_order(rng, limbs, vec![0xffu8; group_limbs * 8], extension_degree)
+    }
+
+    fn with_order(rng: &mut XorShiftRng, limbs: usize, order: Vec<u8>, extension_degree: usize) -> Self {
+        debug_assert!((1..=3).contains(&extension_degree));
+        let modulus = random_modulus(rng, limbs);
+
+        let mut encoding = vec![modulus.len() as u8];
+        encoding.extend_from_slice(&modulus);
+        if extension_degree > 1 {
+            encoding.push(extension_degree as u8);
+            encoding.extend(random_field_element(rng, &modulus));
+        }
+        for _ in 0..(2 * extension_degree) {
+            encoding.extend(random_field_element(rng, &modulus));
+        }
+        encoding.push(order.len() as u8);
+        encoding.extend_from_slice(&order);
+
+        Self {
+            encoding,
+            modulus,
+            extension_degree,
+            order,
+        }
+    }
+
+    fn operation(&self, g1_operation: u8, g2_operation: u8) -> u8 {
+        if self.extension_degree == 1 {
+            g1_operation
+        } else {
+            g2_operation
+        }
+    }
+
+    fn random_point(&self, rng: &mut XorShiftRng) -> Vec<u8> {
+        let mut point = vec![];
+        for _ in 0..(2 * self.extension_degree) {
+            point.extend(random_field_element(rng, &self.modulus));
+        }
+
+        point
+    }
+
+    fn random_scalar(&self, rng: &mut XorShiftRng) -> Vec<u8> {
+        let mut scalar = rng.bytes(self.order.len());
+        scalar[0] %= self.order[0];
+
+        scalar
+    }
+
+    /// Scalar `order - 1`, that has the maximal bit length and almost maximal hamming weight
+    fn worst_case_scalar(&self) -> Vec<u8> {
+        let mut scalar = self.order.clone();
+        let last = scalar.len() - 1;
+        scalar[last] &= !1u8;
+
+        scalar
+    }
+
+    pub(crate) fn addition_input(&self, rng: &mut XorShiftRng) -> Vec<u8> {
+        let mut input = vec![self.operation(OPERATION_G1_ADD, OPERATION_G2_ADD)];
+        input.extend_from_slice(&self.encoding);
+        input.extend(self.random_point(rng));
+        input.extend(self.random_point(rng));
+
+        input
+    }
+
+    pub(crate) fn worst_case_multiplication_input(&self, rng: &mut XorShiftRng) -> Vec<u8> {
+        let mut input = vec![self.operation(OPERATION_G1_MUL, OPERATION_G2_MUL)];
+        input.extend_from_slice(&self.encoding);
+        input.extend(self.random_point(rng));
+        input.extend(self.worst_case_scalar());
+
+        input
+    }
+
+    pub(crate) fn multiplication_input(&self, rng: &mut XorShiftRng) -> Vec<u8> {
+        let mut input = vec![self.operation(OPERATION_G1_MUL, OPERATION_G2_MUL)];
+        input.extend_from_slice(&self.encoding);
+        input.extend(self.random_point(rng));
+        input.extend(self.random_scalar(rng));
+
+        input
+    }
+
+    pub(crate) fn multiexp_input(&self, rng: &mut XorShiftRng, num_pairs: usize) -> Vec<u8> {
+        debug_assert!(num_pairs > 0 && num_pairs <= 255);
+        let mut input = vec![self.operation(OPERATION_G1_MULTIEXP, OPERATION_G2_MULTIEXP)];
+        input.extend_from_slice(&self.encoding);
+        input.push(num_pairs as u8);
+        for _ in 0..num_pairs {
+            input.extend(self.random_point(rng));
+            input.extend(self.random_scalar(rng));
+        }
+
+        input
+    }
+}
+
+/// Loop parameter with exactly `bits` bits and `hamming` of them set
+pub(crate) fn random_loop_parameter(rng: &mut XorShiftRng, bits: usize, hamming: usize) -> MaxLoopParametersUint {
+    debug_assert!(hamming >= 1 && hamming <= bits);
+    let mut positions: Vec<usize> = (0..(bits - 1)).collect();
+    // partial Fisher-Yates shuffle to select `hamming - 1` lower bits
+    for i in 0..(hamming - 1) {
+        let j = rng.range(i, positions.len() - 1);
+        positions.swap(i, j);
+    }
+
+    let mut value = MaxLoopParametersUint::one() << ((bits - 1) as u32);
+    for position in positions.iter().take(hamming - 1) {
+        value = value | (MaxLoopParametersUint::one() << (*position as u32));
+    }
+
+    value
+}
+
+fn random_bits_and_hamming(rng: &mut XorShiftRng, max_bits: usize, max_hamming: usize) -> (usize, usize) {
+    let bits = rng.range(1, max_bits);
+    let hamming = rng.range(1, std::cmp::min(bits, max_hamming));
+
+    (bits, hamming)
+}
+
+/// Without subgroup checks pairing does not depend on the group order
+fn random_pairing_group_order(rng: &mut XorShiftRng) -> MaxGroupSizeUint {
+    let group_limbs = rng.range(NUM_GROUP_LIMBS_MIN, NUM_GROUP_LIMBS_MAX);
+
+    MaxGroupSizeUint::from_big_endian(&random_group_order(rng, group_limbs))
+}
+
+fn bits_and_hamming(value: &MaxLoopParametersUint) -> (f64, f64) {
+    (value.bits() as f64, calculate_hamming_weight(value.as_ref()) as f64)
+}
+
+fn uint_from_bytes(bytes: &[u8]) -> MaxFieldUint {
+    MaxFieldUint::from_big_endian(bytes)
+}
+
+/// Shortest big-endian encoding, the same as the ABI uses for the modulus
+fn uint_to_bytes(value: &MaxFieldUint) -> Vec<u8> {
+    let mut bytes = vec![0u8; value.as_ref().len() * 8];
+    value.to_big_endian(&mut bytes);
+    let len = value.bits().div_ceil(8);
+
+    bytes.split_off(bytes.len() - len)
+}
+
+/// Number of attempts to draw a loop parameter that gives a family modulus of the requested size
+const FAMILY_MODULUS_ATTEMPTS: usize = 1000;
+
+/// Loop parameter of `bits_range` bits with the modulus `modulus(x, x_is_negative)` of exactly
+/// `limbs` limbs as metering counts them, or `None` if the size is not reachable
+fn random_family_modulus<M: Fn(&MaxFieldUint, bool) -> Option<MaxFieldUint>>(
+    rng: &mut XorShiftRng,
+    limbs: usize,
+    bits_range: (usize, usize),
+    max_hamming: usize,
+    modulus: M
+) -> Option<(Vec<u8>, MaxLoopParametersUint, bool)> {
+    let (min_bits, max_bits) = bits_range;
+    if min_bits > max_bits {
+        return None;
+    }
+    for _ in 0..FAMILY_MODULUS_ATTEMPTS {
+        let bits = rng.range(min_bits, max_bits);
+        let hamming = rng.range(1, std::cmp::min(bits, max_hamming));
+        let x = random_loop_parameter(rng, bits, hamming);
+        let x_is_negative = rng.bool();
+        let p = match modulus(&MaxFieldUint::from(x.as_ref()), x_is_negative) {
+            Some(p) => p,
+            None => continue,
+        };
+        if crate::field::calculate_num_limbs(p.bits()) == Ok(limbs) {
+            return Some((uint_to_bytes(&p), x, x_is_negative));
+        }
+    }
+
+    None
+}
+
+/// |6u + 2| that is the Miller loop parameter of BN curves
+fn six_u_plus_two(u: &MaxLoopParametersUint, u_is_negative: bool) -> MaxLoopParametersUint {
+    let six_u = MaxLoopParametersUint::from(6u64) * *u;
+    let two = MaxLoopParametersUint::from(2u64);
+
+    if u_is_negative { six_u - two } else { six_u + two }
+}
+
+/// Pairing friendly pseudo-curve together with the features used by the pairing models.
+/// Miller loop and final exponentiation features do not include modulus limbs
+#[derive(Clone, Debug)]
+pub(crate) struct PairingCurve {
+    parameters: Vec<u8>,
+    modulus: Vec<u8>,
+    extension_degree: usize,
+    pub(crate) limbs: usize,
+    pub(crate) miller_features: Vec<f64>,
+    pub(crate) final_exp_features: Vec<f64>,
+}
+
+impl PairingCurve {
+    pub(crate) fn random_bls12(rng: &mut XorShiftRng, limbs: usize) -> Self {
+        let modulus = random_modulus(rng, limbs);
+        let (bits, hamming) = random_bits_and_hamming(rng, MAX_BLS12_X_BIT_LENGTH, MAX_BLS12_X_HAMMING as usize);
+        let x = random_loop_parameter(rng, bits, hamming);
+        let x_is_negative = rng.bool();
+
+        Self::bls12_with_modulus(rng, limbs, modulus, x, x_is_negative)
+    }
+
+    /// BLS12 pseudo-curve with the modulus `(x - 1)^2 * (x^4 - x^2 + 1) / 3 + x` and a curve
+    /// with the same `x` and a random modulus of the same size. `None` for sizes that such
+    /// a modulus can not have within the limit on `x`
+    pub(crate) fn family_bls12(rng: &mut XorShiftRng, limbs: usize) -> Option<(Self, Self)> {
+        // modulus has 6 * bits(x) - 1 or 6 * bits(x) - 2 bits
+        let bits_range = (64 * (limbs - 1) / 6 + 1, std::cmp::min((64 * limbs + 1) / 6, MAX_BLS12_X_BIT_LENGTH));
+        let (modulus, x, x_is_negative) = random_family_modulus(rng, limbs, bits_range, MAX_BLS12_X_HAMMING as usize, |x, x_is_negative| {
+            let one = MaxFieldUint::from(1u64);
+            let three = MaxFieldUint::from(3u64);
+            // (x - 1)^2 with x = -|x| is (|x| + 1)^2
+            let x_minus_one = if x_is_negative { *x + one } else { *x - one };
+            let x2 = *x * *x;
+            let q = x_minus_one * x_minus_one * (x2 * x2 - x2 + one);
+            if q % three != MaxFieldUint::zero() || x.is_zero() {
+                return None;
+            }
+
+            Some(if x_is_negative { q / three - *x } else { q / three + *x })
+        })?;
+        debug_assert!(crate::engines::const_arithmetic::is_bls12_modulus(uint_from_bytes(&modulus).as_ref(), x.as_ref(), x_is_negative));
+
+        let generic_modulus = random_modulus(rng, limbs);
+
+        Some((
+            Self::bls12_with_modulus(rng, limbs, modulus, x, x_is_negative),
+            Self::bls12_with_modulus(rng, limbs, generic_modulus, x, x_is_negative)
+        ))
+    }
+
+    fn bls12_with_modulus(rng: &mut XorShiftRng, limbs: usize, modulus: Vec<u8>, x: MaxLoopParametersUint, x_is_negative: bool) -> Self {
+        let features = bits_and_hamming(&x);
+
+        let description = Bls12InstanceDescription {
+            modulus: uint_from_bytes(&modulus),
+            b: uint_from_bytes(&random_field_element(rng, &modulus)),
+            group_order: random_pairing_group_order(rng),
+            fp2_non_residue: uint_from_bytes(&random_field_element(rng, &modulus)),
+            fp6_non_residue: [uint_from_bytes(&random_field_element(rng, &modulus)), uint_from_bytes(&random_field_element(rng, &modulus))],
+            twist_type: if rng.bool() { TwistType::M } else { TwistType::D },
+            x,
+            x_is_negative,
+        };
+
+        Self {
+            parameters: description.encode_pairing_parameters(),
+            modulus,
+            extension_degree: 2,
+            limbs,
+            miller_features: vec![features.0, features.1],
+            final_exp_features: vec![features.0, features.1],
+        }
+    }
+
+    pub(crate) fn random_bls24(rng: &mut XorShiftRng, limbs: usize) -> Self {
+        let modulus = random_modulus(rng, limbs);
+        let (bits, hamming) = random_bits_and_hamming(rng, MAX_BLS24_X_BIT_LENGTH, MAX_BLS24_X_HAMMING as usize);
+        let x = random_loop_parameter(rng, bits, hamming);
+        let features = bits_and_hamming(&x);
+
+        let description = Bls24InstanceDescription {
+            modulus: uint_from_bytes(&modulus),
+            b: uint_from_bytes(&random_field_element(rng, &modulus)),
+            group_order: random_pairing_group_order(rng),
+            fp2_non_residue: uint_from_bytes(&random_field_element(rng, &modulus)),
+            twist_type: if rng.bool() { TwistType::M } else { TwistType::D },
+            x,
+            x_is_negative: rng.bool(),
+        };
+
+        Self {
+            parameters: description.encode_pairing_parameters(),
+            modulus,
+            extension_degree: 4,
+            limbs,
+            miller_features: vec![features.0, features.1],
+            final_exp_features: vec![features.0, features.1],
+        }
+    }
+
+    pub(crate) fn random_bn(rng: &mut XorShiftRng, limbs: usize) -> Self {
+        let modulus = random_modulus(rng, limbs);
+        // |6u + 2| must also fit into the hamming weight limit
+        let (u, u_is_negative) = loop {
+            let (bits, hamming) = random_bits_and_hamming(rng, MAX_BN_U_BIT_LENGTH, MAX_BN_U_BIT_LENGTH);
+            let u = random_loop_parameter(rng, bits, hamming);
+            let u_is_negative = rng.bool();
+            if calculate_hamming_weight(six_u_plus_two(&u, u_is_negative).as_ref()) <= MAX_BN_SIX_U_PLUS_TWO_HAMMING {
+                break (u, u_is_negative);
+            }
+        };
+
+        Self::bn_with_modulus(rng, limbs, modulus, u, u_is_negative)
+    }
+
+    /// BN pseudo-curve with the modulus `36u^4 + 36u^3 + 24u^2 + 6u + 1` and a curve with
+    /// the same `u` and a random modulus of the same size. `None` for sizes that such
+    /// a modulus can not have within the limit on `u`
+    pub(crate) fn family_bn(rng: &mut XorShiftRng, limbs: usize) -> Option<(Self, Self)> {
+        // modulus has 4 * bits(u) + 1 or 4 * bits(u) + 2 bits
+        let bits_range = (64 * (limbs - 1) / 4, std::cmp::min((64 * limbs - 2) / 4, MAX_BN_U_BIT_LENGTH));
+        let (modulus, u, u_is_negative) = random_family_modulus(rng, limbs, bits_range, MAX_BN_U_BIT_LENGTH, |u, u_is_negative| {
+            let u2 = *u * *u;
+            // terms of even powers of u do not depend on the sign
+            let even = MaxFieldUint::from(36u64) * u2 * u2 + MaxFieldUint::from(24u64) * u2 + MaxFieldUint::from(1u64);
+            let odd = MaxFieldUint::from(36u64) * u2 * *u + MaxFieldUint::from(6u64) * *u;
+
+            Some(if u_is_negative { even - odd } else { even + odd })
+        })?;
+        if calculate_hamming_weight(six_u_plus_two(&u, u_is_negative).as_ref()) > MAX_BN_SIX_U_PLUS_TWO_HAMMING {
+            return None;
+        }
+        debug_assert!(crate::engines::const_arithmetic::is_bn_modulus(uint_from_bytes(&modulus).as_ref(), u.as_ref(), u_is_negative));
+
+        let generic_modulus = random_modulus(rng, limbs);
+
+        Some((
+            Self::bn_with_modulus(rng, limbs, modulus, u, u_is_negative),
+            Self::bn_with_modulus(rng, limbs, generic_modulus, u, u_is_negative)
+        ))
+    }
+
+    fn bn_with_modulus(rng: &mut XorShiftRng, limbs: usize, modulus: Vec<u8>, u: MaxLoopParametersUint, u_is_negative: bool) -> Self {
+        let six_u_plus_two = six_u_plus_two(&u, u_is_negative);
+        let u_features = bits_and_hamming(&u);
+        let six_u_plus_two_features = bits_and_hamming(&six_u_plus_two);
+
+        let description = BnInstanceDescription {
+            modulus: uint_from_bytes(&modulus),
+            b: uint_from_bytes(&random_field_element(rng, &modulus)),
+            group_order: random_pairing_group_order(rng),
+            fp2_non_residue: uint_from_bytes(&random_field_element(rng, &modulus)),
+            fp6_non_residue: [uint_from_bytes(&random_field_element(rng, &modulus)), uint_from_bytes(&random_field_element(rng, &modulus))],
+            twist_type: if rng.bool() { TwistType::M } else { TwistType::D },
+            u,
+            u_is_negative,
+        };
+
+        Self {
+            parameters: description.encode_pairing_parameters(),
+            modulus,
+            extension_degree: 2,
+            limbs,
+            miller_features: vec![six_u_plus_two_features.0, six_u_plus_two_features.1],
+            final_exp_features: vec![u_features.0, u_features.1],
+        }
+    }
+
+    pub(crate) fn random_mnt4(rng: &mut XorShiftRng, limbs: usize) -> Self {
+        let (description, modulus, miller_features, final_exp_features) = random_mnt_description(rng, limbs);
+        let description = Mnt4InstanceDescription {
+            modulus: description.modulus,
+            a: description.a,
+            b: description.b,
+            group_order: description.group_order,
+            fp2_non_residue: description.non_residue,
+            x: description.x,
+            x_is_negative: description.x_is_negative,
+            exp_w0: description.exp_w0,
+            exp_w1: description.exp_w1,
+            exp_w0_is_negative: description.exp_w0_is_negative,
+        };
+
+        Self {
+            parameters: description.encode_pairing_parameters(),
+            modulus,
+            extension_degree: 2,
+            limbs,
+            miller_features,
+            final_exp_features,
+        }
+    }
+
+    pub(crate) fn random_mnt6(rng: &mut XorShiftRng, limbs: usize) -> Self {
+        let (description, modulus, miller_features, final_exp_features) = random_mnt_description(rng, limbs);
+        let description = Mnt6InstanceDescription {
+            modulus: description.modulus,
+            a: description.a,
+            b: description.b,
+            group_order: description.group_order,
+            fp3_non_residue: description.non_residue,
+            x: description.x,
+            x_is_negative: description.x_is_negative,
+            exp_w0: description.exp_w0,
+            exp_w1: description.exp_w1,
+            exp_w0_is_negative: description.exp_w0_is_negative,
+        };
+
+        Self {
+            parameters: description.encode_pairing_parameters(),
+            modulus,
+            extension_degree: 3,
+            limbs,
+            miller_features,
+            final_exp_features,
+        }
+    }
+
+    /// BW6 runs a Miller loop for each of the two loop parameters, so features are summed over both loops
+    pub(crate) fn random_bw6(rng: &mut XorShiftRng, limbs: usize) -> Self {
+        let (description, modulus, x_1_features, final_exp_features) = random_mnt_description(rng, limbs);
+        let (bits, hamming) = random_bits_and_hamming(rng, MAX_ATE_PAIRING_ATE_LOOP_COUNT, MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING as usize);
+        let x_2 = random_loop_parameter(rng, bits, hamming);
+        let x_2_features = bits_and_hamming(&x_2);
+
+        let description = Bw6InstanceDescription {
+            modulus: description.modulus,
+            b: description.b,
+            group_order: description.group_order,
+            fp3_non_residue: description.non_residue,
+            twist_type: if rng.bool() { TwistType::M } else { TwistType::D },
+            ate_loop_count_1: description.x,
+            ate_loop_count_1_is_negative: description.x_is_negative,
+            ate_loop_count_2: x_2,
+            ate_loop_count_2_is_negative: rng.bool(),
+            exp_w0: description.exp_w0,
+            exp_w1: description.exp_w1,
+            exp_w0_is_negative: description.exp_w0_is_negative,
+        };
+
+        Self {
+            parameters: description.encode_pairing_parameters(),
+            modulus,
+            // G2 points are on the twist over the base field
+            extension_degree: 1,
+            limbs,
+            miller_features: vec![x_1_features[0] + x_2_features.0, x_1_features[1] + x_2_features.1],
+            final_exp_features,
+        }
+    }
+
+    /// Pairing call for `num_pairs` random pairs without subgroup checks, that are metered separately
+    pub(crate) fn pairing_input(&self, rng: &mut XorShiftRng, num_pairs: usize) -> Vec<u8> {
+        let mut input = vec![OPERATION_PAIRING];
+        input.extend_from_slice(&self.parameters);
+        input.push(num_pairs as u8);
+        for _ in 0..num_pairs {
+            input.push(0u8);
+            for _ in 0..2 {
+                input.extend(random_field_element(rng, &self.modulus));
+            }
+            input.push(0u8);
+            for _ in 0..(2 * self.extension_degree) {
+                input.extend(random_field_element(rng, &self.modulus));
+            }
+        }
+
+        input
+    }
+}
+
+/// Parameters that are common for MNT4 and MNT6
+struct MntDescription {
+    modulus: MaxFieldUint,
+    a: MaxFieldUint,
+    b: MaxFieldUint,
+    group_order: MaxGroupSizeUint,
+    non_residue: MaxFieldUint,
+    x: MaxLoopParametersUint,
+    x_is_negative: bool,
+    exp_w0: MaxLoopParametersUint,
+    exp_w1: MaxLoopParametersUint,
+    exp_w0_is_negative: bool,
+}
+
+fn random_mnt_description(rng: &mut XorShiftRng, limbs: usize) -> (MntDescription, Vec<u8>, Vec<f64>, Vec<f64>) {
+    let modulus = random_modulus(rng, limbs);
+
+    let (bits, hamming) = random_bits_and_hamming(rng, MAX_ATE_PAIRING_ATE_LOOP_COUNT, MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING as usize);
+    let x = random_loop_parameter(rng, bits, hamming);
+    let (bits, hamming) = random_bits_and_hamming(rng, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH);
+    let exp_w0 = random_loop_parameter(rng, bits, hamming);
+    let (bits, hamming) = random_bits_and_hamming(rng, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH);
+    let exp_w1 = random_loop_parameter(rng, bits, hamming);
+
+    let x_features = bits_and_hamming(&x);
+    let w0_features = bits_and_hamming(&exp_w0);
+    let w1_features = bits_and_hamming(&exp_w1);
+
+    let description = MntDescription {
+        modulus: uint_from_bytes(&modulus),
+        a: uint_from_bytes(&random_field_element(rng, &modulus)),
+        b: uint_from_bytes(&random_field_element(rng, &modulus)),
+        group_order: random_pairing_group_order(rng),
+        non_residue: uint_from_bytes(&random_field_element(rng, &modulus)),
+        x,
+        x_is_negative: rng.bool(),
+        exp_w0,
+        exp_w1,
+        exp_w0_is_negative: rng.bool(),
+    };
+
+    (
+        description,
+        modulus,
+        vec![x_features.0, x_features.1],
+        vec![w0_features.0, w0_features.1, w1_features.0, w1_features.1]
+    )
+}
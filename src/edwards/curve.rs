@@ -0,0 +1,473 @@
+use crate::traits::{FieldElement, BitIterator};
+use crate::weierstrass::{CurveParameters, Group};
+use crate::traits::ZeroAndOne;
+use super::EdwardsCurveType;
+
+pub struct EdwardsCurve<'a, C: CurveParameters> {
+    pub(crate) a: C::BaseFieldElement,
+    pub(crate) d: C::BaseFieldElement,
+    pub(crate) curve_type: EdwardsCurveType,
+    pub(crate) subgroup_order_repr: &'a [u64],
+    pub(crate) params: &'a C
+}
+
+impl<'a, C: CurveParameters> Clone for EdwardsCurve<'a, C> {
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            d: self.d.clone(),
+            curve_type: self.curve_type,
+            subgroup_order_repr: self.subgroup_order_repr,
+            params: self.params
+        }
+    }
+}
+
+impl<'a, C: CurveParameters> EdwardsCurve<'a, C> {
+    pub(crate) fn new(
+        subgroup_order: &'a [u64],
+        a: C::BaseFieldElement,
+        d: C::BaseFieldElement,
+        params: &'a C
+    ) -> Result<Self, ()> {
+        if a.is_zero() || d.is_zero() || a == d {
+            return Err(());
+        }
+
+        let mut minus_one = C::BaseFieldElement::one(params.params());
+        minus_one.negate();
+
+        let curve_type = if a == minus_one {
+            EdwardsCurveType::AIsMinusOne
+        } else {
+            EdwardsCurveType::Generic
+        };
+
+        Ok(Self {
+            a,
+            d,
+            curve_type,
+            subgroup_order_repr: subgroup_order,
+            params
+        })
+    }
+
+    fn mul_by_a(&self, el: &mut C::BaseFieldElement) {
+        match self.curve_type {
+            EdwardsCurveType::AIsMinusOne => {
+                el.negate();
+            },
+            EdwardsCurveType::Generic => {
+                el.mul_assign(&self.a);
+            }
+        }
+    }
+}
+
+/// Point in extended coordinates (X : Y : T : Z) with x = X/Z, y = Y/Z and x*y = T/Z.
+/// Neutral element is (0 : 1 : 0 : 1), so unlike the Weierstrass form it has a regular affine representation
+pub struct EdwardsPoint<'a, C: CurveParameters> {
+    pub(crate) curve: &'a EdwardsCurve<'a, C>,
+    pub(crate) x: C::BaseFieldElement,
+    pub(crate) y: C::BaseFieldElement,
+    pub(crate) t: C::BaseFieldElement,
+    pub(crate) z: C::BaseFieldElement,
+}
+
+impl<'a, C: CurveParameters> Clone for EdwardsPoint<'a, C> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            curve: self.curve,
+            x: self.x.clone(),
+            y: self.y.clone(),
+            t: self.t.clone(),
+            z: self.z.clone()
+        }
+    }
+}
+
+impl<'a, C: CurveParameters> EdwardsPoint<'a, C> {
+    pub fn zero(curve: &'a EdwardsCurve<C>) -> Self {
+        Self {
+            curve,
+            x: C::BaseFieldElement::zero(curve.params.params()),
+            y: C::BaseFieldElement::one(curve.params.params()),
+            t: C::BaseFieldElement::zero(curve.params.params()),
+            z: C::BaseFieldElement::one(curve.params.params()),
+        }
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        if self.z.is_zero() {
+            return false;
+        }
+
+        // X*Y = T*Z
+        let mut xy = self.x.clone();
+        xy.mul_assign(&self.y);
+
+        let mut tz = self.t.clone();
+        tz.mul_assign(&self.z);
+
+        if xy != tz {
+            return false;
+        }
+
+        // (a*X^2 + Y^2)*Z^2 = Z^4 + d*X^2*Y^2
+        let mut x_2 = self.x.clone();
+        x_2.square();
+
+        let mut y_2 = self.y.clone();
+        y_2.square();
+
+        let mut z_2 = self.z.clone();
+        z_2.square();
+
+        let mut lhs = x_2.clone();
+        self.curve.mul_by_a(&mut lhs);
+        lhs.add_assign(&y_2);
+        lhs.mul_assign(&z_2);
+
+        let mut rhs = x_2;
+        rhs.mul_assign(&y_2);
+        rhs.mul_assign(&self.curve.d);
+        z_2.square();
+        rhs.add_assign(&z_2);
+
+        lhs == rhs
+    }
+
+    pub fn point_from_xy(
+        curve: &'a EdwardsCurve<'a, C>,
+        x: C::BaseFieldElement,
+        y: C::BaseFieldElement,
+    ) -> EdwardsPoint<'a, C> {
+        let mut t = x.clone();
+        t.mul_assign(&y);
+
+        EdwardsPoint {
+            curve,
+            x,
+            y,
+            t,
+            z: C::BaseFieldElement::one(curve.params.params())
+        }
+    }
+
+    pub fn is_normalized(&self) -> bool {
+        let one = C::BaseFieldElement::one(self.curve.params.params());
+
+        self.z == one
+    }
+
+    pub fn normalize(&mut self) {
+        let one = C::BaseFieldElement::one(self.curve.params.params());
+        if self.z == one {
+            return;
+        }
+
+        match self.z.inverse() {
+            Some(z_inv) => {
+                self.x.mul_assign(&z_inv);
+                self.y.mul_assign(&z_inv);
+                self.t.mul_assign(&z_inv);
+
+                self.z = one;
+            },
+            None => {
+                // only reachable for inputs that were not on curve
+                self.z = C::BaseFieldElement::zero(self.curve.params.params());
+            }
+        }
+    }
+
+    pub fn into_xy(&self) -> (C::BaseFieldElement, C::BaseFieldElement) {
+        let mut point = self.clone();
+        point.normalize();
+
+        (point.x, point.y)
+    }
+
+    fn is_zero_impl(&self) -> bool {
+        self.x.is_zero() && !self.z.is_zero() && self.y == self.z
+    }
+
+    fn negate_impl(&mut self) {
+        self.x.negate();
+        self.t.negate();
+    }
+
+    fn add_assign_impl(&mut self, other: &Self) {
+        let one = C::BaseFieldElement::one(self.curve.params.params());
+        if other.z == one {
+            self.add_assign_mixed_impl(other);
+            return;
+        }
+
+        // https://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#addition-add-2008-hwcd
+
+        // D = Z1*Z2
+        let mut d = self.z.clone();
+        d.mul_assign(&other.z);
+
+        self.add_assign_with_z_product(other, d);
+    }
+
+    fn add_assign_mixed_impl(&mut self, other: &Self) {
+        let one = C::BaseFieldElement::one(self.curve.params.params());
+        if other.z != one {
+            self.add_assign_impl(other);
+            return;
+        }
+
+        // https://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#addition-madd-2008-hwcd
+
+        // D = Z1
+        let d = self.z.clone();
+
+        self.add_assign_with_z_product(other, d);
+    }
+
+    // Unified addition, so it's also valid for doubling and the neutral element.
+    // It's complete if a is a square and d is not, otherwise exceptional
+    // points exist only outside of the prime order subgroup
+    fn add_assign_with_z_product(&mut self, other: &Self, d: C::BaseFieldElement) {
+        // A = X1*X2
+        let mut a = self.x.clone();
+        a.mul_assign(&other.x);
+
+        // B = Y1*Y2
+        let mut b = self.y.clone();
+        b.mul_assign(&other.y);
+
+        // C = T1*d*T2
+        let mut c = self.t.clone();
+        c.mul_assign(&self.curve.d);
+        c.mul_assign(&other.t);
+
+        // E = (X1+Y1)*(X2+Y2)-A-B
+        let mut e = self.x.clone();
+        e.add_assign(&self.y);
+        let mut tmp = other.x.clone();
+        tmp.add_assign(&other.y);
+        e.mul_assign(&tmp);
+        e.sub_assign(&a);
+        e.sub_assign(&b);
+
+        // F = D-C
+        let mut f = d.clone();
+        f.sub_assign(&c);
+
+        // G = D+C
+        let mut g = d;
+        g.add_assign(&c);
+
+        // H = B-a*A
+        self.curve.mul_by_a(&mut a);
+        let mut h = b;
+        h.sub_assign(&a);
+
+        // X3 = E*F
+        self.x = e.clone();
+        self.x.mul_assign(&f);
+
+        // Y3 = G*H
+        self.y = g.clone();
+        self.y.mul_assign(&h);
+
+        // T3 = E*H
+        self.t = e;
+        self.t.mul_assign(&h);
+
+        // Z3 = F*G
+        self.z = f;
+        self.z.mul_assign(&g);
+    }
+
+    fn double_impl(&mut self) {
+        // https://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#doubling-dbl-2008-hwcd
+
+        // A = X1^2
+        let mut a = self.x.clone();
+        a.square();
+
+        // B = Y1^2
+        let mut b = self.y.clone();
+        b.square();
+
+        // C = 2*Z1^2
+        let mut c = self.z.clone();
+        c.square();
+        c.double();
+
+        // D = a*A
+        self.curve.mul_by_a(&mut a);
+        let d = a;
+
+        // E = (X1+Y1)^2-A-B = 2*X1*Y1
+        let mut e = self.x.clone();
+        e.mul_assign(&self.y);
+        e.double();
+
+        // G = D+B
+        let mut g = d.clone();
+        g.add_assign(&b);
+
+        // F = G-C
+        let mut f = g.clone();
+        f.sub_assign(&c);
+
+        // H = D-B
+        let mut h = d;
+        h.sub_assign(&b);
+
+        // X3 = E*F
+        self.x = e.clone();
+        self.x.mul_assign(&f);
+
+        // Y3 = G*H
+        self.y = g.clone();
+        self.y.mul_assign(&h);
+
+        // T3 = E*H
+        self.t = e;
+        self.t.mul_assign(&h);
+
+        // Z3 = F*G
+        self.z = f;
+        self.z.mul_assign(&g);
+    }
+
+    pub(crate) fn mul_impl<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut res = Self::zero(self.curve);
+
+        let mut found_one = false;
+
+        for i in BitIterator::new(exp)
+        {
+            if found_one {
+                res.double_impl();
+            } else {
+                found_one = i;
+            }
+
+            if i {
+                res.add_assign_impl(self);
+            }
+        }
+
+        res
+    }
+
+    pub(crate) fn wnaf_mul_impl<S: crate::representation::IntoWnaf>(&self, exp: S) -> Self {
+        const WINDOW_SIZE: u32 = 4;
+
+        self.wnaf_mul_with_window_size_impl(exp, WINDOW_SIZE)
+    }
+
+    pub(crate) fn wnaf_mul_with_window_size_impl<S: crate::representation::IntoWnaf>(&self, exp: S, window_size: u32) -> Self {
+        assert!(window_size >= 2u32);
+        let mut precomp_table = vec![Self::zero(self.curve); (1 << (window_size-1)) as usize];
+
+        let index_for_positive = (1 << (window_size-2)) as usize;
+
+        let mut two_self = self.clone();
+        two_self.double_impl();
+
+        let mut precomp = self.clone();
+        precomp_table[index_for_positive] = precomp.clone();
+        let mut neg_precomp = precomp.clone();
+        neg_precomp.negate_impl();
+        precomp_table[index_for_positive-1] = neg_precomp;
+
+        for i in 1..index_for_positive {
+            precomp.add_assign_impl(&two_self);
+            precomp_table[index_for_positive+i] = precomp.clone();
+            let mut neg_precomp = precomp.clone();
+            neg_precomp.negate_impl();
+            precomp_table[index_for_positive-1-i] = neg_precomp;
+        }
+
+        let wnaf = exp.wnaf(window_size);
+
+        let mut res = Self::zero(self.curve);
+        let mut found_nonzero = false;
+
+        for w in wnaf.into_iter().rev() {
+            if found_nonzero {
+                res.double_impl();
+            }
+            if w != 0 {
+                found_nonzero = true;
+                if w > 0 {
+                    let idx = (w >> 1) as usize;
+                    res.add_assign_impl(&precomp_table[index_for_positive + idx]);
+                } else {
+                    let idx = ((-w) >> 1) as usize;
+                    res.add_assign_impl(&precomp_table[index_for_positive - 1 - idx]);
+                }
+            }
+        }
+
+        res
+    }
+
+    fn check_correct_subgroup_impl(&self) -> bool {
+        let p = self.wnaf_mul_impl(self.curve.subgroup_order_repr);
+
+        p.is_zero_impl()
+    }
+}
+
+impl<'a, C: CurveParameters> Group for EdwardsPoint<'a, C> {
+    fn add_assign(&mut self, other: &Self) {
+        self.add_assign_impl(other);
+    }
+
+    fn add_assign_mixed(&mut self, other: &Self) {
+        self.add_assign_mixed_impl(other);
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        let mut other_neg = other.clone();
+        other_neg.negate_impl();
+        self.add_assign_impl(&other_neg);
+    }
+
+    fn negate(&mut self) {
+        self.negate_impl();
+    }
+
+    fn double(&mut self) {
+        self.double_impl();
+    }
+
+    fn mul<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        self.mul_impl(exp)
+    }
+
+    fn wnaf_mul<S: crate::representation::IntoWnaf>(&self, exp: S) -> Self {
+        self.wnaf_mul_impl(exp)
+    }
+
+    fn wnaf_mul_with_window_size<S: crate::representation::IntoWnaf>(&self, exp: S, window_size: u32) -> Self {
+        self.wnaf_mul_with_window_size_impl(exp, window_size)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_zero_impl()
+    }
+
+    fn check_correct_subgroup(&self) -> bool {
+        self.check_correct_subgroup_impl()
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::zero(self.curve)
+    }
+
+    fn subgroup_order(&self) -> &[u64] {
+        self.curve.subgroup_order_repr
+    }
+}
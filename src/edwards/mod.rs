@@ -0,0 +1,12 @@
+/// Twisted Edwards curves `a*x^2 + y^2 = 1 + d*x^2*y^2` in extended coordinates
+/// (Hisil, Wong, Carter, Dawson, "Twisted Edwards Curves Revisited").
+/// Curve parameters are shared with the short Weierstrass form, so the same
+/// `CurveOverFpParameters` can be used for both
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub(crate) enum EdwardsCurveType {
+    Generic,
+    AIsMinusOne,
+}
+
+pub mod curve;
@@ -0,0 +1,143 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::weierstrass::*;
+use crate::edwards::*;
+use crate::edwards::curve::*;
+use crate::integers::MaxFieldUint;
+
+// Baby Jubjub (EIP-2494) is defined over the scalar field of BN254
+
+const REPR_ZERO: U256Repr = U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
+
+pub const BABY_JUBJUB_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0x43e1f593f0000001,0x2833e84879b97091,0xb85045b68181585d,0x30644e72e131a029,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000
+    ]
+);
+
+pub const BABY_JUBJUB_MODULUS: U256Repr = U256Repr([0x43e1f593f0000001,0x2833e84879b97091,0xb85045b68181585d,0x30644e72e131a029]);
+const BABY_JUBJUB_R: U256Repr = U256Repr([0xac96341c4ffffffb,0x36fc76959f60cd29,0x666ea36f7879462e,0x0e0a77c19a07df2f]);
+const BABY_JUBJUB_R2: U256Repr = U256Repr([0x1bb8e645ae216da7,0x53fe3ab1e35c59e3,0x8c49833d53bb8085,0x0216d0b17f4e44a5]);
+const BABY_JUBJUB_MONT_INV: u64 = 0xc2e1f593efffffff;
+
+pub const BABY_JUBJUB_FIELD: PrimeField<U256Repr> = PrimeField::<U256Repr> {
+    mont_power: 256,
+    modulus_bits: 254,
+    modulus: BABY_JUBJUB_MODULUS,
+    mont_r: BABY_JUBJUB_R,
+    mont_r2: BABY_JUBJUB_R2,
+    mont_inv: BABY_JUBJUB_MONT_INV,
+};
+
+// prime order subgroup, cofactor is 8. Generator is the `Base8` point of EIP-2494
+pub const BABY_JUBJUB_SUBGROUP_ORDER: [u64; 4] = [
+    0x677297dc392126f1,
+    0xab3eedb83920ee0a,
+    0x370a08b6d0302b0b,
+    0x060c89ce5c263405
+];
+
+pub const BABY_JUBJUB_FP_ZERO: decl_fp!(U256Repr) = repr_into_fp!(
+    REPR_ZERO,
+    U256Repr,
+    BABY_JUBJUB_FIELD
+);
+
+pub const BABY_JUBJUB_FP_ONE: decl_fp!(U256Repr) = repr_into_fp!(
+    BABY_JUBJUB_R,
+    U256Repr,
+    BABY_JUBJUB_FIELD
+);
+
+// a = 168700
+const BABY_JUBJUB_A: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x95accf61fff261e0,0x24780d659df7d378,0xe0ac11b07e906ae8,0x0f35db2216d3def3]),
+    U256Repr,
+    BABY_JUBJUB_FIELD
+);
+
+// d = 168696
+const BABY_JUBJUB_D: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x2735f484aff261f5,0x70ba1b579a2e0f63,0xff41c9a91e2caa8c,0x07704a8e8fe6025f]),
+    U256Repr,
+    BABY_JUBJUB_FIELD
+);
+
+pub const BABY_JUBJUB_CURVE_PARAMETERS: CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>> =
+    CurveOverFpParameters::<'static, U256Repr, PrimeField<U256Repr>> {
+        field: &BABY_JUBJUB_FIELD
+    };
+
+pub const BABY_JUBJUB_CURVE: EdwardsCurve<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> =
+    EdwardsCurve::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> {
+        a: BABY_JUBJUB_A,
+        d: BABY_JUBJUB_D,
+        curve_type: EdwardsCurveType::Generic,
+        subgroup_order_repr: &BABY_JUBJUB_SUBGROUP_ORDER,
+        params: &BABY_JUBJUB_CURVE_PARAMETERS
+    };
+
+const BABY_JUBJUB_GENERATOR_X: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x0a8fc7bc1a89fa86,0xa7d9d786e9e48627,0xee6158b465bea369,0x14a0ff6d2f874519]),
+    U256Repr,
+    BABY_JUBJUB_FIELD
+);
+
+const BABY_JUBJUB_GENERATOR_Y: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xb83342d20d0201aa,0x2ffef2f7cdcfeac7,0xbfa79a9425a6e625,0x0dfb859dc3a44b70]),
+    U256Repr,
+    BABY_JUBJUB_FIELD
+);
+
+const BABY_JUBJUB_GENERATOR_T: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x772a59f816ea5a4b,0x741affa90241bdaf,0x99d6d544c95278f7,0x13f55de666de5aa7]),
+    U256Repr,
+    BABY_JUBJUB_FIELD
+);
+
+pub const BABY_JUBJUB_GENERATOR: EdwardsPoint<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> =
+    EdwardsPoint::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>>
+    {
+        curve: &BABY_JUBJUB_CURVE,
+        x: BABY_JUBJUB_GENERATOR_X,
+        y: BABY_JUBJUB_GENERATOR_Y,
+        t: BABY_JUBJUB_GENERATOR_T,
+        z: BABY_JUBJUB_FP_ONE,
+    };
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generator_and_order() {
+        let g = BABY_JUBJUB_GENERATOR;
+        assert!(g.is_on_curve());
+        assert!(!g.is_zero());
+
+        let should_be_zero = g.mul(&BABY_JUBJUB_SUBGROUP_ORDER[..]);
+        assert!(should_be_zero.is_zero());
+        assert!(should_be_zero.is_on_curve());
+        assert!(g.check_correct_subgroup());
+    }
+
+    #[test]
+    fn test_field_constants() {
+        let field = field_from_modulus::<U256Repr>(&BABY_JUBJUB_MODULUS_UINT).unwrap();
+        assert_eq!(field.mont_r(), BABY_JUBJUB_FIELD.mont_r());
+        assert_eq!(field.mont_r2(), BABY_JUBJUB_FIELD.mont_r2());
+        assert_eq!(field.mont_inv(), BABY_JUBJUB_FIELD.mont_inv());
+
+        let params = CurveOverFpParameters::new(&field);
+        let curve = EdwardsCurve::new(&BABY_JUBJUB_SUBGROUP_ORDER[..], BABY_JUBJUB_A, BABY_JUBJUB_D, &params).unwrap();
+        assert_eq!(curve.curve_type, EdwardsCurveType::Generic);
+
+        let a = Fp::from_repr(&BABY_JUBJUB_FIELD, U256Repr::from(168700)).unwrap();
+        assert_eq!(a, BABY_JUBJUB_A);
+        let d = Fp::from_repr(&BABY_JUBJUB_FIELD, U256Repr::from(168696)).unwrap();
+        assert_eq!(d, BABY_JUBJUB_D);
+    }
+}
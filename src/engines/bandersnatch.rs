@@ -0,0 +1,105 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::weierstrass::*;
+use crate::edwards::*;
+use crate::edwards::curve::*;
+
+use super::jubjub::{JUBJUB_FIELD, JUBJUB_FP_ONE};
+
+// Bandersnatch is defined over the same field as Jubjub, that is the scalar field of BLS12-381
+
+// prime order subgroup, cofactor is 4
+pub const BANDERSNATCH_SUBGROUP_ORDER: [u64; 4] = [
+    0x74fd06b52876e7e1,
+    0xff8f870074190471,
+    0x0cce760202687600,
+    0x1cfb69d4ca675f52
+];
+
+// a = -5
+const BANDERSNATCH_A: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xfffffff40000000c,0xece3b023ffec4ff3,0x66b620607396203f,0x6f23d7e5f361df62]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+const BANDERSNATCH_D: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xa8dced1b47a2c730,0x381c065aad3cccc7,0x53ff52e1188351f8,0x362e8d63990fe940]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+pub const BANDERSNATCH_CURVE_PARAMETERS: CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>> =
+    CurveOverFpParameters::<'static, U256Repr, PrimeField<U256Repr>> {
+        field: &JUBJUB_FIELD
+    };
+
+// a is not a square, so addition is only complete in the prime order subgroup
+pub const BANDERSNATCH_CURVE: EdwardsCurve<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> =
+    EdwardsCurve::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> {
+        a: BANDERSNATCH_A,
+        d: BANDERSNATCH_D,
+        curve_type: EdwardsCurveType::Generic,
+        subgroup_order_repr: &BANDERSNATCH_SUBGROUP_ORDER,
+        params: &BANDERSNATCH_CURVE_PARAMETERS
+    };
+
+const BANDERSNATCH_GENERATOR_X: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xec2627e1e7ab47f5,0x3e63de484f01aa9c,0xfe0f5c3b53946dc4,0x2d71920baeb2cfcd]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+const BANDERSNATCH_GENERATOR_Y: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x4e30593e1895bd34,0x156d738f32afbe4b,0x45ef0b1ccdeb75f4,0x6a7cca0037d2e71f]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+const BANDERSNATCH_GENERATOR_T: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x5a92e8f697adb6b9,0xf1388d4606b14609,0x101c783640a64516,0x1e9ae7073cc7a9fc]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+pub const BANDERSNATCH_GENERATOR: EdwardsPoint<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> =
+    EdwardsPoint::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>>
+    {
+        curve: &BANDERSNATCH_CURVE,
+        x: BANDERSNATCH_GENERATOR_X,
+        y: BANDERSNATCH_GENERATOR_Y,
+        t: BANDERSNATCH_GENERATOR_T,
+        z: JUBJUB_FP_ONE,
+    };
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traits::FieldElement;
+
+    #[test]
+    fn test_generator_and_order() {
+        let g = BANDERSNATCH_GENERATOR;
+        assert!(g.is_on_curve());
+        assert!(!g.is_zero());
+
+        let should_be_zero = g.mul(&BANDERSNATCH_SUBGROUP_ORDER[..]);
+        assert!(should_be_zero.is_zero());
+        assert!(should_be_zero.is_on_curve());
+        assert!(g.check_correct_subgroup());
+    }
+
+    #[test]
+    fn test_curve_constants() {
+        let mut minus_five = JUBJUB_FP_ONE;
+        minus_five.double();
+        minus_five.double();
+        minus_five.add_assign(&JUBJUB_FP_ONE);
+        minus_five.negate();
+        assert_eq!(minus_five, BANDERSNATCH_A);
+
+        let params = CurveOverFpParameters::new(&JUBJUB_FIELD);
+        let curve = EdwardsCurve::new(&BANDERSNATCH_SUBGROUP_ORDER[..], BANDERSNATCH_A, BANDERSNATCH_D, &params).unwrap();
+        assert_eq!(curve.curve_type, EdwardsCurveType::Generic);
+    }
+}
@@ -7,6 +7,7 @@ use crate::weierstrass::*;
 use crate::weierstrass::curve::*;
 use crate::pairings::bls12::*;
 use crate::pairings::TwistType;
+use crate::engines::const_arithmetic::is_bls12_modulus;
 use crate::integers::MaxFieldUint;
 
 const REPR_ZERO: U384Repr = U384Repr([0,0,0,0,0,0]);
@@ -512,7 +513,8 @@ pub const BLS12_377_PAIRING_ENGINE: Bls12Instance<
     fp6_extension: &BLS12_377_EXTENSION_6_FIELD,
     fp12_extension: &BLS12_377_EXTENSION_12_FIELD,
     prefer_naf: false,
-    x_naf: Vec::new()
+    x_naf: Vec::new(),
+    prefer_hht_final_exp: is_bls12_modulus(&BLS12_377_MODULUS.0, &BLS12_377_X, BLS12_377_X_IS_NEGATIVE)
 };
 
 #[cfg(test)]
@@ -8,7 +8,9 @@ use crate::weierstrass::*;
 use crate::weierstrass::curve::*;
 use crate::pairings::bls12::*;
 use crate::pairings::TwistType;
+use crate::engines::const_arithmetic::is_bls12_modulus;
 use crate::integers::MaxFieldUint;
+use eth_pairings_repr_derive::SizedPrimeField;
 
 struct Bls12_381Extension2;
 
@@ -79,6 +81,16 @@ pub const BLS12_381_FIELD: PrimeField<U384Repr> = PrimeField::<U384Repr> {
     mont_inv: BLS12_381_MONT_INV,  
 };
 
+/// Same base field as `BLS12_381_FIELD`, but with parameters known at compile time,
+/// so Montgomery arithmetic in `Fp` can be specialized for it
+#[derive(Clone, Copy, Debug, SizedPrimeField)]
+#[PrimeFieldModulus = "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787"]
+#[PrimeFieldRepr = "U384Repr"]
+#[NumberOfLimbs = "6"]
+pub struct Bls12_381Field;
+
+pub const BLS12_381_SPECIALIZED_FIELD: Bls12_381Field = Bls12_381Field;
+
 const BLS12_381_FP_NON_RESIDUE_REPR: U384Repr = U384Repr([0x43f5fffffffcaaae,0x32b7fff2ed47fffd,0x07e83a49a2e99d69,0xeca8f3318332bb7a,0xef148d1ea0f4c069,0x040ab3263eff0206]);
 
 const BLS12_381_FP_NON_RESIDUE: decl_fp!(U384Repr) = repr_into_fp!(
@@ -556,7 +568,8 @@ pub const BLS12_381_PAIRING_ENGINE: Bls12Instance<
     fp6_extension: &BLS12_381_EXTENSION_6_FIELD,
     fp12_extension: &BLS12_381_EXTENSION_12_FIELD,
     prefer_naf: false,
-    x_naf: Vec::new()
+    x_naf: Vec::new(),
+    prefer_hht_final_exp: is_bls12_modulus(&BLS12_381_MODULUS.0, &BLS12_381_X, BLS12_381_X_IS_NEGATIVE)
 };
 
 
@@ -684,7 +697,7 @@ pub mod mapping {
             el, 
             &swu, 
             coerce_static(el, &BLS12_381_G1_CURVE_ISOGENY)
-        );
+        ).ok_or(ApiError::InputError("mapped value has no square root".to_owned()))?;
 
         let (x, y) = apply_isogeny_map(
             &x_prime,
@@ -704,7 +717,7 @@ pub mod mapping {
         Ok(cofactor_cleared)
     }
 
-    pub fn fp2_to_g2(
+    pub(crate) fn fp2_to_twist(
         el: &Fp2<'static, U384Repr, PrimeField<U384Repr>>
     ) -> Result<CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
     {
@@ -713,7 +726,7 @@ pub mod mapping {
             el, 
             &swu, 
             coerce_static(el, &BLS12_381_G2_CURVE_ISOGENY)
-        );
+        ).ok_or(ApiError::InputError("mapped value has no square root".to_owned()))?;
 
         let (x, y) = apply_isogeny_map(
             &x_prime,
@@ -727,7 +740,16 @@ pub mod mapping {
             return Err(ApiError::InputError("mapped point is not on the curve".to_owned()));
         }
 
-        let mut cofactor_cleared = point.wnaf_mul_with_window_size(&BLS12_381_G2_MAPPING_H_EFF[..], 5);
+        Ok(point)
+    }
+
+    pub fn fp2_to_g2(
+        el: &Fp2<'static, U384Repr, PrimeField<U384Repr>>
+    ) -> Result<CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>, ApiError>
+    {
+        let point = fp2_to_twist(el)?;
+
+        let mut cofactor_cleared = BLS12_381_PAIRING_ENGINE.clear_cofactor_g2(&point);
         cofactor_cleared.normalize();
 
         Ok(cofactor_cleared)
@@ -763,6 +785,24 @@ mod test {
         assert!(ans1 == ans3);
     }
 
+    #[test]
+    fn test_hht_final_exponentiation() {
+        use crate::pairings::PairingEngine;
+
+        let engine = BLS12_381_PAIRING_ENGINE;
+        assert!(engine.prefer_hht_final_exp);
+        let mut generic_engine = engine.clone();
+        generic_engine.prefer_hht_final_exp = false;
+
+        let p = BLS12_381_G1_GENERATOR;
+        let q = BLS12_381_G2_GENERATOR;
+        let result = engine.pair(std::slice::from_ref(&p), std::slice::from_ref(&q)).unwrap();
+        let generic = generic_engine.pair(&[p], &[q]).unwrap();
+
+        // both chains raise to 3 * (p^4 - p^2 + 1) / r
+        assert!(result == generic);
+    }
+
     fn output_test_vector(input: &[u8], output: &[u8]) {
         println!("Input: 0x{}", hex::encode(input));
         println!("Output: 0x{}", hex::encode(output));
@@ -796,4 +836,57 @@ mod test {
 
         output_test_vector(&input_encoding, &output_encoding);
     }
+
+    #[test]
+    fn test_clear_cofactor_g1() {
+        use crate::weierstrass::Group;
+
+        let p = BLS12_381_G1_GENERATOR.mul(vec![12345678]);
+
+        let mut expected = p.mul(&BLS12_381_G1_MAPPING_H_EFF[..]);
+        expected.normalize();
+
+        let mut cleared = BLS12_381_PAIRING_ENGINE.clear_cofactor_g1(&p);
+        cleared.normalize();
+
+        assert!(cleared.into_xy() == expected.into_xy());
+    }
+
+    #[cfg(feature = "mappings")]
+    #[test]
+    fn test_clear_cofactor_g2_on_eip2537_vectors() {
+        use crate::weierstrass::Group;
+
+        let decode_fp = |encoding: &[u8]| {
+            assert!(encoding[..16].iter().all(|b| *b == 0));
+            Fp::from_be_bytes(&BLS12_381_FIELD, &encoding[16..], true).unwrap()
+        };
+
+        let mut reader = csv::Reader::from_path("src/test/test_vectors/eip2537/fp2_to_g2.csv").unwrap();
+        for r in reader.records() {
+            let r = r.unwrap();
+            let input = hex::decode(r.get(0).unwrap()).unwrap();
+            let expected_output = hex::decode(r.get(1).unwrap()).unwrap();
+
+            let mut el = BLS12_381_FP2_ZERO;
+            el.c0 = decode_fp(&input[0..64]);
+            el.c1 = decode_fp(&input[64..128]);
+
+            let point = mapping::fp2_to_twist(&el).unwrap();
+
+            let mut by_h_eff = point.wnaf_mul_with_window_size(&BLS12_381_G2_MAPPING_H_EFF[..], 5);
+            by_h_eff.normalize();
+
+            let mut cleared = BLS12_381_PAIRING_ENGINE.clear_cofactor_g2(&point);
+            cleared.normalize();
+
+            assert!(cleared.into_xy() == by_h_eff.into_xy());
+
+            let (x, y) = mapping::fp2_to_g2(&el).unwrap().into_xy();
+            assert!(x.c0 == decode_fp(&expected_output[0..64]));
+            assert!(x.c1 == decode_fp(&expected_output[64..128]));
+            assert!(y.c0 == decode_fp(&expected_output[128..192]));
+            assert!(y.c1 == decode_fp(&expected_output[192..256]));
+        }
+    }
 }
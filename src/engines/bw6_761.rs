@@ -0,0 +1,299 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::extension_towers::fp3::*;
+use crate::extension_towers::fp6_as_2_over_3::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::pairings::bw6::*;
+use crate::pairings::TwistType;
+use crate::integers::MaxFieldUint;
+
+const REPR_ZERO: U768Repr = U768Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
+
+pub const BW6_761_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0xf49d00000000008b,0xe6913e6870000082,0x160cf8aeeaf0a437,0x98a116c25667a8f8,
+        0x71dcd3dc73ebff2e,0x8689c8ed12f9fd90,0x03cebaff25b42304,0x707ba638e584e919,
+        0x528275ef8087be41,0xb926186a81d14688,0xd187c94004faff3e,0x0122e824fb83ce0a,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000
+    ]
+);
+
+pub const BW6_761_MODULUS: U768Repr = U768Repr([0xf49d00000000008b,0xe6913e6870000082,0x160cf8aeeaf0a437,0x98a116c25667a8f8,0x71dcd3dc73ebff2e,0x8689c8ed12f9fd90,0x03cebaff25b42304,0x707ba638e584e919,0x528275ef8087be41,0xb926186a81d14688,0xd187c94004faff3e,0x0122e824fb83ce0a]);
+const BW6_761_R: U768Repr = U768Repr([0x0202ffffffff85d5,0x5a5826358fff8ce7,0x9e996e43827faade,0xda6aff320ee47df4,0xece9cb3e1d94b80b,0xc0e667a25248240b,0xa74da5bfdcad3905,0x2352e7fe462f2103,0x7b56588008b1c87c,0x45848a63e711022f,0xd7a81ebb9f65a9df,0x0051f77ef127e87d]);
+const BW6_761_R2: U768Repr = U768Repr([0xc686392d2d1fa659,0x7b14c9b2f79484ab,0x7fa1e825c1d2b459,0xd6ec28f848329d88,0x4afb427b73a1ed40,0x972c69400d5930ae,0x2c7a26bf8c995976,0xac52e458c6e57af9,0xac731bfa0c536dfe,0x121e5c630b103f50,0x8f1b0953b886cda4,0x00ad253c2da8d807]);
+const BW6_761_MONT_INV: u64 = 0x0a5593568fa798dd;
+
+pub const BW6_761_FIELD: PrimeField<U768Repr> = PrimeField::<U768Repr> {
+    mont_power: 768,
+    modulus_bits: 761,
+    modulus: BW6_761_MODULUS,
+    mont_r: BW6_761_R,
+    mont_r2: BW6_761_R2,
+    mont_inv: BW6_761_MONT_INV,
+};
+
+const BW6_761_FP_NON_RESIDUE: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xe12e00000001e9c2,0x63c1e3faa001cd69,0xb1b4384fcbe29cf6,0xc79630bc713d5a1d,0x30127ac071851e2d,0x0979f350dcd36af1,0x6a66defed8b361f2,0x53abac78b24d4e23,0xb7ab89dede485a92,0x5c3a0745675e8452,0x446f17918c5f5700,0x00fdf24e3267fa1e]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_FP_ZERO: decl_fp!(U768Repr) = repr_into_fp!(
+    REPR_ZERO, 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_FP_ONE: decl_fp!(U768Repr) = repr_into_fp!(
+    BW6_761_R, 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C1_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x0202ffffffff85d5,0x5a5826358fff8ce7,0x9e996e43827faade,0xda6aff320ee47df4,0xece9cb3e1d94b80b,0xc0e667a25248240b,0xa74da5bfdcad3905,0x2352e7fe462f2103,0x7b56588008b1c87c,0x45848a63e711022f,0xd7a81ebb9f65a9df,0x0051f77ef127e87d]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C1_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x7f96b51bd840c549,0xd59782096496171f,0x49b046fd9ce14bbc,0x4b6163bba7527a56,0xef6c92fb771d59f1,0x0425bedbac1dfdc7,0xd3ac39de759c0ffd,0x9f43ed0e063a81d0,0x5bd7d20b4f9a3ce2,0x0411f03c36cf5c3c,0x2d658fd49661c472,0x01100249ae760b93]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C1_2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x67a04ae427bfb5f8,0x9d32d491eb6a5cff,0x43d03c1cb68051d4,0x0b75ca96f69859a5,0x0763497f5325ec60,0x48076b5c278dd94d,0x8ca3965ff91efd06,0x1e6077657ea02f5d,0xcdd6c153a8c37724,0x28b5b634e5c22ea4,0x9e01e3efd42e902c,0x00e3d6815769a804]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C2_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x0202ffffffff85d5,0x5a5826358fff8ce7,0x9e996e43827faade,0xda6aff320ee47df4,0xece9cb3e1d94b80b,0xc0e667a25248240b,0xa74da5bfdcad3905,0x2352e7fe462f2103,0x7b56588008b1c87c,0x45848a63e711022f,0xd7a81ebb9f65a9df,0x0051f77ef127e87d]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C2_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x67a04ae427bfb5f8,0x9d32d491eb6a5cff,0x43d03c1cb68051d4,0x0b75ca96f69859a5,0x0763497f5325ec60,0x48076b5c278dd94d,0x8ca3965ff91efd06,0x1e6077657ea02f5d,0xcdd6c153a8c37724,0x28b5b634e5c22ea4,0x9e01e3efd42e902c,0x00e3d6815769a804]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP3_FROB_C2_2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x7f96b51bd840c549,0xd59782096496171f,0x49b046fd9ce14bbc,0x4b6163bba7527a56,0xef6c92fb771d59f1,0x0425bedbac1dfdc7,0xd3ac39de759c0ffd,0x9f43ed0e063a81d0,0x5bd7d20b4f9a3ce2,0x0411f03c36cf5c3c,0x2d658fd49661c472,0x01100249ae760b93]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_EXTENSION_3_FIELD: Extension3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Extension3::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &BW6_761_FIELD,
+        non_residue: BW6_761_FP_NON_RESIDUE,
+        frobenius_coeffs_c1: [BW6_761_FP3_FROB_C1_0, BW6_761_FP3_FROB_C1_1, BW6_761_FP3_FROB_C1_2],
+        frobenius_coeffs_c2: [BW6_761_FP3_FROB_C2_0, BW6_761_FP3_FROB_C2_1, BW6_761_FP3_FROB_C2_2],
+        frobenius_coeffs_are_calculated: true
+    };
+
+const BW6_761_FP3_ZERO: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: BW6_761_FP_ZERO,
+        c1: BW6_761_FP_ZERO,
+        c2: BW6_761_FP_ZERO,
+        extension_field: &BW6_761_EXTENSION_3_FIELD
+    };
+
+const BW6_761_FP6_FROB_C1_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x0202ffffffff85d5,0x5a5826358fff8ce7,0x9e996e43827faade,0xda6aff320ee47df4,0xece9cb3e1d94b80b,0xc0e667a25248240b,0xa74da5bfdcad3905,0x2352e7fe462f2103,0x7b56588008b1c87c,0x45848a63e711022f,0xd7a81ebb9f65a9df,0x0051f77ef127e87d]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP6_FROB_C1_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x8cfcb51bd8404a93,0x495e69d68495a383,0xd23cbc9234705263,0x8d2b4c2b5fcf4f52,0x6a798a5d20c612ce,0x3e825d90eb6c2443,0x772b249f2c9525fe,0x521b2ed366e4b9bb,0x84abb49bd7c4471d,0x907062359c0f17e3,0x3385e55030cc6f12,0x003f11a3a41a2606]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP6_FROB_C1_2: decl_fp!(U768Repr) = BW6_761_FP_ZERO;
+const BW6_761_FP6_FROB_C1_3: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xf29a000000007ab6,0x8c391832e000739b,0x77738a6b6870f959,0xbe36179047832b03,0x84f3089e56574722,0xc5a3614ac0b1d984,0x5c81153f4906e9fe,0x4d28be3a9f55c815,0xd72c1d6f77d5f5c5,0x73a18e069ac04458,0xf9dfaa846595555f,0x00d0f0a60a5be58c]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_FP6_FROB_C1_4: decl_fp!(U768Repr) = BW6_761_FP_ZERO;
+const BW6_761_FP6_FROB_C1_5: decl_fp!(U768Repr) = BW6_761_FP_ZERO;
+
+pub const BW6_761_EXTENSION_6_FIELD: Extension2Over3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Extension2Over3::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &BW6_761_EXTENSION_3_FIELD,
+        non_residue: BW6_761_FP3_ZERO,
+        frobenius_coeffs_c1: [
+            BW6_761_FP6_FROB_C1_0, BW6_761_FP6_FROB_C1_1, BW6_761_FP6_FROB_C1_2,
+            BW6_761_FP6_FROB_C1_3, BW6_761_FP6_FROB_C1_4, BW6_761_FP6_FROB_C1_5
+        ],
+        frobenius_coeffs_are_calculated: true
+    };
+
+pub const BW6_761_SUBGROUP_ORDER: [u64; 6] = [
+    0x8508c00000000001,
+    0x170b5d4430000000,
+    0x1ef3622fba094800,
+    0x1a22d9f300f5138f,
+    0xc63b05c06ca1493b,
+    0x01ae3a4617c510ea
+];
+
+const BW6_761_ATE_LOOP_COUNT_1: [u64; 1] = [0x8508c00000000002];
+const BW6_761_ATE_LOOP_COUNT_1_IS_NEGATIVE: bool = false;
+const BW6_761_ATE_LOOP_COUNT_2: [u64; 3] = [0xffffffffffffffff, 0x8a442f991fffffff, 0x23ed1347970dec00];
+const BW6_761_ATE_LOOP_COUNT_2_IS_NEGATIVE: bool = false;
+
+const BW6_761_FINAL_EXP_W0: [u64; 11] = [0x6f9440000000008c, 0x1aff40fcf0000082, 0x9521646d73808c51, 0x3ba806d298c79fc5, 0xb521a3d9309c6dd0, 0x824cd7cfb1e8685a, 0xa7f6ef02c228c497, 0xa311dc0a5ef6ff10, 0x96a147eaf584608d, 0x828e2c6f9f4f1494, 0x068f6427062e1b0b];
+const BW6_761_FINAL_EXP_W1: [u64; 6] = [0x3de5800000000089, 0x832ba4061000003b, 0xc61c554757551c0c, 0xc856a0853c9db94c, 0x2c77d5ac34cb12ef, 0xad1972339049ce76];
+const BW6_761_FINAL_EXP_W0_IS_NEGATIVE: bool = false;
+
+pub const BW6_761_B_FOR_G1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xf29a000000007ab6,0x8c391832e000739b,0x77738a6b6870f959,0xbe36179047832b03,0x84f3089e56574722,0xc5a3614ac0b1d984,0x5c81153f4906e9fe,0x4d28be3a9f55c815,0xd72c1d6f77d5f5c5,0x73a18e069ac04458,0xf9dfaa846595555f,0x00d0f0a60a5be58c]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_B_FOR_G2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x136efffffffe16c9,0x82cf5a6dcffe3319,0x6458c05f1f0e0741,0xd10ae605e52a4eda,0x41ca591c0266e100,0x7d0fd59c3626929f,0x9967dc004d00c112,0x1ccff9c033379af5,0x9ad6ec10a23f63af,0x5cec11251a72c235,0x8d18b1ae789ba83e,0x0024f5d6c91bd3ec]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_G1_CURVE_PARAMETERS: CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>> = 
+    CurveOverFpParameters::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &BW6_761_FIELD
+    };
+
+pub const BW6_761_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> {
+        a: BW6_761_FP_ZERO,
+        b: BW6_761_B_FOR_G1,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &BW6_761_SUBGROUP_ORDER,
+        params: &BW6_761_G1_CURVE_PARAMETERS
+    };
+
+pub const BW6_761_G2_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> {
+        a: BW6_761_FP_ZERO,
+        b: BW6_761_B_FOR_G2,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &BW6_761_SUBGROUP_ORDER,
+        params: &BW6_761_G1_CURVE_PARAMETERS
+    };
+
+const BW6_761_G1_GENERATOR_X: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xd6e42d7614c2d770,0x4bb886eddbc3fc21,0x64648b044098b4d2,0x1a585c895a422985,0xf1a9ac17cf8685c9,0x352785830727aea5,0xddf8cb12306266fe,0x6913b4bfbc9e949a,0x3a4b78d67ba5f6ab,0x0f481c06a8d02a04,0x91d4e7365c43edac,0x00f4d17cd48beca5]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_G1_GENERATOR_Y: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x97e805c4bd16411f,0x870d844e1ee6dd08,0x1eba7a37cb9eab4d,0xd544c4df10b9889a,0x8fe37f21a33897be,0xe9bf99a43a0885d2,0xd7ee0c9e273de139,0xaa6a9ec7a38dd791,0x8f95d3fcf765da8e,0x42326e7db7357c99,0xe217e407e218695f,0x009d1eb23b7cf684]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_G2_GENERATOR_X: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x3d902a84cd9f4f78,0x864e451b8a9c05dd,0xc2b3c0d6646c5673,0x17a7682def1ecb9d,0xbe31a1e0fb768fe3,0x4df125e09b92d1a6,0x0943fce635b02ee9,0xffc8e7ad0605e780,0x8165c00a39341e95,0x8ccc2ae90a0f094f,0x73a8b8cc0ad09e0c,0x011027e203edd9f4]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+const BW6_761_G2_GENERATOR_Y: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x9a159be4e773f67c,0x6b957244aa8f4e6b,0xa27b70c9c945a38c,0xacb6a09fda11d0ab,0x3abbdaa9bb6b1291,0xdbdf642af5694c36,0xb6360bb9560b369f,0xac0bd1e822b8d6da,0xfa355d17afe6945f,0x8d6a0fc1fbcad35e,0x72a63c7874409840,0x0114976e5b0db280]), 
+    U768Repr,
+    BW6_761_FIELD
+);
+
+pub const BW6_761_G1_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    CurvePoint::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> 
+    {
+        curve: &BW6_761_G1_CURVE,
+        x: BW6_761_G1_GENERATOR_X,
+        y: BW6_761_G1_GENERATOR_Y,
+        z: BW6_761_FP_ONE,
+    };
+
+pub const BW6_761_G2_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    CurvePoint::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> 
+    {
+        curve: &BW6_761_G2_CURVE,
+        x: BW6_761_G2_GENERATOR_X,
+        y: BW6_761_G2_GENERATOR_Y,
+        z: BW6_761_FP_ONE,
+    };
+
+pub const BW6_761_PAIRING_ENGINE: Bw6Instance<
+    'static, 
+    U768Repr, 
+    PrimeField<U768Repr>, 
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>,
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>
+> = Bw6Instance::<
+    'static, 
+    U768Repr, 
+    PrimeField<U768Repr>, 
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>,
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>
+> {
+    ate_loop_count_1: &BW6_761_ATE_LOOP_COUNT_1,
+    ate_loop_count_1_is_negative: BW6_761_ATE_LOOP_COUNT_1_IS_NEGATIVE,
+    ate_loop_count_2: &BW6_761_ATE_LOOP_COUNT_2,
+    ate_loop_count_2_is_negative: BW6_761_ATE_LOOP_COUNT_2_IS_NEGATIVE,
+    exp_w0: &BW6_761_FINAL_EXP_W0,
+    exp_w1: &BW6_761_FINAL_EXP_W1,
+    exp_w0_is_negative: BW6_761_FINAL_EXP_W0_IS_NEGATIVE,
+    twist_type: TwistType::M,
+    base_field: &BW6_761_FIELD,
+    curve: &BW6_761_G1_CURVE,
+    curve_twist: &BW6_761_G2_CURVE,
+    fp3_extension: &BW6_761_EXTENSION_3_FIELD,
+    fp6_extension: &BW6_761_EXTENSION_6_FIELD,
+    prefer_naf_1: false,
+    ate_loop_count_1_naf: Vec::new(),
+    prefer_naf_2: false,
+    ate_loop_count_2_naf: Vec::new()
+};
+
+#[cfg(test)]
+mod test {
+    use crate::traits::FieldElement;
+    use super::*;
+
+    #[test]
+    fn test_engine_bilinearity() {
+        use crate::weierstrass::Group;
+        use crate::pairings::PairingEngine;
+
+        let p = BW6_761_G1_GENERATOR.clone();
+        let q = BW6_761_G2_GENERATOR.clone();
+
+        assert!(p.is_on_curve());
+        assert!(q.is_on_curve());
+        assert!(p.check_correct_subgroup());
+        assert!(q.check_correct_subgroup());
+
+        let mut p2 = p.mul(vec![12345678]);
+        p2.normalize();
+
+        let mut q2 = q.mul(vec![12345678]);
+        q2.normalize();
+
+        let ans1 = BW6_761_PAIRING_ENGINE.pair(&[p.clone()], &[q2]).unwrap();
+        let ans2 = BW6_761_PAIRING_ENGINE.pair(&[p2], &[q.clone()]).unwrap();
+        let ans3 = BW6_761_PAIRING_ENGINE.pair(&[p], &[q]).unwrap();
+        let ans3 = ans3.pow(&vec![12345678]);
+
+        assert!(ans1 == ans2);
+        assert!(ans1 == ans3);
+    }
+}
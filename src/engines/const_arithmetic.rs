@@ -0,0 +1,659 @@
+//! Compile-time arithmetic used by `define_bls12_engine!` to derive Montgomery
+//! constants, Frobenius coefficients and curve points from human-readable parameters.
+//!
+//! Everything here is `const fn` and works over little-endian limb arrays. Invalid
+//! parameters (malformed numbers, values above the modulus, a modulus that is not
+//! 1 mod 6) panic, which is a compilation error when evaluated in a `const` item.
+//! This code is not constant time and is not meant for runtime use, except for the
+//! pairing family checks that only look at public curve parameters.
+
+/// Largest number of limbs of a parsed integer (same as `MaxFieldUint`)
+pub const MAX_LIMBS: usize = 16;
+
+const fn digit_value(digit: u8, radix: u64) -> u64 {
+    let value = match digit {
+        b'0'..=b'9' => (digit - b'0') as u64,
+        b'a'..=b'f' => (digit - b'a') as u64 + 10,
+        b'A'..=b'F' => (digit - b'A') as u64 + 10,
+        _ => panic!("invalid digit in a number"),
+    };
+    if value >= radix {
+        panic!("invalid digit in a number");
+    }
+
+    value
+}
+
+/// Parses a decimal or `0x`-prefixed hex string into `N` limbs. Panics on overflow
+pub const fn parse_limbs<const N: usize>(value: &str) -> [u64; N] {
+    let bytes = value.as_bytes();
+    let (radix, mut i) = if bytes.len() > 2 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X') {
+        (16u64, 2)
+    } else {
+        (10u64, 0)
+    };
+    if i == bytes.len() {
+        panic!("empty number");
+    }
+
+    let mut result = [0u64; N];
+    while i < bytes.len() {
+        let mut carry = digit_value(bytes[i], radix) as u128;
+        let mut j = 0;
+        while j < N {
+            let t = (result[j] as u128) * (radix as u128) + carry;
+            result[j] = t as u64;
+            carry = t >> 64;
+            j += 1;
+        }
+        if carry != 0 {
+            panic!("number does not fit into limbs");
+        }
+        i += 1;
+    }
+
+    result
+}
+
+/// Number of limbs required to represent a decimal or hex string (at least one)
+pub const fn num_limbs(value: &str) -> usize {
+    let limbs = parse_limbs::<MAX_LIMBS>(value);
+    let mut len = MAX_LIMBS;
+    while len > 1 && limbs[len - 1] == 0 {
+        len -= 1;
+    }
+
+    len
+}
+
+/// Copies limbs into a wider (or equal) array
+pub const fn widen<const N: usize, const M: usize>(value: [u64; N]) -> [u64; M] {
+    if N > M {
+        panic!("can not widen into a shorter array");
+    }
+    let mut result = [0u64; M];
+    let mut i = 0;
+    while i < N {
+        result[i] = value[i];
+        i += 1;
+    }
+
+    result
+}
+
+pub const fn is_zero<const N: usize>(value: &[u64; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if value[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+pub const fn is_equal<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+const fn greater_or_equal<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+
+    true
+}
+
+const fn add_with_carry<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = [0u64; N];
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < N {
+        let t = (a[i] as u128) + (b[i] as u128) + (carry as u128);
+        result[i] = t as u64;
+        carry = (t >> 64) as u64;
+        i += 1;
+    }
+
+    (result, carry != 0)
+}
+
+const fn sub_with_borrow<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = [0u64; N];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < N {
+        let (t, b0) = a[i].overflowing_sub(b[i]);
+        let (t, b1) = t.overflowing_sub(borrow as u64);
+        result[i] = t;
+        borrow = b0 | b1;
+        i += 1;
+    }
+
+    (result, borrow)
+}
+
+/// Subtracts a small number, panics on underflow
+pub const fn sub_small<const N: usize>(a: &[u64; N], b: u64) -> [u64; N] {
+    let mut b_limbs = [0u64; N];
+    b_limbs[0] = b;
+    let (result, borrow) = sub_with_borrow(a, &b_limbs);
+    if borrow {
+        panic!("subtraction underflow");
+    }
+
+    result
+}
+
+/// Computes `a * m + c` for small `m` and `c`, panics on overflow
+pub const fn mul_small_add_small<const N: usize>(a: &[u64; N], m: u64, c: u64) -> [u64; N] {
+    let mut result = [0u64; N];
+    let mut carry = c;
+    let mut i = 0;
+    while i < N {
+        let (limb, next_carry) = mac_with_carry(0, a[i], m, carry);
+        result[i] = limb;
+        carry = next_carry;
+        i += 1;
+    }
+    if carry != 0 {
+        panic!("multiplication overflow");
+    }
+
+    result
+}
+
+/// Divides by a small number returning a quotient and a remainder
+pub const fn div_small<const N: usize>(a: &[u64; N], divisor: u64) -> ([u64; N], u64) {
+    let mut result = [0u64; N];
+    let mut remainder = 0u128;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        let t = (remainder << 64) | (a[i] as u128);
+        result[i] = (t / (divisor as u128)) as u64;
+        remainder = t % (divisor as u128);
+    }
+
+    (result, remainder as u64)
+}
+
+pub const fn num_bits<const N: usize>(value: &[u64; N]) -> usize {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if value[i] != 0 {
+            return i * 64 + 64 - value[i].leading_zeros() as usize;
+        }
+    }
+
+    0
+}
+
+#[inline(always)]
+const fn mac_with_carry(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Prime field parameters in Montgomery form with `R = 2^(64 * N)`
+#[derive(Clone, Copy)]
+pub struct ConstPrimeField<const N: usize> {
+    pub modulus: [u64; N],
+    pub mont_r: [u64; N],
+    pub mont_r2: [u64; N],
+    pub mont_inv: u64,
+    pub modulus_bits: usize,
+}
+
+impl<const N: usize> ConstPrimeField<N> {
+    pub const fn from_str(modulus: &str) -> Self {
+        Self::new(parse_limbs::<N>(modulus))
+    }
+
+    pub const fn new(modulus: [u64; N]) -> Self {
+        if modulus[0] & 1 == 0 {
+            panic!("modulus must be odd");
+        }
+        let modulus_bits = num_bits(&modulus);
+        if modulus_bits < 2 {
+            panic!("modulus is too small");
+        }
+
+        // -(modulus^-1) mod 2^64
+        let mut inv = 1u64;
+        let mut i = 0;
+        while i < 63 {
+            inv = inv.wrapping_mul(inv);
+            inv = inv.wrapping_mul(modulus[0]);
+            i += 1;
+        }
+        let mont_inv = inv.wrapping_neg();
+
+        let mut one = [0u64; N];
+        one[0] = 1;
+        let mont_r = Self::double_times(one, &modulus, 64 * N);
+        let mont_r2 = Self::double_times(mont_r, &modulus, 64 * N);
+
+        Self {
+            modulus,
+            mont_r,
+            mont_r2,
+            mont_inv,
+            modulus_bits,
+        }
+    }
+
+    const fn double_times(mut value: [u64; N], modulus: &[u64; N], times: usize) -> [u64; N] {
+        let mut i = 0;
+        while i < times {
+            let (doubled, carry) = add_with_carry(&value, &value);
+            value = doubled;
+            if carry || greater_or_equal(&value, modulus) {
+                value = sub_with_borrow(&value, modulus).0;
+            }
+            i += 1;
+        }
+
+        value
+    }
+
+    pub const fn zero(&self) -> [u64; N] {
+        [0u64; N]
+    }
+
+    pub const fn one(&self) -> [u64; N] {
+        self.mont_r
+    }
+
+    pub const fn add(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let (result, carry) = add_with_carry(a, b);
+        if carry || greater_or_equal(&result, &self.modulus) {
+            sub_with_borrow(&result, &self.modulus).0
+        } else {
+            result
+        }
+    }
+
+    pub const fn sub(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let (result, borrow) = sub_with_borrow(a, b);
+        if borrow {
+            add_with_carry(&result, &self.modulus).0
+        } else {
+            result
+        }
+    }
+
+    pub const fn negate(&self, a: &[u64; N]) -> [u64; N] {
+        if is_zero(a) {
+            *a
+        } else {
+            sub_with_borrow(&self.modulus, a).0
+        }
+    }
+
+    /// Montgomery multiplication (CIOS)
+    pub const fn mul(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let mut t = [0u64; N];
+        let mut t_high = 0u64;
+        let mut i = 0;
+        while i < N {
+            let mut carry = 0u64;
+            let mut j = 0;
+            while j < N {
+                let (value, c) = mac_with_carry(t[j], a[j], b[i], carry);
+                t[j] = value;
+                carry = c;
+                j += 1;
+            }
+            let (sum, overflow) = t_high.overflowing_add(carry);
+            t_high = sum;
+            let t_top = overflow as u64;
+
+            let m = t[0].wrapping_mul(self.mont_inv);
+            let (_, mut carry) = mac_with_carry(t[0], m, self.modulus[0], 0);
+            let mut j = 1;
+            while j < N {
+                let (value, c) = mac_with_carry(t[j], m, self.modulus[j], carry);
+                t[j - 1] = value;
+                carry = c;
+                j += 1;
+            }
+            let (sum, overflow) = t_high.overflowing_add(carry);
+            t[N - 1] = sum;
+            t_high = t_top + overflow as u64;
+            i += 1;
+        }
+
+        if t_high != 0 || greater_or_equal(&t, &self.modulus) {
+            t = sub_with_borrow(&t, &self.modulus).0;
+        }
+
+        t
+    }
+
+    pub const fn square(&self, a: &[u64; N]) -> [u64; N] {
+        self.mul(a, a)
+    }
+
+    pub const fn pow<const M: usize>(&self, base: &[u64; N], exponent: &[u64; M]) -> [u64; N] {
+        let mut result = self.one();
+        let mut i = num_bits(exponent);
+        while i > 0 {
+            i -= 1;
+            result = self.square(&result);
+            if (exponent[i / 64] >> (i % 64)) & 1 == 1 {
+                result = self.mul(&result, base);
+            }
+        }
+
+        result
+    }
+
+    pub const fn inverse(&self, a: &[u64; N]) -> [u64; N] {
+        if is_zero(a) {
+            panic!("zero has no inverse");
+        }
+        let exponent = sub_small(&self.modulus, 2);
+
+        self.pow(a, &exponent)
+    }
+
+    /// Converts a canonical value into Montgomery form
+    pub const fn into_montgomery(&self, value: &[u64; N]) -> [u64; N] {
+        if greater_or_equal(value, &self.modulus) {
+            panic!("value is not smaller than the modulus");
+        }
+
+        self.mul(value, &self.mont_r2)
+    }
+
+    /// Parses a decimal or hex string, optionally with a leading minus,
+    /// into Montgomery form
+    pub const fn element_from_str(&self, value: &str) -> [u64; N] {
+        let bytes = value.as_bytes();
+        if !bytes.is_empty() && bytes[0] == b'-' {
+            let (_, digits) = bytes.split_at(1);
+            let digits = match core::str::from_utf8(digits) {
+                Ok(digits) => digits,
+                Err(_) => panic!("invalid number"),
+            };
+
+            self.negate(&self.into_montgomery(&parse_limbs::<N>(digits)))
+        } else {
+            self.into_montgomery(&parse_limbs::<N>(value))
+        }
+    }
+}
+
+/// Element of Fp2 = Fp[u] / (u^2 - non_residue) in Montgomery form
+pub type ConstFp2<const N: usize> = ([u64; N], [u64; N]);
+
+/// Quadratic extension with the same non-residue convention as `Extension2`
+#[derive(Clone, Copy)]
+pub struct ConstExtension2<const N: usize> {
+    pub field: ConstPrimeField<N>,
+    pub non_residue: [u64; N],
+}
+
+impl<const N: usize> ConstExtension2<N> {
+    pub const fn zero(&self) -> ConstFp2<N> {
+        (self.field.zero(), self.field.zero())
+    }
+
+    pub const fn one(&self) -> ConstFp2<N> {
+        (self.field.one(), self.field.zero())
+    }
+
+    pub const fn mul(&self, a: &ConstFp2<N>, b: &ConstFp2<N>) -> ConstFp2<N> {
+        let f = &self.field;
+        let a0b0 = f.mul(&a.0, &b.0);
+        let a1b1 = f.mul(&a.1, &b.1);
+        let a0b1 = f.mul(&a.0, &b.1);
+        let a1b0 = f.mul(&a.1, &b.0);
+
+        (f.add(&a0b0, &f.mul(&a1b1, &self.non_residue)), f.add(&a0b1, &a1b0))
+    }
+
+    pub const fn square(&self, a: &ConstFp2<N>) -> ConstFp2<N> {
+        self.mul(a, a)
+    }
+
+    pub const fn mul_by_fp(&self, a: &ConstFp2<N>, b: &[u64; N]) -> ConstFp2<N> {
+        (self.field.mul(&a.0, b), self.field.mul(&a.1, b))
+    }
+
+    /// Raising into the power of modulus is a conjugation
+    pub const fn conjugate(&self, a: &ConstFp2<N>) -> ConstFp2<N> {
+        (a.0, self.field.negate(&a.1))
+    }
+
+    pub const fn pow<const M: usize>(&self, base: &ConstFp2<N>, exponent: &[u64; M]) -> ConstFp2<N> {
+        let mut result = self.one();
+        let mut i = num_bits(exponent);
+        while i > 0 {
+            i -= 1;
+            result = self.square(&result);
+            if (exponent[i / 64] >> (i % 64)) & 1 == 1 {
+                result = self.mul(&result, base);
+            }
+        }
+
+        result
+    }
+
+    pub const fn inverse(&self, a: &ConstFp2<N>) -> ConstFp2<N> {
+        let f = &self.field;
+        let norm = f.sub(&f.square(&a.0), &f.mul(&f.square(&a.1), &self.non_residue));
+        let norm_inv = f.inverse(&norm);
+
+        (f.mul(&a.0, &norm_inv), f.negate(&f.mul(&a.1, &norm_inv)))
+    }
+
+    /// Frobenius coefficients of Fp2 over Fp
+    pub const fn frobenius_coeffs_c1(&self) -> [[u64; N]; 2] {
+        let (power, _) = div_small(&self.field.modulus, 2);
+
+        [self.field.one(), self.field.pow(&self.non_residue, &power)]
+    }
+
+    /// `non_residue^((p - 1) / divisor)`, panics if `divisor` doesn't divide `p - 1`
+    pub const fn non_residue_in_p_minus_one_by(&self, non_residue: &ConstFp2<N>, divisor: u64) -> ConstFp2<N> {
+        let (power, remainder) = div_small(&sub_small(&self.field.modulus, 1), divisor);
+        if remainder != 0 {
+            panic!("divisor must divide modulus - 1");
+        }
+
+        self.pow(non_residue, &power)
+    }
+
+    /// Frobenius coefficients of Fp6 = Fp2[v] / (v^3 - non_residue) with the same
+    /// set of calculated powers as `Extension3Over2::calculate_frobenius_coeffs_optimized`
+    pub const fn fp6_frobenius_coeffs(&self, non_residue: &ConstFp2<N>) -> ([ConstFp2<N>; 6], [ConstFp2<N>; 6]) {
+        let f_0 = self.one();
+        let f_1 = self.non_residue_in_p_minus_one_by(non_residue, 3);
+        // non_residue^((p^2 - 1) / 3) = f_1^(p + 1)
+        let f_2 = self.mul(&self.conjugate(&f_1), &f_1);
+        let f_3 = self.mul(&self.conjugate(&f_2), &f_1);
+        let zero = self.zero();
+
+        (
+            [f_0, f_1, f_2, f_3, zero, zero],
+            [f_0, self.square(&f_1), self.square(&f_2), self.square(&f_3), zero, zero]
+        )
+    }
+
+    /// Frobenius coefficients of Fp12 = Fp6[w] / (w^2 - v) with the same
+    /// set of calculated powers as `Extension2Over3Over2::calculate_frobenius_coeffs_optimized`
+    pub const fn fp12_frobenius_coeffs(&self, non_residue: &ConstFp2<N>) -> [ConstFp2<N>; 12] {
+        let f_0 = self.one();
+        let f_1 = self.non_residue_in_p_minus_one_by(non_residue, 6);
+        // non_residue^((p^2 - 1) / 6) = f_1^(p + 1)
+        let f_2 = self.mul(&self.conjugate(&f_1), &f_1);
+        let f_3 = self.mul(&self.conjugate(&f_2), &f_1);
+        let f_6 = self.mul(&self.square(&f_2), &f_2);
+        let zero = self.zero();
+
+        [f_0, f_1, f_2, f_3, zero, zero, f_6, zero, zero, zero, zero, zero]
+    }
+}
+
+/// Width of integers in the pairing family checks, enough for `3 * modulus`
+const WIDE_LIMBS: usize = MAX_LIMBS + 1;
+
+macro_rules! some_or_false {
+    ($value:expr) => {
+        match $value {
+            Some(value) => value,
+            None => return false,
+        }
+    };
+}
+
+const fn wide_from_slice(value: &[u64]) -> Option<[u64; WIDE_LIMBS]> {
+    let mut result = [0u64; WIDE_LIMBS];
+    let mut i = 0;
+    while i < value.len() {
+        if i < WIDE_LIMBS {
+            result[i] = value[i];
+        } else if value[i] != 0 {
+            return None;
+        }
+        i += 1;
+    }
+
+    Some(result)
+}
+
+const fn wide_small(value: u64) -> [u64; WIDE_LIMBS] {
+    let mut result = [0u64; WIDE_LIMBS];
+    result[0] = value;
+
+    result
+}
+
+const fn checked_add<const N: usize>(a: &[u64; N], b: &[u64; N]) -> Option<[u64; N]> {
+    let (result, carry) = add_with_carry(a, b);
+    if carry {
+        return None;
+    }
+
+    Some(result)
+}
+
+const fn checked_sub<const N: usize>(a: &[u64; N], b: &[u64; N]) -> Option<[u64; N]> {
+    let (result, borrow) = sub_with_borrow(a, b);
+    if borrow {
+        return None;
+    }
+
+    Some(result)
+}
+
+const fn checked_mul<const N: usize>(a: &[u64; N], b: &[u64; N]) -> Option<[u64; N]> {
+    let mut result = [0u64; N];
+    let mut i = 0;
+    while i < N {
+        if a[i] != 0 {
+            let mut carry = 0u64;
+            let mut j = 0;
+            while j < N {
+                if i + j < N {
+                    let (limb, c) = mac_with_carry(result[i + j], a[i], b[j], carry);
+                    result[i + j] = limb;
+                    carry = c;
+                } else if b[j] != 0 || carry != 0 {
+                    return None;
+                }
+                j += 1;
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        i += 1;
+    }
+
+    Some(result)
+}
+
+/// Checks that the modulus is `(x - 1)^2 * (x^4 - x^2 + 1) / 3 + x` for a signed BLS12 parameter `x`.
+/// Works for both compile-time and runtime (public) parameters, returns `false` on overflow
+pub const fn is_bls12_modulus(modulus: &[u64], x: &[u64], x_is_negative: bool) -> bool {
+    let p = some_or_false!(wide_from_slice(modulus));
+    let x = some_or_false!(wide_from_slice(x));
+    if is_zero(&x) {
+        return false;
+    }
+    let one = wide_small(1);
+    let three = wide_small(3);
+
+    let x2 = some_or_false!(checked_mul(&x, &x));
+    let x4 = some_or_false!(checked_mul(&x2, &x2));
+    // x^4 >= x^2 for non-zero x
+    let r = some_or_false!(checked_sub(&x4, &x2));
+    let r = some_or_false!(checked_add(&r, &one));
+
+    // (x - 1)^2 with x = -|x| is (|x| + 1)^2
+    let x_minus_one = if x_is_negative {
+        some_or_false!(checked_add(&x, &one))
+    } else {
+        some_or_false!(checked_sub(&x, &one))
+    };
+    let x_minus_one_squared = some_or_false!(checked_mul(&x_minus_one, &x_minus_one));
+    let q = some_or_false!(checked_mul(&x_minus_one_squared, &r));
+
+    let three_p = some_or_false!(checked_mul(&p, &three));
+    let three_x = some_or_false!(checked_mul(&x, &three));
+    if x_is_negative {
+        is_equal(&some_or_false!(checked_add(&three_p, &three_x)), &q)
+    } else {
+        is_equal(&three_p, &some_or_false!(checked_add(&q, &three_x)))
+    }
+}
+
+/// Checks that the modulus is `36u^4 + 36u^3 + 24u^2 + 6u + 1` for a signed BN parameter `u`.
+/// Works for both compile-time and runtime (public) parameters, returns `false` on overflow
+pub const fn is_bn_modulus(modulus: &[u64], u: &[u64], u_is_negative: bool) -> bool {
+    let p = some_or_false!(wide_from_slice(modulus));
+    let u = some_or_false!(wide_from_slice(u));
+    if is_zero(&u) {
+        return false;
+    }
+    let one = wide_small(1);
+    let six = wide_small(6);
+    let twenty_four = wide_small(24);
+    let thirty_six = wide_small(36);
+
+    let u2 = some_or_false!(checked_mul(&u, &u));
+    let u3 = some_or_false!(checked_mul(&u2, &u));
+    let u4 = some_or_false!(checked_mul(&u2, &u2));
+
+    // terms of even powers of u do not depend on the sign
+    let even = some_or_false!(checked_mul(&u4, &thirty_six));
+    let even = some_or_false!(checked_add(&even, &some_or_false!(checked_mul(&u2, &twenty_four))));
+    let even = some_or_false!(checked_add(&even, &one));
+
+    let odd = some_or_false!(checked_mul(&u3, &thirty_six));
+    let odd = some_or_false!(checked_add(&odd, &some_or_false!(checked_mul(&u, &six))));
+
+    if u_is_negative {
+        is_equal(&some_or_false!(checked_add(&p, &odd)), &even)
+    } else {
+        is_equal(&p, &some_or_false!(checked_add(&even, &odd)))
+    }
+}
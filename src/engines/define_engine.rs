@@ -0,0 +1,379 @@
+/// Emits the Fp -> Fp2 -> Fp6 -> Fp12 tower, G1 and G2 curves with `a = 0` and generators
+/// shared by `define_bls12_engine!` and `define_bn_engine!`. Must be invoked inside a module
+#[allow(unused_macros)]
+macro_rules! define_fp12_tower {
+    (@twist_multiplier M, $extension:expr, $non_residue:expr) => {
+        $non_residue
+    };
+    (@twist_multiplier D, $extension:expr, $non_residue:expr) => {
+        $extension.inverse(&$non_residue)
+    };
+    (
+        repr: $repr:ident,
+        modulus: $modulus:expr,
+        subgroup_order: $subgroup_order:expr,
+        twist_type: $twist_type:ident,
+        b: $b:expr,
+        fp_non_residue: $fp_non_residue:expr,
+        fp2_non_residue: ($fp2_non_residue_c0:expr, $fp2_non_residue_c1:expr),
+        g1_generator: ($g1_x:expr, $g1_y:expr),
+        g2_generator: (($g2_x_c0:expr, $g2_x_c1:expr), ($g2_y_c0:expr, $g2_y_c1:expr))
+    ) => {
+        use $crate::field::*;
+        use $crate::representation::ElementRepr;
+        use $crate::fp::Fp;
+        use $crate::extension_towers::fp2::*;
+        use $crate::extension_towers::fp6_as_3_over_2::*;
+        use $crate::extension_towers::fp12_as_2_over3_over_2::*;
+        use $crate::weierstrass::*;
+        use $crate::weierstrass::curve::*;
+        use $crate::pairings::TwistType;
+        use $crate::integers::MaxFieldUint;
+        use $crate::engines::const_arithmetic::*;
+
+        const LIMBS: usize = <$repr as ElementRepr>::NUM_LIMBS;
+
+        type Repr = $repr;
+        type Field = PrimeField<$repr>;
+        type G1Parameters = CurveOverFpParameters<'static, Repr, Field>;
+        type G2Parameters = CurveOverFp2Parameters<'static, Repr, Field>;
+
+        const CONST_FIELD: ConstPrimeField<LIMBS> = ConstPrimeField::from_str($modulus);
+
+        const fn fp(repr: [u64; LIMBS]) -> Fp<'static, Repr, Field> {
+            Fp::<'static, Repr, Field> {
+                field: &FIELD,
+                repr: $repr(repr)
+            }
+        }
+
+        const fn fp_from_str(value: &str) -> Fp<'static, Repr, Field> {
+            fp(CONST_FIELD.element_from_str(value))
+        }
+
+        const fn fp2(value: ConstFp2<LIMBS>) -> Fp2<'static, Repr, Field> {
+            Fp2::<'static, Repr, Field> {
+                c0: fp(value.0),
+                c1: fp(value.1),
+                extension_field: &EXTENSION_2_FIELD
+            }
+        }
+
+        const fn fp2_from_str(c0: &str, c1: &str) -> Fp2<'static, Repr, Field> {
+            fp2((CONST_FIELD.element_from_str(c0), CONST_FIELD.element_from_str(c1)))
+        }
+
+        pub const MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(widen::<LIMBS, MAX_LIMBS>(CONST_FIELD.modulus));
+        pub const MODULUS: Repr = $repr(CONST_FIELD.modulus);
+
+        pub const FIELD: Field = PrimeField::<Repr> {
+            mont_power: (LIMBS * 64) as u64,
+            modulus_bits: CONST_FIELD.modulus_bits as u64,
+            modulus: MODULUS,
+            mont_r: $repr(CONST_FIELD.mont_r),
+            mont_r2: $repr(CONST_FIELD.mont_r2),
+            mont_inv: CONST_FIELD.mont_inv,
+        };
+
+        pub const FP_ZERO: Fp<'static, Repr, Field> = fp(CONST_FIELD.zero());
+        pub const FP_ONE: Fp<'static, Repr, Field> = fp(CONST_FIELD.one());
+
+        const CONST_EXTENSION_2: ConstExtension2<LIMBS> = ConstExtension2 {
+            field: CONST_FIELD,
+            non_residue: CONST_FIELD.element_from_str($fp_non_residue),
+        };
+        const FP2_FROBENIUS_COEFFS: [[u64; LIMBS]; 2] = CONST_EXTENSION_2.frobenius_coeffs_c1();
+
+        pub const EXTENSION_2_FIELD: Extension2<'static, Repr, Field> = Extension2::<'static, Repr, Field> {
+            field: &FIELD,
+            non_residue: fp(CONST_EXTENSION_2.non_residue),
+            frobenius_coeffs_c1: [fp(FP2_FROBENIUS_COEFFS[0]), fp(FP2_FROBENIUS_COEFFS[1])],
+            non_residue_mul_policy: if is_equal(&CONST_EXTENSION_2.non_residue, &CONST_FIELD.negate(&CONST_FIELD.one())) {
+                NonResidueMulPolicy::Negate
+            } else {
+                NonResidueMulPolicy::Full
+            },
+            frobenius_coeffs_are_calculated: true
+        };
+
+        pub const FP2_ZERO: Fp2<'static, Repr, Field> = fp2(CONST_EXTENSION_2.zero());
+        pub const FP2_ONE: Fp2<'static, Repr, Field> = fp2(CONST_EXTENSION_2.one());
+
+        pub const FP_NON_RESIDUE: Fp<'static, Repr, Field> = fp(CONST_EXTENSION_2.non_residue);
+        pub const FP2_NON_RESIDUE: Fp2<'static, Repr, Field> = fp2(CONST_FP2_NON_RESIDUE);
+
+        const CONST_FP2_NON_RESIDUE: ConstFp2<LIMBS> = (
+            CONST_FIELD.element_from_str($fp2_non_residue_c0),
+            CONST_FIELD.element_from_str($fp2_non_residue_c1)
+        );
+        const FP6_FROBENIUS_COEFFS: ([ConstFp2<LIMBS>; 6], [ConstFp2<LIMBS>; 6]) = CONST_EXTENSION_2.fp6_frobenius_coeffs(&CONST_FP2_NON_RESIDUE);
+        const FP12_FROBENIUS_COEFFS: [ConstFp2<LIMBS>; 12] = CONST_EXTENSION_2.fp12_frobenius_coeffs(&CONST_FP2_NON_RESIDUE);
+
+        const fn fp6_non_residue_mul_policy() -> NonResidueMulPolicyFp6 {
+            let one = CONST_FIELD.one();
+            let c0_is_zero = is_zero(&CONST_FP2_NON_RESIDUE.0);
+            let c0_is_one = is_equal(&CONST_FP2_NON_RESIDUE.0, &one);
+            let c1_is_one = is_equal(&CONST_FP2_NON_RESIDUE.1, &one);
+
+            if c0_is_zero && c1_is_one {
+                NonResidueMulPolicyFp6::ZeroOne
+            } else {
+                match (c0_is_one, c1_is_one) {
+                    (true, true) => NonResidueMulPolicyFp6::OneOne,
+                    (false, true) => NonResidueMulPolicyFp6::FullOne,
+                    (true, false) => NonResidueMulPolicyFp6::OneFull,
+                    (false, false) => NonResidueMulPolicyFp6::Full,
+                }
+            }
+        }
+
+        const fn fp2_array<const M: usize>(values: [ConstFp2<LIMBS>; M]) -> [Fp2<'static, Repr, Field>; M] {
+            let mut result = [FP2_ZERO; M];
+            let mut i = 0;
+            while i < M {
+                result[i] = fp2(values[i]);
+                i += 1;
+            }
+
+            result
+        }
+
+        pub const EXTENSION_6_FIELD: Extension3Over2<'static, Repr, Field> = Extension3Over2::<'static, Repr, Field> {
+            non_residue: fp2(CONST_FP2_NON_RESIDUE),
+            field: &EXTENSION_2_FIELD,
+            frobenius_coeffs_c1: fp2_array(FP6_FROBENIUS_COEFFS.0),
+            frobenius_coeffs_c2: fp2_array(FP6_FROBENIUS_COEFFS.1),
+            non_residue_mul_policy: fp6_non_residue_mul_policy(),
+            frobenius_coeffs_are_calculated: true
+        };
+
+        const FP6_ZERO: Fp6<'static, Repr, Field> = Fp6::<'static, Repr, Field> {
+            c0: FP2_ZERO,
+            c1: FP2_ZERO,
+            c2: FP2_ZERO,
+            extension_field: &EXTENSION_6_FIELD
+        };
+
+        pub const EXTENSION_12_FIELD: Extension2Over3Over2<'static, Repr, Field> = Extension2Over3Over2::<'static, Repr, Field> {
+            non_residue: FP6_ZERO,
+            field: &EXTENSION_6_FIELD,
+            frobenius_coeffs_c1: fp2_array(FP12_FROBENIUS_COEFFS),
+            frobenius_coeffs_are_calculated: true
+        };
+
+        const SUBGROUP_ORDER_LIMBS: usize = num_limbs($subgroup_order);
+        pub const SUBGROUP_ORDER: [u64; SUBGROUP_ORDER_LIMBS] = parse_limbs::<SUBGROUP_ORDER_LIMBS>($subgroup_order);
+
+        pub const B_FOR_G1: Fp<'static, Repr, Field> = fp_from_str($b);
+
+        // b * non_residue for M-twist and b / non_residue for D-twist
+        pub const B_FOR_G2: Fp2<'static, Repr, Field> = fp2(CONST_EXTENSION_2.mul_by_fp(
+            &define_fp12_tower!(@twist_multiplier $twist_type, CONST_EXTENSION_2, CONST_FP2_NON_RESIDUE),
+            &B_FOR_G1.repr.0
+        ));
+
+        pub const G1_CURVE_PARAMETERS: G1Parameters = CurveOverFpParameters::<'static, Repr, Field> {
+            field: &FIELD
+        };
+
+        pub const G2_CURVE_PARAMETERS: G2Parameters = CurveOverFp2Parameters::<'static, Repr, Field> {
+            field: &EXTENSION_2_FIELD
+        };
+
+        pub const G1_CURVE: WeierstrassCurve<'static, G1Parameters> = WeierstrassCurve::<'static, G1Parameters> {
+            a: FP_ZERO,
+            b: B_FOR_G1,
+            curve_type: CurveType::AIsZero,
+            subgroup_order_repr: &SUBGROUP_ORDER,
+            params: &G1_CURVE_PARAMETERS
+        };
+
+        pub const G2_CURVE: WeierstrassCurve<'static, G2Parameters> = WeierstrassCurve::<'static, G2Parameters> {
+            a: FP2_ZERO,
+            b: B_FOR_G2,
+            curve_type: CurveType::AIsZero,
+            subgroup_order_repr: &SUBGROUP_ORDER,
+            params: &G2_CURVE_PARAMETERS
+        };
+
+        pub const G1_GENERATOR: CurvePoint<'static, G1Parameters> = CurvePoint::<'static, G1Parameters> {
+            curve: &G1_CURVE,
+            x: fp_from_str($g1_x),
+            y: fp_from_str($g1_y),
+            z: FP_ONE,
+        };
+
+        pub const G2_GENERATOR: CurvePoint<'static, G2Parameters> = CurvePoint::<'static, G2Parameters> {
+            curve: &G2_CURVE,
+            x: fp2_from_str($g2_x_c0, $g2_x_c1),
+            y: fp2_from_str($g2_y_c0, $g2_y_c1),
+            z: FP2_ONE,
+        };
+    };
+}
+
+/// Defines a module with constant BLS12 field tower, curves, generators and a pairing engine
+/// from human-readable parameters. Montgomery constants, Frobenius coefficients,
+/// the twist `b` and Montgomery forms of all the elements are computed at compile time,
+/// so the emitted `const` items are the same as hand-written ones in e.g. `bls12_381.rs`,
+/// but without the engine name prefix (`FIELD`, `EXTENSION_2_FIELD`, `G1_CURVE`, `PAIRING_ENGINE`, ...).
+///
+/// Numbers are decimal or `0x`-prefixed hex strings, field elements may have a leading minus.
+/// Invalid parameters fail compilation.
+///
+/// ```ignore
+/// define_bls12_engine! {
+///     pub mod bls12_381_engine {
+///         repr: U384Repr,
+///         modulus: "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+///         subgroup_order: "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+///         x: "0xd201000000010000",
+///         x_is_negative: true,
+///         twist_type: M,
+///         b: "4",
+///         fp_non_residue: "-1",
+///         fp2_non_residue: ("1", "1"),
+///         g1_generator: ("0x17f1...", "0x08b3..."),
+///         g2_generator: (("0x024a...", "0x13e0..."), ("0x0ce5...", "0x0606...")),
+///     }
+/// }
+/// ```
+#[allow(unused_macros)]
+macro_rules! define_bls12_engine {
+    (
+        $vis:vis mod $name:ident {
+            repr: $repr:ident,
+            modulus: $modulus:expr,
+            subgroup_order: $subgroup_order:expr,
+            x: $x:expr,
+            x_is_negative: $x_is_negative:expr,
+            twist_type: $twist_type:ident,
+            b: $b:expr,
+            fp_non_residue: $fp_non_residue:expr,
+            fp2_non_residue: ($fp2_non_residue_c0:expr, $fp2_non_residue_c1:expr),
+            g1_generator: ($g1_x:expr, $g1_y:expr),
+            g2_generator: (($g2_x_c0:expr, $g2_x_c1:expr), ($g2_y_c0:expr, $g2_y_c1:expr)) $(,)*
+        }
+    ) => {
+        $vis mod $name {
+            #![allow(dead_code)]
+
+            define_fp12_tower! {
+                repr: $repr,
+                modulus: $modulus,
+                subgroup_order: $subgroup_order,
+                twist_type: $twist_type,
+                b: $b,
+                fp_non_residue: $fp_non_residue,
+                fp2_non_residue: ($fp2_non_residue_c0, $fp2_non_residue_c1),
+                g1_generator: ($g1_x, $g1_y),
+                g2_generator: (($g2_x_c0, $g2_x_c1), ($g2_y_c0, $g2_y_c1))
+            }
+
+            use $crate::pairings::bls12::Bls12Instance;
+
+            const X_LIMBS: usize = num_limbs($x);
+            pub const X: [u64; X_LIMBS] = parse_limbs::<X_LIMBS>($x);
+            pub const X_IS_NEGATIVE: bool = $x_is_negative;
+
+            pub const PAIRING_ENGINE: Bls12Instance<'static, Repr, Field, G1Parameters, G2Parameters> = Bls12Instance::<'static, Repr, Field, G1Parameters, G2Parameters> {
+                x: &X,
+                x_is_negative: X_IS_NEGATIVE,
+                twist_type: TwistType::$twist_type,
+                base_field: &FIELD,
+                curve: &G1_CURVE,
+                curve_twist: &G2_CURVE,
+                fp2_extension: &EXTENSION_2_FIELD,
+                fp6_extension: &EXTENSION_6_FIELD,
+                fp12_extension: &EXTENSION_12_FIELD,
+                prefer_naf: false,
+                x_naf: Vec::new(),
+                prefer_hht_final_exp: is_bls12_modulus(&MODULUS.0, &X, X_IS_NEGATIVE)
+            };
+        }
+    };
+}
+
+/// Same as `define_bls12_engine!` for BN curves, that are parametrized by `u` instead of `x`.
+/// `6u + 2` and `non_residue^((p - 1) / 2)` used by the Miller loop are computed at compile time.
+///
+/// ```ignore
+/// define_bn_engine! {
+///     pub mod bn254_engine {
+///         repr: U256Repr,
+///         modulus: "21888242871839275222246405745257275088696311157297823662689037894645226208583",
+///         subgroup_order: "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+///         u: "4965661367192848881",
+///         u_is_negative: false,
+///         twist_type: D,
+///         b: "3",
+///         fp_non_residue: "-1",
+///         fp2_non_residue: ("9", "1"),
+///         g1_generator: ("1", "2"),
+///         g2_generator: (("1085...", "1155..."), ("8495...", "4082...")),
+///     }
+/// }
+/// ```
+#[allow(unused_macros)]
+macro_rules! define_bn_engine {
+    (
+        $vis:vis mod $name:ident {
+            repr: $repr:ident,
+            modulus: $modulus:expr,
+            subgroup_order: $subgroup_order:expr,
+            u: $u:expr,
+            u_is_negative: $u_is_negative:expr,
+            twist_type: $twist_type:ident,
+            b: $b:expr,
+            fp_non_residue: $fp_non_residue:expr,
+            fp2_non_residue: ($fp2_non_residue_c0:expr, $fp2_non_residue_c1:expr),
+            g1_generator: ($g1_x:expr, $g1_y:expr),
+            g2_generator: (($g2_x_c0:expr, $g2_x_c1:expr), ($g2_y_c0:expr, $g2_y_c1:expr)) $(,)*
+        }
+    ) => {
+        $vis mod $name {
+            #![allow(dead_code)]
+
+            define_fp12_tower! {
+                repr: $repr,
+                modulus: $modulus,
+                subgroup_order: $subgroup_order,
+                twist_type: $twist_type,
+                b: $b,
+                fp_non_residue: $fp_non_residue,
+                fp2_non_residue: ($fp2_non_residue_c0, $fp2_non_residue_c1),
+                g1_generator: ($g1_x, $g1_y),
+                g2_generator: (($g2_x_c0, $g2_x_c1), ($g2_y_c0, $g2_y_c1))
+            }
+
+            use $crate::pairings::bn::BnInstance;
+
+            const U_LIMBS: usize = num_limbs($u);
+            pub const U: [u64; U_LIMBS] = parse_limbs::<U_LIMBS>($u);
+            pub const U_IS_NEGATIVE: bool = $u_is_negative;
+
+            // one more limb for 6u + 2 to never overflow
+            const SIX_U_PLUS_2_LIMBS: usize = U_LIMBS + 1;
+            pub const SIX_U_PLUS_2: [u64; SIX_U_PLUS_2_LIMBS] = mul_small_add_small(&widen::<U_LIMBS, SIX_U_PLUS_2_LIMBS>(U), 6, 2);
+
+            pub const FP2_NON_RESIDUE_IN_P_MINUS_ONE_OVER_TWO: Fp2<'static, Repr, Field> = fp2(CONST_EXTENSION_2.non_residue_in_p_minus_one_by(&CONST_FP2_NON_RESIDUE, 2));
+
+            pub const PAIRING_ENGINE: BnInstance<'static, Repr, Field, G1Parameters, G2Parameters> = BnInstance::<'static, Repr, Field, G1Parameters, G2Parameters> {
+                u: &U,
+                six_u_plus_2: &SIX_U_PLUS_2,
+                u_is_negative: U_IS_NEGATIVE,
+                twist_type: TwistType::$twist_type,
+                base_field: &FIELD,
+                curve: &G1_CURVE,
+                curve_twist: &G2_CURVE,
+                fp2_extension: &EXTENSION_2_FIELD,
+                fp6_extension: &EXTENSION_6_FIELD,
+                fp12_extension: &EXTENSION_12_FIELD,
+                non_residue_in_p_minus_one_over_2: FP2_NON_RESIDUE_IN_P_MINUS_ONE_OVER_TWO,
+                prefer_naf: false,
+                six_u_plus_2_naf: Vec::new(),
+                prefer_fuentes_final_exp: false
+            };
+        }
+    };
+}
@@ -0,0 +1,143 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::weierstrass::*;
+use crate::edwards::*;
+use crate::edwards::curve::*;
+use crate::integers::MaxFieldUint;
+
+// Jubjub is defined over the scalar field of BLS12-381
+
+const REPR_ZERO: U256Repr = U256Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
+
+pub const JUBJUB_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0xffffffff00000001,0x53bda402fffe5bfe,0x3339d80809a1d805,0x73eda753299d7d48,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000
+    ]
+);
+
+pub const JUBJUB_MODULUS: U256Repr = U256Repr([0xffffffff00000001,0x53bda402fffe5bfe,0x3339d80809a1d805,0x73eda753299d7d48]);
+const JUBJUB_R: U256Repr = U256Repr([0x00000001fffffffe,0x5884b7fa00034802,0x998c4fefecbc4ff5,0x1824b159acc5056f]);
+const JUBJUB_R2: U256Repr = U256Repr([0xc999e990f3f29c6d,0x2b6cedcb87925c23,0x05d314967254398f,0x0748d9d99f59ff11]);
+const JUBJUB_MONT_INV: u64 = 0xfffffffeffffffff;
+
+pub const JUBJUB_FIELD: PrimeField<U256Repr> = PrimeField::<U256Repr> {
+    mont_power: 256,
+    modulus_bits: 255,
+    modulus: JUBJUB_MODULUS,
+    mont_r: JUBJUB_R,
+    mont_r2: JUBJUB_R2,
+    mont_inv: JUBJUB_MONT_INV,
+};
+
+// prime order subgroup, cofactor is 8
+pub const JUBJUB_SUBGROUP_ORDER: [u64; 4] = [
+    0xd0970e5ed6f72cb7,
+    0xa6682093ccc81082,
+    0x06673b0101343b00,
+    0x0e7db4ea6533afa9
+];
+
+pub const JUBJUB_FP_ZERO: decl_fp!(U256Repr) = repr_into_fp!(
+    REPR_ZERO,
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+pub const JUBJUB_FP_ONE: decl_fp!(U256Repr) = repr_into_fp!(
+    JUBJUB_R,
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+// a = -1
+const JUBJUB_A: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xfffffffd00000003,0xfb38ec08fffb13fc,0x99ad88181ce5880f,0x5bc8f5f97cd877d8]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+// d = -(10240/10241)
+const JUBJUB_D: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x2a522455b974f6b0,0xfc6cc9ef0d9acab3,0x7a08fb94c27628d1,0x57f8f6a8fe0e262e]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+pub const JUBJUB_CURVE_PARAMETERS: CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>> =
+    CurveOverFpParameters::<'static, U256Repr, PrimeField<U256Repr>> {
+        field: &JUBJUB_FIELD
+    };
+
+pub const JUBJUB_CURVE: EdwardsCurve<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> =
+    EdwardsCurve::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> {
+        a: JUBJUB_A,
+        d: JUBJUB_D,
+        curve_type: EdwardsCurveType::AIsMinusOne,
+        subgroup_order_repr: &JUBJUB_SUBGROUP_ORDER,
+        params: &JUBJUB_CURVE_PARAMETERS
+    };
+
+const JUBJUB_GENERATOR_X: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x36d32c5896c2f1c5,0x56f8ca079b9bc5f6,0x98e8d1d8a8683c8d,0x015ad7c5e2e165cc]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+const JUBJUB_GENERATOR_Y: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0xfe0637f4ed09b09f,0x5ad237c95e4b2d2c,0xd943ede0fed9a448,0x5e2655b261c9d780]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+const JUBJUB_GENERATOR_T: decl_fp!(U256Repr) = repr_into_fp!(
+    U256Repr([0x33660004481a4091,0x1a05eb64d68ff1f2,0x00384ae179e23e96,0x70ffecca7de90513]),
+    U256Repr,
+    JUBJUB_FIELD
+);
+
+pub const JUBJUB_GENERATOR: EdwardsPoint<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>> =
+    EdwardsPoint::<'static, CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>>
+    {
+        curve: &JUBJUB_CURVE,
+        x: JUBJUB_GENERATOR_X,
+        y: JUBJUB_GENERATOR_Y,
+        t: JUBJUB_GENERATOR_T,
+        z: JUBJUB_FP_ONE,
+    };
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traits::FieldElement;
+
+    #[test]
+    fn test_generator_and_order() {
+        let g = JUBJUB_GENERATOR;
+        assert!(g.is_on_curve());
+        assert!(!g.is_zero());
+
+        let should_be_zero = g.mul(&JUBJUB_SUBGROUP_ORDER[..]);
+        assert!(should_be_zero.is_zero());
+        assert!(should_be_zero.is_on_curve());
+        assert!(g.check_correct_subgroup());
+    }
+
+    #[test]
+    fn test_field_constants() {
+        let field = field_from_modulus::<U256Repr>(&JUBJUB_MODULUS_UINT).unwrap();
+        assert_eq!(field.mont_r(), JUBJUB_FIELD.mont_r());
+        assert_eq!(field.mont_r2(), JUBJUB_FIELD.mont_r2());
+        assert_eq!(field.mont_inv(), JUBJUB_FIELD.mont_inv());
+
+        let params = CurveOverFpParameters::new(&field);
+        let curve = EdwardsCurve::new(&JUBJUB_SUBGROUP_ORDER[..], JUBJUB_A, JUBJUB_D, &params).unwrap();
+        assert_eq!(curve.curve_type, EdwardsCurveType::AIsMinusOne);
+
+        let mut minus_one = JUBJUB_FP_ONE;
+        minus_one.negate();
+        assert_eq!(minus_one, JUBJUB_A);
+    }
+}
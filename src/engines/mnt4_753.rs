@@ -0,0 +1,342 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::extension_towers::fp2::*;
+use crate::extension_towers::fp4_as_2_over_2::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::pairings::mnt4::*;
+use crate::integers::MaxFieldUint;
+
+const REPR_ZERO: U768Repr = U768Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
+
+pub const MNT4_753_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0x5e9063de245e8001,0xe39d54522cdd119f,0x638810719ac425f0,0x685acce9767254a4,
+        0xb80f0da5cb537e38,0xb117e776f218059d,0x99d124d9a15af79d,0x07fdb925e8a0ed8d,
+        0x5eb7e8f96c97d873,0xb7f997505b8fafed,0x10229022eee2cdad,0x0001c4c62d92c411,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000
+    ]
+);
+
+pub const MNT4_753_MODULUS: U768Repr = U768Repr([0x5e9063de245e8001,0xe39d54522cdd119f,0x638810719ac425f0,0x685acce9767254a4,0xb80f0da5cb537e38,0xb117e776f218059d,0x99d124d9a15af79d,0x07fdb925e8a0ed8d,0x5eb7e8f96c97d873,0xb7f997505b8fafed,0x10229022eee2cdad,0x0001c4c62d92c411]);
+const MNT4_753_R: U768Repr = U768Repr([0x98a8ecabd9dc6f42,0x91cd31c65a034686,0x97c3e4a0cd14572e,0x79589819c788b601,0xed269c942108976f,0x1e0f4d8acf031d68,0x320c3bb713338559,0x598b4302d2f00a62,0x4074c9cbfd8ca621,0x0fa47edb3865e88c,0x95455fb31ff9a195,0x00007b479ec8e242]);
+const MNT4_753_R2: U768Repr = U768Repr([0x84717088cfd190c8,0xc7d9ff8e7df03c0a,0xa24bea56242b3507,0xa896a656a0714c7d,0x80a46659ff6f3ddf,0x2f47839ef88d7ce8,0xa8c86d4604a3b597,0xe03c79cac4f7ef07,0x2505daf1f4a81245,0x8e4605754c381723,0xb081f15bcbfdacaf,0x00002a33e89cb485]);
+const MNT4_753_MONT_INV: u64 = 0xf2044cfbe45e7fff;
+
+pub const MNT4_753_FIELD: PrimeField<U768Repr> = PrimeField::<U768Repr> {
+    mont_power: 768,
+    modulus_bits: 753,
+    modulus: MNT4_753_MODULUS,
+    mont_r: MNT4_753_R,
+    mont_r2: MNT4_753_R2,
+    mont_inv: MNT4_753_MONT_INV,
+};
+
+const MNT4_753_FP_NON_RESIDUE: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xa4e2d91fa3162657,0xbc938a1c0b935ff7,0x8a5a6ad599bbfb8a,0xf06f5292be9a4027,0xe2c8ca944b7535ff,0x737f39a7ace06d7a,0xbd2b99bf158cdead,0x74193bb2fc4dbe53,0x29c6846f9a5ce658,0xa36dab30ca7dbf57,0x641e2bafd304cb88,0x0000f450877b312e]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+pub const MNT4_753_FP_ZERO: decl_fp!(U768Repr) = repr_into_fp!(
+    REPR_ZERO, 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+pub const MNT4_753_FP_ONE: decl_fp!(U768Repr) = repr_into_fp!(
+    MNT4_753_R, 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+const MNT4_753_FP2_FROB_C1_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x98a8ecabd9dc6f42,0x91cd31c65a034686,0x97c3e4a0cd14572e,0x79589819c788b601,0xed269c942108976f,0x1e0f4d8acf031d68,0x320c3bb713338559,0x598b4302d2f00a62,0x4074c9cbfd8ca621,0x0fa47edb3865e88c,0x95455fb31ff9a195,0x00007b479ec8e242]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+const MNT4_753_FP2_FROB_C1_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xc5e777324a8210bf,0x51d0228bd2d9cb18,0xcbc42bd0cdafcec2,0xef0234cfaee99ea2,0xcae87111aa4ae6c8,0x930899ec2314e834,0x67c4e9228e277244,0xae72762315b0e32b,0x1e431f2d6f0b3251,0xa85518752329c761,0x7add306fcee92c18,0x0001497e8ec9e1ce]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+pub const MNT4_753_EXTENSION_2_FIELD: Extension2<'static, U768Repr, PrimeField<U768Repr>> = 
+    Extension2::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &MNT4_753_FIELD,
+        non_residue: MNT4_753_FP_NON_RESIDUE,
+        frobenius_coeffs_c1: [MNT4_753_FP2_FROB_C1_0, MNT4_753_FP2_FROB_C1_1],
+        non_residue_mul_policy: NonResidueMulPolicy::Full,
+        frobenius_coeffs_are_calculated: true
+    };
+
+pub const MNT4_753_FP2_ZERO: decl_fp2!(U768Repr) = repr_into_fp2!(
+    MNT4_753_FP_ZERO, 
+    MNT4_753_FP_ZERO,
+    U768Repr,
+    MNT4_753_EXTENSION_2_FIELD
+);
+
+pub const MNT4_753_FP2_ONE: decl_fp2!(U768Repr) = repr_into_fp2!(
+    MNT4_753_FP_ONE, 
+    MNT4_753_FP_ZERO,
+    U768Repr,
+    MNT4_753_EXTENSION_2_FIELD
+);
+
+const MNT4_753_FP4_FROB_C1_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x98a8ecabd9dc6f42,0x91cd31c65a034686,0x97c3e4a0cd14572e,0x79589819c788b601,0xed269c942108976f,0x1e0f4d8acf031d68,0x320c3bb713338559,0x598b4302d2f00a62,0x4074c9cbfd8ca621,0x0fa47edb3865e88c,0x95455fb31ff9a195,0x00007b479ec8e242]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+const MNT4_753_FP4_FROB_C1_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x25eac118209420db,0x1e27fd3b9ead8a2c,0xc729995df0c17078,0x580f0950ee2d0f91,0x2c5f103cbe99cc71,0x1a3253f2c75a19d1,0xe6c206bb24a7f2be,0x19912707c043191a,0xbad8bbb7cc6e9c35,0x0a0181e8fd0ad0bd,0x10dbb90d8450ff4e,0x000094c44a4e9872]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+const MNT4_753_FP4_FROB_C1_2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xc5e777324a8210bf,0x51d0228bd2d9cb18,0xcbc42bd0cdafcec2,0xef0234cfaee99ea2,0xcae87111aa4ae6c8,0x930899ec2314e834,0x67c4e9228e277244,0xae72762315b0e32b,0x1e431f2d6f0b3251,0xa85518752329c761,0x7add306fcee92c18,0x0001497e8ec9e1ce]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+const MNT4_753_FP4_FROB_C1_3: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x38a5a2c603ca5f26,0xc57557168e2f8773,0x9c5e7713aa02b578,0x104bc39888454512,0x8baffd690cb9b1c7,0x96e593842abdebcc,0xb30f1e1e7cb304df,0xee6c921e285dd472,0xa3df2d41a0293c3d,0xadf815675e84df2f,0xff46d7156a91ce5f,0x00013001e3442b9e]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+pub const MNT4_753_EXTENSION_4_FIELD: Extension2Over2<'static, U768Repr, PrimeField<U768Repr>> = 
+    Extension2Over2::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &MNT4_753_EXTENSION_2_FIELD,
+        non_residue: MNT4_753_FP2_ZERO,
+        frobenius_coeffs_c1: [MNT4_753_FP4_FROB_C1_0, MNT4_753_FP4_FROB_C1_1, MNT4_753_FP4_FROB_C1_2, MNT4_753_FP4_FROB_C1_3],
+        frobenius_coeffs_are_calculated: true
+    };
+
+pub const MNT4_753_SUBGROUP_ORDER: [u64; 12] = [
+    0xd90776e240000001,
+    0x4ea099170fa13a4f,
+    0xd6c381bc3f005797,
+    0xb9dff97634993aa4,
+    0x3eebca9429212636,
+    0xb26c5c28c859a99b,
+    0x99d124d9a15af79d,
+    0x07fdb925e8a0ed8d,
+    0x5eb7e8f96c97d873,
+    0xb7f997505b8fafed,
+    0x10229022eee2cdad,
+    0x0001c4c62d92c411
+];
+
+const MNT4_753_X: [u64; 6] = [0x7a7713041ba18000, 0x6b0344c4e2c428b0, 0x733b714aa43c31a6, 0x51852c8cbe26e600, 0x86dcbcee5dcda7fe, 0x015474b1d641a3fd];
+const MNT4_753_X_IS_NEGATIVE: bool = true;
+const MNT4_753_FINAL_EXP_W0: [u64; 6] = [0x7a7713041ba17fff, 0x6b0344c4e2c428b0, 0x733b714aa43c31a6, 0x51852c8cbe26e600, 0x86dcbcee5dcda7fe, 0x015474b1d641a3fd];
+const MNT4_753_FINAL_EXP_W1: [u64; 1] = [0x0000000000000001];
+const MNT4_753_FINAL_EXP_W0_IS_NEGATIVE: bool = true;
+
+pub const MNT4_753_A_FOR_G1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x3151d957b3b8de84,0x239a638cb4068d0d,0x2f87c9419a28ae5d,0xf2b130338f116c03,0xda4d392842112ede,0x3c1e9b159e063ad1,0x6418776e26670ab2,0xb3168605a5e014c4,0x80e99397fb194c42,0x1f48fdb670cbd118,0x2a8abf663ff3432a,0x0000f68f3d91c485]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+pub const MNT4_753_B_FOR_G1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x25171e93506fb062,0xf4144ce402eb4f2a,0x122b02a617b0220b,0x18600804d0bcdc36,0x4adf7a3e41c5734c,0x082ed0a83dae31be,0x6626f99c37f11ab9,0x88696442003f5f9d,0x81a3dd7644095968,0x51b835e3477a5600,0x8d306223ebeb7591,0x00019f483a5ca38b]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+pub const MNT4_753_TWIST: decl_fp2!(U768Repr) = repr_into_fp2!(
+    MNT4_753_FP_ZERO, 
+    MNT4_753_FP_ONE,
+    U768Repr,
+    MNT4_753_EXTENSION_2_FIELD
+);
+
+pub const MNT4_753_A_FOR_G2: decl_fp2!(U768Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U768Repr([0xeb354e6121cdccad,0x9589bfe5ea49ae4f,0xb12cc53998b3d124,0x7883d83c06c22baa,0x0d828782cb96edc7,0x35e68bd867a8d558,0xe0860ea489bec5bd,0xe034be400ffa8f19,0xf4d51fe5c821f43d,0x8ee1bf11396bcec0,0xb819c73cb726c963,0x000023dae1639e4b]), 
+        U768Repr,
+        MNT4_753_FIELD
+    ), 
+    MNT4_753_FP_ZERO,
+    U768Repr,
+    MNT4_753_EXTENSION_2_FIELD
+);
+
+pub const MNT4_753_B_FOR_G2: decl_fp2!(U768Repr) = repr_into_fp2!(
+    MNT4_753_FP_ZERO, 
+    repr_into_fp!(
+        U768Repr([0xd1f842ef859c74ef,0x9d45480c3873434a,0xa5566d8d8d841941,0xc0f99a3682ad8bae,0xe4b39f099a706e70,0xce59a66ebad048e2,0x93fe1794e855b79e,0x957322b9044da5e8,0x836b3c49c9f33d5d,0x3ea13c16b209ced3,0x79f8ca52b73621ea,0x0001a2270165e15a]), 
+        U768Repr,
+        MNT4_753_FIELD
+    ),
+    U768Repr,
+    MNT4_753_EXTENSION_2_FIELD
+);
+
+pub const MNT4_753_G1_CURVE_PARAMETERS: CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>> = 
+    CurveOverFpParameters::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &MNT4_753_FIELD
+    };
+
+pub const MNT4_753_G2_CURVE_PARAMETERS: CurveOverFp2Parameters<'static, U768Repr, PrimeField<U768Repr>> = 
+    CurveOverFp2Parameters::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &MNT4_753_EXTENSION_2_FIELD
+    };
+
+pub const MNT4_753_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> {
+        a: MNT4_753_A_FOR_G1,
+        b: MNT4_753_B_FOR_G1,
+        curve_type: CurveType::Generic,
+        subgroup_order_repr: &MNT4_753_SUBGROUP_ORDER,
+        params: &MNT4_753_G1_CURVE_PARAMETERS
+    };
+
+pub const MNT4_753_G2_CURVE: WeierstrassCurve<'static, CurveOverFp2Parameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFp2Parameters<'static, U768Repr, PrimeField<U768Repr>>> {
+        a: MNT4_753_A_FOR_G2,
+        b: MNT4_753_B_FOR_G2,
+        curve_type: CurveType::Generic,
+        subgroup_order_repr: &MNT4_753_SUBGROUP_ORDER,
+        params: &MNT4_753_G2_CURVE_PARAMETERS
+    };
+
+const MNT4_753_G1_GENERATOR_X: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x7876ddd0a31812f5,0xad70b09b30117872,0xd374919a93b5f821,0x01753abb928ea144,0xa0b5381c7945ad4d,0x52c65e190710ecc7,0x1b65b218f0544643,0xb9c566e732f2a4c6,0x89783f5858bbd0a3,0x344f882291d1e7ab,0x110563e2b3d318e5,0x000174396501b715]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+const MNT4_753_G1_GENERATOR_Y: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x756119882ca43b9a,0xea084f28ce9d98d5,0x6457f624e76d3c61,0xef6495b725d2ab6a,0xd760a28a516b679d,0x301708ad41ccecda,0x4fa46169a601ddcf,0xb016504bf9291045,0x33190fed639f4ce7,0x94794376a0d73fb1,0x8d00fac5037b31cf,0x000073bbfddcc672]), 
+    U768Repr,
+    MNT4_753_FIELD
+);
+
+const MNT4_753_G2_GENERATOR_X: decl_fp2!(U768Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U768Repr([0x53a7c1095e030a7d,0xfe31519c2994fef4,0xaade83011fcb8fed,0x4f965f726102180f,0xd3e85dd41158539a,0x27fd1005318b2e85,0xfdc586a3d680f9e9,0x7e9699cd06a23ce6,0x8cc3e47379879603,0x72d44f80915e7e8e,0xe28cb3b3f69a8667,0x0000bb9909e3751f]), 
+        U768Repr,
+        MNT4_753_FIELD
+    ), 
+    repr_into_fp!(
+        U768Repr([0x7076e412eef30b3d,0x2d4e1d1212644717,0x9e7d4daf51a394da,0x0f05e65ae3347868,0x8422fdb50d41a4ad,0x0d2702a81c54b421,0x0d9b7e7823cadbcc,0x8197f1096e00ba0e,0xaa68e5a367101271,0x6a9f54d0c53b536b,0x519c8973b17d138c,0x00016648afea5952]), 
+        U768Repr,
+        MNT4_753_FIELD
+    ),
+    U768Repr,
+    MNT4_753_EXTENSION_2_FIELD
+);
+
+const MNT4_753_G2_GENERATOR_Y: decl_fp2!(U768Repr) = repr_into_fp2!(
+    repr_into_fp!(
+        U768Repr([0x837a3f1b9e67b8f3,0xeed98be67d96e1b6,0x6dc1aa6a4b818c87,0xce95e1537b709015,0xd959b1c92e03c372,0xb35fa5960491425d,0x76be4af8b4f1c086,0xa6ca930f6f3213a8,0xa7b7a80da0a3c858,0xe402b1a49132ec20,0x890dd46eeaac9c8d,0x0001633e9089494c]), 
+        U768Repr,
+        MNT4_753_FIELD
+    ), 
+    repr_into_fp!(
+        U768Repr([0x1b395d48a6aa87ec,0xcc05a1fc6effde0c,0x43f68f8a050d59a5,0x7ebf85c39415e378,0x5093d33580f8453c,0xdc08223827ccc1a5,0x2ddfcad12f797d22,0x310229b33ca92ae3,0x6518cbe993efdef2,0x2c0a6ffd8b0218f8,0x165864a10ed6113f,0x0000feb0111201b7]), 
+        U768Repr,
+        MNT4_753_FIELD
+    ),
+    U768Repr,
+    MNT4_753_EXTENSION_2_FIELD
+);
+
+pub const MNT4_753_G1_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    CurvePoint::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> 
+    {
+        curve: &MNT4_753_G1_CURVE,
+        x: MNT4_753_G1_GENERATOR_X,
+        y: MNT4_753_G1_GENERATOR_Y,
+        z: MNT4_753_FP_ONE,
+    };
+
+pub const MNT4_753_G2_GENERATOR: CurvePoint<'static, CurveOverFp2Parameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    CurvePoint::<'static, CurveOverFp2Parameters<'static, U768Repr, PrimeField<U768Repr>>> 
+    {
+        curve: &MNT4_753_G2_CURVE,
+        x: MNT4_753_G2_GENERATOR_X,
+        y: MNT4_753_G2_GENERATOR_Y,
+        z: MNT4_753_FP2_ONE,
+    };
+
+pub const MNT4_753_PAIRING_ENGINE: MNT4Instance<
+    'static, 
+    U768Repr, 
+    PrimeField<U768Repr>, 
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>,
+    CurveOverFp2Parameters<'static, U768Repr, PrimeField<U768Repr>>
+> = MNT4Instance::<
+    'static, 
+    U768Repr, 
+    PrimeField<U768Repr>, 
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>,
+    CurveOverFp2Parameters<'static, U768Repr, PrimeField<U768Repr>>
+> {
+    x: &MNT4_753_X,
+    x_is_negative: MNT4_753_X_IS_NEGATIVE,
+    exp_w0: &MNT4_753_FINAL_EXP_W0,
+    exp_w1: &MNT4_753_FINAL_EXP_W1,
+    exp_w0_is_negative: MNT4_753_FINAL_EXP_W0_IS_NEGATIVE,
+    base_field: &MNT4_753_FIELD,
+    curve: &MNT4_753_G1_CURVE,
+    curve_twist: &MNT4_753_G2_CURVE,
+    twist: MNT4_753_TWIST,
+    fp2_extension: &MNT4_753_EXTENSION_2_FIELD,
+    fp4_extension: &MNT4_753_EXTENSION_4_FIELD,
+    prefer_naf: false,
+    x_naf: Vec::new(),
+    twist_points_in_subgroup: false
+};
+
+#[cfg(test)]
+mod test {
+    use crate::traits::FieldElement;
+    use super::*;
+
+    #[test]
+    fn test_engine_bilinearity() {
+        use crate::weierstrass::Group;
+        use crate::pairings::PairingEngine;
+
+        let p = MNT4_753_G1_GENERATOR;
+        let q = MNT4_753_G2_GENERATOR;
+
+        assert!(p.is_on_curve());
+        assert!(q.is_on_curve());
+        assert!(p.check_correct_subgroup());
+        assert!(q.check_correct_subgroup());
+
+        let mut p2 = p.mul(vec![12345678]);
+        p2.normalize();
+
+        let mut q2 = q.mul(vec![12345678]);
+        q2.normalize();
+
+        let ans1 = MNT4_753_PAIRING_ENGINE.pair(std::slice::from_ref(&p), &[q2]).unwrap();
+        let ans2 = MNT4_753_PAIRING_ENGINE.pair(&[p2], std::slice::from_ref(&q)).unwrap();
+        let ans3 = MNT4_753_PAIRING_ENGINE.pair(&[p], &[q]).unwrap();
+        let ans3 = ans3.pow(vec![12345678]);
+
+        assert!(ans1 == ans2);
+        assert!(ans1 == ans3);
+    }
+
+    #[test]
+    fn test_precomputed_frobenius_coeffs() {
+        let mut ext_2 = Extension2::new(MNT4_753_FP_NON_RESIDUE);
+        ext_2.calculate_frobenius_coeffs(&MNT4_753_MODULUS_UINT).expect("must calculate frobenius for Fp2");
+        assert!(ext_2.frobenius_coeffs_c1 == MNT4_753_EXTENSION_2_FIELD.frobenius_coeffs_c1);
+
+        let mut ext_4 = Extension2Over2::new(MNT4_753_FP2_ZERO);
+        ext_4.calculate_frobenius_coeffs_optimized(&MNT4_753_MODULUS_UINT).expect("must calculate frobenius for Fp4");
+        assert!(ext_4.frobenius_coeffs_c1 == MNT4_753_EXTENSION_4_FIELD.frobenius_coeffs_c1);
+    }
+}
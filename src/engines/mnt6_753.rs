@@ -0,0 +1,392 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::extension_towers::fp3::*;
+use crate::extension_towers::fp6_as_2_over_3::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::pairings::mnt6::*;
+use crate::integers::MaxFieldUint;
+
+const REPR_ZERO: U768Repr = U768Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
+
+pub const MNT6_753_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0xd90776e240000001,0x4ea099170fa13a4f,0xd6c381bc3f005797,0xb9dff97634993aa4,
+        0x3eebca9429212636,0xb26c5c28c859a99b,0x99d124d9a15af79d,0x07fdb925e8a0ed8d,
+        0x5eb7e8f96c97d873,0xb7f997505b8fafed,0x10229022eee2cdad,0x0001c4c62d92c411,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000
+    ]
+);
+
+pub const MNT6_753_MODULUS: U768Repr = U768Repr([0xd90776e240000001,0x4ea099170fa13a4f,0xd6c381bc3f005797,0xb9dff97634993aa4,0x3eebca9429212636,0xb26c5c28c859a99b,0x99d124d9a15af79d,0x07fdb925e8a0ed8d,0x5eb7e8f96c97d873,0xb7f997505b8fafed,0x10229022eee2cdad,0x0001c4c62d92c411]);
+const MNT6_753_R: U768Repr = U768Repr([0xb99680147fff6f42,0x4eb16817b589cea8,0xa1ebd2d90c79e179,0x0f725caec549c0da,0xab0c4ee6d3e6dad4,0x9fbca908de0ccb62,0x320c3bb713338498,0x598b4302d2f00a62,0x4074c9cbfd8ca621,0x0fa47edb3865e88c,0x95455fb31ff9a195,0x00007b479ec8e242]);
+const MNT6_753_R2: U768Repr = U768Repr([0x3f9c69c7b7f4c8d1,0x70a50fa9ee48d127,0xcdbe6702009569cb,0x6bd8c6c6c49edc38,0x7955876cc35ee94e,0xc7285529be54a3f4,0xded52121ecec77cf,0x99be80f2ee12ee8e,0xc8a0ff01493bdcef,0xacc27988f3d9a316,0xd9e817a8fb44b3c9,0x000005b58037e0e4]);
+const MNT6_753_MONT_INV: u64 = 0xc90776e23fffffff;
+
+pub const MNT6_753_FIELD: PrimeField<U768Repr> = PrimeField::<U768Repr> {
+    mont_power: 768,
+    modulus_bits: 753,
+    modulus: MNT6_753_MODULUS,
+    mont_r: MNT6_753_R,
+    mont_r2: MNT6_753_R2,
+    mont_inv: MNT6_753_MONT_INV,
+};
+
+const MNT6_753_FP_NON_RESIDUE: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x4768931cfff9c7d4,0xc45e46d6ada96ca0,0x479b0bdb0b3c0107,0x362a089610f8d41b,0xdbafcec2c8a91aaf,0x78428b0ff9d96a06,0xf2e4472a9080c353,0xc9006ed33f0e971c,0x0794d9d10bdb7288,0x3c1e44cab5419e2c,0x49b5fc6c81f4560c,0x0001c287777c30ba]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+pub const MNT6_753_FP_ZERO: decl_fp!(U768Repr) = repr_into_fp!(
+    REPR_ZERO, 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+pub const MNT6_753_FP_ONE: decl_fp!(U768Repr) = repr_into_fp!(
+    MNT6_753_R, 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP3_FROB_C1_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xb99680147fff6f42,0x4eb16817b589cea8,0xa1ebd2d90c79e179,0x0f725caec549c0da,0xab0c4ee6d3e6dad4,0x9fbca908de0ccb62,0x320c3bb713338498,0x598b4302d2f00a62,0x4074c9cbfd8ca621,0x0fa47edb3865e88c,0x95455fb31ff9a195,0x00007b479ec8e242]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP3_FROB_C1_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x6b66f7b83f968680,0x1379b1ebf803e51e,0x9bb6f43b5282969c,0x3f64a98166c46a97,0x524a1cc56c78e977,0xf480725d1dc6e2f1,0xe660b05c89764d7d,0xe5b38512c92d9f5b,0xa75658e33e25f9f0,0xb4b96c948f0e9992,0xb8b087523d7db902,0x00011d5033223a5d]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP3_FROB_C1_2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xb409ff15806a0a3f,0xec757f1362138688,0x9920baa7e003df81,0x6b08f346088b0f32,0x41955ee7e8c161eb,0x1e2f40c2cc85fb47,0x816438c604b12587,0xc8bef1104c8343cf,0x76ecc64a30e53860,0xf39babe0941b2dce,0xc22ca91d916b7315,0x00002c2e5ba7a770]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP3_FROB_C2_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xb99680147fff6f42,0x4eb16817b589cea8,0xa1ebd2d90c79e179,0x0f725caec549c0da,0xab0c4ee6d3e6dad4,0x9fbca908de0ccb62,0x320c3bb713338498,0x598b4302d2f00a62,0x4074c9cbfd8ca621,0x0fa47edb3865e88c,0x95455fb31ff9a195,0x00007b479ec8e242]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP3_FROB_C2_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xb409ff15806a0a3f,0xec757f1362138688,0x9920baa7e003df81,0x6b08f346088b0f32,0x41955ee7e8c161eb,0x1e2f40c2cc85fb47,0x816438c604b12587,0xc8bef1104c8343cf,0x76ecc64a30e53860,0xf39babe0941b2dce,0xc22ca91d916b7315,0x00002c2e5ba7a770]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP3_FROB_C2_2: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x6b66f7b83f968680,0x1379b1ebf803e51e,0x9bb6f43b5282969c,0x3f64a98166c46a97,0x524a1cc56c78e977,0xf480725d1dc6e2f1,0xe660b05c89764d7d,0xe5b38512c92d9f5b,0xa75658e33e25f9f0,0xb4b96c948f0e9992,0xb8b087523d7db902,0x00011d5033223a5d]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+pub const MNT6_753_EXTENSION_3_FIELD: Extension3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Extension3::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &MNT6_753_FIELD,
+        non_residue: MNT6_753_FP_NON_RESIDUE,
+        frobenius_coeffs_c1: [MNT6_753_FP3_FROB_C1_0, MNT6_753_FP3_FROB_C1_1, MNT6_753_FP3_FROB_C1_2],
+        frobenius_coeffs_c2: [MNT6_753_FP3_FROB_C2_0, MNT6_753_FP3_FROB_C2_1, MNT6_753_FP3_FROB_C2_2],
+        frobenius_coeffs_are_calculated: true
+    };
+
+pub const MNT6_753_FP3_ZERO: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: MNT6_753_FP_ZERO,
+        c1: MNT6_753_FP_ZERO,
+        c2: MNT6_753_FP_ZERO,
+        extension_field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+pub const MNT6_753_FP3_ONE: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: MNT6_753_FP_ONE,
+        c1: MNT6_753_FP_ZERO,
+        c2: MNT6_753_FP_ZERO,
+        extension_field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+const MNT6_753_FP3_NON_RESIDUE: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: MNT6_753_FP_NON_RESIDUE,
+        c1: MNT6_753_FP_ZERO,
+        c2: MNT6_753_FP_ZERO,
+        extension_field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+const MNT6_753_FP6_FROB_C1_0: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xb99680147fff6f42,0x4eb16817b589cea8,0xa1ebd2d90c79e179,0x0f725caec549c0da,0xab0c4ee6d3e6dad4,0x9fbca908de0ccb62,0x320c3bb713338498,0x598b4302d2f00a62,0x4074c9cbfd8ca621,0x0fa47edb3865e88c,0x95455fb31ff9a195,0x00007b479ec8e242]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP6_FROB_C1_1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x24fd77ccbf95f5c2,0x622b1a03ad8db3c7,0x3da2c7145efc7815,0x4ed706302c0e2b72,0xfd566bac405fc44b,0x943d1b65fbd3ae53,0x186cec139ca9d216,0x3f3ec8159c1da9be,0xe7cb22af3bb2a012,0xc45deb6fc774821e,0x4df5e7055d775a97,0x00019897d1eb1ca0]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP6_FROB_C1_2: decl_fp!(U768Repr) = MNT6_753_FP_ZERO;
+
+const MNT6_753_FP6_FROB_C1_3: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x1f70f6cdc00090bf,0xffef30ff5a176ba7,0x34d7aee33286761d,0xaa6d9cc76f4f79ca,0x93df7bad553a4b62,0x12afb31fea4cde38,0x67c4e9228e277305,0xae72762315b0e32b,0x1e431f2d6f0b3251,0xa85518752329c761,0x7add306fcee92c18,0x0001497e8ec9e1ce]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_FP6_FROB_C1_4: decl_fp!(U768Repr) = MNT6_753_FP_ZERO;
+const MNT6_753_FP6_FROB_C1_5: decl_fp!(U768Repr) = MNT6_753_FP_ZERO;
+
+pub const MNT6_753_EXTENSION_6_FIELD: Extension2Over3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Extension2Over3::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &MNT6_753_EXTENSION_3_FIELD,
+        non_residue: MNT6_753_FP3_NON_RESIDUE,
+        frobenius_coeffs_c1: [
+            MNT6_753_FP6_FROB_C1_0, MNT6_753_FP6_FROB_C1_1, MNT6_753_FP6_FROB_C1_2,
+            MNT6_753_FP6_FROB_C1_3, MNT6_753_FP6_FROB_C1_4, MNT6_753_FP6_FROB_C1_5
+        ],
+        frobenius_coeffs_are_calculated: true
+    };
+
+pub const MNT6_753_SUBGROUP_ORDER: [u64; 12] = [
+    0x5e9063de245e8001,
+    0xe39d54522cdd119f,
+    0x638810719ac425f0,
+    0x685acce9767254a4,
+    0xb80f0da5cb537e38,
+    0xb117e776f218059d,
+    0x99d124d9a15af79d,
+    0x07fdb925e8a0ed8d,
+    0x5eb7e8f96c97d873,
+    0xb7f997505b8fafed,
+    0x10229022eee2cdad,
+    0x0001c4c62d92c411
+];
+
+const MNT6_753_X: [u64; 6] = [0x7a7713041ba18000, 0x6b0344c4e2c428b0, 0x733b714aa43c31a6, 0x51852c8cbe26e600, 0x86dcbcee5dcda7fe, 0x015474b1d641a3fd];
+const MNT6_753_X_IS_NEGATIVE: bool = false;
+const MNT6_753_FINAL_EXP_W0: [u64; 6] = [0x7a7713041ba18000, 0x6b0344c4e2c428b0, 0x733b714aa43c31a6, 0x51852c8cbe26e600, 0x86dcbcee5dcda7fe, 0x015474b1d641a3fd];
+const MNT6_753_FINAL_EXP_W1: [u64; 1] = [0x0000000000000001];
+const MNT6_753_FINAL_EXP_W0_IS_NEGATIVE: bool = false;
+
+pub const MNT6_753_A_FOR_G1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x4768931cfff9c7d4,0xc45e46d6ada96ca0,0x479b0bdb0b3c0107,0x362a089610f8d41b,0xdbafcec2c8a91aaf,0x78428b0ff9d96a06,0xf2e4472a9080c353,0xc9006ed33f0e971c,0x0794d9d10bdb7288,0x3c1e44cab5419e2c,0x49b5fc6c81f4560c,0x0001c287777c30ba]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+pub const MNT6_753_B_FOR_G1: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0x7a85e23c6984298a,0xb08f89f10deb6f43,0x1ff8d652bcdd2b90,0x6fe8b22127f7f097,0x57007df447700e3e,0x2f8aca277da9258d,0x14385d51ca5422fb,0x47d8f3de65c79d1d,0xfa9ac2fe4bd09711,0x9175a8b5ef915920,0xf83fa70b67d17c00,0x00010804126ecf16]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+pub const MNT6_753_TWIST: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: MNT6_753_FP_ZERO,
+        c1: MNT6_753_FP_ONE,
+        c2: MNT6_753_FP_ZERO,
+        extension_field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+pub const MNT6_753_A_FOR_G2: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: MNT6_753_FP_ZERO,
+        c1: MNT6_753_FP_ZERO,
+        c2: repr_into_fp!(
+            U768Repr([0x4768931cfff9c7d4,0xc45e46d6ada96ca0,0x479b0bdb0b3c0107,0x362a089610f8d41b,0xdbafcec2c8a91aaf,0x78428b0ff9d96a06,0xf2e4472a9080c353,0xc9006ed33f0e971c,0x0794d9d10bdb7288,0x3c1e44cab5419e2c,0x49b5fc6c81f4560c,0x0001c287777c30ba]), 
+            U768Repr,
+            MNT6_753_FIELD
+        ),
+        extension_field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+pub const MNT6_753_B_FOR_G2: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: repr_into_fp!(
+            U768Repr([0x2d93ef4b08adc8e8,0xbe6756d13b566a07,0x571c2b24a37ed1ab,0x73bfcea77c0ff6a1,0x437eaa061b09b766,0xdc6c86bdb42aa36f,0x43852569e97bb318,0xe66023aaebce2eee,0x8c56eb14b6676b0b,0xf034b3ef25e0b4da,0x49ecccabdcaf81f3,0x0000bb87b9524d96]), 
+            U768Repr,
+            MNT6_753_FIELD
+        ),
+        c1: MNT6_753_FP_ZERO,
+        c2: MNT6_753_FP_ZERO,
+        extension_field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+pub const MNT6_753_G1_CURVE_PARAMETERS: CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>> = 
+    CurveOverFpParameters::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &MNT6_753_FIELD
+    };
+
+pub const MNT6_753_G2_CURVE_PARAMETERS: CurveOverFp3Parameters<'static, U768Repr, PrimeField<U768Repr>> = 
+    CurveOverFp3Parameters::<'static, U768Repr, PrimeField<U768Repr>> {
+        field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+pub const MNT6_753_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> {
+        a: MNT6_753_A_FOR_G1,
+        b: MNT6_753_B_FOR_G1,
+        curve_type: CurveType::Generic,
+        subgroup_order_repr: &MNT6_753_SUBGROUP_ORDER,
+        params: &MNT6_753_G1_CURVE_PARAMETERS
+    };
+
+pub const MNT6_753_G2_CURVE: WeierstrassCurve<'static, CurveOverFp3Parameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    WeierstrassCurve::<'static, CurveOverFp3Parameters<'static, U768Repr, PrimeField<U768Repr>>> {
+        a: MNT6_753_A_FOR_G2,
+        b: MNT6_753_B_FOR_G2,
+        curve_type: CurveType::Generic,
+        subgroup_order_repr: &MNT6_753_SUBGROUP_ORDER,
+        params: &MNT6_753_G2_CURVE_PARAMETERS
+    };
+
+const MNT6_753_G1_GENERATOR_X: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xe3a856605652f582,0xea2ad6adb232d3cc,0x006917a62cf94e5d,0xb0cf88593f1f8d9c,0xdf4294279d098622,0xd1805f5f25762cae,0x0ce84eed156d448a,0x092939a0aaa29f11,0x4851f2bd56e6d412,0xd6a3f94887cc2c08,0xa3870d376b51b4de,0x00001262a0793b60]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_G1_GENERATOR_Y: decl_fp!(U768Repr) = repr_into_fp!(
+    U768Repr([0xa17be03d3de9993a,0xd23d47f834d6e6a7,0xc835b816dad2a400,0xb067d33661cbda12,0x34917ee69c71eaa3,0x69dcbdab27c304e6,0xeea1a2a6d6c76015,0x5e60253078c4f3e3,0x1eee46f45880e189,0xd8de606656eb5e1c,0xbf48f43a878dac3a,0x000037d7e759d51c]), 
+    U768Repr,
+    MNT6_753_FIELD
+);
+
+const MNT6_753_G2_GENERATOR_X: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: repr_into_fp!(
+            U768Repr([0x257f8bfaf8527bfb,0x6b73ddd8ac255a3f,0xde66e8481745de80,0xb380845711408e0a,0xad612b007da2b2b1,0x8e461290bd968200,0x113b2f5b3774e121,0xba58c0eb68cdb7db,0xba57a79a5801f08b,0x858d63359d5f32d6,0x48d2b4ee1ef62ddf,0x0000f353ebef0794]), 
+            U768Repr,
+            MNT6_753_FIELD
+        ),
+        c1: repr_into_fp!(
+            U768Repr([0x4ce69a9d1528dd16,0xea3a9cdc79ed8529,0x16a79912bca49f62,0xc6f60211f0a0f48b,0x595c4d51695e9b1c,0xcd6ae3180ea7a782,0x489ec1ab0925f6d2,0xcf3dac491584e7a8,0xfd5f0795c45342b3,0x8ce1c8fc4132e5d8,0xdacb51a133c8ff58,0x000073c25d60289f]), 
+            U768Repr,
+            MNT6_753_FIELD
+        ),
+        c2: repr_into_fp!(
+            U768Repr([0xe190d75a4a65cfc1,0x3d47cc3dc9ed7f5e,0x9f6f9de64dfdf529,0x7bdbf660f138ea77,0x40a7c1b90e90279b,0x89b2700e6fb91567,0x454f2b4a9c10dfdd,0x3ee76ade08becca7,0x8762dc0e1df51460,0x9e482485c5e6ab5c,0x3bc8be9bd9efae62,0x0000c3d404142e71]), 
+            U768Repr,
+            MNT6_753_FIELD
+        ),
+        extension_field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+const MNT6_753_G2_GENERATOR_Y: Fp3<'static, U768Repr, PrimeField<U768Repr>> = 
+    Fp3::<'static, U768Repr, PrimeField<U768Repr>> {
+        c0: repr_into_fp!(
+            U768Repr([0x2039e153a153a52b,0x5e6dc74f8a92de7a,0xd8f7c9d4c4d5eb43,0xb4f3460cf0ef3eb9,0xb1a5536e3fdf0549,0x21617550e9b529cc,0x664f4a26ea5e80f3,0x9f95c9a819e8cb2d,0xc1af1c581e87ca51,0x41011cdbc2744bd2,0xfbc9286b41fd2ca1,0x0000af870334b4ef]), 
+            U768Repr,
+            MNT6_753_FIELD
+        ),
+        c1: repr_into_fp!(
+            U768Repr([0x42816f610bf2e3df,0xddb6396d4a4ca53b,0xb2ce90f2094075df,0xdc51fc2747e6b36b,0xee2c941264e57e89,0x7d2106aa8bcf215a,0xe5b9576a6dde738a,0x0da780a7801e2d89,0xc704c6d795634c75,0xd572ee8d9343d5df,0xcf33b505a1c385eb,0x0001947fb8749784]), 
+            U768Repr,
+            MNT6_753_FIELD
+        ),
+        c2: repr_into_fp!(
+            U768Repr([0x4ca72cc223c3ff17,0x0e93d8bf30340f40,0x3bfe5c45074e30c2,0xd9f6ff52bd15527c,0x7e9ff4c2537f982f,0x66e97afeb86c5f6c,0x3c43ede08ef42d4d,0x6cb4c5e465365ddc,0x4501933f46aefc51,0x38dae063ab5da6b7,0x69c077bc86b64117,0x000009c4d50ced84]), 
+            U768Repr,
+            MNT6_753_FIELD
+        ),
+        extension_field: &MNT6_753_EXTENSION_3_FIELD
+    };
+
+pub const MNT6_753_G1_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    CurvePoint::<'static, CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>> 
+    {
+        curve: &MNT6_753_G1_CURVE,
+        x: MNT6_753_G1_GENERATOR_X,
+        y: MNT6_753_G1_GENERATOR_Y,
+        z: MNT6_753_FP_ONE,
+    };
+
+pub const MNT6_753_G2_GENERATOR: CurvePoint<'static, CurveOverFp3Parameters<'static, U768Repr, PrimeField<U768Repr>>> = 
+    CurvePoint::<'static, CurveOverFp3Parameters<'static, U768Repr, PrimeField<U768Repr>>> 
+    {
+        curve: &MNT6_753_G2_CURVE,
+        x: MNT6_753_G2_GENERATOR_X,
+        y: MNT6_753_G2_GENERATOR_Y,
+        z: MNT6_753_FP3_ONE,
+    };
+
+pub const MNT6_753_PAIRING_ENGINE: MNT6Instance<
+    'static, 
+    U768Repr, 
+    PrimeField<U768Repr>, 
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>,
+    CurveOverFp3Parameters<'static, U768Repr, PrimeField<U768Repr>>
+> = MNT6Instance::<
+    'static, 
+    U768Repr, 
+    PrimeField<U768Repr>, 
+    CurveOverFpParameters<'static, U768Repr, PrimeField<U768Repr>>,
+    CurveOverFp3Parameters<'static, U768Repr, PrimeField<U768Repr>>
+> {
+    x: &MNT6_753_X,
+    x_is_negative: MNT6_753_X_IS_NEGATIVE,
+    exp_w0: &MNT6_753_FINAL_EXP_W0,
+    exp_w1: &MNT6_753_FINAL_EXP_W1,
+    exp_w0_is_negative: MNT6_753_FINAL_EXP_W0_IS_NEGATIVE,
+    base_field: &MNT6_753_FIELD,
+    curve: &MNT6_753_G1_CURVE,
+    curve_twist: &MNT6_753_G2_CURVE,
+    twist: MNT6_753_TWIST,
+    fp3_extension: &MNT6_753_EXTENSION_3_FIELD,
+    fp6_extension: &MNT6_753_EXTENSION_6_FIELD,
+    prefer_naf: false,
+    x_naf: Vec::new(),
+    twist_points_in_subgroup: false
+};
+
+#[cfg(test)]
+mod test {
+    use crate::traits::FieldElement;
+    use super::*;
+
+    #[test]
+    fn test_engine_bilinearity() {
+        use crate::weierstrass::Group;
+        use crate::pairings::PairingEngine;
+
+        let p = MNT6_753_G1_GENERATOR;
+        let q = MNT6_753_G2_GENERATOR;
+
+        assert!(p.is_on_curve());
+        assert!(q.is_on_curve());
+        assert!(p.check_correct_subgroup());
+        assert!(q.check_correct_subgroup());
+
+        let mut p2 = p.mul(vec![12345678]);
+        p2.normalize();
+
+        let mut q2 = q.mul(vec![12345678]);
+        q2.normalize();
+
+        let ans1 = MNT6_753_PAIRING_ENGINE.pair(std::slice::from_ref(&p), &[q2]).unwrap();
+        let ans2 = MNT6_753_PAIRING_ENGINE.pair(&[p2], std::slice::from_ref(&q)).unwrap();
+        let ans3 = MNT6_753_PAIRING_ENGINE.pair(&[p], &[q]).unwrap();
+        let ans3 = ans3.pow(vec![12345678]);
+
+        assert!(ans1 == ans2);
+        assert!(ans1 == ans3);
+    }
+
+    #[test]
+    fn test_precomputed_frobenius_coeffs() {
+        let mut ext_3 = Extension3::new(MNT6_753_FP_NON_RESIDUE);
+        ext_3.calculate_frobenius_coeffs_optimized(&MNT6_753_MODULUS_UINT).expect("must calculate frobenius for Fp3");
+        assert!(ext_3.frobenius_coeffs_c1 == MNT6_753_EXTENSION_3_FIELD.frobenius_coeffs_c1);
+        assert!(ext_3.frobenius_coeffs_c2 == MNT6_753_EXTENSION_3_FIELD.frobenius_coeffs_c2);
+
+        let mut ext_6 = Extension2Over3::new(MNT6_753_FP3_NON_RESIDUE);
+        ext_6.calculate_frobenius_coeffs_optimized(&MNT6_753_MODULUS_UINT).expect("must calculate frobenius for Fp6");
+        assert!(ext_6.frobenius_coeffs_c1 == MNT6_753_EXTENSION_6_FIELD.frobenius_coeffs_c1);
+    }
+}
@@ -1,9 +1,21 @@
 #[macro_use]
 mod convenience;
+#[macro_use]
+mod define_engine;
+
+pub mod const_arithmetic;
 
 pub mod generic;
 pub mod bls12_381;
 pub mod bls12_377;
+pub mod bw6_761;
+pub mod mnt4_753;
+pub mod mnt6_753;
+pub mod secp256k1;
+pub mod secp256r1;
+pub mod jubjub;
+pub mod baby_jubjub;
+pub mod bandersnatch;
 
 #[cfg(feature = "eip_196")]
 pub mod bn254;
@@ -311,6 +323,256 @@ mod test {
         }
     }
 
+    define_bls12_engine! {
+        mod derived_bls12_381 {
+            repr: U384Repr,
+            modulus: "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787",
+            subgroup_order: "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+            x: "0xd201000000010000",
+            x_is_negative: true,
+            twist_type: M,
+            b: "4",
+            fp_non_residue: "-1",
+            fp2_non_residue: ("1", "1"),
+            g1_generator: (
+                "3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507",
+                "1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569"
+            ),
+            g2_generator: (
+                (
+                    "352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160",
+                    "3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758"
+                ),
+                (
+                    "1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905",
+                    "927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582"
+                )
+            ),
+        }
+    }
+
+    define_bls12_engine! {
+        mod derived_bls12_377 {
+            repr: U384Repr,
+            modulus: "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177",
+            subgroup_order: "0x12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001",
+            x: "0x8508c00000000001",
+            x_is_negative: false,
+            twist_type: D,
+            b: "1",
+            fp_non_residue: "-5",
+            fp2_non_residue: ("0", "1"),
+            g1_generator: (
+                "0x008848defe740a67c8fc6225bf87ff5485951e2caa9d41bb188282c8bd37cb5cd5481512ffcd394eeab9b16eb21be9ef",
+                "0x01914a69c5102eff1f674f5d30afeec4bd7fb348ca3e52d96d182ad44fb82305c2fe3d3634a9591afd82de55559c8ea6"
+            ),
+            g2_generator: (
+                (
+                    "0x018480be71c785fec89630a2a3841d01c565f071203e50317ea501f557db6b9b71889f52bb53540274e3e48f7c005196",
+                    "0x00ea6040e700403170dc5a51b1b140d5532777ee6651cecbe7223ece0799c9de5cf89984bff76fe6b26bfefa6ea16afe"
+                ),
+                (
+                    "0x00690d665d446f7bd960736bcbb2efb4de03ed7274b49a58e458c282f832d204f2cf88886d8c7c2ef094094409fd4ddf",
+                    "0x00f8169fd28355189e549da3151a70aa61ef11ac3d591bf12463b01acee304c24279b83f5e52270bd9a1cdd185eb8f93"
+                )
+            ),
+        }
+    }
+
+    type Bls12_384Engine = crate::public_interface::known_curves::KnownBls12Engine;
+
+    fn assert_same_bls12_engine(derived: &Bls12_384Engine, expected: &Bls12_384Engine) {
+        let field = derived.base_field;
+        let expected_field = expected.base_field;
+        assert_eq!(field.modulus, expected_field.modulus);
+        assert_eq!(field.mont_r, expected_field.mont_r);
+        assert_eq!(field.mont_r2, expected_field.mont_r2);
+        assert_eq!(field.mont_inv, expected_field.mont_inv);
+        assert_eq!(field.mont_power, expected_field.mont_power);
+        assert_eq!(field.modulus_bits, expected_field.modulus_bits);
+
+        let (fp2, expected_fp2) = (derived.fp2_extension, expected.fp2_extension);
+        assert!(fp2.non_residue == expected_fp2.non_residue);
+        assert!(fp2.non_residue_mul_policy == expected_fp2.non_residue_mul_policy);
+        assert!(fp2.frobenius_coeffs_c1 == expected_fp2.frobenius_coeffs_c1);
+
+        let (fp6, expected_fp6) = (derived.fp6_extension, expected.fp6_extension);
+        assert!(fp6.non_residue == expected_fp6.non_residue);
+        assert!(fp6.non_residue_mul_policy == expected_fp6.non_residue_mul_policy);
+        assert!(fp6.frobenius_coeffs_c1 == expected_fp6.frobenius_coeffs_c1);
+        assert!(fp6.frobenius_coeffs_c2 == expected_fp6.frobenius_coeffs_c2);
+
+        assert!(derived.fp12_extension.frobenius_coeffs_c1 == expected.fp12_extension.frobenius_coeffs_c1);
+
+        assert!(derived.curve.a == expected.curve.a);
+        assert!(derived.curve.b == expected.curve.b);
+        assert_eq!(derived.curve.curve_type, expected.curve.curve_type);
+        assert_eq!(derived.curve.subgroup_order_repr, expected.curve.subgroup_order_repr);
+        assert!(derived.curve_twist.a == expected.curve_twist.a);
+        assert!(derived.curve_twist.b == expected.curve_twist.b);
+        assert_eq!(derived.curve_twist.curve_type, expected.curve_twist.curve_type);
+        assert_eq!(derived.curve_twist.subgroup_order_repr, expected.curve_twist.subgroup_order_repr);
+
+        assert_eq!(derived.x, expected.x);
+        assert_eq!(derived.x_is_negative, expected.x_is_negative);
+        assert_eq!(derived.twist_type, expected.twist_type);
+        assert_eq!(derived.prefer_hht_final_exp, expected.prefer_hht_final_exp);
+    }
+
+    #[test]
+    fn test_define_engine_matches_bls12_381_constants() {
+        use super::bls12_381::*;
+
+        assert_same_bls12_engine(&derived_bls12_381::PAIRING_ENGINE, &BLS12_381_PAIRING_ENGINE);
+        assert_eq!(derived_bls12_381::MODULUS_UINT, BLS12_381_MODULUS_UINT);
+        assert!(derived_bls12_381::G1_GENERATOR.x == BLS12_381_G1_GENERATOR.x);
+        assert!(derived_bls12_381::G1_GENERATOR.y == BLS12_381_G1_GENERATOR.y);
+        assert!(derived_bls12_381::G1_GENERATOR.z == BLS12_381_G1_GENERATOR.z);
+        assert!(derived_bls12_381::G2_GENERATOR.x == BLS12_381_G2_GENERATOR.x);
+        assert!(derived_bls12_381::G2_GENERATOR.y == BLS12_381_G2_GENERATOR.y);
+        assert!(derived_bls12_381::G2_GENERATOR.z == BLS12_381_G2_GENERATOR.z);
+    }
+
+    #[test]
+    fn test_define_engine_matches_bls12_377_constants() {
+        use super::bls12_377::*;
+
+        assert_same_bls12_engine(&derived_bls12_377::PAIRING_ENGINE, &BLS12_377_PAIRING_ENGINE);
+        assert_eq!(derived_bls12_377::MODULUS_UINT, BLS12_377_MODULUS_UINT);
+        assert!(derived_bls12_377::G1_GENERATOR.x == BLS12_377_G1_GENERATOR.x);
+        assert!(derived_bls12_377::G1_GENERATOR.y == BLS12_377_G1_GENERATOR.y);
+        assert!(derived_bls12_377::G2_GENERATOR.x == BLS12_377_G2_GENERATOR.x);
+        assert!(derived_bls12_377::G2_GENERATOR.y == BLS12_377_G2_GENERATOR.y);
+    }
+
+    #[test]
+    #[cfg(feature = "eip_196")]
+    fn test_define_engine_matches_bn254_runtime_tower() {
+        use super::bn254::*;
+        use crate::extension_towers::fp6_as_3_over_2::*;
+        use crate::extension_towers::fp12_as_2_over3_over_2::*;
+        use crate::weierstrass::curve::WeierstrassCurve;
+        use crate::weierstrass::CurveOverFp2Parameters;
+
+        let modulus = MaxFieldUint::from_big_endian(&BigUint::from_str_radix("21888242871839275222246405745257275088696311157297823662689037894645226208583", 10).unwrap().to_bytes_be());
+        assert_eq!(BN254_MODULUS, modulus);
+
+        let field = field_from_modulus::<U256Repr>(&modulus).unwrap();
+        assert_eq!(BN254_BASE_FIELD.modulus, field.modulus);
+        assert_eq!(BN254_BASE_FIELD.mont_r, field.mont_r);
+        assert_eq!(BN254_BASE_FIELD.mont_r2, field.mont_r2);
+        assert_eq!(BN254_BASE_FIELD.mont_inv, field.mont_inv);
+        assert_eq!(BN254_BASE_FIELD.mont_power, field.mont_power);
+        assert_eq!(BN254_BASE_FIELD.modulus_bits, field.modulus_bits);
+
+        let mut fp_non_residue = Fp::one(&field);
+        fp_non_residue.negate();
+        let mut extension_2 = Extension2::new(fp_non_residue);
+        extension_2.calculate_frobenius_coeffs(&modulus).unwrap();
+        assert!(BN254_EXT2_FIELD.non_residue == extension_2.non_residue);
+        assert!(BN254_EXT2_FIELD.non_residue_mul_policy == extension_2.non_residue_mul_policy);
+        assert!(BN254_EXT2_FIELD.frobenius_coeffs_c1 == extension_2.frobenius_coeffs_c1);
+
+        let mut fp2_non_residue = Fp2::zero(&extension_2);
+        fp2_non_residue.c0 = Fp::from_repr(&field, U256Repr::from(9)).unwrap();
+        fp2_non_residue.c1 = Fp::one(&field);
+        let mut extension_6 = Extension3Over2::new(fp2_non_residue);
+        extension_6.calculate_frobenius_coeffs_optimized(&modulus).unwrap();
+        assert!(BN254_EXT6_FIELD.non_residue == extension_6.non_residue);
+        assert!(BN254_EXT6_FIELD.non_residue_mul_policy == extension_6.non_residue_mul_policy);
+        assert!(BN254_EXT6_FIELD.frobenius_coeffs_c1 == extension_6.frobenius_coeffs_c1);
+        assert!(BN254_EXT6_FIELD.frobenius_coeffs_c2 == extension_6.frobenius_coeffs_c2);
+
+        let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&extension_6));
+        extension_12.calculate_frobenius_coeffs_optimized(&modulus).unwrap();
+        assert!(BN254_EXT12_FIELD.frobenius_coeffs_c1 == extension_12.frobenius_coeffs_c1);
+
+        // b / (u + 9) for D-twist
+        let mut b_for_g2 = fp2_non_residue.inverse().unwrap();
+        b_for_g2.mul_by_fp(&Fp::from_repr(&field, U256Repr::from(3)).unwrap());
+        assert!(BN254_G2_B_COEFF == b_for_g2);
+        let g2_params = CurveOverFp2Parameters::new(&extension_2);
+        let twist = WeierstrassCurve::new(&BN254_SUBGROUP_ORDER, Fp2::zero(&extension_2), b_for_g2, &g2_params).unwrap();
+        assert_eq!(BN254_G2_CURVE.curve_type, twist.curve_type);
+
+        let mut minus_one_over_2 = Fp::one(&field);
+        minus_one_over_2.negate();
+        let mut two = Fp::one(&field);
+        two.double();
+        minus_one_over_2.mul_assign(&two.inverse().unwrap());
+        let non_residue_in_p_minus_one_over_2 = fp2_non_residue.pow(&minus_one_over_2.into_repr());
+        assert!(BN254_PAIRING_ENGINE.non_residue_in_p_minus_one_over_2 == non_residue_in_p_minus_one_over_2);
+
+        let u = U256Repr::from(BN254_U);
+        let mut six_u_plus_2 = u;
+        six_u_plus_2.mul2();
+        let two_u = six_u_plus_2;
+        six_u_plus_2.mul2();
+        six_u_plus_2.add_nocarry(&two_u);
+        six_u_plus_2.add_nocarry(&U256Repr::from(2));
+        assert_eq!(BN254_PAIRING_ENGINE.six_u_plus_2, &six_u_plus_2.0[..2]);
+
+        assert!(BN254_G1_GENERATOR.is_on_curve());
+        assert!(BN254_G2_GENERATOR.is_on_curve());
+        assert!(BN254_G2_GENERATOR.wnaf_mul(&BN254_SUBGROUP_ORDER[..]).is_zero());
+    }
+
+    #[test]
+    fn test_define_engine_pairing() {
+        use crate::pairings::PairingEngine;
+        use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+
+        let engine = &derived_bls12_381::PAIRING_ENGINE;
+        let mut minus_g1 = derived_bls12_381::G1_GENERATOR;
+        minus_g1.negate();
+        let result = engine.pair(
+            &[derived_bls12_381::G1_GENERATOR, minus_g1],
+            &[derived_bls12_381::G2_GENERATOR, derived_bls12_381::G2_GENERATOR]
+        ).unwrap();
+
+        assert!(result == Fp12::one(&derived_bls12_381::EXTENSION_12_FIELD));
+    }
+
+    #[test]
+    fn test_const_field_arithmetic() {
+        use super::const_arithmetic::*;
+
+        let field = ConstPrimeField::<1>::from_str("101");
+        let two = field.element_from_str("2");
+        let minus_two = field.element_from_str("-2");
+        assert!(is_zero(&field.add(&two, &minus_two)));
+        assert_eq!(field.mul(&field.inverse(&two), &two), field.one());
+        assert_eq!(field.pow(&two, &[100u64]), field.one());
+        assert_eq!(num_limbs("0x10000000000000000"), 2);
+        assert_eq!(num_limbs("18446744073709551615"), 1);
+        assert_eq!(parse_limbs::<2>("18446744073709551616"), [0, 1]);
+    }
+
+    #[test]
+    fn test_pairing_family_checks() {
+        use super::const_arithmetic::*;
+
+        let bls12_381_modulus = parse_limbs::<6>("0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+        assert!(is_bls12_modulus(&bls12_381_modulus, &[0xd201000000010000], true));
+        assert!(!is_bls12_modulus(&bls12_381_modulus, &[0xd201000000010000], false));
+        assert!(!is_bls12_modulus(&bls12_381_modulus, &[0xd201000000010001], true));
+        for engine in [super::bls12_381::BLS12_381_PAIRING_ENGINE, super::bls12_377::BLS12_377_PAIRING_ENGINE].iter() {
+            assert!(engine.prefer_hht_final_exp);
+        }
+
+        let bn254_modulus = super::bn254::BN254_BASE_FIELD.modulus().0;
+        assert!(is_bn_modulus(&bn254_modulus, &[super::bn254::BN254_U], false));
+        assert!(!is_bn_modulus(&bn254_modulus, &[super::bn254::BN254_U], true));
+        assert!(!is_bls12_modulus(&bn254_modulus, &[super::bn254::BN254_U], false));
+        assert!(!super::bn254::BN254_PAIRING_ENGINE.prefer_fuentes_final_exp);
+        assert!(super::bn254::BN254_PAIRING_ENGINE.for_pairing_checks().prefer_fuentes_final_exp);
+
+        // overflowing parameters are rejected instead of wrapping around
+        assert!(!is_bls12_modulus(&bls12_381_modulus, &[0, 0, 0, 1], false));
+        assert!(!is_bn_modulus(&bn254_modulus, &[u64::MAX; 17], false));
+        assert!(!is_bn_modulus(&bn254_modulus, &[0], false));
+    }
+
     #[test]
     fn calculate_bls12_381_g1_isogeny_constants() {
         let fp_field = &super::bls12_381::BLS12_381_FIELD;
@@ -641,4 +903,70 @@ mod test {
 
         assert!(should_be_zero.is_zero());
     }
-}
\ No newline at end of file
+
+    // the hash-to-curve specification does not list BN254 suites, these vectors come from an independent implementation
+
+    #[cfg(feature = "eip_196")]
+    #[test]
+    fn test_bn254_hash_to_g1() {
+        use crate::weierstrass::Group;
+
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+
+        let vectors = [
+            (&b""[..], "0x0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86", "0x02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5"),
+            (&b"abc"[..], "0x23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1", "0x04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d"),
+        ];
+
+        for (msg, expected_x, expected_y) in vectors.iter() {
+            let point = crate::engines::bn254::mapping::hash_to_g1(msg, dst).unwrap();
+            let (x, y) = point.into_xy();
+            assert_eq!(format!("{}", x), *expected_x);
+            assert_eq!(format!("{}", y), *expected_y);
+
+            assert!(point.is_on_curve());
+            assert!(point.mul(&super::bn254::BN254_SUBGROUP_ORDER).is_zero());
+        }
+    }
+
+    #[cfg(feature = "eip_196")]
+    #[test]
+    fn test_bn254_hash_to_g2() {
+        use crate::weierstrass::Group;
+
+        let dst = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_";
+
+        let vectors = [
+            (
+                &b""[..],
+                [
+                    "0x22cef87c4dd45a4cc4d32df4295ba3c3e488bd331b07b6b2514b25cf5aeb7cf3",
+                    "0x303dbd430a583c946596158ce500a5ff37babc6dd1ed482aca4daa881581480c",
+                    "0x27054759d88e6b9a0b3419858be3b27c3a3d53d21f744e73356a5c41b9a4e815",
+                    "0x2eca508abbba76b69a78f7b8c2d22b03403b216a091195a834b64bc512099e12"
+                ]
+            ),
+            (
+                &b"abc"[..],
+                [
+                    "0x28f105b439abd57dfdd29c4818df5e8ed9b0f67296e5cdd178864ca6e75c36ce",
+                    "0x0ab016609756d6c217d6c0e41ba9b9202b82ef8f1bb86ec51bc4c02a3c8acbbf",
+                    "0x18bafc8d9cae1eff18aebc4da5803046da89ff3e30c5214618ec396878299d43",
+                    "0x0efbfebf454feaa177a5c97b70b665e7b239f5b63599bc225848321ed059e010"
+                ]
+            ),
+        ];
+
+        for (msg, expected) in vectors.iter() {
+            let point = crate::engines::bn254::mapping::hash_to_g2(msg, dst).unwrap();
+            let (x, y) = point.into_xy();
+            assert_eq!(format!("{}", x.c0), expected[0]);
+            assert_eq!(format!("{}", x.c1), expected[1]);
+            assert_eq!(format!("{}", y.c0), expected[2]);
+            assert_eq!(format!("{}", y.c1), expected[3]);
+
+            assert!(point.is_on_curve());
+            assert!(point.mul(&super::bn254::BN254_SUBGROUP_ORDER).is_zero());
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::integers::MaxFieldUint;
+
+const REPR_ZERO: U320Repr = U320Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
+
+pub const SECP256K1_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0xfffffffefffffc2f,0xffffffffffffffff,0xffffffffffffffff,0xffffffffffffffff,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000
+    ]
+);
+
+pub const SECP256K1_MODULUS: U320Repr = U320Repr([0xfffffffefffffc2f,0xffffffffffffffff,0xffffffffffffffff,0xffffffffffffffff,0x0000000000000000]);
+const SECP256K1_R: U320Repr = U320Repr([0x0000000000000000,0x00000001000003d1,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
+const SECP256K1_R2: U320Repr = U320Repr([0x0000000000000000,0x0000000000000000,0x000007a2000e90a1,0x0000000000000001,0x0000000000000000]);
+const SECP256K1_MONT_INV: u64 = 0xd838091dd2253531;
+
+pub const SECP256K1_FIELD: PrimeField<U320Repr> = PrimeField::<U320Repr> {
+    mont_power: 320,
+    modulus_bits: 256,
+    modulus: SECP256K1_MODULUS,
+    mont_r: SECP256K1_R,
+    mont_r2: SECP256K1_R2,
+    mont_inv: SECP256K1_MONT_INV,
+};
+
+// scalar field is required for signature verification and public key recovery
+
+pub const SECP256K1_SUBGROUP_ORDER: [u64; 4] = [
+    0xbfd25e8cd0364141,
+    0xbaaedce6af48a03b,
+    0xfffffffffffffffe,
+    0xffffffffffffffff
+];
+
+const SECP256K1_SCALAR_MODULUS: U320Repr = U320Repr([0xbfd25e8cd0364141,0xbaaedce6af48a03b,0xfffffffffffffffe,0xffffffffffffffff,0x0000000000000000]);
+const SECP256K1_SCALAR_R: U320Repr = U320Repr([0x0000000000000000,0x402da1732fc9bebf,0x4551231950b75fc4,0x0000000000000001,0x0000000000000000]);
+const SECP256K1_SCALAR_R2: U320Repr = U320Repr([0x1e004f504dfd7f79,0x08fcf59774a052ea,0x27c4120fc94e1653,0x3c1a6191e5702644,0x0000000000000000]);
+const SECP256K1_SCALAR_MONT_INV: u64 = 0x4b0dff665588b13f;
+
+pub const SECP256K1_SCALAR_FIELD: PrimeField<U320Repr> = PrimeField::<U320Repr> {
+    mont_power: 320,
+    modulus_bits: 256,
+    modulus: SECP256K1_SCALAR_MODULUS,
+    mont_r: SECP256K1_SCALAR_R,
+    mont_r2: SECP256K1_SCALAR_R2,
+    mont_inv: SECP256K1_SCALAR_MONT_INV,
+};
+
+pub const SECP256K1_FP_ZERO: decl_fp!(U320Repr) = repr_into_fp!(
+    REPR_ZERO,
+    U320Repr,
+    SECP256K1_FIELD
+);
+
+pub const SECP256K1_FP_ONE: decl_fp!(U320Repr) = repr_into_fp!(
+    SECP256K1_R,
+    U320Repr,
+    SECP256K1_FIELD
+);
+
+pub const SECP256K1_SCALAR_ZERO: decl_fp!(U320Repr) = repr_into_fp!(
+    REPR_ZERO,
+    U320Repr,
+    SECP256K1_SCALAR_FIELD
+);
+
+pub const SECP256K1_SCALAR_ONE: decl_fp!(U320Repr) = repr_into_fp!(
+    SECP256K1_SCALAR_R,
+    U320Repr,
+    SECP256K1_SCALAR_FIELD
+);
+
+const SECP256K1_B_FOR_G1: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x0000000000000000,0x0000000700001ab7,0x0000000000000000,0x0000000000000000,0x0000000000000000]),
+    U320Repr,
+    SECP256K1_FIELD
+);
+
+pub const SECP256K1_G1_CURVE_PARAMETERS: CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>> =
+    CurveOverFpParameters::<'static, U320Repr, PrimeField<U320Repr>> {
+        field: &SECP256K1_FIELD
+    };
+
+pub const SECP256K1_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> =
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> {
+        a: SECP256K1_FP_ZERO,
+        b: SECP256K1_B_FOR_G1,
+        curve_type: CurveType::AIsZero,
+        subgroup_order_repr: &SECP256K1_SUBGROUP_ORDER,
+        params: &SECP256K1_G1_CURVE_PARAMETERS
+    };
+
+const SECP256K1_G1_GENERATOR_X: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0xc1c8687459e7e1c8,0xd7362e5ae2000924,0x231e295329bc66db,0x979f48c033fd129c,0x0000000000000000]),
+    U320Repr,
+    SECP256K1_FIELD
+);
+
+const SECP256K1_G1_GENERATOR_Y: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0xc61091508ba852b6,0xb15ea6d3a31b3418,0x8dfc5d5d1f1dc64d,0x70b6b59aac19c136,0x0000000000000000]),
+    U320Repr,
+    SECP256K1_FIELD
+);
+
+pub const SECP256K1_G1_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> =
+    CurvePoint::<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>>
+    {
+        curve: &SECP256K1_G1_CURVE,
+        x: SECP256K1_G1_GENERATOR_X,
+        y: SECP256K1_G1_GENERATOR_Y,
+        z: SECP256K1_FP_ONE,
+    };
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traits::FieldElement;
+
+    #[test]
+    fn test_generator_and_order() {
+        let g = SECP256K1_G1_GENERATOR;
+        assert!(g.is_on_curve());
+        assert!(!g.is_zero());
+
+        let should_be_zero = g.mul(&SECP256K1_SUBGROUP_ORDER[..]);
+        assert!(should_be_zero.is_zero());
+    }
+
+    #[test]
+    fn test_field_constants() {
+        let field = field_from_modulus::<U320Repr>(&SECP256K1_MODULUS_UINT).unwrap();
+        assert_eq!(field.mont_r(), SECP256K1_FIELD.mont_r());
+        assert_eq!(field.mont_r2(), SECP256K1_FIELD.mont_r2());
+        assert_eq!(field.mont_inv(), SECP256K1_FIELD.mont_inv());
+
+        let mut order = MaxFieldUint::zero();
+        order.as_mut()[..4].copy_from_slice(&SECP256K1_SUBGROUP_ORDER[..]);
+        let scalar_field = field_from_modulus::<U320Repr>(&order).unwrap();
+        assert_eq!(scalar_field.mont_r(), SECP256K1_SCALAR_FIELD.mont_r());
+        assert_eq!(scalar_field.mont_r2(), SECP256K1_SCALAR_FIELD.mont_r2());
+        assert_eq!(scalar_field.mont_inv(), SECP256K1_SCALAR_FIELD.mont_inv());
+
+        let mut two = SECP256K1_SCALAR_ONE;
+        two.double();
+        let mut should_be_one = two.inverse().unwrap();
+        should_be_one.mul_assign(&two);
+        assert_eq!(should_be_one, SECP256K1_SCALAR_ONE);
+    }
+}
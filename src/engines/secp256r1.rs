@@ -0,0 +1,162 @@
+use crate::field::*;
+use crate::fp::*;
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::integers::MaxFieldUint;
+
+const REPR_ZERO: U320Repr = U320Repr([0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000]);
+
+pub const SECP256R1_MODULUS_UINT: MaxFieldUint = MaxFieldUint::from_limbs(
+    [
+        0xffffffffffffffff,0x00000000ffffffff,0x0000000000000000,0xffffffff00000001,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000,
+        0x0000000000000000,0x0000000000000000,0x0000000000000000,0x0000000000000000
+    ]
+);
+
+pub const SECP256R1_MODULUS: U320Repr = U320Repr([0xffffffffffffffff,0x00000000ffffffff,0x0000000000000000,0xffffffff00000001,0x0000000000000000]);
+const SECP256R1_R: U320Repr = U320Repr([0x00000000ffffffff,0x0000000100000001,0xfffffffeffffffff,0xfffffffe00000000,0x0000000000000000]);
+const SECP256R1_R2: U320Repr = U320Repr([0xfffffffdfffffff9,0x0000000c00000002,0xfffffffd00000005,0xfffffff700000000,0x0000000000000000]);
+const SECP256R1_MONT_INV: u64 = 0x0000000000000001;
+
+pub const SECP256R1_FIELD: PrimeField<U320Repr> = PrimeField::<U320Repr> {
+    mont_power: 320,
+    modulus_bits: 256,
+    modulus: SECP256R1_MODULUS,
+    mont_r: SECP256R1_R,
+    mont_r2: SECP256R1_R2,
+    mont_inv: SECP256R1_MONT_INV,
+};
+
+// scalar field is required for signature verification
+
+pub const SECP256R1_SUBGROUP_ORDER: [u64; 4] = [
+    0xf3b9cac2fc632551,
+    0xbce6faada7179e84,
+    0xffffffffffffffff,
+    0xffffffff00000000
+];
+
+const SECP256R1_SCALAR_MODULUS: U320Repr = U320Repr([0xf3b9cac2fc632551,0xbce6faada7179e84,0xffffffffffffffff,0xffffffff00000000,0x0000000000000000]);
+const SECP256R1_SCALAR_R: U320Repr = U320Repr([0xf756a571fc632551,0x22159165b6faae70,0x431905529c0166cd,0xfffffffe00000001,0x0000000000000000]);
+const SECP256R1_SCALAR_R2: U320Repr = U320Repr([0x1d867c1433e0aef3,0xec1ef1a5ef21d5d4,0xe6707de7ee321683,0x3241df4f6500fade,0x0000000000000000]);
+const SECP256R1_SCALAR_MONT_INV: u64 = 0xccd1c8aaee00bc4f;
+
+pub const SECP256R1_SCALAR_FIELD: PrimeField<U320Repr> = PrimeField::<U320Repr> {
+    mont_power: 320,
+    modulus_bits: 256,
+    modulus: SECP256R1_SCALAR_MODULUS,
+    mont_r: SECP256R1_SCALAR_R,
+    mont_r2: SECP256R1_SCALAR_R2,
+    mont_inv: SECP256R1_SCALAR_MONT_INV,
+};
+
+pub const SECP256R1_FP_ZERO: decl_fp!(U320Repr) = repr_into_fp!(
+    REPR_ZERO,
+    U320Repr,
+    SECP256R1_FIELD
+);
+
+pub const SECP256R1_FP_ONE: decl_fp!(U320Repr) = repr_into_fp!(
+    SECP256R1_R,
+    U320Repr,
+    SECP256R1_FIELD
+);
+
+pub const SECP256R1_SCALAR_ZERO: decl_fp!(U320Repr) = repr_into_fp!(
+    REPR_ZERO,
+    U320Repr,
+    SECP256R1_SCALAR_FIELD
+);
+
+pub const SECP256R1_SCALAR_ONE: decl_fp!(U320Repr) = repr_into_fp!(
+    SECP256R1_SCALAR_R,
+    U320Repr,
+    SECP256R1_SCALAR_FIELD
+);
+
+// a = -3
+const SECP256R1_A_FOR_G1: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0xfffffffd00000000,0xfffffffffffffffc,0x0000000300000002,0x0000000300000000,0x0000000000000000]),
+    U320Repr,
+    SECP256R1_FIELD
+);
+
+const SECP256R1_B_FOR_G1: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0xdc30061de0b74e51,0xf7e5911129c4bddf,0xacf005cc9c542a72,0xea2968df1669e085,0x0000000000000000]),
+    U320Repr,
+    SECP256R1_FIELD
+);
+
+pub const SECP256R1_G1_CURVE_PARAMETERS: CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>> =
+    CurveOverFpParameters::<'static, U320Repr, PrimeField<U320Repr>> {
+        field: &SECP256R1_FIELD
+    };
+
+pub const SECP256R1_G1_CURVE: WeierstrassCurve<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> =
+    WeierstrassCurve::<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> {
+        a: SECP256R1_A_FOR_G1,
+        b: SECP256R1_B_FOR_G1,
+        curve_type: CurveType::Generic,
+        subgroup_order_repr: &SECP256R1_SUBGROUP_ORDER,
+        params: &SECP256R1_G1_CURVE_PARAMETERS
+    };
+
+const SECP256R1_G1_GENERATOR_X: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x18905f76bdc7b53d,0xbc1f7b9718a9143c,0x75ba95fc475d568a,0x1f32c8f1b99a6fd3,0x0000000000000000]),
+    U320Repr,
+    SECP256R1_FIELD
+);
+
+const SECP256R1_G1_GENERATOR_Y: decl_fp!(U320Repr) = repr_into_fp!(
+    U320Repr([0x8571ff18aafa5c9d,0x32f7f6bace95560a,0x8b4ab8e434a7e544,0xf870e40d32279688,0x0000000000000000]),
+    U320Repr,
+    SECP256R1_FIELD
+);
+
+pub const SECP256R1_G1_GENERATOR: CurvePoint<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>> =
+    CurvePoint::<'static, CurveOverFpParameters<'static, U320Repr, PrimeField<U320Repr>>>
+    {
+        curve: &SECP256R1_G1_CURVE,
+        x: SECP256R1_G1_GENERATOR_X,
+        y: SECP256R1_G1_GENERATOR_Y,
+        z: SECP256R1_FP_ONE,
+    };
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traits::{FieldElement, ZeroAndOne};
+
+    #[test]
+    fn test_generator_and_order() {
+        let g = SECP256R1_G1_GENERATOR;
+        assert!(g.is_on_curve());
+        assert!(!g.is_zero());
+
+        let should_be_zero = g.mul(&SECP256R1_SUBGROUP_ORDER[..]);
+        assert!(should_be_zero.is_zero());
+    }
+
+    #[test]
+    fn test_field_constants() {
+        let field = field_from_modulus::<U320Repr>(&SECP256R1_MODULUS_UINT).unwrap();
+        assert_eq!(field.mont_r(), SECP256R1_FIELD.mont_r());
+        assert_eq!(field.mont_r2(), SECP256R1_FIELD.mont_r2());
+        assert_eq!(field.mont_inv(), SECP256R1_FIELD.mont_inv());
+
+        let mut order = MaxFieldUint::zero();
+        order.as_mut()[..4].copy_from_slice(&SECP256R1_SUBGROUP_ORDER[..]);
+        let scalar_field = field_from_modulus::<U320Repr>(&order).unwrap();
+        assert_eq!(scalar_field.mont_r(), SECP256R1_SCALAR_FIELD.mont_r());
+        assert_eq!(scalar_field.mont_r2(), SECP256R1_SCALAR_FIELD.mont_r2());
+        assert_eq!(scalar_field.mont_inv(), SECP256R1_SCALAR_FIELD.mont_inv());
+
+        let mut minus_three = Fp::one(&SECP256R1_FIELD);
+        minus_three.double();
+        minus_three.add_assign(&SECP256R1_FP_ONE);
+        minus_three.negate();
+        assert_eq!(minus_three, SECP256R1_A_FOR_G1);
+    }
+}
@@ -2,6 +2,7 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ApiError {
     Overflow,
     UnexpectedZero(String),
@@ -10,6 +11,7 @@ pub enum ApiError {
     UnknownParameter(String),
     OutputError(String),
     MissingValue,
+    OutOfGas { gas_required: u64, gas_limit: u64 },
 }
 
 impl Error for ApiError {
@@ -22,6 +24,7 @@ impl Error for ApiError {
             ApiError::UnknownParameter(_) => "parameter has value out of bounds",
             ApiError::OutputError(_) => "error outputing results",
             ApiError::MissingValue => "missing value",
+            ApiError::OutOfGas { .. } => "gas limit is exceeded",
         }
     }
 }
@@ -36,6 +39,7 @@ impl fmt::Display for ApiError {
             ApiError::UnknownParameter(descr) => write!(f, "parameter has value out of bounds, {}", descr),
             ApiError::OutputError(descr) => write!(f, "error outputing results, {}", descr),
             ApiError::MissingValue => write!(f, "missing value"),
+            ApiError::OutOfGas { gas_required, gas_limit } => write!(f, "gas limit is exceeded, operation requires {} gas with the limit of {}", gas_required, gas_limit),
         }
     }
 }
\ No newline at end of file
@@ -4,6 +4,7 @@ use crate::traits::{FieldElement, BitIterator, FieldExtension, ZeroAndOne};
 use super::fp6_as_3_over_2::{Fp6, Extension3Over2};
 use super::fp2::Fp2;
 use super::Fp6Fp12FrobeniusBaseElements;
+use super::lazy_reduction::{Fp6Wide, supports_lazy_reduction};
 
 // this implementation assumes extension using polynomial w^2 - v = 0
 pub struct Fp12<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> >{
@@ -54,6 +55,63 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp12<'a, E, F> {
         c0: & Fp2<'a, E, F>,
         c3: & Fp2<'a, E, F>,
         c4: & Fp2<'a, E, F>,
+    ) {
+        if !supports_lazy_reduction(self.c0.c0.c0.field) {
+            return self.mul_by_034_with_reduction(c0, c3, c4);
+        }
+
+        // products are accumulated without reduction, so every coefficient is reduced only once
+        let a = Fp6Wide::mul_by_fp2(&self.c0, c0);
+        let mut b = Fp6Wide::mul_by_01(&self.c1, c3, c4);
+
+        let mut t0 = *c0;
+        t0.add_assign(c3);
+
+        let mut e = self.c0;
+        e.add_assign(&self.c1);
+        let mut e = Fp6Wide::mul_by_01(&e, &t0, c4);
+        e.sub_assign(&a);
+        e.sub_assign(&b);
+
+        b.mul_by_nonresidue();
+        b.add_assign(&a);
+
+        self.c0 = b.reduce();
+        self.c1 = e.reduce();
+    }
+
+    pub fn mul_by_014(
+        &mut self,
+        c0: & Fp2<'a, E, F>,
+        c1: & Fp2<'a, E, F>,
+        c4: & Fp2<'a, E, F>,
+    ) {
+        if !supports_lazy_reduction(self.c0.c0.c0.field) {
+            return self.mul_by_014_with_reduction(c0, c1, c4);
+        }
+
+        let aa = Fp6Wide::mul_by_01(&self.c0, c0, c1);
+        let mut bb = Fp6Wide::mul_by_1(&self.c1, c4);
+        let mut o = *c1;
+        o.add_assign(c4);
+        let mut e = self.c1;
+        e.add_assign(&self.c0);
+        let mut e = Fp6Wide::mul_by_01(&e, c0, &o);
+        e.sub_assign(&aa);
+        e.sub_assign(&bb);
+
+        bb.mul_by_nonresidue();
+        bb.add_assign(&aa);
+
+        self.c0 = bb.reduce();
+        self.c1 = e.reduce();
+    }
+
+    pub(crate) fn mul_by_034_with_reduction(
+        &mut self,
+        c0: & Fp2<'a, E, F>,
+        c3: & Fp2<'a, E, F>,
+        c4: & Fp2<'a, E, F>,
     ) {
         let mut a = self.c0;
         a.c0.mul_assign(c0);
@@ -81,7 +139,7 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp12<'a, E, F> {
         self.c0.add_assign(&t1);
     }
 
-    pub fn mul_by_014(
+    pub(crate) fn mul_by_014_with_reduction(
         &mut self,
         c0: & Fp2<'a, E, F>,
         c1: & Fp2<'a, E, F>,
@@ -102,6 +160,23 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp12<'a, E, F> {
         self.c0.add_assign(&aa);
     }
 
+    pub(crate) fn mul_assign_with_reduction(&mut self, other: &Self)
+    {
+        let mut aa = self.c0;
+        aa.mul_assign(&other.c0);
+        let mut bb = self.c1;
+        bb.mul_assign(&other.c1);
+        let mut o = other.c0;
+        o.add_assign(&other.c1);
+        self.c1.add_assign(&self.c0);
+        self.c1.mul_assign(&o);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        self.c0 = bb;
+        self.c0.mul_by_nonresidue(self.extension_field);
+        self.c0.add_assign(&aa);
+    }
+
     pub fn cyclotomic_square(&mut self) {
         let z0 = self.c0.c0;
         let z4 = self.c0.c1;
@@ -243,6 +318,274 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp12<'a, E, F> {
 
         res
     }
+
+    /// Compressed form for squarings in the cyclotomic subgroup, see `CompressedFp12`.
+    /// Only valid if this element is in the cyclotomic subgroup
+    pub fn compress(&self) -> CompressedFp12<'a, E, F> {
+        CompressedFp12 {
+            g1: self.c0.c1,
+            g2: self.c0.c2,
+            g3: self.c1.c0,
+            g5: self.c1.c2,
+            extension_field: self.extension_field
+        }
+    }
+
+    /// Exponentiation in the cyclotomic subgroup with compressed squarings. Squarings are done from
+    /// the lowest bit and powers for every set bit are decompressed in batch, so it's faster than
+    /// `cyclotomic_exp` for exponents with low Hamming weight
+    pub fn cyclotomic_exp_compressed<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let exp = exp.as_ref();
+        let bits = crate::pairings::calculate_bits(exp) as usize;
+
+        let mut multiply_by_self = false;
+        let mut powers = vec![];
+        let mut tmp = self.compress();
+        for i in 0..bits {
+            let bit_is_set = (exp[i / 64] >> (i % 64)) & 1 == 1;
+            if i == 0 {
+                multiply_by_self = bit_is_set;
+            } else {
+                tmp.square();
+                if bit_is_set {
+                    powers.push(tmp);
+                }
+            }
+        }
+
+        let mut res = if multiply_by_self { *self } else { Self::one(self.extension_field) };
+        for power in CompressedFp12::batch_decompress(&powers).iter() {
+            res.mul_assign(power);
+        }
+
+        res
+    }
+}
+
+/// Element of the cyclotomic subgroup in compressed form of Karabina, "Squaring in cyclotomic subgroups",
+/// <https://eprint.iacr.org/2010/542.pdf>. Only four of six Fp2 coefficients are stored
+/// (`g1 = c0.c1`, `g2 = c0.c2`, `g3 = c1.c0` and `g5 = c1.c2`), that is enough to square,
+/// and the other two are recovered at the cost of one inversion
+pub struct CompressedFp12<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> >{
+    pub g1: Fp2<'a, E, F>,
+    pub g2: Fp2<'a, E, F>,
+    pub g3: Fp2<'a, E, F>,
+    pub g5: Fp2<'a, E, F>,
+    pub extension_field: &'a Extension2Over3Over2<'a, E, F>
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Clone for CompressedFp12<'a, E, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Copy for CompressedFp12<'a, E, F> {}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > PartialEq for CompressedFp12<'a, E, F> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.g1 == other.g1 &&
+        self.g2 == other.g2 &&
+        self.g3 == other.g3 &&
+        self.g5 == other.g5
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Eq for CompressedFp12<'a, E, F> {
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > std::fmt::Debug for CompressedFp12<'a, E, F> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "CompressedFq12({}, {}, {}, {})", self.g1, self.g2, self.g3, self.g5)
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > CompressedFp12<'a, E, F> {
+    pub fn square(&mut self) {
+        let fp6_extension = self.extension_field.field;
+
+        // g1^2, g5^2 and 2 * g1 * g5
+        let mut g1_squared = self.g1;
+        g1_squared.square();
+        let mut g5_squared = self.g5;
+        g5_squared.square();
+        let mut g1_g5 = self.g1;
+        g1_g5.add_assign(&self.g5);
+        g1_g5.square();
+        g1_g5.sub_assign(&g1_squared);
+        g1_g5.sub_assign(&g5_squared);
+
+        // g2^2, g3^2 and 2 * g2 * g3
+        let mut g2_squared = self.g2;
+        g2_squared.square();
+        let mut g3_squared = self.g3;
+        g3_squared.square();
+        let mut g2_g3 = self.g2;
+        g2_g3.add_assign(&self.g3);
+        g2_g3.square();
+        g2_g3.sub_assign(&g2_squared);
+        g2_g3.sub_assign(&g3_squared);
+
+        // g3' = 3 * xi * (2 * g1 * g5) + 2 * g3
+        let mut tmp = g1_g5;
+        tmp.mul_by_nonresidue(fp6_extension);
+        let mut g3 = tmp;
+        g3.add_assign(&self.g3);
+        g3.double();
+        g3.add_assign(&tmp);
+
+        // g2' = 3 * (xi * g5^2 + g1^2) - 2 * g2
+        let mut tmp = g5_squared;
+        tmp.mul_by_nonresidue(fp6_extension);
+        tmp.add_assign(&g1_squared);
+        let mut g2 = tmp;
+        g2.sub_assign(&self.g2);
+        g2.double();
+        g2.add_assign(&tmp);
+
+        // g1' = 3 * (xi * g2^2 + g3^2) - 2 * g1
+        let mut tmp = g2_squared;
+        tmp.mul_by_nonresidue(fp6_extension);
+        tmp.add_assign(&g3_squared);
+        let mut g1 = tmp;
+        g1.sub_assign(&self.g1);
+        g1.double();
+        g1.add_assign(&tmp);
+
+        // g5' = 3 * (2 * g2 * g3) + 2 * g5
+        let mut g5 = g2_g3;
+        g5.add_assign(&self.g5);
+        g5.double();
+        g5.add_assign(&g2_g3);
+
+        self.g1 = g1;
+        self.g2 = g2;
+        self.g3 = g3;
+        self.g5 = g5;
+    }
+
+    // g4 = numerator / denominator, denominator is zero only for one
+    fn g4_fraction(&self) -> (Fp2<'a, E, F>, Fp2<'a, E, F>) {
+        if self.g3.is_zero() {
+            // g4 = 2 * g1 * g5 / g2
+            let mut numerator = self.g1;
+            numerator.mul_assign(&self.g5);
+            numerator.double();
+
+            (numerator, self.g2)
+        } else {
+            // g4 = (xi * g5^2 + 3 * g1^2 - 2 * g2) / (4 * g3)
+            let mut g1_squared = self.g1;
+            g1_squared.square();
+            let mut numerator = g1_squared;
+            numerator.sub_assign(&self.g2);
+            numerator.double();
+            numerator.add_assign(&g1_squared);
+            let mut tmp = self.g5;
+            tmp.square();
+            tmp.mul_by_nonresidue(self.extension_field.field);
+            numerator.add_assign(&tmp);
+
+            let mut denominator = self.g3;
+            denominator.double();
+            denominator.double();
+
+            (numerator, denominator)
+        }
+    }
+
+    fn decompress_with_g4(&self, g4: Fp2<'a, E, F>) -> Fp12<'a, E, F> {
+        let fp6_extension = self.extension_field.field;
+
+        // g0 = xi * (2 * g4^2 + g3 * g5 - 3 * g2 * g1) + 1
+        let mut g1_g2 = self.g1;
+        g1_g2.mul_assign(&self.g2);
+        let mut g0 = g4;
+        g0.square();
+        g0.sub_assign(&g1_g2);
+        g0.double();
+        g0.sub_assign(&g1_g2);
+        let mut g3_g5 = self.g3;
+        g3_g5.mul_assign(&self.g5);
+        g0.add_assign(&g3_g5);
+        g0.mul_by_nonresidue(fp6_extension);
+        g0.add_assign(&Fp2::one(fp6_extension.field));
+
+        Fp12 {
+            c0: Fp6 {
+                c0: g0,
+                c1: self.g1,
+                c2: self.g2,
+                extension_field: fp6_extension
+            },
+            c1: Fp6 {
+                c0: self.g3,
+                c1: g4,
+                c2: self.g5,
+                extension_field: fp6_extension
+            },
+            extension_field: self.extension_field
+        }
+    }
+
+    pub fn decompress(&self) -> Fp12<'a, E, F> {
+        let (numerator, denominator) = self.g4_fraction();
+        match denominator.inverse() {
+            Some(mut g4) => {
+                g4.mul_assign(&numerator);
+
+                self.decompress_with_g4(g4)
+            },
+            None => {
+                Fp12::one(self.extension_field)
+            }
+        }
+    }
+
+    /// Decompresses all the elements with a single inversion
+    pub fn batch_decompress(elements: &[Self]) -> Vec<Fp12<'a, E, F>> {
+        if elements.is_empty() {
+            return vec![];
+        }
+
+        let fractions: Vec<_> = elements.iter().map(|el| el.g4_fraction()).collect();
+
+        // Montgomery's trick, skipping zero denominators
+        let one = Fp2::one(elements[0].extension_field.field.field);
+        let mut prefix_products = Vec::with_capacity(elements.len());
+        let mut accumulator = one;
+        for (_, denominator) in fractions.iter() {
+            prefix_products.push(accumulator);
+            if !denominator.is_zero() {
+                accumulator.mul_assign(denominator);
+            }
+        }
+
+        let mut inverse = match accumulator.inverse() {
+            Some(inverse) => inverse,
+            None => {
+                // only possible if modulus is not prime
+                return elements.iter().map(|el| el.decompress()).collect();
+            }
+        };
+
+        let mut result = vec![Fp12::one(elements[0].extension_field); elements.len()];
+        for (i, (numerator, denominator)) in fractions.iter().enumerate().rev() {
+            if denominator.is_zero() {
+                continue;
+            }
+            let mut g4 = inverse;
+            g4.mul_assign(&prefix_products[i]);
+            inverse.mul_assign(denominator);
+            g4.mul_assign(numerator);
+
+            result[i] = elements[i].decompress_with_g4(g4);
+        }
+
+        result
+    }
 }
 
 impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > ZeroAndOne for Fp12<'a, E, F> {
@@ -321,19 +664,25 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > FieldElement for Fp12<'a
 
     fn mul_assign(&mut self, other: &Self)
     {
-        let mut aa = self.c0;
-        aa.mul_assign(&other.c0);
-        let mut bb = self.c1;
-        bb.mul_assign(&other.c1);
+        if !supports_lazy_reduction(self.c0.c0.c0.field) {
+            return self.mul_assign_with_reduction(other);
+        }
+
+        let aa = Fp6Wide::mul(&self.c0, &other.c0);
+        let mut bb = Fp6Wide::mul(&self.c1, &other.c1);
         let mut o = other.c0;
         o.add_assign(&other.c1);
-        self.c1.add_assign(&self.c0);
-        self.c1.mul_assign(&o);
-        self.c1.sub_assign(&aa);
-        self.c1.sub_assign(&bb);
-        self.c0 = bb;
-        self.c0.mul_by_nonresidue(self.extension_field);
-        self.c0.add_assign(&aa);
+        let mut e = self.c1;
+        e.add_assign(&self.c0);
+        let mut e = Fp6Wide::mul(&e, &o);
+        e.sub_assign(&aa);
+        e.sub_assign(&bb);
+
+        bb.mul_by_nonresidue();
+        bb.add_assign(&aa);
+
+        self.c0 = bb.reduce();
+        self.c1 = e.reduce();
     }
 
     fn square(&mut self)
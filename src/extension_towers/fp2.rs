@@ -5,6 +5,7 @@ use crate::traits::{FieldElement, BitIterator, FieldExtension};
 use crate::traits::ZeroAndOne;
 use crate::integers::*;
 use super::Fp2Fp4FrobeniusBaseElements;
+use super::lazy_reduction::{Fp2Wide, supports_lazy_reduction};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum NonResidueMulPolicy {
@@ -56,6 +57,25 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Eq for Fp2<'a, E, F> {
 }
 
 impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp2<'a, E, F> {
+    // Karatsuba multiplication where every product is reduced
+    pub(crate) fn mul_assign_with_reduction(&mut self, other: &Self)
+    {
+        let mut v0 = self.c0;
+        v0.mul_assign(&other.c0);
+        let mut v1 = self.c1;
+        v1.mul_assign(&other.c1);
+
+        self.c1.add_assign(&self.c0);
+        let mut t0 = other.c0;
+        t0.add_assign(&other.c1);
+        self.c1.mul_assign(&t0);
+        self.c1.sub_assign(&v0);
+        self.c1.sub_assign(&v1);
+        self.c0 = v0;
+        v1.mul_by_nonresidue(self.extension_field);
+        self.c0.add_assign(&v1);
+    }
+
     pub fn mul_by_fp(&mut self, element: &Fp<'a, E, F>) {
         self.c0.mul_assign(&element);
         self.c1.mul_assign(&element);
@@ -160,20 +180,11 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > FieldElement for Fp2<'a,
 
     fn mul_assign(&mut self, other: &Self)
     {
-        let mut v0 = self.c0;
-        v0.mul_assign(&other.c0);
-        let mut v1 = self.c1;
-        v1.mul_assign(&other.c1);
+        if !supports_lazy_reduction(self.c0.field) {
+            return self.mul_assign_with_reduction(other);
+        }
 
-        self.c1.add_assign(&self.c0);
-        let mut t0 = other.c0;
-        t0.add_assign(&other.c1);
-        self.c1.mul_assign(&t0);
-        self.c1.sub_assign(&v0);
-        self.c1.sub_assign(&v1);
-        self.c0 = v0;
-        v1.mul_by_nonresidue(self.extension_field);
-        self.c0.add_assign(&v1);
+        *self = Fp2Wide::mul(self, other).reduce();
     }
 
     fn square(&mut self)
@@ -48,6 +48,11 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Eq for Fp4<'a, E, F> {
 }
 
 impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp4<'a, E, F> {
+    pub fn mul_by_fp(&mut self, element: &Fp<'a, E, F>) {
+        self.c0.mul_by_fp(element);
+        self.c1.mul_by_fp(element);
+    }
+
     pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
         let mut res = Self::one(self.extension_field);
         let mut self_inverse = *self;
@@ -257,14 +262,6 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > FieldElement for Fp4<'a,
 
     fn frobenius_map(&mut self, power: usize) {
         assert!(self.extension_field.frobenius_coeffs_are_calculated);
-        match power {
-            1 | 2 => {
-
-            },
-            _ => {
-                unreachable!("can not reach power {}", power);
-            }
-        }
         self.c0.frobenius_map(power);
         self.c1.frobenius_map(power);
         self.c1.mul_by_fp(&self.extension_field.frobenius_coeffs_c1[power % 4]);
@@ -325,7 +322,7 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Extension2Over2<'a, E, F
         // c1 = Fp**( (q^1 - 1) / 4) has to be calculated
         // c2 = Fp**( (q^2 - 1) / 4) = Fp**( ((q - 1)/4) *(q+1)) = 
         // = c1 * c1.frobenius(1) = c1 ** 2
-        // c3 = c1**3 is not calculated
+        // c3 = Fp**( (q^3 - 1) / 4) = c1 ** (q^2 + q + 1) = c1 ** 3
 
         // NON_REDISUE**(((q^0) - 1) / 4)
         let non_residue = &self.field.non_residue;
@@ -340,7 +337,9 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Extension2Over2<'a, E, F
         // f_2.frobenius_map(1); // we could leave it formally, but it's an identity
         f_2.square();
 
-        let f_3 = Fp::zero(self.field.field);
+        // c2 * c1.frobenius(2) == c1^3
+        let mut f_3 = f_2;
+        f_3.mul_assign(&f_1);
 
         self.frobenius_coeffs_c1 = [f_0, f_1, f_2, f_3];
         self.frobenius_coeffs_are_calculated = true;
@@ -361,7 +360,9 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Extension2Over2<'a, E, F
         // f_2.frobenius_map(1); // we could leave it formally, but it's an identity
         f_2.square();
 
-        let f_3 = Fp::zero(self.field.field);
+        // c2 * c1.frobenius(2) == c1^3
+        let mut f_3 = f_2;
+        f_3.mul_assign(&f_1);
 
         self.frobenius_coeffs_c1 = [f_0, f_1, f_2, f_3];
         self.frobenius_coeffs_are_calculated = true;
@@ -4,6 +4,7 @@ use crate::traits::{FieldElement, BitIterator, FieldExtension, ZeroAndOne};
 use super::fp2::{Fp2, Extension2};
 use crate::fp::Fp;
 use super::Fp6Fp12FrobeniusBaseElements;
+use super::lazy_reduction::{Fp6Wide, supports_lazy_reduction};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum NonResidueMulPolicyFp6 {
@@ -65,6 +66,22 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Eq for Fp6<'a, E, F> {
 
 impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp6<'a, E, F> {
     pub fn mul_by_1(&mut self, c1: &Fp2<'a, E, F>) {
+        if !supports_lazy_reduction(self.c0.c0.field) {
+            return self.mul_by_1_with_reduction(c1);
+        }
+
+        *self = Fp6Wide::mul_by_1(self, c1).reduce();
+    }
+
+    pub fn mul_by_01(&mut self, c0: &Fp2<'a, E, F>, c1: &Fp2<'a, E, F>) {
+        if !supports_lazy_reduction(self.c0.c0.field) {
+            return self.mul_by_01_with_reduction(c0, c1);
+        }
+
+        *self = Fp6Wide::mul_by_01(self, c0, c1).reduce();
+    }
+
+    pub(crate) fn mul_by_1_with_reduction(&mut self, c1: &Fp2<'a, E, F>) {
         let mut b_b = self.c1;
         b_b.mul_assign(c1);
 
@@ -92,7 +109,7 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp6<'a, E, F> {
         self.c2 = b_b;
     }
 
-    pub fn mul_by_01(&mut self, c0: &Fp2<'a, E, F>, c1: &Fp2<'a, E, F>) {
+    pub(crate) fn mul_by_01_with_reduction(&mut self, c0: &Fp2<'a, E, F>, c1: &Fp2<'a, E, F>) {
         let mut a_a = self.c0;
         let mut b_b = self.c1;
         a_a.mul_assign(c0);
@@ -134,6 +151,58 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp6<'a, E, F> {
         self.c1 = t2;
         self.c2 = t3;
     }
+
+    pub(crate) fn mul_assign_with_reduction(&mut self, other: &Self)
+    {
+        let mut a_a = self.c0;
+        let mut b_b = self.c1;
+        let mut c_c = self.c2;
+        a_a.mul_assign(&other.c0);
+        b_b.mul_assign(&other.c1);
+        c_c.mul_assign(&other.c2);
+
+        let mut t1 = other.c1;
+        t1.add_assign(&other.c2);
+        {
+            let mut tmp = self.c1;
+            tmp.add_assign(&self.c2);
+
+            t1.mul_assign(&tmp);
+            t1.sub_assign(&b_b);
+            t1.sub_assign(&c_c);
+            t1.mul_by_nonresidue(self.extension_field);
+            t1.add_assign(&a_a);
+        }
+
+        let mut t3 = other.c0;
+        t3.add_assign(&other.c2);
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c2);
+
+            t3.mul_assign(&tmp);
+            t3.sub_assign(&a_a);
+            t3.add_assign(&b_b);
+            t3.sub_assign(&c_c);
+        }
+
+        let mut t2 = other.c0;
+        t2.add_assign(&other.c1);
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c1);
+
+            t2.mul_assign(&tmp);
+            t2.sub_assign(&a_a);
+            t2.sub_assign(&b_b);
+            c_c.mul_by_nonresidue(self.extension_field);
+            t2.add_assign(&c_c);
+        }
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = t3;
+    }
 }
 
 impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > ZeroAndOne for Fp6<'a, E, F> {
@@ -251,54 +320,11 @@ impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > FieldElement for Fp6<'a,
 
     fn mul_assign(&mut self, other: &Self)
     {
-        let mut a_a = self.c0;
-        let mut b_b = self.c1;
-        let mut c_c = self.c2;
-        a_a.mul_assign(&other.c0);
-        b_b.mul_assign(&other.c1);
-        c_c.mul_assign(&other.c2);
-
-        let mut t1 = other.c1;
-        t1.add_assign(&other.c2);
-        {
-            let mut tmp = self.c1;
-            tmp.add_assign(&self.c2);
-
-            t1.mul_assign(&tmp);
-            t1.sub_assign(&b_b);
-            t1.sub_assign(&c_c);
-            t1.mul_by_nonresidue(self.extension_field);
-            t1.add_assign(&a_a);
+        if !supports_lazy_reduction(self.c0.c0.field) {
+            return self.mul_assign_with_reduction(other);
         }
 
-        let mut t3 = other.c0;
-        t3.add_assign(&other.c2);
-        {
-            let mut tmp = self.c0;
-            tmp.add_assign(&self.c2);
-
-            t3.mul_assign(&tmp);
-            t3.sub_assign(&a_a);
-            t3.add_assign(&b_b);
-            t3.sub_assign(&c_c);
-        }
-
-        let mut t2 = other.c0;
-        t2.add_assign(&other.c1);
-        {
-            let mut tmp = self.c0;
-            tmp.add_assign(&self.c1);
-
-            t2.mul_assign(&tmp);
-            t2.sub_assign(&a_a);
-            t2.sub_assign(&b_b);
-            c_c.mul_by_nonresidue(self.extension_field);
-            t2.add_assign(&c_c);
-        }
-
-        self.c0 = t1;
-        self.c1 = t2;
-        self.c2 = t3;
+        *self = Fp6Wide::mul(self, other).reduce();
     }
 
     fn square(&mut self)
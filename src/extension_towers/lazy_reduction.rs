@@ -0,0 +1,434 @@
+// Double width (unreduced) elements for lazy Montgomery reduction in the Fp2 -> Fp6 -> Fp12 tower.
+//
+// A product of two reduced elements is kept as a number below p * R (R = 2^(64 * NUM_LIMBS)),
+// and additions and subtractions are done modulo p * R. Montgomery reduction of such a number gives
+// the same result as if every product was reduced separately, but it's only done once per
+// final coefficient. It requires at least one spare bit in the modulus (p < R / 2), so sums below
+// 2 * p * R never overflow the double width. Callers check it with `supports_lazy_reduction` and
+// fall back to reducing every product otherwise.
+
+use crate::fp::Fp;
+use crate::field::SizedPrimeField;
+use crate::representation::ElementRepr;
+use crate::traits::FieldElement;
+use crate::arithmetics::{adc, sbb};
+use super::fp2::{Fp2, Extension2, NonResidueMulPolicy};
+use super::fp6_as_3_over_2::{Fp6, Extension3Over2, NonResidueMulPolicyFp6};
+
+/// Lazy reduction can be used if the highest limb of the modulus is below 2^63.
+#[inline(always)]
+pub(crate) fn supports_lazy_reduction<E: ElementRepr, F: SizedPrimeField<Repr = E> >(field: &F) -> bool {
+    field.modulus().as_ref()[E::NUM_LIMBS - 1] < 0x8000000000000000
+}
+
+#[inline(always)]
+fn add_with_carry<E: ElementRepr>(a: &mut E, b: &E, mut carry: u64) -> u64 {
+    for (a, b) in a.as_mut().iter_mut().zip(b.as_ref().iter()) {
+        *a = adc(*a, *b, &mut carry);
+    }
+
+    carry
+}
+
+#[inline(always)]
+fn sub_with_borrow<E: ElementRepr>(a: &mut E, b: &E, mut borrow: u64) -> u64 {
+    for (a, b) in a.as_mut().iter_mut().zip(b.as_ref().iter()) {
+        *a = sbb(*a, *b, &mut borrow);
+    }
+
+    borrow
+}
+
+pub(crate) struct FpWide<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > {
+    low: E,
+    high: E,
+    field: &'a F,
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Clone for FpWide<'a, E, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Copy for FpWide<'a, E, F> {}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > FpWide<'a, E, F> {
+    #[inline(always)]
+    pub(crate) fn mul(a: &Fp<'a, E, F>, b: &Fp<'a, E, F>) -> Self {
+        let (low, high) = a.repr.mul_wide(&b.repr);
+
+        Self {
+            low,
+            high,
+            field: a.field
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn reduce(&self) -> Fp<'a, E, F> {
+        let repr = E::mont_reduce_wide(&self.low, &self.high, self.field.modulus(), self.field.mont_inv());
+
+        Fp {
+            repr,
+            field: self.field
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn add_assign(&mut self, other: &Self) {
+        let carry = add_with_carry(&mut self.low, &other.low, 0);
+        add_with_carry(&mut self.high, &other.high, carry);
+        // high part is below 2p, so subtract p * R if necessary
+        if &self.high >= self.field.modulus() {
+            self.high.sub_noborrow(self.field.modulus());
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub_assign(&mut self, other: &Self) {
+        let borrow = sub_with_borrow(&mut self.low, &other.low, 0);
+        let borrow = sub_with_borrow(&mut self.high, &other.high, borrow);
+        // went below zero, so add p * R
+        if borrow != 0 {
+            self.high.add_nocarry(self.field.modulus());
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn negate(&mut self) {
+        if self.low.is_zero() && self.high.is_zero() {
+            return;
+        }
+        let mut tmp = Self {
+            low: E::default(),
+            high: *self.field.modulus(),
+            field: self.field
+        };
+        tmp.sub_assign(self);
+        *self = tmp;
+    }
+
+    // multiplication by a constant requires reduction first
+    #[inline(always)]
+    fn mul_by_fp(&mut self, element: &Fp<'a, E, F>) {
+        *self = Self::mul(&self.reduce(), element);
+    }
+}
+
+pub(crate) struct Fp2Wide<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > {
+    pub(crate) c0: FpWide<'a, E, F>,
+    pub(crate) c1: FpWide<'a, E, F>,
+    extension_field: &'a Extension2<'a, E, F>,
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Clone for Fp2Wide<'a, E, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Copy for Fp2Wide<'a, E, F> {}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp2Wide<'a, E, F> {
+    // multiplication by the non-residue of Fp2
+    #[inline(always)]
+    fn mul_by_fp2_nonresidue(el: &mut FpWide<'a, E, F>, extension_field: &Extension2<'a, E, F>) {
+        match extension_field.non_residue_mul_policy {
+            NonResidueMulPolicy::Negate => {
+                el.negate();
+            },
+            NonResidueMulPolicy::Full => {
+                el.mul_by_fp(&extension_field.non_residue);
+            }
+        }
+    }
+
+    /// Karatsuba multiplication without reduction
+    #[inline(always)]
+    pub(crate) fn mul(a: &Fp2<'a, E, F>, b: &Fp2<'a, E, F>) -> Self {
+        let v0 = FpWide::mul(&a.c0, &b.c0);
+        let mut v1 = FpWide::mul(&a.c1, &b.c1);
+
+        let mut t0 = a.c0;
+        t0.add_assign(&a.c1);
+        let mut t1 = b.c0;
+        t1.add_assign(&b.c1);
+        let mut c1 = FpWide::mul(&t0, &t1);
+        c1.sub_assign(&v0);
+        c1.sub_assign(&v1);
+
+        Self::mul_by_fp2_nonresidue(&mut v1, a.extension_field);
+        let mut c0 = v0;
+        c0.add_assign(&v1);
+
+        Self {
+            c0,
+            c1,
+            extension_field: a.extension_field
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn reduce(&self) -> Fp2<'a, E, F> {
+        Fp2 {
+            c0: self.c0.reduce(),
+            c1: self.c1.reduce(),
+            extension_field: self.extension_field
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(&other.c0);
+        self.c1.add_assign(&other.c1);
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+    }
+
+    /// Multiplication by the non-residue of Fp6, mirrors `Extension3Over2::multiply_by_non_residue`
+    #[inline(always)]
+    pub(crate) fn mul_by_nonresidue(&mut self, extension_field: &Extension3Over2<'a, E, F>) {
+        // (x0 + x1 * u) * (n0 + n1 * u) = (x0 * n0 + beta * x1 * n1) + (x0 * n1 + x1 * n0) * u
+        let (n0_is_one, n1_is_one) = match extension_field.non_residue_mul_policy {
+            NonResidueMulPolicyFp6::ZeroOne => {
+                let mut c0 = self.c1;
+                Self::mul_by_fp2_nonresidue(&mut c0, self.extension_field);
+                self.c1 = self.c0;
+                self.c0 = c0;
+
+                return;
+            },
+            NonResidueMulPolicyFp6::OneOne => (true, true),
+            NonResidueMulPolicyFp6::OneFull => (true, false),
+            NonResidueMulPolicyFp6::FullOne => (false, true),
+            NonResidueMulPolicyFp6::Full => (false, false),
+        };
+
+        let non_residue = &extension_field.non_residue;
+
+        let (x0_n0, x0_n1) = if n0_is_one && n1_is_one {
+            (self.c0, self.c0)
+        } else {
+            let x0 = self.c0.reduce();
+            let x0_n0 = if n0_is_one { self.c0 } else { FpWide::mul(&x0, &non_residue.c0) };
+            let x0_n1 = if n1_is_one { self.c0 } else { FpWide::mul(&x0, &non_residue.c1) };
+
+            (x0_n0, x0_n1)
+        };
+
+        let (x1_n0, mut x1_n1) = if n0_is_one && n1_is_one {
+            (self.c1, self.c1)
+        } else {
+            let x1 = self.c1.reduce();
+            let x1_n0 = if n0_is_one { self.c1 } else { FpWide::mul(&x1, &non_residue.c0) };
+            let x1_n1 = if n1_is_one { self.c1 } else { FpWide::mul(&x1, &non_residue.c1) };
+
+            (x1_n0, x1_n1)
+        };
+
+        Self::mul_by_fp2_nonresidue(&mut x1_n1, self.extension_field);
+
+        self.c0 = x0_n0;
+        self.c0.add_assign(&x1_n1);
+        self.c1 = x0_n1;
+        self.c1.add_assign(&x1_n0);
+    }
+}
+
+pub(crate) struct Fp6Wide<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > {
+    pub(crate) c0: Fp2Wide<'a, E, F>,
+    pub(crate) c1: Fp2Wide<'a, E, F>,
+    pub(crate) c2: Fp2Wide<'a, E, F>,
+    extension_field: &'a Extension3Over2<'a, E, F>,
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Clone for Fp6Wide<'a, E, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Copy for Fp6Wide<'a, E, F> {}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> > Fp6Wide<'a, E, F> {
+    /// Karatsuba multiplication without reduction, same formulas as `Fp6::mul_assign`
+    pub(crate) fn mul(a: &Fp6<'a, E, F>, b: &Fp6<'a, E, F>) -> Self {
+        let extension_field = a.extension_field;
+
+        let a_a = Fp2Wide::mul(&a.c0, &b.c0);
+        let b_b = Fp2Wide::mul(&a.c1, &b.c1);
+        let mut c_c = Fp2Wide::mul(&a.c2, &b.c2);
+
+        let mut t1 = {
+            let mut tmp0 = a.c1;
+            tmp0.add_assign(&a.c2);
+            let mut tmp1 = b.c1;
+            tmp1.add_assign(&b.c2);
+
+            Fp2Wide::mul(&tmp0, &tmp1)
+        };
+        t1.sub_assign(&b_b);
+        t1.sub_assign(&c_c);
+        t1.mul_by_nonresidue(extension_field);
+        t1.add_assign(&a_a);
+
+        let mut t3 = {
+            let mut tmp0 = a.c0;
+            tmp0.add_assign(&a.c2);
+            let mut tmp1 = b.c0;
+            tmp1.add_assign(&b.c2);
+
+            Fp2Wide::mul(&tmp0, &tmp1)
+        };
+        t3.sub_assign(&a_a);
+        t3.add_assign(&b_b);
+        t3.sub_assign(&c_c);
+
+        let mut t2 = {
+            let mut tmp0 = a.c0;
+            tmp0.add_assign(&a.c1);
+            let mut tmp1 = b.c0;
+            tmp1.add_assign(&b.c1);
+
+            Fp2Wide::mul(&tmp0, &tmp1)
+        };
+        t2.sub_assign(&a_a);
+        t2.sub_assign(&b_b);
+        c_c.mul_by_nonresidue(extension_field);
+        t2.add_assign(&c_c);
+
+        Self {
+            c0: t1,
+            c1: t2,
+            c2: t3,
+            extension_field
+        }
+    }
+
+    /// Same as `Fp6::mul_by_01` without reduction
+    pub(crate) fn mul_by_01(a: &Fp6<'a, E, F>, c0: &Fp2<'a, E, F>, c1: &Fp2<'a, E, F>) -> Self {
+        let extension_field = a.extension_field;
+
+        let a_a = Fp2Wide::mul(&a.c0, c0);
+        let b_b = Fp2Wide::mul(&a.c1, c1);
+
+        let mut t1 = {
+            let mut tmp = a.c1;
+            tmp.add_assign(&a.c2);
+
+            Fp2Wide::mul(c1, &tmp)
+        };
+        t1.sub_assign(&b_b);
+        t1.mul_by_nonresidue(extension_field);
+        t1.add_assign(&a_a);
+
+        let mut t3 = {
+            let mut tmp = a.c0;
+            tmp.add_assign(&a.c2);
+
+            Fp2Wide::mul(c0, &tmp)
+        };
+        t3.sub_assign(&a_a);
+        t3.add_assign(&b_b);
+
+        let mut t2 = {
+            let mut tmp0 = *c0;
+            tmp0.add_assign(c1);
+            let mut tmp1 = a.c0;
+            tmp1.add_assign(&a.c1);
+
+            Fp2Wide::mul(&tmp0, &tmp1)
+        };
+        t2.sub_assign(&a_a);
+        t2.sub_assign(&b_b);
+
+        Self {
+            c0: t1,
+            c1: t2,
+            c2: t3,
+            extension_field
+        }
+    }
+
+    /// Same as `Fp6::mul_by_1` without reduction
+    pub(crate) fn mul_by_1(a: &Fp6<'a, E, F>, c1: &Fp2<'a, E, F>) -> Self {
+        let extension_field = a.extension_field;
+
+        let b_b = Fp2Wide::mul(&a.c1, c1);
+
+        let mut t1 = {
+            let mut tmp = a.c1;
+            tmp.add_assign(&a.c2);
+
+            Fp2Wide::mul(c1, &tmp)
+        };
+        t1.sub_assign(&b_b);
+        t1.mul_by_nonresidue(extension_field);
+
+        let mut t2 = {
+            let mut tmp = a.c0;
+            tmp.add_assign(&a.c1);
+
+            Fp2Wide::mul(c1, &tmp)
+        };
+        t2.sub_assign(&b_b);
+
+        Self {
+            c0: t1,
+            c1: t2,
+            c2: b_b,
+            extension_field
+        }
+    }
+
+    /// Multiplication of every coefficient by an element of Fp2
+    pub(crate) fn mul_by_fp2(a: &Fp6<'a, E, F>, element: &Fp2<'a, E, F>) -> Self {
+        Self {
+            c0: Fp2Wide::mul(&a.c0, element),
+            c1: Fp2Wide::mul(&a.c1, element),
+            c2: Fp2Wide::mul(&a.c2, element),
+            extension_field: a.extension_field
+        }
+    }
+
+    pub(crate) fn reduce(&self) -> Fp6<'a, E, F> {
+        Fp6 {
+            c0: self.c0.reduce(),
+            c1: self.c1.reduce(),
+            c2: self.c2.reduce(),
+            extension_field: self.extension_field
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(&other.c0);
+        self.c1.add_assign(&other.c1);
+        self.c2.add_assign(&other.c2);
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+        self.c2.sub_assign(&other.c2);
+    }
+
+    /// Multiplication by the non-residue of Fp12, mirrors `Extension2Over3Over2::multiply_by_non_residue`
+    #[inline(always)]
+    pub(crate) fn mul_by_nonresidue(&mut self) {
+        let mut new_c0 = self.c2;
+        new_c0.mul_by_nonresidue(self.extension_field);
+        self.c2 = self.c1;
+        self.c1 = self.c0;
+        self.c0 = new_c0;
+    }
+}
@@ -4,6 +4,9 @@ pub mod fp4_as_2_over_2;
 pub mod fp6_as_2_over_3;
 pub mod fp6_as_3_over_2;
 pub mod fp12_as_2_over3_over_2;
+pub mod fp12_as_3_over_4;
+pub mod fp24_as_2_over3_over_4;
+pub(crate) mod lazy_reduction;
 
 use crate::fp::Fp;
 use crate::field::{SizedPrimeField};
@@ -1,4 +1,4 @@
-use serde::{Deserialize};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use crate::errors::ApiError;
 
@@ -6,6 +6,7 @@ use once_cell::sync::Lazy;
 use serde_json;
 
 use super::parsers::*;
+use crate::public_interface::constants::{NUM_LIMBS_MIN, NUM_LIMBS_MAX};
 
 pub(crate) trait ArithmeticAdditionParams {
     fn params(&self) -> &HashMap<usize, u64>;
@@ -19,11 +20,55 @@ pub(crate) trait ArithmeticMultiexpParams {
     fn params(&self) -> (u64, (usize, u64), &HashMap<usize, u64>);
 }
 
-#[derive(Clone, Deserialize, Debug)]
-pub(crate) struct G1G2AdditionParams {
-    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints")]
+/// Price lookup table over the number of modulus limbs
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct G1G2AdditionParams {
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
     #[serde(rename = "price")]
-    lookup_parameters: HashMap<usize, u64>
+    pub lookup_parameters: HashMap<usize, u64>
+}
+
+/// Lookup tables over the number of modulus limbs must cover every supported number of limbs
+pub(crate) fn validate_limbs_lookup(model: &str, table: &HashMap<usize, u64>) -> Result<(), ApiError> {
+    for limbs in NUM_LIMBS_MIN..=NUM_LIMBS_MAX {
+        if !table.contains_key(&limbs) {
+            return Err(ApiError::InputError(format!("{} gas model has no price for {} modulus limbs", model, limbs)));
+        }
+    }
+
+    Ok(())
+}
+
+impl G1G2AdditionParams {
+    pub(crate) fn validate(&self, model: &str) -> Result<(), ApiError> {
+        validate_limbs_lookup(model, &self.lookup_parameters)
+    }
+}
+
+impl G1G2MultiplicationParams {
+    pub(crate) fn validate(&self, model: &str) -> Result<(), ApiError> {
+        validate_limbs_lookup(model, &self.base)?;
+        validate_limbs_lookup(model, &self.per_limb)
+    }
+}
+
+impl G1G2MultiexpParams {
+    pub(crate) fn validate(&self, model: &str) -> Result<(), ApiError> {
+        if self.discount_multiplier == 0 {
+            return Err(ApiError::InputError(format!("{} gas model has zero discount multiplier", model)));
+        }
+        if self.max_pairs == 0 {
+            return Err(ApiError::InputError(format!("{} gas model has zero maximum number of pairs", model)));
+        }
+        for num_pairs in 1..=self.max_pairs {
+            if !self.discounts.contains_key(&num_pairs) {
+                return Err(ApiError::InputError(format!("{} gas model has no discount for {} pairs", model, num_pairs)));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl ArithmeticAdditionParams for G1G2AdditionParams {
@@ -32,15 +77,17 @@ impl ArithmeticAdditionParams for G1G2AdditionParams {
     }
 }
 
-#[derive(Clone, Deserialize, Debug)]
-pub(crate) struct G1G2MultiplicationParams {
-    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints")]
+/// One-off and per group order limb price lookup tables over the number of modulus limbs
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct G1G2MultiplicationParams {
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
     #[serde(rename = "base")]
-    base: HashMap<usize, u64>,
+    pub base: HashMap<usize, u64>,
 
-    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints")]
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
     #[serde(rename = "per_limb")]
-    per_limb: HashMap<usize, u64>
+    pub per_limb: HashMap<usize, u64>
 }
 
 impl ArithmeticMultiplicationParams for G1G2MultiplicationParams {
@@ -49,24 +96,26 @@ impl ArithmeticMultiplicationParams for G1G2MultiplicationParams {
     }
 }
 
-#[derive(Clone, Deserialize, Debug)]
-pub(crate) struct G1G2MultiexpParams {
+/// Multiexp discounts lookup table over the number of pairs
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct G1G2MultiexpParams {
     // #[serde(deserialize_with = "parse_usize")]
     #[serde(rename = "max_pairs")]
-    max_pairs: usize,
+    pub max_pairs: usize,
 
     // #[serde(deserialize_with = "parse_u64")]
     #[serde(rename = "max_discount")]
-    max_discount: u64,
+    pub max_discount: u64,
 
     // #[serde(deserialize_with = "parse_u64")]
     #[serde(rename = "discount_multiplier")]
-    discount_multiplier: u64,
+    pub discount_multiplier: u64,
 
     // #[serde(deserialize_with = "parse_hashmap_usize_u64")]
-    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints")]
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
     #[serde(rename = "discounts")]
-    discounts: HashMap<usize, u64>
+    pub discounts: HashMap<usize, u64>
 }
 
 impl ArithmeticMultiexpParams for G1G2MultiexpParams {
@@ -159,6 +208,47 @@ pub(crate) fn meter_multiexp<P: ArithmeticMultiplicationParams, M: ArithmeticMul
     Ok(result)
 }
 
+/// Map to curve is dominated by exponentiations with modulus sized exponents for the square root,
+/// Legendre symbols and inversions, that is cheaper than a multiplication by a scalar of the same
+/// size, so it's priced as such a multiplication. Tonelli-Shanks adds up to s^2 / 2 squarings per
+/// square root for modulus - 1 = 2^s * t, that is bounded by rejecting s above `MAX_MODULUS_TWO_ADICITY`.
+/// Evaluation of the isogeny map takes a few field multiplications per degree and is priced as
+/// one point addition per degree
+#[cfg(feature = "mappings")]
+pub(crate) fn meter_map_to_curve<A: ArithmeticAdditionParams, P: ArithmeticMultiplicationParams>(
+    modulus_limbs: usize,
+    modulus_units: usize,
+    map_degree: usize,
+    addition_parameters: &A,
+    multiplication_parameters: &P
+) -> Result<u64, ApiError> {
+    let mapping = meter_multiplication(modulus_limbs, modulus_units, multiplication_parameters, true)?;
+    let per_degree = meter_addition(modulus_limbs, addition_parameters)?;
+
+    let isogeny = per_degree.checked_mul(map_degree as u64).ok_or(ApiError::Overflow)?;
+    let result = mapping.checked_add(isogeny).ok_or(ApiError::Overflow)?;
+
+    Ok(result)
+}
+
+/// Decompression takes a square root in the field of coordinates that is an exponentiation with
+/// an exponent of the modulus size (a few of them in extensions), and it's priced as a multiplication
+/// in the corresponding group for a scalar of the same size. Square roots that use Tonelli-Shanks
+/// also search for a quadratic non-residue, and in Fp3 take five exponentiations, so those are
+/// charged as `multiplications_per_point` multiplications
+pub(crate) fn meter_decompression<P: ArithmeticMultiplicationParams>(
+    modulus_limbs: usize,
+    modulus_units: usize,
+    num_points: usize,
+    multiplications_per_point: usize,
+    multiplication_parameters: &P
+) -> Result<u64, ApiError> {
+    let per_point = meter_multiplication(modulus_limbs, modulus_units, multiplication_parameters, true)?;
+    let per_point = per_point.checked_mul(multiplications_per_point as u64).ok_or(ApiError::Overflow)?;
+
+    per_point.checked_mul(num_points as u64).ok_or(ApiError::Overflow)
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -1,4 +1,4 @@
-use serde::{Deserialize};
+use serde::{Deserialize, Serialize};
 use crate::errors::ApiError;
 
 use once_cell::sync::Lazy;
@@ -6,8 +6,10 @@ use serde_json;
 use std::collections::HashMap;
 
 use super::parsers::*;
+use super::schedule::GasSchedule;
 
 use crate::pairings::calculate_hamming_weight;
+use crate::engines::const_arithmetic::{is_bls12_modulus, is_bn_modulus};
 use crate::public_interface::decode_utils::*;
 use crate::public_interface::sane_limits::*;
 
@@ -15,59 +17,253 @@ pub(crate) const MNT4_MAX_MODULUS_POWER: usize = 4;
 pub(crate) const MNT6_MAX_MODULUS_POWER: usize = 6;
 pub(crate) const BN_MAX_MODULUS_POWER: usize = 6;
 pub(crate) const BLS12_MAX_MODULUS_POWER: usize = 6;
+pub(crate) const BW6_MAX_MODULUS_POWER: usize = 6;
+pub(crate) const BLS24_MAX_MODULUS_POWER: usize = 6;
 
-#[derive(Clone, Deserialize, Debug)]
-pub(crate) struct MntPairingParams {
-    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints")]
-    one_off: HashMap<usize, u64>,
+// Fp4 multiplication is 3 Fp2 multiplications
+pub(crate) const BLS24_G2_TO_EXT_2_COST_MULTIPLIER: u64 = 3;
 
-    multiplier: u64,
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct MntPairingParams {
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
+    pub one_off: HashMap<usize, u64>,
 
-    miller_features: Vec<(String, u64)>,
+    pub multiplier: u64,
 
-    miller: Vec<(u64, Vec<(usize, usize)>)>,
+    pub miller_features: Vec<(String, u64)>,
 
-    final_exp_features: Vec<(String, u64)>,
+    pub miller: Vec<(u64, Vec<(usize, usize)>)>,
 
-    final_exp: Vec<(u64, Vec<(usize, usize)>)>
+    pub final_exp_features: Vec<(String, u64)>,
+
+    pub final_exp: Vec<(u64, Vec<(usize, usize)>)>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Bw6PairingParams {
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
+    pub one_off: HashMap<usize, u64>,
+
+    pub multiplier: u64,
+
+    /// Features of both Miller loops are summed, as they only differ in the loop parameter
+    pub miller_features: Vec<(String, u64)>,
+
+    pub miller: Vec<(u64, Vec<(usize, usize)>)>,
+
+    pub final_exp_features: Vec<(String, u64)>,
+
+    pub final_exp: Vec<(u64, Vec<(usize, usize)>)>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Bls12PairingParams {
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
+    pub one_off: HashMap<usize, u64>,
+
+    /// One-off costs for moduli given by the family polynomial in the loop parameter, that
+    /// use a shorter final exponentiation chain. Only sizes that such moduli can have are present
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
+    pub family_one_off: HashMap<usize, u64>,
+
+    pub multiplier: u64,
+
+    pub miller_features: Vec<(String, u64)>,
+
+    pub miller: Vec<(u64, Vec<(usize, usize)>)>,
+
+    pub final_exp_features: Vec<(String, u64)>,
+
+    pub final_exp: Vec<(u64, Vec<(usize, usize)>)>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Bls24PairingParams {
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
+    pub one_off: HashMap<usize, u64>,
+
+    pub multiplier: u64,
+
+    pub miller_features: Vec<(String, u64)>,
+
+    pub miller: Vec<(u64, Vec<(usize, usize)>)>,
+
+    pub final_exp_features: Vec<(String, u64)>,
+
+    pub final_exp: Vec<(u64, Vec<(usize, usize)>)>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct BnPairingParams {
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
+    pub one_off: HashMap<usize, u64>,
+
+    /// One-off costs for moduli given by the family polynomial in the loop parameter, that
+    /// use a shorter final exponentiation chain. Only sizes that such moduli can have are present
+    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints", serialize_with = "serialize_hashmap_usize_u64_as_ints")]
+    pub family_one_off: HashMap<usize, u64>,
+
+    pub multiplier: u64,
+
+    pub miller_features: Vec<(String, u64)>,
+
+    pub miller: Vec<(u64, Vec<(usize, usize)>)>,
+
+    pub final_exp_features: Vec<(String, u64)>,
+
+    pub final_exp: Vec<(u64, Vec<(usize, usize)>)>
 }
 
-#[derive(Clone, Deserialize, Debug)]
-pub(crate) struct Bls12PairingParams {
-    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints")]
-    one_off: HashMap<usize, u64>,
+const BLS12_MILLER_FEATURES: [(&str, usize); 3] = [("x_bit_length", 1), ("x_hamming_weight", 1), ("modulus_limbs", BLS12_MAX_MODULUS_POWER)];
+const BLS12_FINAL_EXP_FEATURES: [(&str, usize); 3] = [("x_bit_length", 1), ("x_hamming_weight", 1), ("modulus_limbs", BLS12_MAX_MODULUS_POWER)];
+const BLS24_MILLER_FEATURES: [(&str, usize); 3] = [("x_bit_length", 1), ("x_hamming_weight", 1), ("modulus_limbs", BLS24_MAX_MODULUS_POWER)];
+const BLS24_FINAL_EXP_FEATURES: [(&str, usize); 3] = [("x_bit_length", 1), ("x_hamming_weight", 1), ("modulus_limbs", BLS24_MAX_MODULUS_POWER)];
+const BN_MILLER_FEATURES: [(&str, usize); 3] = [("six_u_plus_two_bit_length", 1), ("six_u_plus_two_hamming", 1), ("modulus_limbs", BN_MAX_MODULUS_POWER)];
+const BN_FINAL_EXP_FEATURES: [(&str, usize); 3] = [("x_bit_length", 1), ("x_hamming_weight", 1), ("modulus_limbs", BN_MAX_MODULUS_POWER)];
+const MNT4_MILLER_FEATURES: [(&str, usize); 3] = [("x_bit_length", 1), ("x_hamming_weight", 1), ("modulus_limbs", MNT4_MAX_MODULUS_POWER)];
+const MNT4_FINAL_EXP_FEATURES: [(&str, usize); 5] = [
+    ("exp_w0_bit_length", 1), ("exp_w0_hamming", 1), ("exp_w1_bit_length", 1), ("exp_w1_hamming", 1), ("modulus_limbs", MNT4_MAX_MODULUS_POWER)
+];
+const MNT6_MILLER_FEATURES: [(&str, usize); 3] = [("x_bit_length", 1), ("x_hamming_weight", 1), ("modulus_limbs", MNT6_MAX_MODULUS_POWER)];
+const MNT6_FINAL_EXP_FEATURES: [(&str, usize); 5] = [
+    ("exp_w0_bit_length", 1), ("exp_w0_hamming", 1), ("exp_w1_bit_length", 1), ("exp_w1_hamming", 1), ("modulus_limbs", MNT6_MAX_MODULUS_POWER)
+];
+const BW6_MILLER_FEATURES: [(&str, usize); 3] = [("ate_loops_bit_length", 1), ("ate_loops_hamming", 1), ("modulus_limbs", BW6_MAX_MODULUS_POWER)];
+const BW6_FINAL_EXP_FEATURES: [(&str, usize); 5] = [
+    ("exp_w0_bit_length", 1), ("exp_w0_hamming", 1), ("exp_w1_bit_length", 1), ("exp_w1_hamming", 1), ("modulus_limbs", BW6_MAX_MODULUS_POWER)
+];
+
+/// Checks that polynomial model is declared over the features that metering function
+/// supplies and that every term can be evaluated by `eval_model`
+fn validate_polynomial_model(
+    model: &str,
+    features: &[(String, u64)],
+    terms: &[(u64, Vec<(usize, usize)>)],
+    expected_features: &[(&str, usize)]
+) -> Result<(), ApiError> {
+    let features_match = features.len() == expected_features.len() && features.iter().zip(expected_features.iter()).all(|((name, power), (expected_name, expected_power))| {
+        name == expected_name && *power == *expected_power as u64
+    });
+    if !features_match {
+        return Err(ApiError::InputError(format!("{} gas model must have features {:?}, got {:?}", model, expected_features, features)));
+    }
+    if terms.is_empty() {
+        return Err(ApiError::InputError(format!("{} gas model has no terms", model)));
+    }
 
-    multiplier: u64,
+    let mut max_variable = 0usize;
+    for (_, variables_and_powers) in terms.iter() {
+        for (variable, power) in variables_and_powers.iter() {
+            let max_power = expected_features.get(*variable).ok_or_else(|| {
+                ApiError::InputError(format!("{} gas model uses unknown variable {}", model, variable))
+            })?.1;
+            if *power == 0 || *power > max_power {
+                return Err(ApiError::InputError(format!("{} gas model uses variable {} in power {}, max power is {}", model, variable, power, max_power)));
+            }
+            max_variable = std::cmp::max(max_variable, *variable);
+        }
+    }
+    if max_variable + 1 != expected_features.len() {
+        return Err(ApiError::InputError(format!("{} gas model does not use the last feature", model)));
+    }
 
-    miller_features: Vec<(String, u64)>,
+    Ok(())
+}
 
-    miller: Vec<(u64, Vec<(usize, usize)>)>,
+macro_rules! impl_pairing_params_validation {
+    ($params: ident) => {
+        impl $params {
+            pub(crate) fn validate(
+                &self,
+                model: &str,
+                expected_miller_features: &[(&str, usize)],
+                expected_final_exp_features: &[(&str, usize)]
+            ) -> Result<(), ApiError> {
+                super::meter_arith::validate_limbs_lookup(model, &self.one_off)?;
+                if self.multiplier == 0 {
+                    return Err(ApiError::InputError(format!("{} gas model has zero multiplier", model)));
+                }
+                validate_polynomial_model(model, &self.miller_features, &self.miller, expected_miller_features)?;
+                validate_polynomial_model(model, &self.final_exp_features, &self.final_exp, expected_final_exp_features)
+            }
+        }
+    };
+}
+
+impl_pairing_params_validation!(MntPairingParams);
+impl_pairing_params_validation!(Bls12PairingParams);
+impl_pairing_params_validation!(Bls24PairingParams);
+impl_pairing_params_validation!(BnPairingParams);
+impl_pairing_params_validation!(Bw6PairingParams);
+
+impl MntPairingParams {
+    pub(crate) fn validate_mnt4(&self) -> Result<(), ApiError> {
+        self.validate("MNT4 pairing", &MNT4_MILLER_FEATURES, &MNT4_FINAL_EXP_FEATURES)
+    }
+
+    pub(crate) fn validate_mnt6(&self) -> Result<(), ApiError> {
+        self.validate("MNT6 pairing", &MNT6_MILLER_FEATURES, &MNT6_FINAL_EXP_FEATURES)
+    }
+}
 
-    final_exp_features: Vec<(String, u64)>,
+/// Family one-off costs are only given for some modulus sizes and can not exceed the generic ones
+fn validate_family_one_off(model: &str, family_one_off: &HashMap<usize, u64>, one_off: &HashMap<usize, u64>) -> Result<(), ApiError> {
+    for (limbs, cost) in family_one_off.iter() {
+        let generic_cost = one_off.get(limbs).ok_or_else(|| {
+            ApiError::InputError(format!("{} gas model has family one-off cost for unsupported {} modulus limbs", model, limbs))
+        })?;
+        if cost > generic_cost {
+            return Err(ApiError::InputError(format!("{} gas model has family one-off cost above the generic one for {} modulus limbs", model, limbs)));
+        }
+    }
 
-    final_exp: Vec<(u64, Vec<(usize, usize)>)>
+    Ok(())
 }
 
-#[derive(Clone, Deserialize, Debug)]
-pub(crate) struct BnPairingParams {
-    #[serde(deserialize_with = "parse_hashmap_usize_u64_from_ints")]
-    one_off: HashMap<usize, u64>,
+/// One-off cost for the modulus size, moduli given by the family polynomial use the family table if it has the size
+fn select_one_off(one_off: &HashMap<usize, u64>, family_one_off: &HashMap<usize, u64>, modulus_limbs: usize, family_modulus: bool) -> Result<u64, ApiError> {
+    let family_cost = if family_modulus { family_one_off.get(&modulus_limbs) } else { None };
 
-    multiplier: u64,
+    family_cost.or_else(|| one_off.get(&modulus_limbs)).copied().ok_or(ApiError::MissingValue)
+}
 
-    miller_features: Vec<(String, u64)>,
+impl Bls12PairingParams {
+    pub(crate) fn validate_bls12(&self) -> Result<(), ApiError> {
+        self.validate("BLS12 pairing", &BLS12_MILLER_FEATURES, &BLS12_FINAL_EXP_FEATURES)?;
+        validate_family_one_off("BLS12 pairing", &self.family_one_off, &self.one_off)
+    }
+}
 
-    miller: Vec<(u64, Vec<(usize, usize)>)>,
+impl Bls24PairingParams {
+    pub(crate) fn validate_bls24(&self) -> Result<(), ApiError> {
+        self.validate("BLS24 pairing", &BLS24_MILLER_FEATURES, &BLS24_FINAL_EXP_FEATURES)
+    }
+}
 
-    final_exp_features: Vec<(String, u64)>,
+impl BnPairingParams {
+    pub(crate) fn validate_bn(&self) -> Result<(), ApiError> {
+        self.validate("BN pairing", &BN_MILLER_FEATURES, &BN_FINAL_EXP_FEATURES)?;
+        validate_family_one_off("BN pairing", &self.family_one_off, &self.one_off)
+    }
+}
 
-    final_exp: Vec<(u64, Vec<(usize, usize)>)>
+impl Bw6PairingParams {
+    pub(crate) fn validate_bw6(&self) -> Result<(), ApiError> {
+        self.validate("BW6 pairing", &BW6_MILLER_FEATURES, &BW6_FINAL_EXP_FEATURES)
+    }
 }
 
 static MNT4_PARAMS_JSON: &'static str = include_str!("mnt4_model.json");
 static MNT6_PARAMS_JSON: &'static str = include_str!("mnt6_model.json");
 static BLS12_PARAMS_JSON: &'static str = include_str!("bls12_model.json");
+static BLS24_PARAMS_JSON: &'static str = include_str!("bls24_model.json");
 static BN_PARAMS_JSON: &'static str = include_str!("bn_model.json");
+static BW6_PARAMS_JSON: &'static str = include_str!("bw6_model.json");
 
 pub(crate) static MNT4_PARAMS_INSTANCE: Lazy<MntPairingParams> = Lazy::new(|| {
     serde_json::from_str(MNT4_PARAMS_JSON).expect("must deserialize parameters")
@@ -81,11 +277,19 @@ pub(crate) static BLS12_PARAMS_INSTANCE: Lazy<Bls12PairingParams> = Lazy::new(||
     serde_json::from_str(BLS12_PARAMS_JSON).expect("must deserialize parameters")
 });
 
+pub(crate) static BLS24_PARAMS_INSTANCE: Lazy<Bls24PairingParams> = Lazy::new(|| {
+    serde_json::from_str(BLS24_PARAMS_JSON).expect("must deserialize parameters")
+});
+
 pub(crate) static BN_PARAMS_INSTANCE: Lazy<BnPairingParams> = Lazy::new(|| {
     serde_json::from_str(BN_PARAMS_JSON).expect("must deserialize parameters")
 });
 
-pub(crate) fn meter_mnt_pairing(input: &[u8], params: &MntPairingParams, max_power: usize, ext_degree: usize) -> Result<u64, ApiError> {
+pub(crate) static BW6_PARAMS_INSTANCE: Lazy<Bw6PairingParams> = Lazy::new(|| {
+    serde_json::from_str(BW6_PARAMS_JSON).expect("must deserialize parameters")
+});
+
+pub(crate) fn meter_mnt_pairing(input: &DecodedInput, params: &MntPairingParams, schedule: &GasSchedule, max_power: usize, ext_degree: usize) -> Result<u64, ApiError> {
     let (
         modulus, 
         order_len, 
@@ -94,10 +298,11 @@ pub(crate) fn meter_mnt_pairing(input: &[u8], params: &MntPairingParams, max_pow
         (exp_w0_bits, exp_w0_hamming),
         (exp_w1_bits, exp_w1_hamming),
         (num_g1_subgroup_checks, num_g2_subgroup_checks),
+        compressed,
         _
-    ) = parse_mnt_pairing_parameters(&input, ext_degree)?;
+    ) = parse_mnt_pairing_parameters(input, ext_degree)?;
 
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let modulus_limbs = input.modulus_limbs;
     // let order_limbs = num_units_for_group_order(&order)?;
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
@@ -112,17 +317,17 @@ pub(crate) fn meter_mnt_pairing(input: &[u8], params: &MntPairingParams, max_pow
         max_power
     )?;
 
-    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
     let g1_subgroup_checks_cost = g1_subgroup_check_cost_per_point.checked_mul(num_g1_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g1_subgroup_checks_cost).ok_or(ApiError::Overflow)?;
 
     let g2_subgroup_check_cost_per_point = match ext_degree {
         2 => {
-            super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE, false)?
+            super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_2_multiplication, false)?
         },
         3 => {
-            super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G2_EXT_3_MULTIPLICATION_PARAMS_INSTANCE, false)?
+            super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_3_multiplication, false)?
         },
         _ => {
             return Err(ApiError::InputError("Invalid extension degree for MNT4/6 pairing cost calculation".to_owned()));
@@ -133,6 +338,11 @@ pub(crate) fn meter_mnt_pairing(input: &[u8], params: &MntPairingParams, max_pow
 
     estimate = estimate.checked_add(g2_subgroup_checks_cost).ok_or(ApiError::Overflow)?;
 
+    // modulus encoding has a non-zero top byte, so its length follows from the bit length
+    let modulus_len = (modulus.bits() + 7) / 8;
+    estimate = super::add_decompression_g1(schedule, estimate, &modulus, modulus_len, compressed, num_pairs)?;
+    estimate = super::add_decompression_g2(schedule, estimate, &modulus, modulus_len, ext_degree, compressed, num_pairs)?;
+
     Ok(estimate)
 }
 
@@ -198,18 +408,111 @@ fn calculate_mnt_pairing_cost(
     Ok(result)
 }
 
-pub(crate) fn meter_bls12_pairing(input: &[u8], params: &Bls12PairingParams, max_power: usize) -> Result<u64, ApiError> {
+pub(crate) fn meter_bw6_pairing(input: &DecodedInput, params: &Bw6PairingParams, schedule: &GasSchedule) -> Result<u64, ApiError> {
+    let (
+        modulus, 
+        order_len, 
+        num_pairs, 
+        (ate_loop_1_bits, ate_loop_1_hamming), 
+        (ate_loop_2_bits, ate_loop_2_hamming), 
+        (exp_w0_bits, exp_w0_hamming),
+        (exp_w1_bits, exp_w1_hamming),
+        (num_g1_subgroup_checks, num_g2_subgroup_checks),
+        compressed,
+        _
+    ) = parse_bw6_pairing_parameters(input)?;
+
+    let modulus_limbs = input.modulus_limbs;
+    let order_limbs = num_units_for_group_order_length(order_len)?;
+
+    let mut estimate = calculate_bw6_pairing_cost(
+        modulus_limbs,
+        num_pairs,
+        (ate_loop_1_bits + ate_loop_2_bits, ate_loop_1_hamming + ate_loop_2_hamming), 
+        (exp_w0_bits, exp_w0_hamming),
+        (exp_w1_bits, exp_w1_hamming),
+        params
+    )?;
+
+    // twist is defined over the base field, so both groups are checked at the G1 price
+    let subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
+    let num_subgroup_checks = (num_g1_subgroup_checks + num_g2_subgroup_checks) as u64;
+    let subgroup_checks_cost = subgroup_check_cost_per_point.checked_mul(num_subgroup_checks).ok_or(ApiError::Overflow)?;
+
+    estimate = estimate.checked_add(subgroup_checks_cost).ok_or(ApiError::Overflow)?;
+
+    // modulus encoding has a non-zero top byte, so its length follows from the bit length
+    let modulus_len = (modulus.bits() + 7) / 8;
+    let num_points = num_pairs.checked_mul(2).ok_or(ApiError::Overflow)?;
+    estimate = super::add_decompression_g1(schedule, estimate, &modulus, modulus_len, compressed, num_points)?;
+
+    Ok(estimate)
+}
+
+fn calculate_bw6_pairing_cost(
+    modulus_limbs: usize,
+    num_pairs: usize,
+    (ate_loops_bits, ate_loops_hamming): (u64, u64), 
+    (exp_w0_bits, exp_w0_hamming): (u64, u64),
+    (exp_w1_bits, exp_w1_hamming): (u64, u64),
+    params: &Bw6PairingParams
+) -> Result<u64, ApiError> {
+    const ATE_LOOPS_BITS_INDEX: usize = 0;
+    const ATE_LOOPS_HAMMING_INDEX: usize = 1;
+    const EXP_W0_LOOP_BITS_INDEX: usize = 2;
+    const EXP_W0_HAMMING_INDEX: usize = 3;
+    const EXP_W1_LOOP_BITS_INDEX: usize = 4;
+    const EXP_W1_HAMMING_INDEX: usize = 5;
+
+    let one_off = *params.one_off.get(&modulus_limbs).ok_or(ApiError::MissingValue)?;
+
+    let modulus_limbs_powers = make_powers(modulus_limbs as u64, BW6_MAX_MODULUS_POWER)?;
+    let params_vector = vec![ate_loops_bits, ate_loops_hamming, exp_w0_bits, exp_w0_hamming, exp_w1_bits, exp_w1_hamming];
+
+    let miller_cost = {
+        let miller_params = vec![
+            &params_vector[ATE_LOOPS_BITS_INDEX..(ATE_LOOPS_BITS_INDEX+1)], 
+            &params_vector[ATE_LOOPS_HAMMING_INDEX..(ATE_LOOPS_HAMMING_INDEX+1)], 
+            &modulus_limbs_powers[..] 
+            ];
+        let miller_cost = eval_model(&params.miller, &miller_params)?;
+
+        miller_cost.checked_mul(num_pairs as u64).ok_or(ApiError::Overflow)?
+    };
+
+    let final_exp_cost = {
+        let final_exp_params = vec![
+            &params_vector[EXP_W0_LOOP_BITS_INDEX..(EXP_W0_LOOP_BITS_INDEX+1)], 
+            &params_vector[EXP_W0_HAMMING_INDEX..(EXP_W0_HAMMING_INDEX+1)], 
+            &params_vector[EXP_W1_LOOP_BITS_INDEX..(EXP_W1_LOOP_BITS_INDEX+1)], 
+            &params_vector[EXP_W1_HAMMING_INDEX..(EXP_W1_HAMMING_INDEX+1)], 
+            &modulus_limbs_powers[..] 
+            ];
+
+        eval_model(&params.final_exp, &final_exp_params)?
+    };
+
+    let mut result = one_off;
+    result = result.checked_add(miller_cost).ok_or(ApiError::Overflow)?;
+    result = result.checked_add(final_exp_cost).ok_or(ApiError::Overflow)?;
+    result = result.checked_div(params.multiplier).ok_or(ApiError::Overflow)?;
+
+    Ok(result)
+}
+
+pub(crate) fn meter_bls12_pairing(input: &DecodedInput, params: &Bls12PairingParams, schedule: &GasSchedule, max_power: usize) -> Result<u64, ApiError> {
     let (
         modulus, 
         order_len, 
         num_pairs, 
         x,
-        _,
+        x_is_negative,
         (num_g1_subgroup_checks, num_g2_subgroup_checks),
+        compressed,
         _
-    ) = parse_bls12_bn_pairing_parameters(&input, MAX_BLS12_X_BIT_LENGTH)?;
+    ) = parse_bls12_bn_pairing_parameters(input, MAX_BLS12_X_BIT_LENGTH)?;
 
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let modulus_limbs = input.modulus_limbs;
     // let order_limbs = num_units_for_group_order(&order)?;
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
@@ -225,16 +528,65 @@ pub(crate) fn meter_bls12_pairing(input: &[u8], params: &Bls12PairingParams, max
         order_limbs,
         num_pairs,
         (x_bits as u64, x_hamming as u64),
+        is_bls12_modulus(modulus.as_ref(), x.as_ref(), x_is_negative),
         params,
         max_power
     )?;
 
-    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
+    let g1_subgroup_check_cost = g1_subgroup_check_cost_per_point.checked_mul(num_g1_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
+
+    estimate = estimate.checked_add(g1_subgroup_check_cost).ok_or(ApiError::Overflow)?;
+
+    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_2_multiplication, false)?;
+    let g2_subgroup_check_cost = g2_subgroup_check_cost_per_point.checked_mul(num_g2_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
+
+    estimate = estimate.checked_add(g2_subgroup_check_cost).ok_or(ApiError::Overflow)?;
+
+    // modulus encoding has a non-zero top byte, so its length follows from the bit length
+    let modulus_len = (modulus.bits() + 7) / 8;
+    estimate = super::add_decompression_g1(schedule, estimate, &modulus, modulus_len, compressed, num_pairs)?;
+    estimate = super::add_decompression_g2(schedule, estimate, &modulus, modulus_len, 2, compressed, num_pairs)?;
+
+    Ok(estimate)
+}
+
+
+pub(crate) fn meter_bls24_pairing(input: &DecodedInput, params: &Bls24PairingParams, schedule: &GasSchedule) -> Result<u64, ApiError> {
+    let (
+        _, 
+        order_len, 
+        num_pairs, 
+        x,
+        _,
+        (num_g1_subgroup_checks, num_g2_subgroup_checks),
+        _
+    ) = parse_bls24_pairing_parameters(input, MAX_BLS24_X_BIT_LENGTH)?;
+
+    let modulus_limbs = input.modulus_limbs;
+    let order_limbs = num_units_for_group_order_length(order_len)?;
+
+    let x_bits = x.bits();
+    let x_hamming = calculate_hamming_weight(x.as_ref());
+
+    if x_hamming > MAX_BLS24_X_HAMMING {
+        return Err(ApiError::InputError(format!("Hamming weight for scalar is too large, file {}, line {}", file!(), line!())));
+    }
+
+    let mut estimate = calculate_bls24_pairing_cost(
+        modulus_limbs,
+        num_pairs,
+        (x_bits as u64, x_hamming as u64),
+        params
+    )?;
+
+    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
     let g1_subgroup_check_cost = g1_subgroup_check_cost_per_point.checked_mul(num_g1_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g1_subgroup_check_cost).ok_or(ApiError::Overflow)?;
 
-    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_2_multiplication, false)?;
+    let g2_subgroup_check_cost_per_point = g2_subgroup_check_cost_per_point.checked_mul(BLS24_G2_TO_EXT_2_COST_MULTIPLIER).ok_or(ApiError::Overflow)?;
     let g2_subgroup_check_cost = g2_subgroup_check_cost_per_point.checked_mul(num_g2_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g2_subgroup_check_cost).ok_or(ApiError::Overflow)?;
@@ -242,8 +594,50 @@ pub(crate) fn meter_bls12_pairing(input: &[u8], params: &Bls12PairingParams, max
     Ok(estimate)
 }
 
+fn calculate_bls24_pairing_cost(
+    modulus_limbs: usize,
+    num_pairs: usize,
+    (x_bits, x_hamming): (u64, u64),
+    params: &Bls24PairingParams
+) -> Result<u64, ApiError> {
+    const X_BITS_INDEX: usize = 0;
+    const X_HAMMING_INDEX: usize = 1;
+
+    let one_off = *params.one_off.get(&modulus_limbs).ok_or(ApiError::MissingValue)?;
+
+    let modulus_limbs_powers = make_powers(modulus_limbs as u64, BLS24_MAX_MODULUS_POWER)?;
+    let params_vector = vec![x_bits, x_hamming];
+
+    let miller_cost = {
+        let miller_params = vec![
+            &params_vector[X_BITS_INDEX..(X_BITS_INDEX+1)], 
+            &params_vector[X_HAMMING_INDEX..(X_HAMMING_INDEX+1)], 
+            &modulus_limbs_powers[..] 
+            ];
+        let miller_cost = eval_model(&params.miller, &miller_params)?;
+
+        miller_cost.checked_mul(num_pairs as u64).ok_or(ApiError::Overflow)?
+    };
 
-pub(crate) fn meter_bn_pairing(input: &[u8], params: &BnPairingParams, max_power: usize) -> Result<u64, ApiError> {
+    let final_exp_cost = {
+        let final_exp_params = vec![
+            &params_vector[X_BITS_INDEX..(X_BITS_INDEX+1)], 
+            &params_vector[X_HAMMING_INDEX..(X_HAMMING_INDEX+1)], 
+            &modulus_limbs_powers[..] 
+            ];
+
+        eval_model(&params.final_exp, &final_exp_params)?
+    };
+
+    let mut result = one_off;
+    result = result.checked_add(miller_cost).ok_or(ApiError::Overflow)?;
+    result = result.checked_add(final_exp_cost).ok_or(ApiError::Overflow)?;
+    result = result.checked_div(params.multiplier).ok_or(ApiError::Overflow)?;
+
+    Ok(result)
+}
+
+pub(crate) fn meter_bn_pairing(input: &DecodedInput, params: &BnPairingParams, schedule: &GasSchedule, max_power: usize) -> Result<u64, ApiError> {
     let (
         modulus, 
         order_len, 
@@ -251,11 +645,12 @@ pub(crate) fn meter_bn_pairing(input: &[u8], params: &BnPairingParams, max_power
         u,
         u_is_negative,
         (num_g1_subgroup_checks, num_g2_subgroup_checks),
+        compressed,
         _
-    ) = parse_bls12_bn_pairing_parameters(&input, MAX_BN_U_BIT_LENGTH)?;
+    ) = parse_bls12_bn_pairing_parameters(input, MAX_BN_U_BIT_LENGTH)?;
     use crate::integers::MaxLoopParametersUint;
 
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let modulus_limbs = input.modulus_limbs;
     // let order_limbs = num_units_for_group_order(&order)?;
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
@@ -290,20 +685,26 @@ pub(crate) fn meter_bn_pairing(input: &[u8], params: &BnPairingParams, max_power
         num_pairs,
         (six_u_plus_two_bits as u64, six_u_plus_two_hamming as u64),
         (u_bits as u64, u_hamming as u64),
+        is_bn_modulus(modulus.as_ref(), u.as_ref(), u_is_negative),
         params,
         max_power
     )?;
 
-    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g1_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, false)?;
     let g1_subgroup_check_cost = g1_subgroup_check_cost_per_point.checked_mul(num_g1_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g1_subgroup_check_cost).ok_or(ApiError::Overflow)?;
 
-    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &*super::meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE, false)?;
+    let g2_subgroup_check_cost_per_point = super::meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g2_ext_2_multiplication, false)?;
     let g2_subgroup_check_cost = g2_subgroup_check_cost_per_point.checked_mul(num_g2_subgroup_checks as u64).ok_or(ApiError::Overflow)?;
 
     estimate = estimate.checked_add(g2_subgroup_check_cost).ok_or(ApiError::Overflow)?;
 
+    // modulus encoding has a non-zero top byte, so its length follows from the bit length
+    let modulus_len = (modulus.bits() + 7) / 8;
+    estimate = super::add_decompression_g1(schedule, estimate, &modulus, modulus_len, compressed, num_pairs)?;
+    estimate = super::add_decompression_g2(schedule, estimate, &modulus, modulus_len, 2, compressed, num_pairs)?;
+
     Ok(estimate)
 }
 
@@ -312,6 +713,7 @@ fn calculate_bls12_pairing_cost(
     _order_limbs: usize,
     num_pairs: usize,
     (x_bits, x_hamming): (u64, u64),
+    family_modulus: bool,
     params: &Bls12PairingParams, 
     max_power: usize
 
@@ -324,7 +726,7 @@ fn calculate_bls12_pairing_cost(
     let modulus_limbs_powers = make_powers(modulus_limbs as u64, max_power)?;
     let params_vector = vec![x_bits, x_hamming];
 
-    let one_off = *params.one_off.get(&modulus_limbs).ok_or(ApiError::MissingValue)?;
+    let one_off = select_one_off(&params.one_off, &params.family_one_off, modulus_limbs, family_modulus)?;
 
     let miller_cost = {
         let miller_params = vec![
@@ -367,6 +769,7 @@ fn calculate_bn_pairing_cost(
     num_pairs: usize,
     (six_u_plus_two_bits, six_u_plus_two_hamming): (u64, u64),
     (u_bits, u_hamming): (u64, u64),
+    family_modulus: bool,
     params: &BnPairingParams, 
     max_power: usize
 ) -> Result<u64, ApiError> {
@@ -380,7 +783,7 @@ fn calculate_bn_pairing_cost(
     let modulus_limbs_powers = make_powers(modulus_limbs as u64, max_power)?;
     let params_vector = vec![u_bits, u_hamming, six_u_plus_two_bits, six_u_plus_two_hamming];
 
-    let one_off = *params.one_off.get(&modulus_limbs).ok_or(ApiError::MissingValue)?;
+    let one_off = select_one_off(&params.one_off, &params.family_one_off, modulus_limbs, family_modulus)?;
 
     let miller_cost = {
         let miller_params = vec![
@@ -477,6 +880,12 @@ mod test {
 
         let t = &*super::BN_PARAMS_INSTANCE;
         println!("Params BN = {:?}", t);
+
+        let t = &*super::BW6_PARAMS_INSTANCE;
+        println!("Params BW6 = {:?}", t);
+
+        let t = &*super::BLS24_PARAMS_INSTANCE;
+        println!("Params BLS24 = {:?}", t);
     }
 
     #[test]
@@ -542,6 +951,7 @@ mod test {
             4, 
             (65, six_u_plus_two_hamming as u64), 
             (63, u_hamming as u64), 
+            true,
             &*super::BN_PARAMS_INSTANCE, 
             6).unwrap();
 
@@ -558,6 +968,7 @@ mod test {
             4, 
             4, 
             (x_bits as u64, x_hamming as u64), 
+            true,
             &*super::BLS12_PARAMS_INSTANCE, 
             6).unwrap();
 
@@ -574,6 +985,7 @@ mod test {
             4, 
             1, 
             (x_bits as u64, x_hamming as u64), 
+            true,
             &*super::BLS12_PARAMS_INSTANCE, 
             6).unwrap();
 
@@ -591,6 +1003,7 @@ mod test {
             4, 
             1, 
             (x_bits as u64, x_hamming as u64), 
+            true,
             &*super::BLS12_PARAMS_INSTANCE, 
             6).unwrap();
 
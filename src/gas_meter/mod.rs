@@ -1,6 +1,7 @@
 mod parsers;
 mod meter_arith;
 mod meter_pairing;
+mod schedule;
 
 extern crate serde;
 extern crate serde_json;
@@ -11,116 +12,189 @@ use crate::public_interface::decode_utils::*;
 use crate::public_interface::constants::*;
 use self::parsers::*;
 use crate::public_interface::OperationType;
+use crate::integers::MaxFieldUint;
 
-pub struct GasMeter;
+use once_cell::sync::Lazy;
+
+pub use self::schedule::{GasModel, GasSchedule};
+pub use self::meter_arith::{G1G2AdditionParams, G1G2MultiplicationParams, G1G2MultiexpParams};
+pub use self::meter_pairing::{MntPairingParams, Bls12PairingParams, BnPairingParams, Bw6PairingParams, Bls24PairingParams};
+
+/// Meters operations using some gas schedule. Static `GasMeter::meter` and `meter_operation`
+/// use the default schedule, instances can be built for custom schedules
+#[derive(Clone, Debug, Default)]
+pub struct GasMeter {
+    schedule: GasSchedule
+}
+
+static DEFAULT_GAS_METER: Lazy<GasMeter> = Lazy::new(GasMeter::default);
 
 // This is pure rust API
 pub fn meter_operation(operation: OperationType, input: &[u8]) -> Result<u64, ApiError> {
+    DEFAULT_GAS_METER.meter_operation(operation, input)
+}
+
+/// Meters and performs an operation in one call using the default schedule,
+/// see `GasMeter::execute_with_gas_limit`
+pub fn execute_with_gas_limit(operation: OperationType, input: &[u8], gas_limit: u64) -> Result<(Vec<u8>, u64), ApiError> {
+    DEFAULT_GAS_METER.execute_with_gas_limit(operation, input, gas_limit)
+}
+
+/// Meters and performs an operation encoded in the `API::run` format using the default
+/// schedule, see `GasMeter::execute`
+pub fn execute(bytes: &[u8], gas_limit: u64) -> Result<(Vec<u8>, u64), ApiError> {
+    DEFAULT_GAS_METER.execute(bytes, gas_limit)
+}
+
+fn meter_operation_with_schedule(schedule: &GasSchedule, operation: OperationType, input: &DecodedInput) -> Result<u64, ApiError> {
     match operation {
         OperationType::G1ADD => {
-            meter_addition_g1(&input)
+            meter_addition_g1(schedule, input)
         },
         OperationType::G1MUL => {
-            meter_multiplication_g1(&input)
+            meter_multiplication_g1(schedule, input)
         },
         OperationType::G1MULTIEXP => {
-            meter_multiexp_g1(&input)
+            meter_multiexp_g1(schedule, input)
         },
         OperationType::G2ADD => {
-            meter_addition_g2(&input)
+            meter_addition_g2(schedule, input)
         },
         OperationType::G2MUL => {
-            meter_multiplication_g2(&input)
+            meter_multiplication_g2(schedule, input)
         },
         OperationType::G2MULTIEXP => {
-            meter_multiexp_g2(&input)
+            meter_multiexp_g2(schedule, input)
         },
         OperationType::MNT4PAIR => {
-            meter_mnt4(&input)
+            meter_mnt4(schedule, input)
         },
         OperationType::MNT6PAIR => {
-            meter_mnt6(&input)
+            meter_mnt6(schedule, input)
         },
         OperationType::BLS12PAIR => {
-            meter_bls12(&input)
+            meter_bls12(schedule, input)
         },
         OperationType::BNPAIR => {
-            meter_bn(&input)
-        } 
+            meter_bn(schedule, input)
+        },
+        OperationType::BW6PAIR => {
+            meter_bw6(schedule, input)
+        },
+        OperationType::BLS24PAIR => {
+            meter_bls24(schedule, input)
+        },
+        #[cfg(feature = "mappings")]
+        OperationType::G1MAP => {
+            meter_map_to_g1(schedule, input)
+        },
+        #[cfg(feature = "mappings")]
+        OperationType::G2MAP => {
+            meter_map_to_g2(schedule, input)
+        },
+        OperationType::EDWARDSADD => {
+            meter_addition_edwards(schedule, input)
+        },
+        OperationType::EDWARDSMUL => {
+            meter_multiplication_edwards(schedule, input)
+        },
+        OperationType::EDWARDSMULTIEXP => {
+            meter_multiexp_edwards(schedule, input)
+        },
+        OperationType::G1DECOMPRESS => {
+            meter_decompression_g1(schedule, input)
+        },
+        OperationType::G2DECOMPRESS => {
+            meter_decompression_g2(schedule, input)
+        }
     }
 }
 
-fn meter_addition_g1(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_addition_g1(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
 
-    let (modulus, modulus_len, _, rest) = parse_g1_curve_parameters(&input)?;
-    if rest.len() != modulus_len * 4 {
+    let (modulus, modulus_len, _, rest) = parse_g1_curve_parameters(input)?;
+    let compressed = rest.len() == 2 * point_encoding_length(modulus_len, true);
+    if rest.len() != 2 * point_encoding_length(modulus_len, compressed) {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g1 addition metering".to_owned()));
     }
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let modulus_limbs = input.modulus_limbs;
+
+    let params = &schedule.g1_addition;
 
-    let params = &*meter_arith::G1_ADDITION_PARAMS_INSTANCE;
+    let price = meter_arith::meter_addition(modulus_limbs, params)?;
 
-    meter_arith::meter_addition(modulus_limbs, params)
+    add_decompression_g1(schedule, price, &modulus, modulus_len, compressed, 2)
 }
 
-fn meter_addition_g2(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_addition_g2(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
 
-    let (modulus, modulus_len, _, ext_degree, rest) = parse_g2_curve_parameters(&input)?;
-    if rest.len() != modulus_len * 4 * (ext_degree as usize) {
+    let (modulus, modulus_len, _, ext_degree, rest) = parse_g2_curve_parameters(input)?;
+    let coordinate_len = modulus_len * (ext_degree as usize);
+    let compressed = rest.len() == 2 * point_encoding_length(coordinate_len, true);
+    if rest.len() != 2 * point_encoding_length(coordinate_len, compressed) {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g2 addition metering".to_owned()));
     }
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let modulus_limbs = input.modulus_limbs;
 
     let params = if ext_degree == EXTENSION_DEGREE_2 {
-        &*meter_arith::G2_EXT_2_ADDITION_PARAMS_INSTANCE
+        &schedule.g2_ext_2_addition
     } else if ext_degree == EXTENSION_DEGREE_3 {
-        &*meter_arith::G2_EXT_3_ADDITION_PARAMS_INSTANCE
+        &schedule.g2_ext_3_addition
     } else {
         unreachable!();
     };
 
-    meter_arith::meter_addition(modulus_limbs, params)
+    let price = meter_arith::meter_addition(modulus_limbs, params)?;
+
+    add_decompression_g2(schedule, price, &modulus, modulus_len, ext_degree as usize, compressed, 2)
 }
 
 
-fn meter_multiplication_g1(input: &[u8]) -> Result<u64, ApiError> {
-    let (modulus, modulus_len, order_len, rest) = parse_g1_curve_parameters(&input)?;
-    if rest.len() != modulus_len * 2 + order_len {
+fn meter_multiplication_g1(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (modulus, modulus_len, order_len, rest) = parse_g1_curve_parameters(input)?;
+    let compressed = rest.len() == point_encoding_length(modulus_len, true) + order_len;
+    if rest.len() != point_encoding_length(modulus_len, compressed) + order_len {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g1 multiplication metering".to_owned()));
     }
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let modulus_limbs = input.modulus_limbs;
     // let order_limbs = num_units_for_group_order(&order)?;
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
-    let params = &*meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE;
+    let params = &schedule.g1_multiplication;
 
-    meter_arith::meter_multiplication(modulus_limbs, order_limbs, params, true)
+    let price = meter_arith::meter_multiplication(modulus_limbs, order_limbs, params, true)?;
+
+    add_decompression_g1(schedule, price, &modulus, modulus_len, compressed, 1)
 }
 
-fn meter_multiplication_g2(input: &[u8]) -> Result<u64, ApiError> {
-    let (modulus, modulus_len, order_len, ext_degree, rest) = parse_g2_curve_parameters(&input)?;
-    if rest.len() != modulus_len * 2 * (ext_degree as usize) + order_len {
+fn meter_multiplication_g2(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (modulus, modulus_len, order_len, ext_degree, rest) = parse_g2_curve_parameters(input)?;
+    let coordinate_len = modulus_len * (ext_degree as usize);
+    let compressed = rest.len() == point_encoding_length(coordinate_len, true) + order_len;
+    if rest.len() != point_encoding_length(coordinate_len, compressed) + order_len {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g2 multiplication metering".to_owned()));
     }
 
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let modulus_limbs = input.modulus_limbs;
     // let order_limbs = num_units_for_group_order(&order)?;
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
     let params = if ext_degree == EXTENSION_DEGREE_2 {
-        &*meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE
+        &schedule.g2_ext_2_multiplication
     } else if ext_degree == EXTENSION_DEGREE_3 {
-        &*meter_arith::G2_EXT_3_MULTIPLICATION_PARAMS_INSTANCE
+        &schedule.g2_ext_3_multiplication
     } else {
         unreachable!();
     };
 
-    meter_arith::meter_multiplication(modulus_limbs, order_limbs, params, true)
+    let price = meter_arith::meter_multiplication(modulus_limbs, order_limbs, params, true)?;
+
+    add_decompression_g2(schedule, price, &modulus, modulus_len, ext_degree as usize, compressed, 1)
 }
 
-fn meter_multiexp_g1(input: &[u8]) -> Result<u64, ApiError> {
-    let (modulus, modulus_len, order_len, rest) = parse_g1_curve_parameters(&input)?;
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+fn meter_multiexp_g1(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (modulus, modulus_len, order_len, rest) = parse_g1_curve_parameters(input)?;
+    let modulus_limbs = input.modulus_limbs;
     // let order_limbs = num_units_for_group_order(&order)?;
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
@@ -131,27 +205,30 @@ fn meter_multiexp_g1(input: &[u8]) -> Result<u64, ApiError> {
         return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
     }
 
-    if rest.len() != num_pairs * (modulus_len * 2 +  order_len) {
+    let compressed = rest.len() == num_pairs * (point_encoding_length(modulus_len, true) + order_len);
+    if rest.len() != num_pairs * (point_encoding_length(modulus_len, compressed) + order_len) {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g1 multiexp metering".to_owned()));
     }
 
-    let params = &*meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE;
-    let discounts = &*meter_arith::MULTIEXP_PARAMS_INSTANCE;
+    let params = &schedule.g1_multiplication;
+    let discounts = &schedule.multiexp_discounts;
+
+    let price = meter_arith::meter_multiexp(modulus_limbs, order_limbs, num_pairs, params, discounts)?;
 
-    meter_arith::meter_multiexp(modulus_limbs, order_limbs, num_pairs, params, discounts)
+    add_decompression_g1(schedule, price, &modulus, modulus_len, compressed, num_pairs)
 }
 
-fn meter_multiexp_g2(input: &[u8]) -> Result<u64, ApiError> {
-    let (modulus, modulus_len, order_len, ext_degree, rest) = parse_g2_curve_parameters(&input)?;
+fn meter_multiexp_g2(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (modulus, modulus_len, order_len, ext_degree, rest) = parse_g2_curve_parameters(input)?;
 
-    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+    let modulus_limbs = input.modulus_limbs;
     // let order_limbs = num_units_for_group_order(&order)?;
     let order_limbs = num_units_for_group_order_length(order_len)?;
 
     let params = if ext_degree == EXTENSION_DEGREE_2 {
-        &*meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE
+        &schedule.g2_ext_2_multiplication
     } else if ext_degree == EXTENSION_DEGREE_3 {
-        &*meter_arith::G2_EXT_3_MULTIPLICATION_PARAMS_INSTANCE
+        &schedule.g2_ext_3_multiplication
     } else {
         unreachable!();
     };
@@ -163,91 +240,273 @@ fn meter_multiexp_g2(input: &[u8]) -> Result<u64, ApiError> {
         return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
     }
 
-    if rest.len() != num_pairs * (modulus_len * 2 * (ext_degree as usize) +  order_len) {
+    let coordinate_len = modulus_len * (ext_degree as usize);
+    let compressed = rest.len() == num_pairs * (point_encoding_length(coordinate_len, true) + order_len);
+    if rest.len() != num_pairs * (point_encoding_length(coordinate_len, compressed) + order_len) {
         return Err(ApiError::InputError("Input is either too short or contains garbage for g2 multiexp metering".to_owned()));
     }
 
-    let discounts = &*meter_arith::MULTIEXP_PARAMS_INSTANCE;
+    let discounts = &schedule.multiexp_discounts;
+
+    let price = meter_arith::meter_multiexp(modulus_limbs, order_limbs, num_pairs, params, discounts)?;
+
+    add_decompression_g2(schedule, price, &modulus, modulus_len, ext_degree as usize, compressed, num_pairs)
+}
+
+fn meter_decompression_g1(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (modulus, modulus_len, _, rest) = parse_g1_curve_parameters(input)?;
+    if rest.len() != point_encoding_length(modulus_len, true) {
+        return Err(ApiError::InputError("Input is either too short or contains garbage for g1 decompression metering".to_owned()));
+    }
+
+    add_decompression_g1(schedule, 0, &modulus, modulus_len, true, 1)
+}
+
+fn meter_decompression_g2(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (modulus, modulus_len, _, ext_degree, rest) = parse_g2_curve_parameters(input)?;
+    if rest.len() != point_encoding_length(modulus_len * (ext_degree as usize), true) {
+        return Err(ApiError::InputError("Input is either too short or contains garbage for g2 decompression metering".to_owned()));
+    }
 
-    meter_arith::meter_multiexp(modulus_limbs, order_limbs, num_pairs, params, discounts)
+    add_decompression_g2(schedule, 0, &modulus, modulus_len, ext_degree as usize, true, 1)
 }
 
-fn meter_bls12(input: &[u8]) -> Result<u64, ApiError> {
-    self::meter_pairing::meter_bls12_pairing(input, &*self::meter_pairing::BLS12_PARAMS_INSTANCE, self::meter_pairing::BLS12_MAX_MODULUS_POWER)
+/// Adds the price of decompression of `num_points` points to `price` if points are compressed.
+/// Decompression takes a square root, so moduli with too large two-adicity are rejected
+// Square root in Fp for a modulus that is 3 mod 4 is one exponentiation, otherwise Tonelli-Shanks
+// first searches for a quadratic non-residue with up to 18 more exponentiations. Square root in Fp3
+// always goes through Tonelli-Shanks and takes five exponentiations in Fp3 (three of them raise
+// into the power of the modulus as the ABI has no Frobenius coefficients). Measured on MNT6-753
+// both cases fit into two multiplications in the corresponding group
+fn decompression_multiplications(modulus: &MaxFieldUint, ext_degree: usize) -> usize {
+    const MASK: u64 = 3; // last two bits
+
+    if ext_degree == 3 || modulus.as_ref()[0] & MASK == 1 {
+        2
+    } else {
+        1
+    }
 }
 
-fn meter_bn(input: &[u8]) -> Result<u64, ApiError> {
-    self::meter_pairing::meter_bn_pairing(input, &*self::meter_pairing::BN_PARAMS_INSTANCE, self::meter_pairing::BN_MAX_MODULUS_POWER)
+fn add_decompression_g1(
+    schedule: &GasSchedule,
+    price: u64,
+    modulus: &MaxFieldUint,
+    modulus_len: usize,
+    compressed: bool,
+    num_points: usize
+) -> Result<u64, ApiError> {
+    if !compressed {
+        return Ok(price);
+    }
+
+    check_modulus_two_adicity(modulus)?;
+    let modulus_limbs = num_limbs_for_modulus(modulus)?;
+    let modulus_units = num_units_for_group_order_length(modulus_len)?;
+
+    let multiplications = decompression_multiplications(modulus, 1);
+
+    let decompression = meter_arith::meter_decompression(modulus_limbs, modulus_units, num_points, multiplications, &schedule.g1_multiplication)?;
+
+    price.checked_add(decompression).ok_or(ApiError::Overflow)
 }
 
-fn meter_mnt4(input: &[u8]) -> Result<u64, ApiError> {
+fn add_decompression_g2(
+    schedule: &GasSchedule,
+    price: u64,
+    modulus: &MaxFieldUint,
+    modulus_len: usize,
+    ext_degree: usize,
+    compressed: bool,
+    num_points: usize
+) -> Result<u64, ApiError> {
+    if !compressed {
+        return Ok(price);
+    }
+
+    check_modulus_two_adicity(modulus)?;
+    let modulus_limbs = num_limbs_for_modulus(modulus)?;
+    let modulus_units = num_units_for_group_order_length(modulus_len)?;
+    let multiplications = decompression_multiplications(modulus, ext_degree);
+
+    let decompression = match ext_degree {
+        2 => {
+            meter_arith::meter_decompression(modulus_limbs, modulus_units, num_points, multiplications, &schedule.g2_ext_2_multiplication)?
+        },
+        3 => {
+            meter_arith::meter_decompression(modulus_limbs, modulus_units, num_points, multiplications, &schedule.g2_ext_3_multiplication)?
+        },
+        _ => {
+            return Err(ApiError::InputError("Decompression is only supported for extensions of degree 2 and 3".to_owned()));
+        }
+    };
+
+    price.checked_add(decompression).ok_or(ApiError::Overflow)
+}
+
+// Twisted Edwards curves share the G1 prefix layout (with `d` in place of `b`) and are
+// priced by the G1 models. Addition in extended coordinates takes 9 multiplications against
+// 16 in Jacobian ones (8 against 11 for mixed addition), doubling takes 8 plus multiplication
+// by `a` against 7 or 9, and scalar multiplication and multiexp run the same double-and-add
+// and Peppinger loops, so G1 prices are an upper bound
+
+fn meter_addition_edwards(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (_, modulus_len, _, rest) = parse_g1_curve_parameters(input)?;
+    if rest.len() != modulus_len * 4 {
+        return Err(ApiError::InputError("Input is either too short or contains garbage for edwards addition metering".to_owned()));
+    }
+    let modulus_limbs = input.modulus_limbs;
+
+    meter_arith::meter_addition(modulus_limbs, &schedule.g1_addition)
+}
+
+fn meter_multiplication_edwards(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (_, modulus_len, order_len, rest) = parse_g1_curve_parameters(input)?;
+    if rest.len() != modulus_len * 2 + order_len {
+        return Err(ApiError::InputError("Input is either too short or contains garbage for edwards multiplication metering".to_owned()));
+    }
+    let modulus_limbs = input.modulus_limbs;
+    let order_limbs = num_units_for_group_order_length(order_len)?;
+
+    meter_arith::meter_multiplication(modulus_limbs, order_limbs, &schedule.g1_multiplication, true)
+}
+
+fn meter_multiexp_edwards(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (_, modulus_len, order_len, rest) = parse_g1_curve_parameters(input)?;
+    let modulus_limbs = input.modulus_limbs;
+    let order_limbs = num_units_for_group_order_length(order_len)?;
+
+    let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+    let num_pairs = num_pairs_encoding[0] as usize;
+
+    if num_pairs == 0 {
+        return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+    }
+
+    if rest.len() != num_pairs * (modulus_len * 2 +  order_len) {
+        return Err(ApiError::InputError("Input is either too short or contains garbage for edwards multiexp metering".to_owned()));
+    }
+
+    meter_arith::meter_multiexp(modulus_limbs, order_limbs, num_pairs, &schedule.g1_multiplication, &schedule.multiexp_discounts)
+}
+
+#[cfg(feature = "mappings")]
+fn meter_map_to_g1(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (modulus, modulus_len, _, rest) = parse_g1_curve_parameters(input)?;
+    check_modulus_two_adicity(&modulus)?;
+    let map_degree = parse_map_to_curve_parameters(rest, modulus_len)?;
+
+    let modulus_limbs = input.modulus_limbs;
+    let modulus_units = num_units_for_group_order_length(modulus_len)?;
+
+    meter_arith::meter_map_to_curve(modulus_limbs, modulus_units, map_degree, &schedule.g1_addition, &schedule.g1_multiplication)
+}
+
+#[cfg(feature = "mappings")]
+fn meter_map_to_g2(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    let (modulus, modulus_len, _, ext_degree, rest) = parse_g2_curve_parameters(input)?;
+    if ext_degree != EXTENSION_DEGREE_2 {
+        return Err(ApiError::InputError("Map to curve is only supported for G2 in quadratic extension".to_owned()));
+    }
+    check_modulus_two_adicity(&modulus)?;
+    let map_degree = parse_map_to_curve_parameters(rest, modulus_len * 2)?;
+
+    let modulus_limbs = input.modulus_limbs;
+    let modulus_units = num_units_for_group_order_length(modulus_len)?;
+
+    meter_arith::meter_map_to_curve(modulus_limbs, modulus_units, map_degree, &schedule.g2_ext_2_addition, &schedule.g2_ext_2_multiplication)
+}
+
+fn meter_bls12(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    self::meter_pairing::meter_bls12_pairing(input, &schedule.bls12_pairing, schedule, self::meter_pairing::BLS12_MAX_MODULUS_POWER)
+}
+
+fn meter_bls24(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    self::meter_pairing::meter_bls24_pairing(input, &schedule.bls24_pairing, schedule)
+}
+
+fn meter_bn(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    self::meter_pairing::meter_bn_pairing(input, &schedule.bn_pairing, schedule, self::meter_pairing::BN_MAX_MODULUS_POWER)
+}
+
+fn meter_bw6(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
+    self::meter_pairing::meter_bw6_pairing(input, &schedule.bw6_pairing, schedule)
+}
+
+fn meter_mnt4(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
     self::meter_pairing::meter_mnt_pairing(
         input, 
-        &*self::meter_pairing::MNT4_PARAMS_INSTANCE, 
+        &schedule.mnt4_pairing,
+        schedule,
         self::meter_pairing::MNT4_MAX_MODULUS_POWER,
         2
     )
 }
 
-fn meter_mnt6(input: &[u8]) -> Result<u64, ApiError> {
+fn meter_mnt6(schedule: &GasSchedule, input: &DecodedInput) -> Result<u64, ApiError> {
     self::meter_pairing::meter_mnt_pairing(
         input, 
-        &*self::meter_pairing::MNT6_PARAMS_INSTANCE, 
+        &schedule.mnt6_pairing,
+        schedule,
         self::meter_pairing::MNT6_MAX_MODULUS_POWER,
         3
     )
 }
 
 impl GasMeter {
+    /// Creates a meter for a custom schedule, schedule is validated first
+    pub fn new(schedule: GasSchedule) -> Result<Self, ApiError> {
+        schedule.validate()?;
+
+        Ok(Self {
+            schedule
+        })
+    }
+
+    pub fn schedule(&self) -> &GasSchedule {
+        &self.schedule
+    }
+
+    /// Meters an input using the default schedule
     pub fn meter(bytes: &[u8]) -> Result<u64, ApiError> {
-        let (op_type, rest) = split(bytes, OPERATION_ENCODING_LENGTH , "Input should be longer than operation type encoding")?;
-        let operation = op_type[0];
-        let result = match operation {
-            OPERATION_G1_ADD => {
-                meter_addition_g1(&rest)
-            },
-            OPERATION_G2_ADD => {
-                meter_addition_g2(&rest)
-            },
-            OPERATION_G1_MUL => {
-                meter_multiplication_g1(&rest)
-            },
-            OPERATION_G2_MUL => {
-                meter_multiplication_g2(&rest)
-            }
-            OPERATION_G1_MULTIEXP => {
-                meter_multiexp_g1(&rest)
-            },
-            OPERATION_G2_MULTIEXP => {
-                meter_multiexp_g2(&rest)
-            },
-            OPERATION_PAIRING => {
-                let (curve_type, rest) = split(rest, CURVE_TYPE_LENGTH, "Input should be longer than curve type encoding")?;
-
-                match curve_type[0] {
-                    BLS12 => {
-                        meter_bls12(&rest)
-                    },
-                    BN => {
-                        meter_bn(&rest)
-                    },
-                    MNT4 => {
-                        meter_mnt4(&rest)
-                    },
-                    MNT6 => {
-                        meter_mnt6(&rest)
-                    },
-                    _ => {
-                        return Err(ApiError::InputError("Unknown curve type".to_owned()));
-                    }
-                }
-            },
-            _ => {
-                Err(ApiError::InputError("Unknown operation type".to_owned()))
-            }
-        };
+        DEFAULT_GAS_METER.meter_input(bytes)
+    }
+
+    pub fn meter_operation(&self, operation: OperationType, input: &[u8]) -> Result<u64, ApiError> {
+        let input = DecodedInput::decode(input)?;
+
+        meter_operation_with_schedule(&self.schedule, operation, &input)
+    }
 
-        result
+    /// Meters an operation and performs it only if it fits into `gas_limit`, returning
+    /// the output together with the gas used. The modulus is decoded once and shared by
+    /// metering and execution. Metering only reads the encoded curve parameters and
+    /// lengths, so if the limit is exceeded `ApiError::OutOfGas` is returned before
+    /// any field, curve or point is constructed
+    pub fn execute_with_gas_limit(&self, operation: OperationType, input: &[u8], gas_limit: u64) -> Result<(Vec<u8>, u64), ApiError> {
+        let input = DecodedInput::decode(input)?;
+        let gas_required = meter_operation_with_schedule(&self.schedule, operation, &input)?;
+        if gas_required > gas_limit {
+            return Err(ApiError::OutOfGas { gas_required, gas_limit });
+        }
+
+        let output = crate::public_interface::perform_decoded_operation(operation, &input)?;
+
+        Ok((output, gas_required))
+    }
+
+    /// Same as `execute_with_gas_limit` for an input in the `API::run` format,
+    /// where the operation is encoded by the leading bytes
+    pub fn execute(&self, bytes: &[u8], gas_limit: u64) -> Result<(Vec<u8>, u64), ApiError> {
+        let (operation, input) = OperationType::from_prefixed_input(bytes)?;
+
+        self.execute_with_gas_limit(operation, input, gas_limit)
+    }
+
+    pub fn meter_input(&self, bytes: &[u8]) -> Result<u64, ApiError> {
+        let (operation, input) = OperationType::from_prefixed_input(bytes)?;
+
+        self.meter_operation(operation, input)
     }
 }
 
@@ -265,4 +524,172 @@ mod test {
         println!("MNT4-753 for 4 pairs = {}", price);
         
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_family_modulus_discount() {
+        use crate::test::pairings::bls12::assemble_bls12_381;
+        use crate::test::pairings::bn::assemble_bn254;
+        use crate::public_interface::OperationType;
+
+        for (operation, calldata) in [
+            (OperationType::BLS12PAIR, assemble_bls12_381(2)),
+            (OperationType::BNPAIR, assemble_bn254(2))
+        ].iter() {
+            let operation = *operation;
+            let price = super::meter_operation(operation, &calldata[1..]).unwrap();
+
+            // metering does not validate the curve, so the same input with a modulus
+            // that is not the family polynomial is priced for the generic final exponentiation
+            let modulus_len = calldata[1] as usize;
+            let mut generic_calldata = calldata.clone();
+            generic_calldata[1 + modulus_len] += 2;
+            let generic_price = super::meter_operation(operation, &generic_calldata[1..]).unwrap();
+
+            assert!(price < generic_price);
+        }
+    }
+
+    #[test]
+    fn test_execute_with_gas_limit() {
+        use crate::test::decompression::*;
+        use crate::test::pairings::bls12::assemble_bls12_381;
+        use crate::test::pairings::bn::assemble_bn254;
+        use crate::test::pairings::mnt4::assemble_mnt4_753;
+        use crate::test::pairings::mnt6::assemble_mnt6_753;
+        use crate::test::pairings::bw6::assemble_bw6_761;
+        use crate::test::pairings::bls24::assemble_bls24_315;
+        use crate::test::edwards_ops;
+        use crate::public_interface::{API, OperationType};
+        use crate::public_interface::constants::*;
+        use crate::public_interface::decode_g1::serialize_g1_point;
+        use crate::public_interface::decode_g2::{serialize_g2_point_in_fp2, serialize_g2_point_in_fp3};
+        use crate::engines::bn254::*;
+        use crate::engines::bls12_377::*;
+        use crate::engines::mnt6_753::*;
+        use crate::engines::baby_jubjub::*;
+        use crate::weierstrass::Group;
+        use crate::errors::ApiError;
+        use super::GasMeter;
+
+        // curve parameters are followed by two points, by a point and a scalar, and by
+        // two pairs for multiexponentiation
+        fn assemble_arithmetic(prefixes: (u8, u8, u8), curve_params: &[u8], points: &[Vec<u8>; 2], scalar: &[u8]) -> Vec<Vec<u8>> {
+            let (add, mul, multiexp) = prefixes;
+
+            let mut add_input = vec![add];
+            add_input.extend_from_slice(curve_params);
+            add_input.extend(points.iter().flatten());
+
+            let mut mul_input = vec![mul];
+            mul_input.extend_from_slice(curve_params);
+            mul_input.extend(points[0].iter().chain(scalar.iter()));
+
+            let mut multiexp_input = vec![multiexp];
+            multiexp_input.extend_from_slice(curve_params);
+            multiexp_input.push(2u8);
+            for point in points.iter() {
+                multiexp_input.extend(point.iter().chain(scalar.iter()));
+            }
+
+            vec![add_input, mul_input, multiexp_input]
+        }
+
+        fn with_prefix(prefix: u8, input: &[u8]) -> Vec<u8> {
+            let mut result = vec![prefix];
+            result.extend_from_slice(input);
+
+            result
+        }
+
+        let mut inputs = vec![];
+
+        let (curve_params, modulus_len) = assemble_g1_curve_params(&BN254_G1_CURVE);
+        let mut p = BN254_G1_GENERATOR.clone();
+        p.double();
+        let points = [serialize_g1_point(modulus_len, &BN254_G1_GENERATOR).unwrap(), serialize_g1_point(modulus_len, &p).unwrap()];
+        let scalar = vec![0x5au8; order_len(BN254_G1_CURVE.subgroup_order_repr)];
+        inputs.extend(assemble_arithmetic((OPERATION_G1_ADD, OPERATION_G1_MUL, OPERATION_G1_MULTIEXP), &curve_params, &points, &scalar));
+        let mut decompression_input = curve_params.clone();
+        decompression_input.extend(compress_encoding(&points[0], modulus_len));
+        inputs.push(with_prefix(OPERATION_G1_DECOMPRESS, &decompression_input));
+
+        let (curve_params, modulus_len) = assemble_g2_fp2_curve_params(&BLS12_377_G2_CURVE);
+        let mut p = BLS12_377_G2_GENERATOR.clone();
+        p.double();
+        let points = [serialize_g2_point_in_fp2(modulus_len, &BLS12_377_G2_GENERATOR).unwrap(), serialize_g2_point_in_fp2(modulus_len, &p).unwrap()];
+        let scalar = vec![0x5au8; order_len(BLS12_377_G2_CURVE.subgroup_order_repr)];
+        inputs.extend(assemble_arithmetic((OPERATION_G2_ADD, OPERATION_G2_MUL, OPERATION_G2_MULTIEXP), &curve_params, &points, &scalar));
+        let mut decompression_input = curve_params.clone();
+        decompression_input.extend(compress_encoding(&points[0], modulus_len));
+        inputs.push(with_prefix(OPERATION_G2_DECOMPRESS, &decompression_input));
+
+        let (curve_params, modulus_len) = assemble_g2_fp3_curve_params(&MNT6_753_G2_CURVE);
+        let mut p = MNT6_753_G2_GENERATOR.clone();
+        p.double();
+        let points = [serialize_g2_point_in_fp3(modulus_len, &MNT6_753_G2_GENERATOR).unwrap(), serialize_g2_point_in_fp3(modulus_len, &p).unwrap()];
+        let scalar = vec![0x5au8; order_len(MNT6_753_G2_CURVE.subgroup_order_repr)];
+        inputs.extend(assemble_arithmetic((OPERATION_G2_ADD, OPERATION_G2_MUL, OPERATION_G2_MULTIEXP), &curve_params, &points, &scalar));
+
+        let curve_params = edwards_ops::assemble_curve_params(&BABY_JUBJUB_CURVE);
+        let mut p = BABY_JUBJUB_GENERATOR.clone();
+        p.double();
+        let points = [edwards_ops::encode_point(&BABY_JUBJUB_GENERATOR), edwards_ops::encode_point(&p)];
+        inputs.extend(assemble_arithmetic((OPERATION_EDWARDS_ADD, OPERATION_EDWARDS_MUL, OPERATION_EDWARDS_MULTIEXP), &curve_params, &points, &edwards_ops::make_scalar(7)));
+
+        #[cfg(feature = "mappings")]
+        {
+            use crate::test::map_to_curve::{bls12_381_g1_map_input, bls12_381_g2_map_input};
+
+            inputs.push(with_prefix(OPERATION_G1_MAP, &bls12_381_g1_map_input(42).0));
+            inputs.push(with_prefix(OPERATION_G2_MAP, &bls12_381_g2_map_input(42).0));
+        }
+
+        for calldata in [
+            assemble_bls12_381(2),
+            assemble_bn254(2),
+            assemble_mnt4_753(2),
+            assemble_mnt6_753(2),
+            assemble_bw6_761(3, 5),
+            assemble_bls24_315(&[false, true])
+        ].iter() {
+            inputs.push(with_prefix(OPERATION_PAIRING, calldata));
+        }
+
+        let mut covered = vec![];
+        for input in inputs.iter() {
+            let (operation, rest) = OperationType::from_prefixed_input(input).unwrap();
+            covered.push(operation);
+
+            let expected_output = API::run(input).unwrap();
+            let price = GasMeter::meter(input).unwrap();
+            assert_eq!(super::meter_operation(operation, rest).unwrap(), price);
+
+            for gas_limit in [price, u64::MAX].iter() {
+                assert_eq!(super::execute(input, *gas_limit).unwrap(), (expected_output.clone(), price));
+                assert_eq!(super::execute_with_gas_limit(operation, rest, *gas_limit).unwrap(), (expected_output.clone(), price));
+            }
+
+            let out_of_gas = ApiError::OutOfGas { gas_required: price, gas_limit: price - 1 };
+            assert_eq!(super::execute(input, price - 1).unwrap_err(), out_of_gas);
+            assert_eq!(super::execute_with_gas_limit(operation, rest, price - 1).unwrap_err(), out_of_gas);
+
+            // malformed input is rejected
+            let mut garbage_input = input.clone();
+            garbage_input.push(0u8);
+            assert!(super::execute(&garbage_input, u64::MAX).is_err());
+        }
+
+        // every operation type is covered
+        for value in 0..=u8::MAX {
+            if let Some(operation) = OperationType::from_u8(value) {
+                assert!(covered.contains(&operation), "operation {} is not covered", value);
+            }
+        }
+
+        // prefixes are rejected as in `API::run`
+        for input in [vec![], vec![0u8], vec![OPERATION_PAIRING], vec![OPERATION_PAIRING, 0u8]].iter() {
+            assert!(API::run(input).is_err());
+            assert!(super::execute(input, u64::MAX).is_err());
+        }
+    }
+}
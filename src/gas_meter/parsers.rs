@@ -11,13 +11,13 @@ use crate::pairings::calculate_hamming_weight;
 /// - scalar field modulus
 /// - rest
 /// eats up to the operation-specific parameters
-pub(crate) fn parse_g1_curve_parameters<'a>(bytes: &'a [u8]) -> Result<(
+pub(crate) fn parse_g1_curve_parameters<'a>(input: &DecodedInput<'a>) -> Result<(
     MaxFieldUint, 
     usize,
     usize,
     &'a [u8]), ApiError> 
 {
-    let ((modulus, modulus_len), rest) = get_base_field_params(&bytes)?;
+    let (modulus, modulus_len, rest) = (input.modulus, input.modulus_len, input.rest);
     let (_, rest) = split(rest, modulus_len, "Input is not long enough to get A parameter")?;
     let (_, rest) = split(rest, modulus_len, "Input is not long enough to get B parameter")?;
 
@@ -43,14 +43,14 @@ pub(crate) fn parse_g1_curve_parameters<'a>(bytes: &'a [u8]) -> Result<(
 /// - extension degree
 /// - rest
 /// eats up to the operation-specific parameters
-pub(crate) fn parse_g2_curve_parameters<'a>(bytes: &'a [u8]) -> Result<(
+pub(crate) fn parse_g2_curve_parameters<'a>(input: &DecodedInput<'a>) -> Result<(
     MaxFieldUint, 
     usize,
     usize,
     u8,
     &'a [u8]), ApiError> 
 {
-    let ((modulus, modulus_len), rest) = get_base_field_params(&bytes)?;
+    let (modulus, modulus_len, rest) = (input.modulus, input.modulus_len, input.rest);
     let (ext_degree_encoding, rest) = split(&rest, EXTENSION_DEGREE_ENCODING_LENGTH, "Input is not long enough to get extension degree")?;
     let extension_degree = ext_degree_encoding[0];
     if !(extension_degree == EXTENSION_DEGREE_2 || extension_degree == EXTENSION_DEGREE_3) {
@@ -77,7 +77,43 @@ pub(crate) fn parse_g2_curve_parameters<'a>(bytes: &'a [u8]) -> Result<(
     )
 }
 
-pub(crate) fn parse_mnt_pairing_parameters<'a>(bytes: &'a [u8], ext_degree: usize) -> Result<(
+/// return:
+/// - isogeny map degree, zero if simplified SWU is applied to the curve directly
+/// eats the map-to-curve parameters and the field element, where `element_len`
+/// is a byte length of the base field element of the curve
+#[cfg(feature = "mappings")]
+pub(crate) fn parse_map_to_curve_parameters(bytes: &[u8], element_len: usize) -> Result<usize, ApiError> {
+    use crate::public_interface::sane_limits::*;
+
+    let (_, rest) = split(bytes, element_len, "Input is not long enough to get SWU Z")?;
+    let (use_isogeny, rest) = decode_boolean(rest)?;
+
+    let (map_degree, rest) = if use_isogeny {
+        let (_, rest) = split(rest, element_len, "Input is not long enough to get isogenous curve A parameter")?;
+        let (_, rest) = split(rest, element_len, "Input is not long enough to get isogenous curve B parameter")?;
+        let (map_degree_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get isogeny map degree")?;
+        let map_degree = map_degree_encoding[0] as usize;
+        if map_degree == 0 {
+            return Err(ApiError::InputError("Isogeny map degree can not be zero".to_owned()));
+        }
+        if map_degree > MAX_ISOGENY_MAP_DEGREE {
+            return Err(ApiError::InputError("Isogeny map degree is too large".to_owned()));
+        }
+        let (_, rest) = split(rest, 4 * (map_degree + 1) * element_len, "Input is not long enough to get isogeny map coefficients")?;
+
+        (map_degree, rest)
+    } else {
+        (0, rest)
+    };
+
+    if rest.len() != element_len {
+        return Err(ApiError::InputError("Input is either too short or contains garbage for map to curve metering".to_owned()));
+    }
+
+    Ok(map_degree)
+}
+
+pub(crate) fn parse_mnt_pairing_parameters<'a>(input: &DecodedInput<'a>, ext_degree: usize) -> Result<(
     MaxFieldUint, 
     usize,
     usize,
@@ -85,11 +121,12 @@ pub(crate) fn parse_mnt_pairing_parameters<'a>(bytes: &'a [u8], ext_degree: usiz
     (u64, u64),
     (u64, u64),
     (usize, usize),
+    bool,
     &'a [u8]), ApiError> 
 {
     use crate::public_interface::sane_limits::*;
 
-    let ((modulus, modulus_len), rest) = get_base_field_params(&bytes)?;
+    let (modulus, modulus_len, rest) = (input.modulus, input.modulus_len, input.rest);
     let (_, rest) = split(rest, modulus_len, "Input is not long enough to get A parameter")?;
     let (_, rest) = split(rest, modulus_len, "Input is not long enough to get B parameter")?;
 
@@ -157,11 +194,13 @@ pub(crate) fn parse_mnt_pairing_parameters<'a>(bytes: &'a [u8], ext_degree: usiz
         return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
     }
 
+    let compressed = pairing_points_are_compressed(rest, num_pairs, modulus_len, modulus_len*ext_degree);
+
     for _ in 0..num_pairs {
         let (check_g1, rest) = decode_boolean(&grobal_rest)?;
-        let (_, rest) = split(rest, modulus_len*2, "input is not long enough to get G1 point encoding")?;
+        let (_, rest) = split(rest, point_encoding_length(modulus_len, compressed), "input is not long enough to get G1 point encoding")?;
         let (check_g2, rest) = decode_boolean(&rest)?;
-        let (_, rest) = split(rest, modulus_len*2*ext_degree, "input is not long enough to get G2 point encoding")?;
+        let (_, rest) = split(rest, point_encoding_length(modulus_len*ext_degree, compressed), "input is not long enough to get G2 point encoding")?;
         grobal_rest = rest;
 
         if check_g1 {
@@ -186,23 +225,153 @@ pub(crate) fn parse_mnt_pairing_parameters<'a>(bytes: &'a [u8], ext_degree: usiz
             (exp_w0_bits as u64, exp_w0_hamming as u64),
             (exp_w1_bits as u64, exp_w1_hamming as u64),
             (num_g1_subgroup_checks, num_g2_subgroup_checks),
+            compressed,
             rest
         )
     )
 }
 
-pub(crate) fn parse_bls12_bn_pairing_parameters<'a>(bytes: &'a [u8], max_x_bit_limit: usize) -> Result<(
+/// BW6 has two Miller loops, so bit lengths and hamming weights are returned for both
+/// loop parameters. G2 points are on the twist over the base field and are encoded as in G1
+pub(crate) fn parse_bw6_pairing_parameters<'a>(input: &DecodedInput<'a>) -> Result<(
+    MaxFieldUint, 
+    usize,
+    usize,
+    (u64, u64),
+    (u64, u64),
+    (u64, u64),
+    (u64, u64),
+    (usize, usize),
+    bool,
+    &'a [u8]), ApiError> 
+{
+    use crate::public_interface::sane_limits::*;
+    use crate::pairings::TwistType;
+
+    let (modulus, modulus_len, rest) = (input.modulus, input.modulus_len, input.rest);
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get A parameter")?;
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get B parameter")?;
+
+    let (order_len, _, rest) = parse_group_order_from_encoding(rest)?;
+
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get Fp3 non-residue")?;
+
+    let (twist_type_encoding, rest) = split(rest, TWIST_TYPE_LENGTH, "Input is not long enough to get twist type")?;
+
+    let _ = match twist_type_encoding[0] {
+        TWIST_TYPE_D => TwistType::D,
+        TWIST_TYPE_M => TwistType::M, 
+        _ => {
+            return Err(ApiError::UnknownParameter("Unknown twist type supplied".to_owned()));
+        },
+    };
+
+    let mut rest = rest;
+    let mut ate_loops = [(0u64, 0u64); 2];
+    for ate_loop in ate_loops.iter_mut() {
+        let (x, r) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+        if x.is_zero() {
+            return Err(ApiError::InputError("Ate pairing loop count parameters can not be zero".to_owned()));
+        }
+
+        let ate_loop_bits = x.bits();
+        let ate_loop_hamming = calculate_hamming_weight(&x.as_ref());
+
+        if ate_loop_hamming > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
+            return Err(ApiError::InputError("Ate pairing loop has too large hamming weight".to_owned()));
+        }
+
+        let (x_sign, r) = split(r, SIGN_ENCODING_LENGTH, "Input is not long enough to get ate loop count sign encoding")?;
+        if x_sign[0] != SIGN_PLUS && x_sign[0] != SIGN_MINUS {
+            return Err(ApiError::InputError("Ate loop count sign is not encoded properly".to_owned()));
+        }
+
+        *ate_loop = (ate_loop_bits as u64, ate_loop_hamming as u64);
+        rest = r;
+    }
+
+    let (exp_w0, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
+    if exp_w0.is_zero() {
+        return Err(ApiError::InputError("Final exp w0 loop count parameters can not be zero".to_owned()));
+    }
+    let exp_w0_bits = exp_w0.bits();
+    let exp_w0_hamming = calculate_hamming_weight(&exp_w0.as_ref());
+
+    let (exp_w1, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH)?;
+    if exp_w1.is_zero() {
+        return Err(ApiError::InputError("Final exp w1 loop count parameters can not be zero".to_owned()));
+    }
+    let exp_w1_bits = exp_w1.bits();
+    let exp_w1_hamming = calculate_hamming_weight(&exp_w1.as_ref());
+
+    let (exp_w0_sign, rest) = split(rest, SIGN_ENCODING_LENGTH, "Input is not long enough to get exp_w0 sign encoding")?;
+    if exp_w0_sign[0] != SIGN_PLUS && exp_w0_sign[0] != SIGN_MINUS {
+        return Err(ApiError::InputError("Exp_w0 sign is not encoded properly".to_owned()));
+    }
+
+    let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+    let num_pairs = num_pairs_encoding[0] as usize;
+
+    if num_pairs == 0 {
+        return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+    }
+
+    let mut num_g1_subgroup_checks = 0;
+    let mut num_g2_subgroup_checks = 0;
+
+    let compressed = pairing_points_are_compressed(rest, num_pairs, modulus_len, modulus_len);
+
+    let mut global_rest = rest;
+
+    for _ in 0..num_pairs {
+        let (check_g1, rest) = decode_boolean(&global_rest)?;
+        let (_, rest) = split(rest, point_encoding_length(modulus_len, compressed), "input is not long enough to get G1 point encoding")?;
+        let (check_g2, rest) = decode_boolean(&rest)?;
+        let (_, rest) = split(rest, point_encoding_length(modulus_len, compressed), "input is not long enough to get G2 point encoding")?;
+        global_rest = rest;
+
+        if check_g1 {
+            num_g1_subgroup_checks += 1;
+        }
+
+        if check_g2 {
+            num_g2_subgroup_checks += 1;
+        }
+    }
+
+    if global_rest.len() != 0 {
+        return Err(ApiError::InputError("Input has garbage at the end for BW6 pairing".to_owned()));
+    }
+
+    Ok(
+        (
+            modulus,
+            order_len,
+            num_pairs,
+            ate_loops[0],
+            ate_loops[1],
+            (exp_w0_bits as u64, exp_w0_hamming as u64),
+            (exp_w1_bits as u64, exp_w1_hamming as u64),
+            (num_g1_subgroup_checks, num_g2_subgroup_checks),
+            compressed,
+            global_rest
+        )
+    )
+}
+
+pub(crate) fn parse_bls12_bn_pairing_parameters<'a>(input: &DecodedInput<'a>, max_x_bit_limit: usize) -> Result<(
     MaxFieldUint, 
     usize,
     usize,
     MaxLoopParametersUint,
     bool,
     (usize, usize),
+    bool,
     &'a [u8]), ApiError> 
 {
     use crate::pairings::TwistType;
 
-    let ((modulus, modulus_len), rest) = get_base_field_params(&bytes)?;
+    let (modulus, modulus_len, rest) = (input.modulus, input.modulus_len, input.rest);
     let (_, rest) = split(rest, modulus_len, "Input is not long enough to get A parameter")?;
     let (_, rest) = split(rest, modulus_len, "Input is not long enough to get B parameter")?;
 
@@ -247,11 +416,13 @@ pub(crate) fn parse_bls12_bn_pairing_parameters<'a>(bytes: &'a [u8], max_x_bit_l
         return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
     }
 
+    let compressed = pairing_points_are_compressed(rest, num_pairs, modulus_len, modulus_len*2);
+
     for _ in 0..num_pairs {
         let (check_g1, rest) = decode_boolean(&grobal_rest)?;
-        let (_, rest) = split(rest, modulus_len*2, "input is not long enough to get G1 point encoding")?;
+        let (_, rest) = split(rest, point_encoding_length(modulus_len, compressed), "input is not long enough to get G1 point encoding")?;
         let (check_g2, rest) = decode_boolean(&rest)?;
-        let (_, rest) = split(rest, modulus_len*4, "input is not long enough to get G2 point encoding")?;
+        let (_, rest) = split(rest, point_encoding_length(modulus_len*2, compressed), "input is not long enough to get G2 point encoding")?;
         grobal_rest = rest;
 
         if check_g1 {
@@ -275,12 +446,101 @@ pub(crate) fn parse_bls12_bn_pairing_parameters<'a>(bytes: &'a [u8], max_x_bit_l
             x,
             x_is_negative,
             (num_g1_subgroup_checks, num_g2_subgroup_checks),
+            compressed,
             rest
         )
     )
 }
 
-use serde::{Deserializer};
+pub(crate) fn parse_bls24_pairing_parameters<'a>(input: &DecodedInput<'a>, max_x_bit_limit: usize) -> Result<(
+    MaxFieldUint, 
+    usize,
+    usize,
+    MaxLoopParametersUint,
+    bool,
+    (usize, usize),
+    &'a [u8]), ApiError> 
+{
+    use crate::pairings::TwistType;
+
+    let (modulus, modulus_len, rest) = (input.modulus, input.modulus_len, input.rest);
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get A parameter")?;
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get B parameter")?;
+
+    let (order_len, _, rest) = parse_group_order_from_encoding(rest)?;
+    
+    let (_, rest) = split(rest, modulus_len, "Input is not long enough to get Fp2 non-residue")?;
+
+    let (twist_type_encoding, rest) = split(rest, TWIST_TYPE_LENGTH, "Input is not long enough to get twist type")?;
+
+    let _ = match twist_type_encoding[0] {
+        TWIST_TYPE_D => TwistType::D,
+        TWIST_TYPE_M => TwistType::M, 
+        _ => {
+            return Err(ApiError::UnknownParameter("Unknown twist type supplied".to_owned()));
+        },
+    };
+
+    let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(rest, max_x_bit_limit)?;
+    if x.is_zero() {
+        return Err(ApiError::InputError("Ate pairing loop count parameters can not be zero".to_owned()));
+    }
+
+    let (x_sign, rest) = split(rest, SIGN_ENCODING_LENGTH, "Input is not long enough to get X sign encoding")?;
+    let x_is_negative = match x_sign[0] {
+        SIGN_PLUS => false,
+        SIGN_MINUS => true,
+        _ => {
+            return Err(ApiError::InputError("X sign is not encoded properly".to_owned()));
+        },
+    };
+
+    let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+    let num_pairs = num_pairs_encoding[0] as usize;
+
+    let mut num_g1_subgroup_checks = 0;
+    let mut num_g2_subgroup_checks = 0;
+
+    let mut grobal_rest = rest;
+
+    if num_pairs == 0 {
+        return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+    }
+
+    for _ in 0..num_pairs {
+        let (check_g1, rest) = decode_boolean(grobal_rest)?;
+        let (_, rest) = split(rest, modulus_len*2, "input is not long enough to get G1 point encoding")?;
+        let (check_g2, rest) = decode_boolean(rest)?;
+        let (_, rest) = split(rest, modulus_len*8, "input is not long enough to get G2 point encoding")?;
+        grobal_rest = rest;
+
+        if check_g1 {
+            num_g1_subgroup_checks += 1;
+        }
+
+        if check_g2 {
+            num_g2_subgroup_checks += 1;
+        }
+    }
+
+    if !grobal_rest.is_empty() {
+        return Err(ApiError::InputError("Input has garbage at the end for BLS24 pairing".to_owned()));
+    }
+
+    Ok(
+        (
+            modulus,
+            order_len,
+            num_pairs,
+            x,
+            x_is_negative,
+            (num_g1_subgroup_checks, num_g2_subgroup_checks),
+            rest
+        )
+    )
+}
+
+use serde::{Deserializer, Serializer};
 use std::collections::HashMap;
 
 pub(crate) fn parse_hashmap_usize_u64_from_ints<'de, D>(deserializer: D) -> Result<HashMap<usize, u64>, D::Error>
@@ -319,4 +579,16 @@ where
     let result = deserializer.deserialize_seq(visitor)?;
 
     Ok(result)
-}
\ No newline at end of file
+}
+
+/// Serializes a lookup table in the same format as `parse_hashmap_usize_u64_from_ints` expects,
+/// entries are sorted by key so model files are stable
+pub(crate) fn serialize_hashmap_usize_u64_as_ints<S>(map: &HashMap<usize, u64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut entries: Vec<[u64; 2]> = map.iter().map(|(k, v)| [*k as u64, *v]).collect();
+    entries.sort_unstable();
+
+    serializer.collect_seq(entries)
+}
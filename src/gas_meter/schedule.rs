@@ -0,0 +1,333 @@
+//! Gas schedule that is used by `GasMeter`. Default schedule is built from the model
+//! files that are embedded into the library, any model can be replaced at runtime by
+//! a JSON document of the same format or by a model struct. Every model is validated
+//! before it is used for metering, so a malformed schedule fails on construction of a
+//! meter instead of on some particular input
+
+use std::path::Path;
+
+use crate::errors::ApiError;
+
+use super::meter_arith::{self, G1G2AdditionParams, G1G2MultiplicationParams, G1G2MultiexpParams};
+use super::meter_pairing::{self, MntPairingParams, Bls12PairingParams, BnPairingParams, Bw6PairingParams, Bls24PairingParams};
+
+/// Models that form a gas schedule
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GasModel {
+    G1Addition,
+    G2Ext2Addition,
+    G2Ext3Addition,
+    G1Multiplication,
+    G2Ext2Multiplication,
+    G2Ext3Multiplication,
+    MultiexpDiscounts,
+    Mnt4Pairing,
+    Mnt6Pairing,
+    Bls12Pairing,
+    BnPairing,
+    Bw6Pairing,
+    Bls24Pairing,
+}
+
+impl GasModel {
+    pub const ALL: [GasModel; 13] = [
+        GasModel::G1Addition,
+        GasModel::G2Ext2Addition,
+        GasModel::G2Ext3Addition,
+        GasModel::G1Multiplication,
+        GasModel::G2Ext2Multiplication,
+        GasModel::G2Ext3Multiplication,
+        GasModel::MultiexpDiscounts,
+        GasModel::Mnt4Pairing,
+        GasModel::Mnt6Pairing,
+        GasModel::Bls12Pairing,
+        GasModel::BnPairing,
+        GasModel::Bw6Pairing,
+        GasModel::Bls24Pairing,
+    ];
+
+    /// Name of the model file as shipped in `src/gas_meter`
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            GasModel::G1Addition => "g1_addition.json",
+            GasModel::G2Ext2Addition => "g2_addition_ext2.json",
+            GasModel::G2Ext3Addition => "g2_addition_ext3.json",
+            GasModel::G1Multiplication => "g1_multiplication.json",
+            GasModel::G2Ext2Multiplication => "g2_multiplication_ext2.json",
+            GasModel::G2Ext3Multiplication => "g2_multiplication_ext3.json",
+            GasModel::MultiexpDiscounts => "multiexp_discounts.json",
+            GasModel::Mnt4Pairing => "mnt4_model.json",
+            GasModel::Mnt6Pairing => "mnt6_model.json",
+            GasModel::Bls12Pairing => "bls12_model.json",
+            GasModel::BnPairing => "bn_model.json",
+            GasModel::Bw6Pairing => "bw6_model.json",
+            GasModel::Bls24Pairing => "bls24_model.json",
+        }
+    }
+
+    pub fn from_file_name(name: &str) -> Option<GasModel> {
+        GasModel::ALL.iter().find(|model| model.file_name() == name).copied()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GasSchedule {
+    pub g1_addition: G1G2AdditionParams,
+    pub g2_ext_2_addition: G1G2AdditionParams,
+    pub g2_ext_3_addition: G1G2AdditionParams,
+    pub g1_multiplication: G1G2MultiplicationParams,
+    pub g2_ext_2_multiplication: G1G2MultiplicationParams,
+    pub g2_ext_3_multiplication: G1G2MultiplicationParams,
+    pub multiexp_discounts: G1G2MultiexpParams,
+    pub mnt4_pairing: MntPairingParams,
+    pub mnt6_pairing: MntPairingParams,
+    pub bls12_pairing: Bls12PairingParams,
+    pub bn_pairing: BnPairingParams,
+    pub bw6_pairing: Bw6PairingParams,
+    pub bls24_pairing: Bls24PairingParams,
+}
+
+impl Default for GasSchedule {
+    fn default() -> Self {
+        Self {
+            g1_addition: meter_arith::G1_ADDITION_PARAMS_INSTANCE.clone(),
+            g2_ext_2_addition: meter_arith::G2_EXT_2_ADDITION_PARAMS_INSTANCE.clone(),
+            g2_ext_3_addition: meter_arith::G2_EXT_3_ADDITION_PARAMS_INSTANCE.clone(),
+            g1_multiplication: meter_arith::G1_MULTIPLICATION_PARAMS_INSTANCE.clone(),
+            g2_ext_2_multiplication: meter_arith::G2_EXT_2_MULTIPLICATION_PARAMS_INSTANCE.clone(),
+            g2_ext_3_multiplication: meter_arith::G2_EXT_3_MULTIPLICATION_PARAMS_INSTANCE.clone(),
+            multiexp_discounts: meter_arith::MULTIEXP_PARAMS_INSTANCE.clone(),
+            mnt4_pairing: meter_pairing::MNT4_PARAMS_INSTANCE.clone(),
+            mnt6_pairing: meter_pairing::MNT6_PARAMS_INSTANCE.clone(),
+            bls12_pairing: meter_pairing::BLS12_PARAMS_INSTANCE.clone(),
+            bn_pairing: meter_pairing::BN_PARAMS_INSTANCE.clone(),
+            bw6_pairing: meter_pairing::BW6_PARAMS_INSTANCE.clone(),
+            bls24_pairing: meter_pairing::BLS24_PARAMS_INSTANCE.clone(),
+        }
+    }
+}
+
+fn parse_model<T: serde::de::DeserializeOwned>(model: GasModel, json: &str) -> Result<T, ApiError> {
+    serde_json::from_str(json).map_err(|e| {
+        ApiError::InputError(format!("Failed to parse gas model {}: {}", model.file_name(), e))
+    })
+}
+
+impl GasSchedule {
+    /// Replaces a single model by the one parsed from JSON in the format of the model files
+    pub fn with_model_json(mut self, model: GasModel, json: &str) -> Result<Self, ApiError> {
+        match model {
+            GasModel::G1Addition => self.g1_addition = parse_model(model, json)?,
+            GasModel::G2Ext2Addition => self.g2_ext_2_addition = parse_model(model, json)?,
+            GasModel::G2Ext3Addition => self.g2_ext_3_addition = parse_model(model, json)?,
+            GasModel::G1Multiplication => self.g1_multiplication = parse_model(model, json)?,
+            GasModel::G2Ext2Multiplication => self.g2_ext_2_multiplication = parse_model(model, json)?,
+            GasModel::G2Ext3Multiplication => self.g2_ext_3_multiplication = parse_model(model, json)?,
+            GasModel::MultiexpDiscounts => self.multiexp_discounts = parse_model(model, json)?,
+            GasModel::Mnt4Pairing => self.mnt4_pairing = parse_model(model, json)?,
+            GasModel::Mnt6Pairing => self.mnt6_pairing = parse_model(model, json)?,
+            GasModel::Bls12Pairing => self.bls12_pairing = parse_model(model, json)?,
+            GasModel::BnPairing => self.bn_pairing = parse_model(model, json)?,
+            GasModel::Bw6Pairing => self.bw6_pairing = parse_model(model, json)?,
+            GasModel::Bls24Pairing => self.bls24_pairing = parse_model(model, json)?,
+        }
+
+        Ok(self)
+    }
+
+    /// Serializes a single model in the format of the model files
+    pub fn model_json(&self, model: GasModel) -> String {
+        let json = match model {
+            GasModel::G1Addition => serde_json::to_string(&self.g1_addition),
+            GasModel::G2Ext2Addition => serde_json::to_string(&self.g2_ext_2_addition),
+            GasModel::G2Ext3Addition => serde_json::to_string(&self.g2_ext_3_addition),
+            GasModel::G1Multiplication => serde_json::to_string(&self.g1_multiplication),
+            GasModel::G2Ext2Multiplication => serde_json::to_string(&self.g2_ext_2_multiplication),
+            GasModel::G2Ext3Multiplication => serde_json::to_string(&self.g2_ext_3_multiplication),
+            GasModel::MultiexpDiscounts => serde_json::to_string(&self.multiexp_discounts),
+            GasModel::Mnt4Pairing => serde_json::to_string(&self.mnt4_pairing),
+            GasModel::Mnt6Pairing => serde_json::to_string(&self.mnt6_pairing),
+            GasModel::Bls12Pairing => serde_json::to_string(&self.bls12_pairing),
+            GasModel::BnPairing => serde_json::to_string(&self.bn_pairing),
+            GasModel::Bw6Pairing => serde_json::to_string(&self.bw6_pairing),
+            GasModel::Bls24Pairing => serde_json::to_string(&self.bls24_pairing),
+        };
+
+        json.expect("models are always serializable")
+    }
+
+    /// Writes every model into a directory, so it can be loaded back with `from_directory`
+    pub fn write_to_directory<P: AsRef<Path>>(&self, path: P) -> Result<(), ApiError> {
+        std::fs::create_dir_all(path.as_ref()).map_err(|e| {
+            ApiError::InputError(format!("Failed to create directory {}: {}", path.as_ref().display(), e))
+        })?;
+        for model in GasModel::ALL.iter() {
+            let file = path.as_ref().join(model.file_name());
+            std::fs::write(&file, self.model_json(*model)).map_err(|e| {
+                ApiError::InputError(format!("Failed to write gas model {}: {}", file.display(), e))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Builds a schedule from model files in a directory. Files use the same names as
+    /// the embedded models, missing files fall back to the default models
+    pub fn from_directory<P: AsRef<Path>>(path: P) -> Result<Self, ApiError> {
+        let mut schedule = Self::default();
+        for model in GasModel::ALL.iter() {
+            let file = path.as_ref().join(model.file_name());
+            if !file.is_file() {
+                continue;
+            }
+            let json = std::fs::read_to_string(&file).map_err(|e| {
+                ApiError::InputError(format!("Failed to read gas model {}: {}", file.display(), e))
+            })?;
+            schedule = schedule.with_model_json(*model, &json)?;
+        }
+
+        schedule.validate()?;
+
+        Ok(schedule)
+    }
+
+    /// Checks that every model covers all the supported modulus limb counts and that
+    /// pairing models are polynomials over the features that metering supplies
+    pub fn validate(&self) -> Result<(), ApiError> {
+        self.g1_addition.validate("G1 addition")?;
+        self.g2_ext_2_addition.validate("G2 (extension degree 2) addition")?;
+        self.g2_ext_3_addition.validate("G2 (extension degree 3) addition")?;
+        self.g1_multiplication.validate("G1 multiplication")?;
+        self.g2_ext_2_multiplication.validate("G2 (extension degree 2) multiplication")?;
+        self.g2_ext_3_multiplication.validate("G2 (extension degree 3) multiplication")?;
+        self.multiexp_discounts.validate("Multiexp discounts")?;
+        self.mnt4_pairing.validate_mnt4()?;
+        self.mnt6_pairing.validate_mnt6()?;
+        self.bls12_pairing.validate_bls12()?;
+        self.bn_pairing.validate_bn()?;
+        self.bw6_pairing.validate_bw6()?;
+        self.bls24_pairing.validate_bls24()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gas_meter::GasMeter;
+    use crate::public_interface::constants::*;
+
+    // G1 addition on BN254 with a = 0 and b = 3
+    fn bn254_g1_addition_input() -> Vec<u8> {
+        let modulus = hex::decode("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47").unwrap();
+        let order = hex::decode("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001").unwrap();
+
+        let mut input = vec![OPERATION_G1_ADD, 32u8];
+        input.extend(modulus);
+        input.extend(vec![0u8; 32]);
+        let mut b = vec![0u8; 32];
+        b[31] = 3;
+        input.extend(b);
+        input.push(32u8);
+        input.extend(order);
+        input.extend(vec![0u8; 128]);
+
+        input
+    }
+
+    #[test]
+    fn test_default_schedule() {
+        use crate::test::pairings::mnt4::assemble_mnt4_753;
+
+        GasSchedule::default().validate().unwrap();
+
+        let meter = GasMeter::default();
+        let calldata = assemble_mnt4_753(2);
+        let mut input = vec![OPERATION_PAIRING];
+        input.extend(calldata);
+        assert_eq!(meter.meter_input(&input).unwrap(), GasMeter::meter(&input).unwrap());
+
+        let input = bn254_g1_addition_input();
+        assert_eq!(meter.meter_input(&input).unwrap(), GasMeter::meter(&input).unwrap());
+    }
+
+    #[test]
+    fn test_repriced_schedule() {
+        let input = bn254_g1_addition_input();
+        let default_price = GasMeter::meter(&input).unwrap();
+
+        let mut schedule = GasSchedule::default();
+        for price in schedule.g1_addition.lookup_parameters.values_mut() {
+            *price *= 2;
+        }
+        let meter = GasMeter::new(schedule).unwrap();
+        assert_eq!(meter.meter_input(&input).unwrap(), 2 * default_price);
+
+        let json = format!("{{\"price\": [{}]}}", (NUM_LIMBS_MIN..=NUM_LIMBS_MAX).map(|l| format!("[{}, 7]", l)).collect::<Vec<_>>().join(", "));
+        let schedule = GasSchedule::default().with_model_json(GasModel::G1Addition, &json).unwrap();
+        let meter = GasMeter::new(schedule).unwrap();
+        assert_eq!(meter.meter_input(&input).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_invalid_schedules() {
+        // limbs are not covered
+        let mut schedule = GasSchedule::default();
+        schedule.g1_multiplication.per_limb.remove(&NUM_LIMBS_MAX);
+        assert!(GasMeter::new(schedule).is_err());
+
+        // zero multiplier
+        let mut schedule = GasSchedule::default();
+        schedule.bn_pairing.multiplier = 0;
+        assert!(GasMeter::new(schedule).is_err());
+
+        // unexpected feature
+        let mut schedule = GasSchedule::default();
+        schedule.bls12_pairing.miller_features[0].0 = "u_bit_length".to_owned();
+        assert!(GasMeter::new(schedule).is_err());
+
+        // variable outside of features
+        let mut schedule = GasSchedule::default();
+        schedule.mnt4_pairing.final_exp[0].1.push((10, 1));
+        assert!(GasMeter::new(schedule).is_err());
+
+        // power above the feature power
+        let mut schedule = GasSchedule::default();
+        schedule.mnt6_pairing.miller[0].1 = vec![(0, 2)];
+        assert!(GasMeter::new(schedule).is_err());
+
+        // unknown field in JSON
+        let json = "{\"price\": [[4, 1]], \"extra\": 1}";
+        assert!(GasSchedule::default().with_model_json(GasModel::G1Addition, json).is_err());
+
+        // not a JSON
+        assert!(GasSchedule::default().with_model_json(GasModel::BnPairing, "price").is_err());
+    }
+
+    #[test]
+    fn test_schedule_from_directory() {
+        let dir = std::env::temp_dir().join(format!("eth_pairings_gas_schedule_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let json = format!("{{\"price\": [{}]}}", (NUM_LIMBS_MIN..=NUM_LIMBS_MAX).map(|l| format!("[{}, 11]", l)).collect::<Vec<_>>().join(", "));
+        std::fs::write(dir.join(GasModel::G1Addition.file_name()), json).unwrap();
+        let schedule = GasSchedule::from_directory(&dir).unwrap();
+        let meter = GasMeter::new(schedule).unwrap();
+        assert_eq!(meter.meter_input(&bn254_g1_addition_input()).unwrap(), 11);
+
+        // written models are the same as the embedded ones
+        GasSchedule::default().write_to_directory(&dir).unwrap();
+        for model in GasModel::ALL.iter() {
+            let written: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(dir.join(model.file_name())).unwrap()).unwrap();
+            let embedded: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(std::path::Path::new("src/gas_meter").join(model.file_name())).unwrap()).unwrap();
+            assert_eq!(written, embedded, "{}", model.file_name());
+            assert_eq!(GasModel::from_file_name(model.file_name()), Some(*model));
+        }
+
+        std::fs::write(dir.join(GasModel::G1Addition.file_name()), "{\"price\": [[4, 11]]}").unwrap();
+        assert!(GasSchedule::from_directory(&dir).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
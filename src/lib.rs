@@ -13,6 +13,7 @@ pub mod representation;
 pub mod field;
 pub mod fp;
 pub mod weierstrass;
+pub mod edwards;
 mod mont_inverse;
 mod multiexp;
 pub mod extension_towers;
@@ -33,6 +34,12 @@ pub mod public_interface;
 #[cfg(feature = "gas_metering")]
 pub mod gas_meter;
 
+#[cfg(feature = "serialization")]
+pub mod serialization;
+
+#[cfg(feature = "calibration")]
+pub mod calibration;
+
 #[cfg(feature = "external_tests")]
 pub mod external_tests;
 
@@ -0,0 +1,130 @@
+use crate::fp::*;
+use crate::representation::*;
+use crate::field::*;
+use crate::traits::*;
+use crate::extension_towers::fp2::{Fp2, Extension2};
+use crate::errors::ApiError;
+
+use sha2::{Sha256, Digest};
+
+const SHA256_BLOCK_BYTES: usize = 64;
+const SHA256_OUTPUT_BYTES: usize = 32;
+
+// target security level of hash-to-field, k in the hash-to-curve specification
+const SECURITY_BITS: u64 = 128;
+
+/// `expand_message_xmd` from the hash-to-curve specification instantiated with SHA-256
+pub fn expand_message_xmd_sha256(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, ApiError> {
+    let ell = len_in_bytes.div_ceil(SHA256_OUTPUT_BYTES);
+    if ell > 255 || len_in_bytes > 0xffff {
+        return Err(ApiError::InputError("requested too many bytes from expand_message_xmd".to_owned()));
+    }
+    if dst.len() > 255 {
+        return Err(ApiError::InputError("domain separation tag is too long".to_owned()));
+    }
+
+    let dst_len = [dst.len() as u8];
+    let len_in_bytes_encoding = (len_in_bytes as u16).to_be_bytes();
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = Sha256::new()
+        .chain(&[0u8; SHA256_BLOCK_BYTES][..])
+        .chain(msg)
+        .chain(&len_in_bytes_encoding[..])
+        .chain([0u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let mut b_i = Sha256::new()
+        .chain(&b_0[..])
+        .chain([1u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_BYTES);
+    uniform_bytes.extend_from_slice(&b_i[..]);
+
+    for i in 2..=ell {
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+        let mut tmp = [0u8; SHA256_OUTPUT_BYTES];
+        for (t, (a, b)) in tmp.iter_mut().zip(b_0.iter().zip(b_i.iter())) {
+            *t = a ^ b;
+        }
+        b_i = Sha256::new()
+            .chain(&tmp[..])
+            .chain([i as u8])
+            .chain(dst)
+            .chain(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i[..]);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+
+    Ok(uniform_bytes)
+}
+
+// L = ceil((ceil(log2(p)) + k) / 8)
+fn bytes_per_element<E: ElementRepr, F: SizedPrimeField<Repr = E>>(field: &F) -> usize {
+    (field.modulus_bits() + SECURITY_BITS).div_ceil(8) as usize
+}
+
+// interprets big endian bytes as an integer and reduces it modulo p
+fn fp_from_be_bytes_reduced<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(field: &'a F, bytes: &[u8]) -> Fp<'a, E, F> {
+    let mut two_in_64 = [0u8; 9];
+    two_in_64[0] = 1;
+    let two_in_64 = Fp::from_be_bytes(field, &two_in_64[..], true).expect("modulus is larger than 2^64");
+
+    let first_chunk_len = match bytes.len() % 8 {
+        0 => 8,
+        l => l
+    };
+
+    let (first_chunk, rest) = bytes.split_at(std::cmp::min(first_chunk_len, bytes.len()));
+    let mut result = Fp::from_be_bytes(field, first_chunk, true).expect("chunk fits into the field");
+    for chunk in rest.chunks(8) {
+        result.mul_assign(&two_in_64);
+        result.add_assign(&Fp::from_be_bytes(field, chunk, true).expect("chunk fits into the field"));
+    }
+
+    result
+}
+
+/// `hash_to_field` from the hash-to-curve specification for a prime field
+pub fn hash_to_fp<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+    field: &'a F
+) -> Result<Vec<Fp<'a, E, F>>, ApiError> {
+    let l = bytes_per_element(field);
+    let uniform_bytes = expand_message_xmd_sha256(msg, dst, count * l)?;
+
+    let result = uniform_bytes.chunks(l).map(|chunk| fp_from_be_bytes_reduced(field, chunk)).collect();
+
+    Ok(result)
+}
+
+/// `hash_to_field` from the hash-to-curve specification for a quadratic extension
+pub fn hash_to_fp2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+    extension: &'a Extension2<'a, E, F>
+) -> Result<Vec<Fp2<'a, E, F>>, ApiError> {
+    let l = bytes_per_element(extension.field);
+    let uniform_bytes = expand_message_xmd_sha256(msg, dst, count * 2 * l)?;
+
+    let result = uniform_bytes.chunks(2 * l).map(|chunk| {
+        let mut el = Fp2::zero(extension);
+        el.c0 = fp_from_be_bytes_reduced(extension.field, &chunk[..l]);
+        el.c1 = fp_from_be_bytes_reduced(extension.field, &chunk[l..]);
+
+        el
+    }).collect();
+
+    Ok(result)
+}
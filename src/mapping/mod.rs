@@ -1,59 +1,18 @@
-use crate::fp::*;
-use crate::representation::*;
-use crate::field::*;
-use crate::traits::*;
-use crate::extension_towers::fp2::Fp2;
-
 pub mod simple_swu;
 pub mod isogeny;
+pub mod svdw;
+pub mod hash_to_field;
 pub mod parameters;
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum Sign {
-    Zero,
-    SignPlus,
-    SignMinus
-}
-
-fn sign_of_fp<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> >(
-    el: &Fp<'a, E, F>
-) -> Sign {
-    if el.is_zero() {
-        return Sign::Zero;
-    }
-
-    let el_repr = el.into_repr();
-
-    if el_repr.as_ref()[0] & 1 == 0 {
-        // 0 mod 2
-        return Sign::SignPlus;
-    } else {
-        return Sign::SignMinus;
-    }
-}
-
-fn sign_of_fp2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> >(
-    el: &Fp2<'a, E, F>
-) -> Sign {
-    // compare c_0 and then c_1
-    let sign_from_c0 = sign_of_fp(&el.c0);
-
-    match sign_from_c0 {
-        s @ Sign::SignMinus | 
-        s @ Sign::SignPlus => {
-            return s;
-        },
-        Sign::Zero => {
-            return sign_of_fp(&el.c1);
-        }
-    }
-}
-
 mod constants {
     use super::isogeny::*;
     use super::simple_swu::*;
+    #[cfg(feature = "eip_196")]
+    use super::svdw::*;
     use crate::engines::bls12_381::*;
     use crate::weierstrass::*;
+    #[cfg(feature = "eip_196")]
+    use crate::weierstrass::curve::WeierstrassCurve;
     use crate::field::*;
     use crate::traits::*;
     use num_bigint::BigUint;
@@ -308,7 +267,23 @@ mod constants {
         (swu, iso)
     }
 
-    
+    #[cfg(feature = "eip_196")]
+    pub(crate) fn calculate_bn254_g1_mapping_params<'a>(curve: &'a WeierstrassCurve<'a, CurveOverFpParameters<'a, U256Repr, PrimeField<U256Repr>>>) -> 
+        SvdwParameters<CurveOverFpParameters<'a, U256Repr, PrimeField<U256Repr>>>
+    {
+        let z = Fp::one(curve.b.field);
+
+        svdw_parameters_fp(&z, curve).expect("Z = 1 is valid for BN254 G1")
+    }
+
+    #[cfg(feature = "eip_196")]
+    pub(crate) fn calculate_bn254_g2_mapping_params<'a>(curve: &'a WeierstrassCurve<'a, CurveOverFp2Parameters<'a, U256Repr, PrimeField<U256Repr>>>) -> 
+        SvdwParameters<CurveOverFp2Parameters<'a, U256Repr, PrimeField<U256Repr>>>
+    {
+        let z = Fp2::one(curve.b.extension_field);
+
+        svdw_parameters_fp2(&z, curve).expect("Z = 1 is valid for BN254 G2")
+    }
 }
 
 #[cfg(test)]
@@ -318,14 +293,14 @@ mod test {
     use super::simple_swu::*;
     use crate::engines::bls12_381::*;
     use crate::weierstrass::curve::*;
-    use super::sign_of_fp2;
+    use crate::square_root::{Sign, sign_of_fp2};
 
     #[test]
     fn test_sign_of_zero() {
         let zero = str_radix_into_ext2("0", "0", 10, &BLS12_381_EXTENSION_2_FIELD);
         let sgn = sign_of_fp2(&zero);
         match sgn {
-            super::Sign::Zero => {},
+            Sign::Zero => {},
             _ => {panic!("invalid sign of zero")}
         }
     }
@@ -339,7 +314,7 @@ mod test {
             &random_el, 
             &swu, 
             &BLS12_381_G1_CURVE_ISOGENY
-        );
+        ).unwrap();
 
         let (x, y) = apply_isogeny_map(
             &x_prime,
@@ -361,7 +336,7 @@ mod test {
             &random_el, 
             &swu, 
             &BLS12_381_G2_CURVE_ISOGENY
-        );
+        ).unwrap();
 
         let (x, y) = apply_isogeny_map(
             &x_prime,
@@ -374,4 +349,75 @@ mod test {
         assert!(point.is_on_curve());
     }
 
+    // these test vectors are from https://www.rfc-editor.org/rfc/rfc9380#appendix-K.1
+
+    #[test]
+    fn test_expand_message_xmd_sha256() {
+        use super::hash_to_field::expand_message_xmd_sha256;
+
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+        let uniform_bytes = expand_message_xmd_sha256(b"", dst, 0x20).unwrap();
+        assert_eq!(hex::encode(&uniform_bytes), "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235");
+
+        let uniform_bytes = expand_message_xmd_sha256(b"abc", dst, 0x20).unwrap();
+        assert_eq!(hex::encode(&uniform_bytes), "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615");
+
+        let uniform_bytes = expand_message_xmd_sha256(b"abc", dst, 0x80).unwrap();
+        assert_eq!(uniform_bytes.len(), 0x80);
+        assert!(expand_message_xmd_sha256(b"abc", dst, 256 * 32).is_err());
+    }
+
+    #[cfg(feature = "eip_196")]
+    #[test]
+    fn test_svdw_mapping_bn254_g1() {
+        use super::svdw::*;
+        use crate::engines::bn254::*;
+        use crate::fp::Fp;
+
+        let params = &*super::parameters::BN254_G1_MAPPING_PARAMS;
+
+        let vectors = [
+            (0u64, "0x183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3", "0x0a6ea289876b139cfe2cd1f08c065a2ab4aad542eaccb013520ea36934e877b4"),
+            (1u64, "0x2b8d79cdcaaca9beddf982188d7d92fd2acc298e53b6ec72d69aab86960a1727", "0x16de5b0e1c87130160106734a03a0e2a4a78ed715dba060f06235c2abdb920e5"),
+            (42u64, "0x0b9ce0a7eb90ea3c2308f9cfdea1c76c7dbb912c28bccf3d50a36497f2bc8542", "0x1f45bb52352c9cb30c05b4c0a6e571b10c3c91ba6170bb9858675c3765ed7ee0"),
+        ];
+
+        for (u, expected_x, expected_y) in vectors.iter() {
+            let u = Fp::from_be_bytes(&BN254_BASE_FIELD, &u.to_be_bytes(), true).unwrap();
+            let (x, y) = svdw_fp(&u, params, &BN254_G1_CURVE).unwrap();
+
+            assert_eq!(format!("{}", x), *expected_x);
+            assert_eq!(format!("{}", y), *expected_y);
+
+            let point = CurvePoint::point_from_xy(&BN254_G1_CURVE, x, y);
+            assert!(point.is_on_curve());
+        }
+    }
+
+    #[cfg(feature = "eip_196")]
+    #[test]
+    fn test_svdw_mapping_bn254_g2() {
+        use super::svdw::*;
+        use crate::engines::bn254::*;
+        use crate::fp::Fp;
+        use crate::extension_towers::fp2::Fp2;
+        use crate::traits::ZeroAndOne;
+
+        let params = &*super::parameters::BN254_G2_MAPPING_PARAMS;
+
+        let mut u = Fp2::zero(&BN254_EXT2_FIELD);
+        u.c0 = Fp::from_be_bytes(&BN254_BASE_FIELD, &[42u8], true).unwrap();
+        u.c1 = u.c0;
+
+        let (x, y) = svdw_fp2(&u, params, &BN254_G2_CURVE).unwrap();
+        assert_eq!(format!("{}", x.c0), "0x0f740640e4e13dd20fff1e5408839390ec1bdf13b83ab2589c2b3bb482eb3191");
+        assert_eq!(format!("{}", x.c1), "0x185598f953e950ee359209e33bb0520a915e27624c56e33c45d05e868059fcf9");
+        assert_eq!(format!("{}", y.c0), "0x247c1bfefd31d3439f9080fb05cf6d19330d5cec3a87c18f57c3edf6ffa76974");
+        assert_eq!(format!("{}", y.c1), "0x0a521e43e9f4fabad8bf285f2e35d9d04e3d789ef14eec32eec3b13f634c0542");
+
+        let point = CurvePoint::point_from_xy(&BN254_G2_CURVE, x, y);
+        assert!(point.is_on_curve());
+    }
+
 }
\ No newline at end of file
@@ -6,6 +6,8 @@ use self::once_cell::sync::Lazy;
 
 use super::isogeny::*;
 use super::simple_swu::*;
+#[cfg(feature = "eip_196")]
+use super::svdw::*;
 use crate::weierstrass::*;
 
 pub static BLS12_G1_MAPPING_PARAMS: Lazy<
@@ -24,4 +26,14 @@ pub static BLS12_G2_MAPPING_PARAMS: Lazy<
         )
         > = Lazy::new(|| {
             super::constants::calculate_bls12_381_g2_mapping_params(&crate::engines::bls12_381::BLS12_381_EXTENSION_2_FIELD)
-});
\ No newline at end of file
+});
+
+#[cfg(feature = "eip_196")]
+pub static BN254_G1_MAPPING_PARAMS: Lazy<SvdwParameters<CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>>> = Lazy::new(|| {
+    super::constants::calculate_bn254_g1_mapping_params(&crate::engines::bn254::BN254_G1_CURVE)
+});
+
+#[cfg(feature = "eip_196")]
+pub static BN254_G2_MAPPING_PARAMS: Lazy<SvdwParameters<CurveOverFp2Parameters<'static, U256Repr, PrimeField<U256Repr>>>> = Lazy::new(|| {
+    super::constants::calculate_bn254_g2_mapping_params(&crate::engines::bn254::BN254_G2_CURVE)
+});
@@ -3,12 +3,13 @@ use crate::representation::*;
 use crate::field::*;
 use crate::traits::*;
 use crate::extension_towers::fp2::Fp2;
-use super::*;
 
 use crate::weierstrass::*;
 use crate::weierstrass::curve::*;
 use crate::square_root::*;
 
+use super::svdw::{is_square_fp, is_square_fp2};
+
 #[derive(Clone)]
 pub struct SwuParameters<C: CurveParameters> {
     pub z: C::BaseFieldElement,
@@ -16,6 +17,62 @@ pub struct SwuParameters<C: CurveParameters> {
     pub minus_z_inv: C::BaseFieldElement
 }
 
+macro_rules! impl_swu_parameters {
+    ($field_el: ident, $field_of: ident, $is_square: ident, $params_fn: ident) => {
+        /// Calculates map constants for a given `Z` on a curve with `A * B != 0`. Returns `None` if the curve
+        /// or `Z` do not satisfy the requirements of the hash-to-curve specification, so the map
+        /// is well defined for every input
+        pub(crate) fn $params_fn<
+            'a,
+            E: ElementRepr,
+            F: SizedPrimeField<Repr = E>,
+            C: CurveParameters<BaseFieldElement = $field_el<'a, E, F>>
+        > (
+            z: &$field_el<'a, E, F>,
+            curve: &WeierstrassCurve<'a, C>
+        ) -> Option<SwuParameters<C>> {
+            if curve.a.is_zero() || curve.b.is_zero() {
+                return None;
+            }
+
+            // Z is a non-square
+            if $is_square(z) {
+                return None;
+            }
+
+            // Z != -1
+            let mut z_plus_one = *z;
+            z_plus_one.add_assign(&$field_el::one(z.$field_of));
+            if z_plus_one.is_zero() {
+                return None;
+            }
+
+            let mut minus_b_by_a = curve.b;
+            minus_b_by_a.mul_assign(&curve.a.inverse()?);
+            minus_b_by_a.negate();
+
+            let mut minus_z_inv = z.inverse()?;
+            minus_z_inv.negate();
+
+            // g(B / (Z * A)) is a square, so the exceptional case of the map gives a point
+            let mut x = minus_b_by_a;
+            x.mul_assign(&minus_z_inv);
+            if !$is_square(&curve.evaluate_rhs(&x)) {
+                return None;
+            }
+
+            Some(SwuParameters::<C> {
+                z: *z,
+                minus_b_by_a,
+                minus_z_inv
+            })
+        }
+    };
+}
+
+impl_swu_parameters!(Fp, field, is_square_fp, swu_parameters_fp);
+impl_swu_parameters!(Fp2, extension_field, is_square_fp2, swu_parameters_fp2);
+
 pub(crate) fn simplified_swu_fp<
     'a,
     E: ElementRepr, 
@@ -25,7 +82,7 @@ pub(crate) fn simplified_swu_fp<
     u: &C::BaseFieldElement,
     params: &SwuParameters<C>,
     curve: &WeierstrassCurve<'a, C>
-) -> (C::BaseFieldElement, C::BaseFieldElement) {
+) -> Option<(C::BaseFieldElement, C::BaseFieldElement)> {
     let one = Fp::one(u.field);
 
     // we do NOT use constant time operations here
@@ -102,7 +159,9 @@ pub(crate) fn simplified_swu_fp<
         gx2
     };
     // 19.   y = sqrt(y2)
-    let mut y = sqrt(&y2).expect("y2 is a square");
+    // one of gx1 and gx2 is a square for a prime modulus, so the root may only
+    // be missing if the modulus is composite
+    let mut y = sqrt(&y2)?;
 
     // 20.  e3 = sgn0(u) == sgn0(y)  # Fix sign of y
     let u_sign = sign_of_fp(&u);
@@ -130,7 +189,7 @@ pub(crate) fn simplified_swu_fp<
     }
 
     // 22. return (x, y)
-    (x, y)
+    Some((x, y))
 }
 
 pub(crate) fn simplified_swu_fp2<
@@ -142,7 +201,7 @@ pub(crate) fn simplified_swu_fp2<
     u: &Fp2<'a, E, F>,
     params: &SwuParameters<C>,
     curve: &WeierstrassCurve<'a, C>
-) -> (Fp2<'a, E, F>, Fp2<'a, E, F>)  {
+) -> Option<(Fp2<'a, E, F>, Fp2<'a, E, F>)>  {
     let one = Fp2::one(u.extension_field);
 
     // we do NOT use constant time operations here
@@ -219,7 +278,9 @@ pub(crate) fn simplified_swu_fp2<
         gx2
     };
     // 19.   y = sqrt(y2)
-    let mut y = sqrt_ext2(&y2).expect("y2 is a square");
+    // one of gx1 and gx2 is a square for a prime modulus, so the root may only
+    // be missing if the modulus is composite
+    let mut y = sqrt_ext2(&y2)?;
 
     // 20.  e3 = sgn0(u) == sgn0(y)  # Fix sign of y
     let u_sign = sign_of_fp2(&u);
@@ -249,5 +310,5 @@ pub(crate) fn simplified_swu_fp2<
     }
 
     // 22. return (x, y)
-    (x, y)
+    Some((x, y))
 }
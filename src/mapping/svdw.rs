@@ -0,0 +1,245 @@
+use crate::fp::*;
+use crate::representation::*;
+use crate::field::*;
+use crate::traits::*;
+use crate::extension_towers::fp2::Fp2;
+
+use crate::weierstrass::*;
+use crate::weierstrass::curve::*;
+use crate::square_root::*;
+
+/// Constants of the Shallue–van de Woestijne map, works for any curve including ones with `A * B == 0`
+/// that do not have a low degree isogeny required for simplified SWU
+#[derive(Clone)]
+pub struct SvdwParameters<C: CurveParameters> {
+    pub z: C::BaseFieldElement,
+    // g(Z)
+    pub c1: C::BaseFieldElement,
+    // -Z / 2
+    pub c2: C::BaseFieldElement,
+    // sqrt(-g(Z) * (3 * Z^2 + 4 * A)), sgn0(c3) == 0
+    pub c3: C::BaseFieldElement,
+    // -4 * g(Z) / (3 * Z^2 + 4 * A)
+    pub c4: C::BaseFieldElement,
+}
+
+pub(crate) fn is_square_fp<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(el: &Fp<'a, E, F>) -> bool {
+    let l = legendre_symbol_fp(el);
+
+    l == LegendreSymbol::Zero || l == LegendreSymbol::QuadraticResidue
+}
+
+pub(crate) fn is_square_fp2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(el: &Fp2<'a, E, F>) -> bool {
+    let l = legendre_symbol_fp2(el);
+
+    l == LegendreSymbol::Zero || l == LegendreSymbol::QuadraticResidue
+}
+
+fn fix_sign<T: FieldElement>(y: &mut T, u_sign: Sign, y_sign: Sign) {
+    // our SignZero is equal to SignPlus
+    let u_is_negative = u_sign == Sign::SignMinus;
+    let y_is_negative = y_sign == Sign::SignMinus;
+    if u_is_negative != y_is_negative {
+        y.negate();
+    }
+}
+
+macro_rules! impl_svdw {
+    ($field_el: ident, $field_of: ident, $is_square: ident, $sqrt: ident, $sign_of: ident, $params_fn: ident, $map_fn: ident) => {
+        /// Calculates map constants for a given `Z`. Returns `None` if `Z` does not satisfy the requirements
+        /// of the hash-to-curve specification
+        pub(crate) fn $params_fn<
+            'a,
+            E: ElementRepr,
+            F: SizedPrimeField<Repr = E>,
+            C: CurveParameters<BaseFieldElement = $field_el<'a, E, F>>
+        > (
+            z: &$field_el<'a, E, F>,
+            curve: &WeierstrassCurve<'a, C>
+        ) -> Option<SvdwParameters<C>> {
+            let field = z.$field_of;
+
+            let mut three = $field_el::one(field);
+            three.double();
+            three.add_assign(&$field_el::one(field));
+
+            let mut four_a = curve.a;
+            four_a.double();
+            four_a.double();
+
+            // g(Z)
+            let mut g_z = *z;
+            g_z.square();
+            g_z.add_assign(&curve.a);
+            g_z.mul_assign(z);
+            g_z.add_assign(&curve.b);
+
+            if g_z.is_zero() {
+                return None;
+            }
+
+            // 3 * Z^2 + 4 * A
+            let mut t = *z;
+            t.square();
+            t.mul_assign(&three);
+            t.add_assign(&four_a);
+
+            // h(Z) = -(3 * Z^2 + 4 * A) / (4 * g(Z)) must be a non-zero square
+            let t_inv = t.inverse()?;
+
+            let mut minus_g_z_by_t = g_z;
+            minus_g_z_by_t.mul_assign(&t_inv);
+            minus_g_z_by_t.negate();
+            if !$is_square(&minus_g_z_by_t) {
+                return None;
+            }
+
+            let mut c2 = *z;
+            c2.negate();
+            let mut two = $field_el::one(field);
+            two.double();
+            c2.mul_assign(&two.inverse()?);
+
+            // g(Z) or g(-Z / 2) must be a square
+            let mut g_c2 = c2;
+            g_c2.square();
+            g_c2.add_assign(&curve.a);
+            g_c2.mul_assign(&c2);
+            g_c2.add_assign(&curve.b);
+            if !$is_square(&g_z) && !$is_square(&g_c2) {
+                return None;
+            }
+
+            let mut c3 = g_z;
+            c3.mul_assign(&t);
+            c3.negate();
+            let mut c3 = $sqrt(&c3)?;
+            if $sign_of(&c3) == Sign::SignMinus {
+                c3.negate();
+            }
+
+            let mut c4 = minus_g_z_by_t;
+            c4.double();
+            c4.double();
+
+            Some(SvdwParameters::<C> {
+                z: *z,
+                c1: g_z,
+                c2,
+                c3,
+                c4
+            })
+        }
+
+        pub(crate) fn $map_fn<
+            'a,
+            E: ElementRepr,
+            F: SizedPrimeField<Repr = E>,
+            C: CurveParameters<BaseFieldElement = $field_el<'a, E, F>>
+        > (
+            u: &$field_el<'a, E, F>,
+            params: &SvdwParameters<C>,
+            curve: &WeierstrassCurve<'a, C>
+        ) -> Option<($field_el<'a, E, F>, $field_el<'a, E, F>)> {
+            let one = $field_el::one(u.$field_of);
+
+            let g = |x: &$field_el<'a, E, F>| {
+                let mut gx = *x;
+                gx.square();
+                gx.add_assign(&curve.a);
+                gx.mul_assign(x);
+                gx.add_assign(&curve.b);
+
+                gx
+            };
+
+            // we do NOT use constant time operations here
+
+            // 1.  tv1 = u^2
+            // 2.  tv1 = tv1 * c1
+            let mut tv1 = *u;
+            tv1.square();
+            tv1.mul_assign(&params.c1);
+
+            // 3.  tv2 = 1 + tv1
+            let mut tv2 = one;
+            tv2.add_assign(&tv1);
+
+            // 4.  tv1 = 1 - tv1
+            let mut t = one;
+            t.sub_assign(&tv1);
+            let tv1 = t;
+
+            // 5.  tv3 = tv1 * tv2
+            let mut tv3 = tv1;
+            tv3.mul_assign(&tv2);
+
+            // 6.  tv3 = inv0(tv3)
+            let tv3 = tv3.inverse().unwrap_or($field_el::zero(u.$field_of));
+
+            // 7.  tv4 = u * tv1
+            // 8.  tv4 = tv4 * tv3
+            // 9.  tv4 = tv4 * c3
+            let mut tv4 = *u;
+            tv4.mul_assign(&tv1);
+            tv4.mul_assign(&tv3);
+            tv4.mul_assign(&params.c3);
+
+            // 10.  x1 = c2 - tv4
+            let mut x1 = params.c2;
+            x1.sub_assign(&tv4);
+
+            // 11-14. gx1 = x1^3 + A * x1 + B
+            // 15.  e1 = is_square(gx1)
+            let e1 = $is_square(&g(&x1));
+
+            // 16.  x2 = c2 + tv4
+            let mut x2 = params.c2;
+            x2.add_assign(&tv4);
+
+            // 17-20. gx2 = x2^3 + A * x2 + B
+            // 21.  e2 = is_square(gx2) AND NOT e1
+            let e2 = !e1 && $is_square(&g(&x2));
+
+            // 27.   x = CMOV(x3, x1, e1)
+            // 28.   x = CMOV(x, x2, e2)
+            let x = if e1 {
+                x1
+            } else if e2 {
+                x2
+            } else {
+                // 22.  x3 = tv2^2
+                // 23.  x3 = x3 * tv3
+                // 24.  x3 = x3^2
+                // 25.  x3 = x3 * c4
+                // 26.  x3 = x3 + Z
+                let mut x3 = tv2;
+                x3.square();
+                x3.mul_assign(&tv3);
+                x3.square();
+                x3.mul_assign(&params.c4);
+                x3.add_assign(&params.z);
+
+                x3
+            };
+
+            // 29-32. gx = x^3 + A * x + B
+            // 33.   y = sqrt(gx)
+            // gx is a square for a prime modulus, so the root may only be missing
+            // if the modulus is composite
+            let mut y = $sqrt(&g(&x))?;
+
+            // 34.  e3 = sgn0(u) == sgn0(y)
+            // 35.   y = CMOV(-y, y, e3)
+            let u_sign = $sign_of(u);
+            let y_sign = $sign_of(&y);
+            fix_sign(&mut y, u_sign, y_sign);
+
+            // 36. return (x, y)
+            Some((x, y))
+        }
+    };
+}
+
+impl_svdw!(Fp, field, is_square_fp, sqrt, sign_of_fp, svdw_parameters_fp, svdw_fp);
+impl_svdw!(Fp2, extension_field, is_square_fp2, sqrt_ext2, sign_of_fp2, svdw_parameters_fp2, svdw_fp2);
@@ -3,8 +3,8 @@ use crate::weierstrass::curve::CurvePoint;
 use crate::weierstrass::CurveParameters;
 use crate::integers::MaxGroupSizeUint;
 
-pub(crate) fn peppinger<'a, C: CurveParameters>
-    (bases: &[CurvePoint<'a, C>], mut scalars: Vec<MaxGroupSizeUint>) -> CurvePoint<'a, C>
+pub(crate) fn peppinger<G: Group>
+    (bases: &[G], mut scalars: Vec<MaxGroupSizeUint>) -> G
 {
     use crate::representation::*;
     debug_assert!(bases.len() == scalars.len());
@@ -17,8 +17,8 @@ pub(crate) fn peppinger<'a, C: CurveParameters>
 
     let mask = (1u64 << c) - 1u64;
     let mut cur = 0;
-    let num_bits = num_bits(&bases[0].curve.subgroup_order_repr);
-    let zero_point = CurvePoint::zero(bases[0].curve);
+    let num_bits = num_bits(bases[0].subgroup_order());
+    let zero_point = bases[0].zero_like();
 
     let mut windows = Vec::with_capacity((num_bits / c + 1) as usize);
     let mut buckets = Vec::with_capacity((1 << c) - 1);
@@ -63,4 +63,44 @@ pub(crate) fn peppinger<'a, C: CurveParameters>
     }
 
     acc
-}
\ No newline at end of file
+}
+
+/// Shamir's trick for `scalar_0 * base_0 + scalar_1 * base_1`. wNAF forms of both scalars
+/// are interleaved, so doublings are shared between two multiplications
+pub(crate) fn shamir<'a, C: CurveParameters, S: AsRef<[u64]>>
+    (base_0: &CurvePoint<'a, C>, scalar_0: S, base_1: &CurvePoint<'a, C>, scalar_1: S) -> CurvePoint<'a, C>
+{
+    use crate::representation::IntoWnaf;
+
+    const WINDOW_SIZE: u32 = 4;
+    let index_for_positive = (1 << (WINDOW_SIZE - 2)) as usize;
+
+    let table_0 = base_0.wnaf_precomputation_table(WINDOW_SIZE);
+    let table_1 = base_1.wnaf_precomputation_table(WINDOW_SIZE);
+
+    let wnaf_0 = scalar_0.as_ref().wnaf(WINDOW_SIZE);
+    let wnaf_1 = scalar_1.as_ref().wnaf(WINDOW_SIZE);
+
+    let mut acc = CurvePoint::zero(base_0.curve);
+    let mut found_nonzero = false;
+
+    for i in (0..std::cmp::max(wnaf_0.len(), wnaf_1.len())).rev() {
+        if found_nonzero {
+            acc.double();
+        }
+
+        for (wnaf, table) in [(&wnaf_0, &table_0), (&wnaf_1, &table_1)].iter() {
+            let w = wnaf.get(i).copied().unwrap_or(0);
+            if w != 0 {
+                found_nonzero = true;
+                if w > 0 {
+                    acc.add_assign(&table[index_for_positive + (w >> 1) as usize]);
+                } else {
+                    acc.add_assign(&table[index_for_positive - 1 - ((-w) >> 1) as usize]);
+                }
+            }
+        }
+    }
+
+    acc
+}
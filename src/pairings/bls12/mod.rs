@@ -10,7 +10,8 @@ use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2
 use crate::extension_towers::fp6_as_3_over_2::{Extension3Over2};
 use crate::pairings::PairingEngine;
 use crate::pairings::TwistType;
-use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf, prefer_compressed_cyclotomic_exp};
+use crate::engines::const_arithmetic::is_bls12_modulus;
 
 pub(crate) struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) ell_coeffs: Vec<(Fp2<'a, FE, F>, Fp2<'a, FE, F>, Fp2<'a, FE, F>)>
@@ -54,7 +55,10 @@ pub struct Bls12Instance<
     pub fp6_extension: &'a Extension3Over2<'a, FE, F>,
     pub fp12_extension: &'a Extension2Over3Over2<'a, FE, F>,
     pub prefer_naf: bool,
-    pub x_naf: Vec<i8>
+    pub x_naf: Vec<i8>,
+    /// Modulus is the BLS12 polynomial in `x`, so the hard part of the final exponentiation
+    /// can use the shorter Hayashida–Hayasaka–Teruya chain
+    pub prefer_hht_final_exp: bool
 }
 
 impl<
@@ -88,6 +92,8 @@ impl<
             }
         };
 
+        let prefer_hht_final_exp = is_bls12_modulus(params.base_field.modulus().as_ref(), params.x, params.x_is_negative);
+
         Self {
             x: params.x,
             x_is_negative: params.x_is_negative,
@@ -99,11 +105,116 @@ impl<
             fp6_extension: params.fp6_extension,
             fp12_extension: params.fp12_extension,
             prefer_naf: prefer_naf,
-            x_naf: naf
+            x_naf: naf,
+            prefer_hht_final_exp
         }
     }
 }
 
+impl<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    > Bls12Instance<'a, FE, F, CB, CTW>
+{
+    // untwist-Frobenius-twist gives (conj(x) * c_x, conj(y) * c_y) with c_x = xi^((p-1)/3), c_y = xi^((p-1)/2)
+    // for a D-type twist and their inverses for an M-type twist
+    fn psi_coefficients(&self) -> (Fp2<'a, FE, F>, Fp2<'a, FE, F>) {
+        assert!(self.fp6_extension.frobenius_coeffs_are_calculated);
+        assert!(self.fp12_extension.frobenius_coeffs_are_calculated);
+
+        let c_x = self.fp6_extension.frobenius_coeffs_c1[1];
+        let mut c_y = self.fp12_extension.frobenius_coeffs_c1[1];
+        let xi_in_one_sixth = c_y;
+        c_y.square();
+        c_y.mul_assign(&xi_in_one_sixth);
+
+        match self.twist_type {
+            TwistType::D => (c_x, c_y),
+            TwistType::M => {
+                let c_x = c_x.inverse().expect("power of non-residue is invertible");
+                let c_y = c_y.inverse().expect("power of non-residue is invertible");
+
+                (c_x, c_y)
+            }
+        }
+    }
+
+    fn psi_with_coefficients(
+        &self,
+        p: &CurvePoint<'a, CTW>,
+        coeffs: &(Fp2<'a, FE, F>, Fp2<'a, FE, F>)
+    ) -> CurvePoint<'a, CTW> {
+        // Frobenius acts on Jacobian coordinates independently, so no normalization is required
+        let mut x = p.x;
+        x.c1.negate();
+        x.mul_assign(&coeffs.0);
+
+        let mut y = p.y;
+        y.c1.negate();
+        y.mul_assign(&coeffs.1);
+
+        let mut z = p.z;
+        z.c1.negate();
+
+        CurvePoint {
+            curve: p.curve,
+            x,
+            y,
+            z
+        }
+    }
+
+    fn mul_by_x<C: CurveParameters>(&self, p: &CurvePoint<'a, C>) -> CurvePoint<'a, C> {
+        let mut result = p.mul(self.x);
+        if self.x_is_negative {
+            result.negate();
+        }
+
+        result
+    }
+
+    /// Untwist-Frobenius-twist endomorphism of the twisted curve
+    pub fn psi(&self, p: &CurvePoint<'a, CTW>) -> CurvePoint<'a, CTW> {
+        let coeffs = self.psi_coefficients();
+
+        self.psi_with_coefficients(p, &coeffs)
+    }
+
+    /// Maps a point of the curve into the prime order subgroup by multiplication by `h_eff = 1 - x`
+    pub fn clear_cofactor_g1(&self, p: &CurvePoint<'a, CB>) -> CurvePoint<'a, CB> {
+        let mut result = p.clone();
+        result.sub_assign(&self.mul_by_x(p));
+
+        result
+    }
+
+    /// Maps a point of the twisted curve into the prime order subgroup using the method of Budroni and Pintore:
+    /// [x^2 - x - 1]P + [x - 1]psi(P) + psi^2(2P). For BLS12-381 it is the same as multiplication by `h_eff`
+    /// from the hash-to-curve specification
+    pub fn clear_cofactor_g2(&self, p: &CurvePoint<'a, CTW>) -> CurvePoint<'a, CTW> {
+        let coeffs = self.psi_coefficients();
+
+        let x_p = self.mul_by_x(p);
+        let mut psi_p = self.psi_with_coefficients(p, &coeffs);
+
+        let mut result = p.clone();
+        result.double();
+        result = self.psi_with_coefficients(&result, &coeffs);
+        result = self.psi_with_coefficients(&result, &coeffs);
+        result.sub_assign(&psi_p);
+
+        psi_p.add_assign(&x_p);
+        result.add_assign(&self.mul_by_x(&psi_p));
+        result.sub_assign(&x_p);
+        result.sub_assign(p);
+
+        result
+    }
+}
+
 impl<
     'a, 
         FE: ElementRepr, 
@@ -137,7 +248,11 @@ impl<
     }
 
     fn exp_by_x(&self, f: &mut Fp12<'a, FE, F>) {
-        *f = f.cyclotomic_exp(&self.x);
+        *f = if prefer_compressed_cyclotomic_exp(self.x) {
+            f.cyclotomic_exp_compressed(self.x)
+        } else {
+            f.cyclotomic_exp(self.x)
+        };
         if self.x_is_negative {
             f.conjugate();
         }
@@ -450,13 +565,18 @@ impl<
         f
     }
 
-    fn final_exponentiation(&self, f: &Fp12<'a, FE, F>) -> Option<Fp12<'a, FE, F>> {
-        // Computing the final exponentation following
-        // https://eprint.iacr.org/2016/130.pdf.
-        // We don't use their "faster" formula because it is difficult to make
-        // it work for curves with odd `P::X`.
-        // Hence we implement the algorithm from Table 1 below.
+    fn multi_miller_loop(&self, pairs: &[(&CurvePoint<'a, CB>, &CurvePoint<'a, CTW>)]) -> Fp12<'a, FE, F> {
+        if self.prefer_naf {
+            let loop_result = self.miller_loop_naf(pairs);
+            debug_assert!(!self.x_naf.is_empty());
+
+            loop_result
+        } else {
+            self.miller_loop(pairs)
+        }
+    }
 
+    fn final_exponentiation(&self, f: &Fp12<'a, FE, F>) -> Option<Fp12<'a, FE, F>> {
         // f1 = r.conjugate() = f^(p^6)
         let mut f1 = f.clone();
         // f1.conjugate();
@@ -478,57 +598,117 @@ impl<
                 // r = f^((p^6 - 1)(p^2 + 1))
                 r.mul_assign(&f2);
 
-                // Hard part of the final exponentation is below:
-                // From https://eprint.iacr.org/2016/130.pdf, Table 1
-                let mut y0 = r.clone();
-                y0.cyclotomic_square();
-                y0.conjugate();
+                if self.prefer_hht_final_exp {
+                    Some(self.final_exponentiation_hard_part_hht(&r))
+                } else {
+                    Some(self.final_exponentiation_hard_part(&r))
+                }
+            },
+            None => None,
+        }
+    }
+
+    fn final_exponentiation_hard_part(&self, r: &Fp12<'a, FE, F>) -> Fp12<'a, FE, F> {
+        // Computing the final exponentation following
+        // https://eprint.iacr.org/2016/130.pdf.
+        // We don't use their "faster" formula because it is difficult to make
+        // it work for curves with odd `P::X`.
+        // Hence we implement the algorithm from Table 1 below.
+        let mut y0 = r.clone();
+        y0.cyclotomic_square();
+        y0.conjugate();
+
+        let mut y5 = r.clone();
+        self.exp_by_x(&mut y5);
 
-                let mut y5 = r.clone();
-                self.exp_by_x(&mut y5);
+        let mut y1 = y5.clone();
+        y1.cyclotomic_square();
 
-                let mut y1 = y5.clone();
-                y1.cyclotomic_square();
+        let mut y3 = y0.clone();
+        y3.mul_assign(&y5);
 
-                let mut y3 = y0.clone();
-                y3.mul_assign(&y5);
+        let mut y0 = y3.clone();
+        self.exp_by_x(&mut y0);
+    
+        let mut y2 = y0.clone();
+        self.exp_by_x(&mut y2);
 
-                let mut y0 = y3.clone();
-                self.exp_by_x(&mut y0);
-            
-                let mut y2 = y0.clone();
-                self.exp_by_x(&mut y2);
+        let mut y4 = y2.clone();
+        self.exp_by_x(&mut y4);
+        y4.mul_assign(&y1);
 
-                let mut y4 = y2.clone();
-                self.exp_by_x(&mut y4);
-                y4.mul_assign(&y1);
+        let mut y1 = y4.clone();
+        self.exp_by_x(&mut y1);
 
-                let mut y1 = y4.clone();
-                self.exp_by_x(&mut y1);
+        y3.conjugate();
+        y1.mul_assign(&y3);
+        y1.mul_assign(r);
 
-                y3.conjugate();
-                y1.mul_assign(&y3);
-                y1.mul_assign(&r);
+        let mut y3 = r.clone();
+        y3.conjugate();
+        y0.mul_assign(r);
+        y0.frobenius_map(3);
 
-                let mut y3 = r.clone();
-                y3.conjugate();
-                y0.mul_assign(&r);
-                y0.frobenius_map(3);
+        y4.mul_assign(&y3);
+        y4.frobenius_map(1);
+        
+        y5.mul_assign(&y2);
+        y5.frobenius_map(2);
 
-                y4.mul_assign(&y3);
-                y4.frobenius_map(1);
-                
-                y5.mul_assign(&y2);
-                y5.frobenius_map(2);
+        y5.mul_assign(&y0);
+        y5.mul_assign(&y4);
+        y5.mul_assign(&y1);
 
-                y5.mul_assign(&y0);
-                y5.mul_assign(&y4);
-                y5.mul_assign(&y1);
+        y5
+    }
 
-                Some(y5)
-            },
-            None => None,
-        }
+    fn final_exponentiation_hard_part_hht(&self, r: &Fp12<'a, FE, F>) -> Fp12<'a, FE, F> {
+        // Hayashida, Hayasaka and Teruya, https://eprint.iacr.org/2020/875.pdf:
+        // 3 * (p^4 - p^2 + 1) / r = (x - 1)^2 * (x + p) * (x^2 + p^2 - 1) + 3
+        // for p = (x - 1)^2 * (x^4 - x^2 + 1) / 3 + x. The generic chain raises to the
+        // same multiple of the exponent, so results are equal. Same number of exponentiations
+        // by x, but 3 multiplications, a squaring and a Frobenius map less
+
+        // t0 = r^2
+        let mut t0 = *r;
+        t0.cyclotomic_square();
+
+        // t1 = r^(x - 1)
+        let mut t1 = *r;
+        self.exp_by_x(&mut t1);
+        let mut t2 = *r;
+        t2.conjugate();
+        t1.mul_assign(&t2);
+
+        // t1 = r^((x - 1)^2)
+        let mut t2 = t1;
+        self.exp_by_x(&mut t2);
+        t1.conjugate();
+        t1.mul_assign(&t2);
+
+        // t1 = r^((x - 1)^2 * (x + p))
+        let mut t2 = t1;
+        self.exp_by_x(&mut t2);
+        t1.frobenius_map(1);
+        t1.mul_assign(&t2);
+
+        // result = r^3
+        let mut result = *r;
+        result.mul_assign(&t0);
+
+        // t1 = r^((x - 1)^2 * (x + p) * (x^2 + p^2 - 1))
+        let mut t2 = t1;
+        self.exp_by_x(&mut t2);
+        self.exp_by_x(&mut t2);
+        let mut t0 = t1;
+        t0.frobenius_map(2);
+        t1.conjugate();
+        t1.mul_assign(&t2);
+        t1.mul_assign(&t0);
+
+        result.mul_assign(&t1);
+
+        result
     }
 }
 
@@ -567,19 +747,37 @@ impl<
                 return Some(Fp12::one(self.fp12_extension));
             }
 
-            let loop_result = if self.prefer_naf {
-                let loop_result = self.miller_loop_naf(&pairs[..]);
-                debug_assert!(self.x_naf.len() > 0);
-
-                loop_result
-            } else {
-                let loop_result = self.miller_loop(&pairs[..]);
-
-                loop_result
-            };
+            let loop_result = self.multi_miller_loop(&pairs[..]);
 
             self.final_exponentiation(&loop_result)
         }   
+
+    #[cfg(feature = "parallel_pairings")]
+    fn pair_parallel<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>], num_threads: usize) -> Option<Self::PairingResult> {
+            crate::pairings::pair_in_parallel(self, points, twists, num_threads)
+        }
+}
+
+#[cfg(feature = "parallel_pairings")]
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    > crate::pairings::MillerLoopEngine for Bls12Instance<'a, FE, F, CB, CTW> {
+    fn miller_loop_on_pairs(&self, pairs: &[(&CurvePoint<'a, CB>, &CurvePoint<'a, CTW>)]) -> Option<Self::PairingResult> {
+        Some(self.multi_miller_loop(pairs))
+    }
+
+    fn final_exponentiation_on_product(&self, f: &Self::PairingResult) -> Option<Self::PairingResult> {
+        self.final_exponentiation(f)
+    }
+
+    fn pairing_result_one(&self) -> Self::PairingResult {
+        Fp12::one(self.fp12_extension)
+    }
 }
 
 #[cfg(test)]
@@ -993,4 +1191,38 @@ mod tests {
 
         assert!(format!("{}",pairing_result.c0.c0.c0) == "0x00b718ff624a95f189bfb44bcd6d6556226837c1f74d1afbf4bea573b71c17d3a243cae41d966e2164aad0991fd790cc");
     }
+
+    #[test]
+    fn test_psi_is_multiplication_by_x_on_g2() {
+        use crate::weierstrass::Group;
+        use crate::engines::bls12_381::{BLS12_381_PAIRING_ENGINE, BLS12_381_G2_GENERATOR};
+        use crate::engines::bls12_377::{BLS12_377_PAIRING_ENGINE, BLS12_377_G2_GENERATOR};
+
+        // p = x mod r for BLS12 curves, so on G2 psi acts as multiplication by x for both twist types
+        let cases = [
+            (BLS12_381_PAIRING_ENGINE, BLS12_381_G2_GENERATOR),
+            (BLS12_377_PAIRING_ENGINE, BLS12_377_G2_GENERATOR)
+        ];
+        for (engine, q) in cases.iter() {
+            let mut q = q.mul(vec![12345678]);
+            q.normalize();
+
+            let mut psi_q = engine.psi(&q);
+            psi_q.normalize();
+
+            let mut x_q = q.mul(engine.x);
+            if engine.x_is_negative {
+                x_q.negate();
+            }
+            x_q.normalize();
+
+            assert!(psi_q.is_on_curve());
+            assert!(psi_q.into_xy() == x_q.into_xy());
+
+            let mut cleared = engine.clear_cofactor_g2(&q);
+            cleared.normalize();
+            assert!(cleared.is_on_curve());
+            assert!(cleared.check_correct_subgroup());
+        }
+    }
 }
\ No newline at end of file
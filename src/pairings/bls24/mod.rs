@@ -0,0 +1,795 @@
+use crate::field::SizedPrimeField;
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::{FieldElement, MsbBitIterator, ZeroAndOne};
+use crate::weierstrass::Group;
+use crate::weierstrass::{CurveParameters};
+use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
+use crate::extension_towers::fp2::Extension2;
+use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
+use crate::extension_towers::fp12_as_3_over_4::Extension3Over4;
+use crate::extension_towers::fp24_as_2_over3_over_4::{Fp24, Extension2Over3Over4};
+use crate::pairings::PairingEngine;
+use crate::pairings::TwistType;
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
+
+type EllCoeffs<'a, FE, F> = (Fp4<'a, FE, F>, Fp4<'a, FE, F>, Fp4<'a, FE, F>);
+
+pub(crate) struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) ell_coeffs: Vec<EllCoeffs<'a, FE, F>>
+}
+
+#[derive(Clone)]
+pub struct Bls24InstanceParams<
+'a, 
+    FE: ElementRepr, 
+    F: SizedPrimeField<Repr = FE>, 
+    CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+    CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+> {
+    pub x: &'a [u64],
+    pub x_is_negative: bool,
+    pub twist_type: TwistType,
+    pub base_field: &'a F,
+    pub curve: &'a WeierstrassCurve<'a, CB>,
+    pub curve_twist: &'a WeierstrassCurve<'a, CTW>,
+    pub fp2_extension: &'a Extension2<'a, FE, F>,
+    pub fp4_extension: &'a Extension2Over2<'a, FE, F>,
+    pub fp12_extension: &'a Extension3Over4<'a, FE, F>,
+    pub fp24_extension: &'a Extension2Over3Over4<'a, FE, F>,
+    pub force_no_naf: bool
+}
+
+#[derive(Clone)]
+pub struct Bls24Instance<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    > {
+    pub x: &'a [u64],
+    pub x_is_negative: bool,
+    pub twist_type: TwistType,
+    pub base_field: &'a F,
+    pub curve: &'a WeierstrassCurve<'a, CB>,
+    pub curve_twist: &'a WeierstrassCurve<'a, CTW>,
+    pub fp2_extension: &'a Extension2<'a, FE, F>,
+    pub fp4_extension: &'a Extension2Over2<'a, FE, F>,
+    pub fp12_extension: &'a Extension3Over4<'a, FE, F>,
+    pub fp24_extension: &'a Extension2Over3Over4<'a, FE, F>,
+    pub prefer_naf: bool,
+    pub x_naf: Vec<i8>
+}
+
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    > Bls24Instance<'a, FE, F, CB, CTW> 
+{
+    pub fn from_params(params: Bls24InstanceParams::<'a, FE, F, CB, CTW>) -> Self {
+        let (prefer_naf, naf) = if params.force_no_naf {
+            (false, vec![])
+        } else {
+            let naf_vec = into_ternary_wnaf(params.x);
+            let original_bits = calculate_bits(params.x);
+            let original_hamming = calculate_hamming_weight(params.x);
+            let naf_hamming = calculate_naf_hamming_weight(&naf_vec);
+            let naf_length = naf_vec.len() as u32;
+
+            let naf_is_beneficial = naf_length + naf_hamming < original_bits + original_hamming;
+
+            if naf_is_beneficial {
+                (true, naf_vec)
+            } else {
+                (false, vec![])
+            }
+        };
+
+        Self {
+            x: params.x,
+            x_is_negative: params.x_is_negative,
+            twist_type: params.twist_type,
+            base_field: params.base_field,
+            curve: params.curve,
+            curve_twist: params.curve_twist,
+            fp2_extension: params.fp2_extension,
+            fp4_extension: params.fp4_extension,
+            fp12_extension: params.fp12_extension,
+            fp24_extension: params.fp24_extension,
+            prefer_naf,
+            x_naf: naf
+        }
+    }
+}
+
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    > Bls24Instance<'a, FE, F, CB, CTW> {
+    fn ell(
+        &self,
+        f: &mut Fp24<'a, FE, F>,
+        coeffs: &EllCoeffs<'a, FE, F>,
+        p: & CurvePoint<'a, CB>,
+    ) {
+        debug_assert!(p.is_normalized());
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match self.twist_type {
+            TwistType::M => {
+                c2.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_014(&c0, &c1, &c2);
+            },
+            TwistType::D => {
+                c0.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_034(&c0, &c1, &c2);
+            },
+        }
+    }
+
+    fn exp_by_x(&self, f: &mut Fp24<'a, FE, F>) {
+        *f = f.cyclotomic_exp(self.x);
+        if self.x_is_negative {
+            f.conjugate();
+        }
+    }
+
+    fn doubling_step(
+        &self,
+        r: &mut CurvePoint<'a, CTW>,
+        two_inv: &Fp<'a, FE, F>,
+    ) -> EllCoeffs<'a, FE, F> {
+        // Use adapted formulas from ZEXE instead
+        let mut a = r.x;
+        a.mul_assign(&r.y);
+        a.mul_by_fp(two_inv);
+        let mut b = r.y;
+        b.square();
+        let mut c = r.z;
+        c.square();
+
+        let mut e = self.curve_twist.b;
+        let mut t0 = c;
+        t0.double();
+        t0.add_assign(&c);
+
+        e.mul_assign(&t0);
+
+        let mut f = e;
+        f.double();
+        f.add_assign(&e);
+
+        let mut g = b;
+        g.add_assign(&f);
+        g.mul_by_fp(two_inv);
+
+        let mut h = r.y;
+        h.add_assign(&r.z);
+        h.square();
+
+        let mut t1 = b;
+        t1.add_assign(&c);
+
+        h.sub_assign(&t1);
+
+        let mut i = e;
+        i.sub_assign(&b);
+
+        let mut j = r.x;
+        j.square();
+
+        let mut e_square = e;
+        e_square.square();
+
+        r.x = b;
+        r.x.sub_assign(&f);
+        r.x.mul_assign(&a);
+
+        let mut e_square_by_3 = e_square;
+        e_square_by_3.double();
+        e_square_by_3.add_assign(&e_square);
+
+        r.y = g;
+        r.y.square();
+        r.y.sub_assign(&e_square_by_3);
+
+        r.z = b;
+        r.z.mul_assign(&h);
+
+        let mut j_by_three = j;
+        j_by_three.double();
+        j_by_three.add_assign(&j);
+        h.negate();
+
+        match self.twist_type {
+            TwistType::M => {
+                (i, j_by_three, h)
+            },
+            TwistType::D => {
+                (h, j_by_three, i)
+            },
+        }
+    }
+
+    fn addition_step(
+        &self,
+        r: &mut CurvePoint<'a, CTW>,
+        q: & CurvePoint<'a, CTW>,
+    ) -> EllCoeffs<'a, FE, F> {
+        debug_assert!(q.is_normalized());
+        // use adapted zexe formulas too instead of ones from pairing crate
+        let mut theta = q.y;
+        theta.mul_assign(&r.z);
+        theta.negate();
+        theta.add_assign(&r.y);
+
+        let mut lambda = q.x;
+        lambda.mul_assign(&r.z);
+        lambda.negate();
+        lambda.add_assign(&r.x);
+
+        let mut c = theta;
+        c.square();
+        let mut d = lambda;
+        d.square();
+        let mut e = lambda;
+        e.mul_assign(&d);
+        let mut f = r.z;
+        f.mul_assign(&c);
+        let mut g = r.x;
+        g.mul_assign(&d);
+
+        let mut h = g;
+        h.double();
+        h.negate();
+        h.add_assign(&e);
+        h.add_assign(&f);
+        
+
+        r.x = lambda;
+        r.x.mul_assign(&h);
+
+        let mut t0 = g;
+        t0.sub_assign(&h);
+        t0.mul_assign(&theta);
+
+        r.y.mul_assign(&e);
+        r.y.negate();
+        r.y.add_assign(&t0);
+
+        r.z.mul_assign(&e);
+
+        let mut t1 = lambda;
+        t1.mul_assign(&q.y);
+        
+        let mut j = theta;
+        j.mul_assign(&q.x);
+        j.sub_assign(&t1);
+
+        theta.negate();
+        match self.twist_type {
+            TwistType::M => (j, theta, lambda),
+            TwistType::D => (lambda, theta, j),
+        }
+    }
+
+    fn prepare(&self, twist_point: & CurvePoint<'a, CTW>, two_inv: &Fp<'a, FE, F>) -> PreparedTwistPoint<'a, FE, F> {
+        debug_assert!(twist_point.is_normalized());
+
+        if twist_point.is_zero() {
+            return PreparedTwistPoint {
+                ell_coeffs: vec![],
+            };
+        }
+
+        let mut ell_coeffs = Vec::with_capacity(self.x.len() * 64 * 2);
+        let mut r = CurvePoint::<CTW>::point_from_xy(self.curve_twist, twist_point.x, twist_point.y);
+
+        for i in MsbBitIterator::new(&self.x).skip(1) {
+            ell_coeffs.push(self.doubling_step(&mut r, two_inv));
+
+            if i {
+                ell_coeffs.push(self.addition_step(&mut r, twist_point));
+            }
+        }
+
+        PreparedTwistPoint {
+            ell_coeffs,
+        }
+    }
+
+    fn prepare_naf(&self, twist_point: & CurvePoint<'a, CTW>, two_inv: &Fp<'a, FE, F>) -> PreparedTwistPoint<'a, FE, F> {
+        debug_assert!(twist_point.is_normalized());
+
+        if twist_point.is_zero() {
+            return PreparedTwistPoint {
+                ell_coeffs: vec![],
+            };
+        }
+
+        let mut ell_coeffs = Vec::with_capacity(self.x.len() * 64 * 2);
+
+        let mut twist_point_negated = twist_point.clone();
+        twist_point_negated.negate();
+
+        let mut r = CurvePoint::<CTW>::point_from_xy(self.curve_twist, twist_point.x, twist_point.y);
+
+        let mut it = self.x_naf.iter().rev();
+        
+        {
+            let first = it.next().expect("naf has enough coefficients");
+            assert_eq!(*first, 1);
+        }
+
+        for &i in it {
+            ell_coeffs.push(self.doubling_step(&mut r, two_inv));
+            
+            if i != 0 {
+                if i > 0 {
+                    ell_coeffs.push(self.addition_step(&mut r, twist_point));
+                } else {
+                    ell_coeffs.push(self.addition_step(&mut r, &twist_point_negated));
+                }
+            }
+        }
+
+        PreparedTwistPoint {
+            ell_coeffs,
+        }
+    }
+
+    fn miller_loop_naf<'b, I>(&self, i: I) -> Fp24<'a, FE, F>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b CurvePoint<'a, CTW>)
+        >
+    {
+        let mut g1_references = vec![];
+        let mut prepared_coeffs = vec![];
+
+        let mut two_inv = Fp::one(self.base_field);
+        two_inv.double();
+        let two_inv = two_inv.inverse().expect("inverse of 2 is guaranteed to exist");
+
+        for (p, q) in i.into_iter() {
+            if !p.is_zero() && !q.is_zero() {
+                let coeffs = self.prepare_naf(q, &two_inv);
+                let ell_coeffs = coeffs.ell_coeffs;
+                prepared_coeffs.push(ell_coeffs);
+                g1_references.push(p);
+            }
+        }
+
+        let mut prepared_coeffs: Vec<_> = prepared_coeffs.into_iter().map(|el| el.into_iter()).collect();
+
+        let mut f = Fp24::one(self.fp24_extension);
+
+        let mut it = self.x_naf.iter().rev();
+        
+        {
+            let first = it.next().expect("naf has enough coefficients");
+            assert_eq!(*first, 1);
+        }
+
+        for &i in it {
+            f.square();
+
+            for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                self.ell(&mut f, &coeffs.next().expect("next miller loop element for doubling step"), p);
+            }
+
+            if i != 0 {
+                for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                    self.ell(&mut f, &coeffs.next().expect("next miller loop element for addition step"), p);
+                }
+            }
+        }
+
+        if self.x_is_negative {
+            f.conjugate();
+        }
+
+        f
+    }
+
+    fn miller_loop<'b, I>(&self, i: I) -> Fp24<'a, FE, F>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b CurvePoint<'a, CTW>)
+        >
+    {
+        let mut g1_references = vec![];
+        let mut prepared_coeffs = vec![];
+
+        let mut two_inv = Fp::one(self.base_field);
+        two_inv.double();
+        let two_inv = two_inv.inverse().expect("inverse of 2 is guaranteed to exist");
+
+        for (p, q) in i.into_iter() {
+            if !p.is_zero() && !q.is_zero() {
+                let coeffs = self.prepare(q, &two_inv);
+                let ell_coeffs = coeffs.ell_coeffs;
+                prepared_coeffs.push(ell_coeffs);
+                g1_references.push(p);
+            }
+        }
+
+        let mut prepared_coeffs: Vec<_> = prepared_coeffs.into_iter().map(|el| el.into_iter()).collect();
+
+        let mut f = Fp24::one(self.fp24_extension);
+
+        for i in MsbBitIterator::new(&self.x).skip(1) {
+            f.square();
+
+            for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                self.ell(&mut f, &coeffs.next().expect("next miller loop element for doubling step"), p);
+            }
+
+            if i {
+                for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                    self.ell(&mut f, &coeffs.next().expect("next miller loop element for addition step"), p);
+                }
+            }
+        }
+
+        if self.x_is_negative {
+            f.conjugate();
+        }
+
+        f
+    }
+
+    fn final_exponentiation(&self, f: &Fp24<'a, FE, F>) -> Option<Fp24<'a, FE, F>> {
+        // Easy part is f^((p^12 - 1)(p^4 + 1)), then the hard part
+        // is computed as a 3rd power of (p^8 - p^4 + 1)/r that allows to decompose
+        // 3 * (p^8 - p^4 + 1)/r = \sum_{i=0}^{7} lambda_i * p^i with
+        // lambda_7 = (x - 1)^2
+        // lambda_6 = x * lambda_7
+        // lambda_5 = x * lambda_6
+        // lambda_4 = x * lambda_5
+        // lambda_3 = x * lambda_4 - lambda_7
+        // lambda_2 = x * lambda_3
+        // lambda_1 = x * lambda_2
+        // lambda_0 = x * lambda_1 + 3
+
+        // f1 = f^(p^12)
+        let mut f1 = *f;
+        f1.frobenius_map(12);
+
+        match f.inverse() {
+            Some(mut f2) => {
+                // r = f^(p^12 - 1)
+                let mut r = f1;
+                r.mul_assign(&f2);
+
+                // f2 = f^(p^12 - 1)
+                f2 = r;
+                // r = f^((p^12 - 1)(p^4))
+                r.frobenius_map(4);
+
+                // r = f^((p^12 - 1)(p^4 + 1))
+                r.mul_assign(&f2);
+
+                // now r is in cyclotomic subgroup, so inversion is just a conjugation
+
+                let mut r_inv = r;
+                r_inv.conjugate();
+
+                // t = r^(x - 1)
+                let mut t = r;
+                self.exp_by_x(&mut t);
+                t.mul_assign(&r_inv);
+
+                let mut t_inv = t;
+                t_inv.conjugate();
+
+                // r^lambda_7 = t^(x - 1)
+                let mut a7 = t;
+                self.exp_by_x(&mut a7);
+                a7.mul_assign(&t_inv);
+
+                let mut a6 = a7;
+                self.exp_by_x(&mut a6);
+
+                let mut a5 = a6;
+                self.exp_by_x(&mut a5);
+
+                let mut a4 = a5;
+                self.exp_by_x(&mut a4);
+
+                let mut a7_inv = a7;
+                a7_inv.conjugate();
+
+                let mut a3 = a4;
+                self.exp_by_x(&mut a3);
+                a3.mul_assign(&a7_inv);
+
+                let mut a2 = a3;
+                self.exp_by_x(&mut a2);
+
+                let mut a1 = a2;
+                self.exp_by_x(&mut a1);
+
+                // r^3
+                let mut r_cubed = r;
+                r_cubed.cyclotomic_square();
+                r_cubed.mul_assign(&r);
+
+                let mut a0 = a1;
+                self.exp_by_x(&mut a0);
+                a0.mul_assign(&r_cubed);
+
+                let mut result = a0;
+                for (power, mut a) in [a1, a2, a3, a4, a5, a6, a7].iter().cloned().enumerate() {
+                    a.frobenius_map(power + 1);
+                    result.mul_assign(&a);
+                }
+
+                Some(result)
+            },
+            None => None,
+        }
+    }
+}
+
+
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    > PairingEngine for Bls24Instance<'a, FE, F, CB, CTW> {
+    type PairingResult = Fp24<'a, FE, F>;
+    type G1 = CurvePoint<'a, CB>;
+    type G2 = CurvePoint<'a, CTW>;
+
+    fn pair<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
+            if points.len() != twists.len() {
+                return None;
+            }
+
+            if !crate::features::in_gas_metering() {
+                if points.is_empty() || twists.is_empty() {
+                    return None;
+                }
+            }
+            
+            let mut pairs = Vec::with_capacity(points.len());
+            for (p, q) in points.iter().zip(twists.iter()) {
+                if !p.is_zero() && !q.is_zero() {
+                    pairs.push((p, q));
+                }
+            }
+
+            if pairs.is_empty() {
+                return Some(Fp24::one(self.fp24_extension));
+            }
+
+            let loop_result = if self.prefer_naf {
+                let loop_result = self.miller_loop_naf(&pairs[..]);
+                debug_assert!(!self.x_naf.is_empty());
+
+                loop_result
+            } else {
+                let loop_result = self.miller_loop(&pairs[..]);
+
+                loop_result
+            };
+
+            self.final_exponentiation(&loop_result)
+        }   
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use crate::field::{U320Repr, new_field, PrimeField};
+    use crate::fp::Fp;
+    use crate::traits::{FieldElement, ZeroAndOne};
+    use crate::extension_towers::fp2::{Fp2, Extension2};
+    use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
+    use crate::extension_towers::fp12_as_3_over_4::Extension3Over4;
+    use crate::extension_towers::fp24_as_2_over3_over_4::{Fp24, Extension2Over3Over4};
+    use num_traits::Num;
+    use crate::weierstrass::Group;
+    use crate::weierstrass::curve::{CurvePoint, WeierstrassCurve};
+    use crate::weierstrass::{CurveOverFpParameters, CurveOverFp4Parameters};
+    use crate::pairings::{PairingEngine};
+    use crate::test::{biguint_to_u64_vec};
+    use crate::integers::MaxFieldUint;
+
+    const MODULUS: &str = "39705142709513438335025689890408969744933502416914749335064285505637884093126342347073617133569";
+    const GROUP_ORDER: &str = "11502027791375260645628074404575422495959608200132055716665986169834464870401";
+
+    fn fp_from_hex<'a>(field: &'a PrimeField<U320Repr>, value: &str) -> Fp<'a, U320Repr, PrimeField<U320Repr>> {
+        let bytes = BigUint::from_str_radix(value, 16).unwrap().to_bytes_be();
+
+        Fp::from_be_bytes(field, &bytes, true).unwrap()
+    }
+
+    fn fp4_from_hex<'a>(
+        extension_4: &'a Extension2Over2<'a, U320Repr, PrimeField<U320Repr>>, 
+        values: [&str; 4]
+    ) -> Fp4<'a, U320Repr, PrimeField<U320Repr>> {
+        let field = extension_4.field.field;
+        let mut result = Fp4::zero(extension_4);
+        result.c0.c0 = fp_from_hex(field, values[0]);
+        result.c0.c1 = fp_from_hex(field, values[1]);
+        result.c1.c0 = fp_from_hex(field, values[2]);
+        result.c1.c1 = fp_from_hex(field, values[3]);
+
+        result
+    }
+
+    fn test_element<'a>(
+        extension_24: &'a Extension2Over3Over4<'a, U320Repr, PrimeField<U320Repr>>,
+        seed: &Fp4<'a, U320Repr, PrimeField<U320Repr>>
+    ) -> Fp24<'a, U320Repr, PrimeField<U320Repr>> {
+        let mut element = Fp24::zero(extension_24);
+        let mut current = *seed;
+        for c in [&mut element.c0.c0, &mut element.c0.c1, &mut element.c0.c2, &mut element.c1.c0, &mut element.c1.c1, &mut element.c1.c2].iter_mut() {
+            **c = current;
+            current.square();
+            current.add_assign(seed);
+        }
+
+        element
+    }
+
+    #[test]
+    fn test_bls24_315_pairing() {
+        let modulus = BigUint::from_str_radix(MODULUS, 10).unwrap();
+        let base_field = new_field::<U320Repr>(MODULUS, 10).unwrap();
+        let group_order = BigUint::from_str_radix(GROUP_ORDER, 10).unwrap();
+        let group_order = biguint_to_u64_vec(group_order);
+        let modulus_limbs = biguint_to_u64_vec(modulus.clone());
+        let modulus = MaxFieldUint::from_big_endian(&modulus.to_bytes_be());
+
+        let fp_non_residue = Fp::from_repr(&base_field, U320Repr::from(13)).unwrap();
+
+        let mut extension_2 = Extension2::new(fp_non_residue);
+        extension_2.calculate_frobenius_coeffs(&modulus).expect("must work");
+
+        // it's just 0 + u
+        let mut fp2_non_residue = Fp2::zero(&extension_2);
+        fp2_non_residue.c1 = Fp::one(&base_field);
+
+        let mut extension_4 = Extension2Over2::new(fp2_non_residue);
+        extension_4.calculate_frobenius_coeffs_optimized(&modulus).expect("must work");
+
+        let mut extension_12 = Extension3Over4::new(&extension_4);
+        extension_12.calculate_frobenius_coeffs(&modulus).expect("must work");
+
+        let mut extension_24 = Extension2Over3Over4::new(&extension_12);
+        extension_24.calculate_frobenius_coeffs(&modulus).expect("must work");
+
+        // D twist, so b' = b / v
+        let b_fp = Fp::one(&base_field);
+        let mut v = Fp4::zero(&extension_4);
+        v.c1 = Fp2::one(&extension_2);
+        let mut b_fp4 = v.inverse().unwrap();
+        b_fp4.mul_by_fp(&b_fp);
+
+        let a_fp = Fp::zero(&base_field);
+        let a_fp4 = Fp4::zero(&extension_4);
+
+        let fp_params = CurveOverFpParameters::new(&base_field);
+        let fp4_params = CurveOverFp4Parameters::new(&extension_4);
+
+        let curve = WeierstrassCurve::new(group_order.as_ref(), a_fp, b_fp, &fp_params).unwrap();
+        let twist = WeierstrassCurve::new(group_order.as_ref(), a_fp4, b_fp4, &fp4_params).unwrap();
+
+        let p_x = fp_from_hex(&base_field, "22d95bf37e1375823d8ca854048131bccaeacac9c58aa77d3b447f3ce77a01c64199491c0a9fb46");
+        let p_y = fp_from_hex(&base_field, "7b61cdad90327e8e8eb84d4783628e5ee44b957c5561428ccd086a9aa0a4f995ac59f0bf1ff07f");
+
+        let q_x = fp4_from_hex(&extension_4, [
+            "1decf6bee3c0774257b7fababca9daaf41fa06e7f7ea26c0646f3cf9c7fa6f425ee3b560d0654e0",
+            "109429877f87d2ffa8b365333bf3f70b586d53b5ad198b911ac401ab66f18c464fbd670db4d4ae3",
+            "1f6de645a2fc183814f0b9eef01b83dfe9ca5d96a79c7e9785c610d28cf0d2453bed5bf0d7358d3",
+            "390b32c8aafbfa49fbaef0f861ad5cf95b6dad31c0d94bb39401829aaf3b6ed73e5d1bc5eb8cbb0"
+        ]);
+        let q_y = fp4_from_hex(&extension_4, [
+            "1eaa7903774e574519e95fc0a99d2b7cc2e65bd4da83ba636d2bd2dc31a1331199d8c7353664803",
+            "14cbeff1538a8c1cb97f6ac11f170ce5fe2426afbec79a58cbf4249b732d2c3afee3ca81a429062",
+            "47899075de8541cdc745394ae9089ab2ceb7ef42ee5ff10fd9bc5f8b373b5b261ff17e6fa67b001",
+            "cf3fc8784598dcfaf41fa35efd39bbad559f9d6fac248b9343d3142e8337603bc11b12795fd555"
+        ]);
+
+        let p = CurvePoint::point_from_xy(&curve, p_x, p_y);
+        let q = CurvePoint::point_from_xy(&twist, q_x, q_y);
+
+        assert!(p.is_on_curve());
+        assert!(q.is_on_curve());
+        assert!(p.check_correct_subgroup());
+        assert!(q.check_correct_subgroup());
+
+        // Frobenius coefficients are consistent with exponentiation by modulus
+        let element = test_element(&extension_24, &q_x);
+        let mut frobenius = element;
+        frobenius.frobenius_map(1);
+        assert_eq!(frobenius, element.pow(&modulus_limbs));
+
+        let mut expected = element;
+        for power in 1..24 {
+            expected.frobenius_map(1);
+            let mut frobenius = element;
+            frobenius.frobenius_map(power);
+            assert_eq!(frobenius, expected);
+        }
+
+        let bls24_engine = super::Bls24InstanceParams {
+            x: &[0xbfcfffff],
+            x_is_negative: true,
+            twist_type: super::TwistType::D,
+            base_field: &base_field,
+            curve: &curve,
+            curve_twist: &twist,
+            fp2_extension: &extension_2,
+            fp4_extension: &extension_4,
+            fp12_extension: &extension_12,
+            fp24_extension: &extension_24,
+            force_no_naf: true
+        };
+
+        let bls24_engine = super::Bls24Instance::from_params(bls24_engine);
+
+        let pairing_result = bls24_engine.pair(std::slice::from_ref(&p), std::slice::from_ref(&q)).unwrap();
+        let one = Fp24::one(&extension_24);
+        assert!(pairing_result != one);
+        assert_eq!(pairing_result.pow(&group_order), one);
+
+        let a = 12345u64;
+        let b = 54321u64;
+
+        let mut p_a = p.mul([a]);
+        p_a.normalize();
+        let mut q_b = q.mul([b]);
+        q_b.normalize();
+        let mut p_ab = p.mul([a * b]);
+        p_ab.normalize();
+
+        let ans1 = bls24_engine.pair(&[p_a], &[q_b]).unwrap();
+        let ans2 = bls24_engine.pair(&[p_ab], std::slice::from_ref(&q)).unwrap();
+        let ans3 = pairing_result.pow([a * b]);
+
+        assert_eq!(ans1, ans2);
+        assert_eq!(ans1, ans3);
+
+        // NAF based Miller loop gives the same result
+        let bls24_naf_engine = super::Bls24InstanceParams {
+            x: &[0xbfcfffff],
+            x_is_negative: true,
+            twist_type: super::TwistType::D,
+            base_field: &base_field,
+            curve: &curve,
+            curve_twist: &twist,
+            fp2_extension: &extension_2,
+            fp4_extension: &extension_4,
+            fp12_extension: &extension_12,
+            fp24_extension: &extension_24,
+            force_no_naf: false
+        };
+
+        let bls24_naf_engine = super::Bls24Instance::from_params(bls24_naf_engine);
+        assert!(bls24_naf_engine.prefer_naf);
+
+        let naf_result = bls24_naf_engine.pair(&[p], &[q]).unwrap();
+        assert_eq!(naf_result, pairing_result);
+    }
+}
@@ -10,7 +10,8 @@ use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2
 use crate::extension_towers::fp6_as_3_over_2::{Extension3Over2};
 use crate::pairings::{PairingEngine};
 use crate::pairings::TwistType;
-use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf, prefer_compressed_cyclotomic_exp};
+use crate::engines::const_arithmetic::is_bn_modulus;
 
 pub(crate) struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
     pub(crate) ell_coeffs: Vec<(Fp2<'a, FE, F>, Fp2<'a, FE, F>, Fp2<'a, FE, F>)>
@@ -58,7 +59,11 @@ pub struct BnInstance<
     pub fp12_extension: &'a Extension2Over3Over2<'a, FE, F>,
     pub non_residue_in_p_minus_one_over_2: Fp2<'a, FE, F>,
     pub prefer_naf: bool,
-    pub six_u_plus_2_naf: Vec<i8>
+    pub six_u_plus_2_naf: Vec<i8>,
+    /// Use the shorter Fuentes-Castañeda–Knapp–Rodríguez-Henríquez chain for the hard part
+    /// of the final exponentiation. It gives a fixed power of the pairing coprime to the group
+    /// order, so it's off by default and only enabled by `for_pairing_checks`
+    pub prefer_fuentes_final_exp: bool
 }
 
 impl<
@@ -105,9 +110,19 @@ impl<
             fp12_extension: params.fp12_extension,
             non_residue_in_p_minus_one_over_2: params.non_residue_in_p_minus_one_over_2,
             prefer_naf: prefer_naf,
-            six_u_plus_2_naf: naf
+            six_u_plus_2_naf: naf,
+            prefer_fuentes_final_exp: false
         }
     }
+
+    /// Engine for checks that a product of pairings is one. Such checks are not affected by
+    /// raising the result to a power coprime to the group order, so the Fuentes-Castañeda
+    /// final exponentiation is used if the modulus is the BN polynomial in `u`
+    pub fn for_pairing_checks(mut self) -> Self {
+        self.prefer_fuentes_final_exp = is_bn_modulus(self.base_field.modulus().as_ref(), self.u, self.u_is_negative);
+
+        self
+    }
 }
 
 impl<
@@ -143,7 +158,11 @@ impl<
     }
 
     fn exp_by_x(&self, f: &mut Fp12<'a, FE, F>) {
-        *f = f.cyclotomic_exp(&self.u);
+        *f = if prefer_compressed_cyclotomic_exp(self.u) {
+            f.cyclotomic_exp_compressed(self.u)
+        } else {
+            f.cyclotomic_exp(self.u)
+        };
         if self.u_is_negative {
             f.conjugate();
         }
@@ -570,10 +589,18 @@ impl<
         f
     }
 
-    fn final_exponentiation(&self, f: &Fp12<'a, FE, F>) -> Option<Fp12<'a, FE, F>> {
-        // use Zexe and pairing crate fused
-        // https://eprint.iacr.org/2012/232.pdf
+    fn multi_miller_loop(&self, pairs: &[(&CurvePoint<'a, CB>, &CurvePoint<'a, CTW>)]) -> Fp12<'a, FE, F> {
+        if self.prefer_naf {
+            let loop_result = self.miller_loop_naf(pairs);
+            debug_assert!(!self.six_u_plus_2_naf.is_empty());
+
+            loop_result
+        } else {
+            self.miller_loop(pairs)
+        }
+    }
 
+    fn final_exponentiation(&self, f: &Fp12<'a, FE, F>) -> Option<Fp12<'a, FE, F>> {
         // f1 = r.conjugate() = f^(p^6)
         let mut f1 = f.clone();
         // f1.conjugate();
@@ -589,81 +616,149 @@ impl<
                 r.frobenius_map(2);
                 r.mul_assign(&f2);
 
-                let mut fp = r.clone();
-                fp.frobenius_map(1);
+                if self.prefer_fuentes_final_exp {
+                    Some(self.final_exponentiation_hard_part_fuentes(&r))
+                } else {
+                    Some(self.final_exponentiation_hard_part(&r))
+                }
+            }
+            None => None,
+        }
+    }
 
-	            let mut fp2 = r.clone();
-                fp2.frobenius_map(2);
-                let mut fp3 = fp2.clone();
-                fp3.frobenius_map(1);
+    fn final_exponentiation_hard_part(&self, r: &Fp12<'a, FE, F>) -> Fp12<'a, FE, F> {
+        // use Zexe and pairing crate fused
+        // https://eprint.iacr.org/2012/232.pdf
+        let mut fp = r.clone();
+        fp.frobenius_map(1);
 
-                let mut fu = r.clone();
-                self.exp_by_x(&mut fu);
+        let mut fp2 = r.clone();
+        fp2.frobenius_map(2);
+        let mut fp3 = fp2.clone();
+        fp3.frobenius_map(1);
 
-                let mut fu2 = fu.clone();
-                self.exp_by_x(&mut fu2);
+        let mut fu = r.clone();
+        self.exp_by_x(&mut fu);
 
-                let mut fu3 = fu2.clone();
-                self.exp_by_x(&mut fu3);
+        let mut fu2 = fu.clone();
+        self.exp_by_x(&mut fu2);
 
-                let mut y3 = fu.clone();
-                y3.frobenius_map(1);
+        let mut fu3 = fu2.clone();
+        self.exp_by_x(&mut fu3);
 
-                let mut fu2p = fu2.clone();
-                fu2p.frobenius_map(1);
+        let mut y3 = fu.clone();
+        y3.frobenius_map(1);
 
-                let mut fu3p = fu3.clone();
-                fu3p.frobenius_map(1);
+        let mut fu2p = fu2.clone();
+        fu2p.frobenius_map(1);
 
-                let mut y2 = fu2.clone();
-                y2.frobenius_map(2);
+        let mut fu3p = fu3.clone();
+        fu3p.frobenius_map(1);
 
-                let mut y0 = fp.clone();
-                y0.mul_assign(&fp2);
-                y0.mul_assign(&fp3);
+        let mut y2 = fu2.clone();
+        y2.frobenius_map(2);
 
-                let mut y1 = r.clone();
-                y1.conjugate();
+        let mut y0 = fp.clone();
+        y0.mul_assign(&fp2);
+        y0.mul_assign(&fp3);
 
-                let mut y5 = fu2.clone();
-                y5.conjugate();
+        let mut y1 = r.clone();
+        y1.conjugate();
 
-                y3.conjugate();
+        let mut y5 = fu2.clone();
+        y5.conjugate();
 
-                let mut y4 = fu.clone();
-                y4.mul_assign(&fu2p);
-                y4.conjugate();
+        y3.conjugate();
 
-                let mut y6 = fu3.clone();
-                y6.mul_assign(&fu3p);
-                y6.conjugate();
+        let mut y4 = fu.clone();
+        y4.mul_assign(&fu2p);
+        y4.conjugate();
 
-                y6.square();
-                y6.mul_assign(&y4);
-                y6.mul_assign(&y5);
+        let mut y6 = fu3.clone();
+        y6.mul_assign(&fu3p);
+        y6.conjugate();
 
-                let mut t1 = y3.clone();
-                t1.mul_assign(&y5);
-                t1.mul_assign(&y6);
+        y6.square();
+        y6.mul_assign(&y4);
+        y6.mul_assign(&y5);
 
-                y6.mul_assign(&y2);
+        let mut t1 = y3.clone();
+        t1.mul_assign(&y5);
+        t1.mul_assign(&y6);
 
-                t1.square();
-                t1.mul_assign(&y6);
-                t1.square();
+        y6.mul_assign(&y2);
 
-                let mut t0 = t1.clone();
-                t0.mul_assign(&y1);
+        t1.square();
+        t1.mul_assign(&y6);
+        t1.square();
 
-                t1.mul_assign(&y0);
+        let mut t0 = t1.clone();
+        t0.mul_assign(&y1);
 
-                t0.square();
-                t0.mul_assign(&t1);
+        t1.mul_assign(&y0);
 
-                Some(t0)
-            }
-            None => None,
-        }
+        t0.square();
+        t0.mul_assign(&t1);
+
+        t0
+    }
+
+    fn final_exponentiation_hard_part_fuentes(&self, r: &Fp12<'a, FE, F>) -> Fp12<'a, FE, F> {
+        // Fuentes-Castañeda, Knapp and Rodríguez-Henríquez, https://eprint.iacr.org/2011/465.pdf:
+        // raises to m * (p^4 - p^2 + 1) / r = l0 + l1 * p + l2 * p^2 + l3 * p^3 for
+        // m = 2u * (6u^2 + 3u + 1) and
+        // l0 = 12u^3 + 12u^2 + 6u + 1, l1 = 12u^3 + 6u^2 + 4u,
+        // l2 = 12u^3 + 6u^2 + 6u, l3 = 12u^3 + 6u^2 + 4u - 1.
+        // |m| < r, so the result is still a non-degenerate pairing. Same number of
+        // exponentiations by u, but 3 multiplications and 4 Frobenius maps less and
+        // cyclotomic squarings instead of the generic ones
+
+        // t0 = r^(-2u), t1 = r^(-6u)
+        let mut t0 = *r;
+        self.exp_by_x(&mut t0);
+        t0.conjugate();
+        t0.cyclotomic_square();
+        let mut t1 = t0;
+        t1.cyclotomic_square();
+        t1.mul_assign(&t0);
+
+        // t2 = r^(6u^2), t1 = r^(6u^2 + 6u)
+        let mut t2 = t1;
+        self.exp_by_x(&mut t2);
+        t2.conjugate();
+        t1.conjugate();
+        t1.mul_assign(&t2);
+
+        // t4 = r^(12u^3 + 6u^2 + 6u) = r^l2
+        let mut t4 = t2;
+        t4.cyclotomic_square();
+        self.exp_by_x(&mut t4);
+        t4.mul_assign(&t1);
+
+        // t3 = r^(12u^3 + 6u^2 + 4u) = r^l1
+        let mut t3 = t0;
+        t3.mul_assign(&t4);
+
+        // result = r^(12u^3 + 12u^2 + 6u + 1) = r^l0
+        let mut result = t2;
+        result.mul_assign(&t4);
+        result.mul_assign(r);
+
+        let mut t2 = t3;
+        t2.frobenius_map(1);
+        result.mul_assign(&t2);
+
+        t4.frobenius_map(2);
+        result.mul_assign(&t4);
+
+        // r^l3 = r^(l1 - 1)
+        let mut t2 = *r;
+        t2.conjugate();
+        t2.mul_assign(&t3);
+        t2.frobenius_map(3);
+        result.mul_assign(&t2);
+
+        result
     }
 }
 
@@ -702,19 +797,37 @@ impl<
                 return Some(Fp12::one(self.fp12_extension));
             }
 
-            let loop_result = if self.prefer_naf {
-                let loop_result = self.miller_loop_naf(&pairs[..]);
-                debug_assert!(self.six_u_plus_2_naf.len() > 0);
-
-                loop_result
-            } else {
-                let loop_result = self.miller_loop(&pairs[..]);
-
-                loop_result
-            };
+            let loop_result = self.multi_miller_loop(&pairs[..]);
 
             self.final_exponentiation(&loop_result)
         }   
+
+    #[cfg(feature = "parallel_pairings")]
+    fn pair_parallel<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>], num_threads: usize) -> Option<Self::PairingResult> {
+            crate::pairings::pair_in_parallel(self, points, twists, num_threads)
+        }
+}
+
+#[cfg(feature = "parallel_pairings")]
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    > crate::pairings::MillerLoopEngine for BnInstance<'a, FE, F, CB, CTW> {
+    fn miller_loop_on_pairs(&self, pairs: &[(&CurvePoint<'a, CB>, &CurvePoint<'a, CTW>)]) -> Option<Self::PairingResult> {
+        Some(self.multi_miller_loop(pairs))
+    }
+
+    fn final_exponentiation_on_product(&self, f: &Self::PairingResult) -> Option<Self::PairingResult> {
+        self.final_exponentiation(f)
+    }
+
+    fn pairing_result_one(&self) -> Self::PairingResult {
+        Fp12::one(self.fp12_extension)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,606 @@
+use crate::field::SizedPrimeField;
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::{FieldElement, MsbBitIterator, ZeroAndOne};
+use crate::weierstrass::Group;
+use crate::weierstrass::{CurveParameters};
+use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
+use crate::extension_towers::fp3::{Extension3};
+use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
+use crate::pairings::PairingEngine;
+use crate::pairings::TwistType;
+use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
+
+type EllCoeffs<'a, FE, F> = (Fp<'a, FE, F>, Fp<'a, FE, F>, Fp<'a, FE, F>);
+
+pub(crate) struct PreparedTwistPoint<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
+    pub(crate) ell_coeffs: Vec<EllCoeffs<'a, FE, F>>
+}
+
+// BW6 curves use an optimal ate pairing from https://eprint.iacr.org/2020/351.pdf
+// with two Miller loops: f_{u+1, Q}(P) * f_{u^3 - u^2 - u, Q}(P)^p for BW6-761,
+// so both loop counts are parameters. G2 is a sextic twist defined over Fp itself
+
+#[derive(Clone)]
+pub struct Bw6InstanceParams<
+'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+    CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+> {
+    pub ate_loop_count_1: &'a [u64],
+    pub ate_loop_count_1_is_negative: bool,
+    pub ate_loop_count_2: &'a [u64],
+    pub ate_loop_count_2_is_negative: bool,
+    pub exp_w0: &'a [u64],
+    pub exp_w1: &'a [u64],
+    pub exp_w0_is_negative: bool,
+    pub twist_type: TwistType,
+    pub base_field: &'a F,
+    pub curve: &'a WeierstrassCurve<'a, CB>,
+    pub curve_twist: &'a WeierstrassCurve<'a, CTW>,
+    pub fp3_extension: &'a Extension3<'a, FE, F>,
+    pub fp6_extension: &'a Extension2Over3<'a, FE, F>,
+    pub force_no_naf: bool
+}
+
+#[derive(Clone)]
+pub struct Bw6Instance<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > {
+    pub ate_loop_count_1: &'a [u64],
+    pub ate_loop_count_1_is_negative: bool,
+    pub ate_loop_count_2: &'a [u64],
+    pub ate_loop_count_2_is_negative: bool,
+    pub exp_w0: &'a [u64],
+    pub exp_w1: &'a [u64],
+    pub exp_w0_is_negative: bool,
+    pub twist_type: TwistType,
+    pub base_field: &'a F,
+    pub curve: &'a WeierstrassCurve<'a, CB>,
+    pub curve_twist: &'a WeierstrassCurve<'a, CTW>,
+    pub fp3_extension: &'a Extension3<'a, FE, F>,
+    pub fp6_extension: &'a Extension2Over3<'a, FE, F>,
+    pub prefer_naf_1: bool,
+    pub ate_loop_count_1_naf: Vec<i8>,
+    pub prefer_naf_2: bool,
+    pub ate_loop_count_2_naf: Vec<i8>
+}
+
+fn naf_if_beneficial(x: &[u64]) -> (bool, Vec<i8>) {
+    let naf_vec = into_ternary_wnaf(x);
+    let original_bits = calculate_bits(x);
+    let original_hamming = calculate_hamming_weight(x);
+    let naf_hamming = calculate_naf_hamming_weight(&naf_vec);
+    let naf_length = naf_vec.len() as u32;
+
+    if naf_length + naf_hamming < original_bits + original_hamming {
+        (true, naf_vec)
+    } else {
+        (false, vec![])
+    }
+}
+
+impl<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > Bw6Instance<'a, FE, F, CB, CTW>
+{
+    pub fn from_params(params: Bw6InstanceParams::<'a, FE, F, CB, CTW>) -> Self {
+        let ((prefer_naf_1, naf_1), (prefer_naf_2, naf_2)) = if params.force_no_naf {
+            ((false, vec![]), (false, vec![]))
+        } else {
+            (naf_if_beneficial(params.ate_loop_count_1), naf_if_beneficial(params.ate_loop_count_2))
+        };
+
+        Self {
+            ate_loop_count_1: params.ate_loop_count_1,
+            ate_loop_count_1_is_negative: params.ate_loop_count_1_is_negative,
+            ate_loop_count_2: params.ate_loop_count_2,
+            ate_loop_count_2_is_negative: params.ate_loop_count_2_is_negative,
+            exp_w0: params.exp_w0,
+            exp_w1: params.exp_w1,
+            exp_w0_is_negative: params.exp_w0_is_negative,
+            twist_type: params.twist_type,
+            base_field: params.base_field,
+            curve: params.curve,
+            curve_twist: params.curve_twist,
+            fp3_extension: params.fp3_extension,
+            fp6_extension: params.fp6_extension,
+            prefer_naf_1,
+            ate_loop_count_1_naf: naf_1,
+            prefer_naf_2,
+            ate_loop_count_2_naf: naf_2
+        }
+    }
+}
+
+impl<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > Bw6Instance<'a, FE, F, CB, CTW> {
+    fn ell(
+        &self,
+        f: &mut Fp6<'a, FE, F>,
+        coeffs: &EllCoeffs<'a, FE, F>,
+        p: & CurvePoint<'a, CB>,
+    ) {
+        debug_assert!(p.is_normalized());
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match self.twist_type {
+            TwistType::M => {
+                c2.mul_assign(&p.y);
+                c1.mul_assign(&p.x);
+                f.mul_by_014(&c0, &c1, &c2);
+            },
+            TwistType::D => {
+                c0.mul_assign(&p.y);
+                c1.mul_assign(&p.x);
+                f.mul_by_034(&c0, &c1, &c2);
+            },
+        }
+    }
+
+    fn doubling_step(
+        &self,
+        r: &mut CurvePoint<'a, CTW>,
+        two_inv: &Fp<'a, FE, F>,
+    ) -> EllCoeffs<'a, FE, F> {
+        // same adapted ZEXE formulas as for BLS12, but all the coefficients are in Fp
+        let mut a = r.x;
+        a.mul_assign(&r.y);
+        a.mul_assign(two_inv);
+        let mut b = r.y;
+        b.square();
+        let mut c = r.z;
+        c.square();
+
+        let mut e = self.curve_twist.b;
+        let mut t0 = c;
+        t0.double();
+        t0.add_assign(&c);
+
+        e.mul_assign(&t0);
+
+        let mut f = e;
+        f.double();
+        f.add_assign(&e);
+
+        let mut g = b;
+        g.add_assign(&f);
+        g.mul_assign(two_inv);
+
+        let mut h = r.y;
+        h.add_assign(&r.z);
+        h.square();
+
+        let mut t1 = b;
+        t1.add_assign(&c);
+
+        h.sub_assign(&t1);
+
+        let mut i = e;
+        i.sub_assign(&b);
+
+        let mut j = r.x;
+        j.square();
+
+        let mut e_square = e;
+        e_square.square();
+
+        r.x = b;
+        r.x.sub_assign(&f);
+        r.x.mul_assign(&a);
+
+        let mut e_square_by_3 = e_square;
+        e_square_by_3.double();
+        e_square_by_3.add_assign(&e_square);
+
+        r.y = g;
+        r.y.square();
+        r.y.sub_assign(&e_square_by_3);
+
+        r.z = b;
+        r.z.mul_assign(&h);
+
+        let mut j_by_three = j;
+        j_by_three.double();
+        j_by_three.add_assign(&j);
+        h.negate();
+
+        match self.twist_type {
+            TwistType::M => {
+                (i, j_by_three, h)
+            },
+            TwistType::D => {
+                (h, j_by_three, i)
+            },
+        }
+    }
+
+    fn addition_step(
+        &self,
+        r: &mut CurvePoint<'a, CTW>,
+        q: & CurvePoint<'a, CTW>,
+    ) -> EllCoeffs<'a, FE, F> {
+        debug_assert!(q.is_normalized());
+        let mut theta = q.y;
+        theta.mul_assign(&r.z);
+        theta.negate();
+        theta.add_assign(&r.y);
+
+        let mut lambda = q.x;
+        lambda.mul_assign(&r.z);
+        lambda.negate();
+        lambda.add_assign(&r.x);
+
+        let mut c = theta;
+        c.square();
+        let mut d = lambda;
+        d.square();
+        let mut e = lambda;
+        e.mul_assign(&d);
+        let mut f = r.z;
+        f.mul_assign(&c);
+        let mut g = r.x;
+        g.mul_assign(&d);
+
+        let mut h = g;
+        h.double();
+        h.negate();
+        h.add_assign(&e);
+        h.add_assign(&f);
+
+        r.x = lambda;
+        r.x.mul_assign(&h);
+
+        let mut t0 = g;
+        t0.sub_assign(&h);
+        t0.mul_assign(&theta);
+
+        r.y.mul_assign(&e);
+        r.y.negate();
+        r.y.add_assign(&t0);
+
+        r.z.mul_assign(&e);
+
+        let mut t1 = lambda;
+        t1.mul_assign(&q.y);
+
+        let mut j = theta;
+        j.mul_assign(&q.x);
+        j.sub_assign(&t1);
+
+        theta.negate();
+        match self.twist_type {
+            TwistType::M => (j, theta, lambda),
+            TwistType::D => (lambda, theta, j),
+        }
+    }
+
+    // Returns loop count digits from the most significant one (exclusive) down to the least significant one,
+    // either binary or in NAF form
+    fn loop_digits(loop_count: &[u64], prefer_naf: bool, naf: &[i8]) -> Vec<i8> {
+        if prefer_naf {
+            let mut it = naf.iter().rev();
+            {
+                let first = it.next().expect("naf has enough coefficients");
+                assert_eq!(*first, 1);
+            }
+
+            it.cloned().collect()
+        } else {
+            MsbBitIterator::new(loop_count).skip(1).map(|bit| bit as i8).collect()
+        }
+    }
+
+    fn prepare(
+        &self,
+        twist_point: & CurvePoint<'a, CTW>,
+        digits: &[i8],
+        two_inv: &Fp<'a, FE, F>
+    ) -> PreparedTwistPoint<'a, FE, F> {
+        debug_assert!(twist_point.is_normalized());
+
+        if twist_point.is_zero() {
+            return PreparedTwistPoint {
+                ell_coeffs: vec![],
+            };
+        }
+
+        let mut ell_coeffs = Vec::with_capacity(digits.len() * 2);
+
+        let mut twist_point_negated = twist_point.clone();
+        twist_point_negated.negate();
+
+        let mut r = CurvePoint::<CTW>::point_from_xy(self.curve_twist, twist_point.x, twist_point.y);
+
+        for &i in digits.iter() {
+            ell_coeffs.push(self.doubling_step(&mut r, two_inv));
+
+            if i > 0 {
+                ell_coeffs.push(self.addition_step(&mut r, twist_point));
+            } else if i < 0 {
+                ell_coeffs.push(self.addition_step(&mut r, &twist_point_negated));
+            }
+        }
+
+        PreparedTwistPoint {
+            ell_coeffs,
+        }
+    }
+
+    fn miller_loop_for_count(
+        &self,
+        pairs: &[(&CurvePoint<'a, CB>, &CurvePoint<'a, CTW>)],
+        digits: &[i8],
+        is_negative: bool,
+        two_inv: &Fp<'a, FE, F>
+    ) -> Fp6<'a, FE, F> {
+        let mut g1_references = vec![];
+        let mut prepared_coeffs = vec![];
+
+        for (p, q) in pairs.iter() {
+            if !p.is_zero() && !q.is_zero() {
+                let coeffs = self.prepare(q, digits, two_inv);
+                prepared_coeffs.push(coeffs.ell_coeffs.into_iter());
+                g1_references.push(p);
+            }
+        }
+
+        let mut f = Fp6::one(self.fp6_extension);
+
+        for &i in digits.iter() {
+            f.square();
+
+            for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                self.ell(&mut f, &coeffs.next().expect("next miller loop element for doubling step"), p);
+            }
+
+            if i != 0 {
+                for (p, coeffs) in g1_references.iter().zip(prepared_coeffs.iter_mut()) {
+                    self.ell(&mut f, &coeffs.next().expect("next miller loop element for addition step"), p);
+                }
+            }
+        }
+
+        // this is not an inverse, but the difference is killed by the final exponentiation
+        if is_negative {
+            f.conjugate();
+        }
+
+        f
+    }
+
+    fn miller_loop<'b>(&self, pairs: &[(&'b CurvePoint<'a, CB>, &'b CurvePoint<'a, CTW>)]) -> Fp6<'a, FE, F> {
+        let mut two_inv = Fp::one(self.base_field);
+        two_inv.double();
+        let two_inv = two_inv.inverse().expect("inverse of 2 is guaranteed to exist");
+
+        let digits_1 = Self::loop_digits(self.ate_loop_count_1, self.prefer_naf_1, &self.ate_loop_count_1_naf);
+        let digits_2 = Self::loop_digits(self.ate_loop_count_2, self.prefer_naf_2, &self.ate_loop_count_2_naf);
+
+        let mut f = self.miller_loop_for_count(pairs, &digits_1, self.ate_loop_count_1_is_negative, &two_inv);
+        let mut f_2 = self.miller_loop_for_count(pairs, &digits_2, self.ate_loop_count_2_is_negative, &two_inv);
+        f_2.frobenius_map(1);
+
+        f.mul_assign(&f_2);
+
+        f
+    }
+
+    fn final_exponentiation(&self, f: &Fp6<'a, FE, F>) -> Option<Fp6<'a, FE, F>> {
+        let value_inv = f.inverse()?;
+        let value_to_first_chunk = self.final_exponentiation_part_one(f, &value_inv);
+
+        Some(self.final_exponentiation_part_two(&value_to_first_chunk))
+    }
+
+    fn final_exponentiation_part_one(&self, elt: &Fp6<'a, FE, F>, elt_inv: &Fp6<'a, FE, F>) -> Fp6<'a, FE, F> {
+        // (q^3-1)*(q+1)
+
+        // elt_q3 = elt^(q^3)
+        let mut elt_q3 = *elt;
+        elt_q3.frobenius_map(3);
+        // elt_q3_over_elt = elt^(q^3-1)
+        let mut elt_q3_over_elt = elt_q3;
+        elt_q3_over_elt.mul_assign(elt_inv);
+        // alpha = elt^((q^3-1) * q)
+        let mut alpha = elt_q3_over_elt;
+        alpha.frobenius_map(1);
+        // beta = elt^((q^3-1)*(q+1)
+        alpha.mul_assign(&elt_q3_over_elt);
+
+        alpha
+    }
+
+    fn final_exponentiation_part_two(&self, elt: &Fp6<'a, FE, F>) -> Fp6<'a, FE, F> {
+        // (q^2 - q + 1) / r = w1 * q + w0, and after the first part
+        // the element is in the cyclotomic subgroup, so inversion is a conjugation
+        let mut elt_q = *elt;
+        elt_q.frobenius_map(1);
+
+        let mut w1_part = elt_q.cyclotomic_exp(self.exp_w1);
+        let mut w0_part = elt.cyclotomic_exp(self.exp_w0);
+        if self.exp_w0_is_negative {
+            w0_part.conjugate();
+        }
+
+        w1_part.mul_assign(&w0_part);
+
+        w1_part
+    }
+}
+
+impl<
+    'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    > PairingEngine for Bw6Instance<'a, FE, F, CB, CTW> {
+    type PairingResult = Fp6<'a, FE, F>;
+    type G1 = CurvePoint<'a, CB>;
+    type G2 = CurvePoint<'a, CTW>;
+
+    fn pair<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>]) -> Option<Self::PairingResult> {
+            if points.len() != twists.len() {
+                return None;
+            }
+
+            if !crate::features::in_gas_metering() && points.is_empty() {
+                return None;
+            }
+
+            let mut pairs = Vec::with_capacity(points.len());
+            for (p, q) in points.iter().zip(twists.iter()) {
+                if !p.is_zero() && !q.is_zero() {
+                    pairs.push((p, q));
+                }
+            }
+
+            if pairs.is_empty() {
+                return Some(Fp6::one(self.fp6_extension));
+            }
+
+            let loop_result = self.miller_loop(&pairs[..]);
+
+            self.final_exponentiation(&loop_result)
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engines::bw6_761::*;
+    use crate::field::{U768Repr, PrimeField};
+    use crate::fp::Fp;
+    use crate::extension_towers::fp3::Fp3;
+    use crate::extension_towers::fp6_as_2_over_3::Fp6;
+    use crate::traits::FieldElement;
+    use crate::weierstrass::Group;
+    use crate::pairings::PairingEngine;
+
+    fn fp6_from_coeffs(
+        coeffs: [u64; 6]
+    ) -> Fp6<'static, U768Repr, PrimeField<U768Repr>> {
+        let els: Vec<_> = coeffs.iter().map(|&c| Fp::from_repr(&BW6_761_FIELD, U768Repr::from(c)).unwrap()).collect();
+
+        let mut f = Fp6::zero(&BW6_761_EXTENSION_6_FIELD);
+        f.c0 = Fp3 { c0: els[0], c1: els[1], c2: els[2], extension_field: &BW6_761_EXTENSION_3_FIELD };
+        f.c1 = Fp3 { c0: els[3], c1: els[4], c2: els[5], extension_field: &BW6_761_EXTENSION_3_FIELD };
+
+        f
+    }
+
+    #[test]
+    fn test_sparse_multiplication() {
+        let f = fp6_from_coeffs([3, 5, 7, 11, 13, 17]);
+        let mut a = BW6_761_FP_ONE;
+        a.double();
+        let mut b = a;
+        b.square();
+        b.add_assign(&BW6_761_FP_ONE);
+        let mut c = b;
+        c.square();
+        c.negate();
+
+        // 014 positions are c0.c0, c0.c1 and c1.c1
+        let mut sparse = Fp6::zero(&BW6_761_EXTENSION_6_FIELD);
+        sparse.c0.c0 = a;
+        sparse.c0.c1 = b;
+        sparse.c1.c1 = c;
+
+        let mut expected = f;
+        expected.mul_assign(&sparse);
+        let mut result = f;
+        result.mul_by_014(&a, &b, &c);
+        assert_eq!(result, expected);
+
+        // 034 positions are c0.c0, c1.c0 and c1.c1
+        let mut sparse = Fp6::zero(&BW6_761_EXTENSION_6_FIELD);
+        sparse.c0.c0 = a;
+        sparse.c1.c0 = b;
+        sparse.c1.c1 = c;
+
+        let mut expected = f;
+        expected.mul_assign(&sparse);
+        let mut result = f;
+        result.mul_by_034(&a, &b, &c);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bw6_761_naf_loop_matches_binary_one() {
+        let engine_params = super::Bw6InstanceParams {
+            ate_loop_count_1: BW6_761_PAIRING_ENGINE.ate_loop_count_1,
+            ate_loop_count_1_is_negative: BW6_761_PAIRING_ENGINE.ate_loop_count_1_is_negative,
+            ate_loop_count_2: BW6_761_PAIRING_ENGINE.ate_loop_count_2,
+            ate_loop_count_2_is_negative: BW6_761_PAIRING_ENGINE.ate_loop_count_2_is_negative,
+            exp_w0: BW6_761_PAIRING_ENGINE.exp_w0,
+            exp_w1: BW6_761_PAIRING_ENGINE.exp_w1,
+            exp_w0_is_negative: BW6_761_PAIRING_ENGINE.exp_w0_is_negative,
+            twist_type: BW6_761_PAIRING_ENGINE.twist_type,
+            base_field: &BW6_761_FIELD,
+            curve: &BW6_761_G1_CURVE,
+            curve_twist: &BW6_761_G2_CURVE,
+            fp3_extension: &BW6_761_EXTENSION_3_FIELD,
+            fp6_extension: &BW6_761_EXTENSION_6_FIELD,
+            force_no_naf: false
+        };
+
+        let engine = super::Bw6Instance::from_params(engine_params);
+        assert!(engine.prefer_naf_2);
+
+        let p = BW6_761_G1_GENERATOR;
+        let q = BW6_761_G2_GENERATOR;
+
+        let ans_naf = engine.pair(std::slice::from_ref(&p), std::slice::from_ref(&q)).unwrap();
+        let ans_binary = BW6_761_PAIRING_ENGINE.pair(&[p], &[q]).unwrap();
+
+        assert!(ans_naf == ans_binary);
+        assert!(ans_naf != Fp6::one(&BW6_761_EXTENSION_6_FIELD));
+    }
+
+    #[test]
+    fn test_bw6_761_pairing_result_is_in_gt() {
+        let p = BW6_761_G1_GENERATOR;
+        let q = BW6_761_G2_GENERATOR;
+
+        let ans = BW6_761_PAIRING_ENGINE.pair(&[p], &[q]).unwrap();
+        let ans_r = ans.pow(BW6_761_SUBGROUP_ORDER);
+
+        assert!(ans_r == Fp6::one(&BW6_761_EXTENSION_6_FIELD));
+    }
+
+    #[test]
+    fn test_bw6_761_product_of_pairings() {
+        let p = BW6_761_G1_GENERATOR;
+        let q = BW6_761_G2_GENERATOR;
+
+        let mut minus_p = p.mul([42u64]);
+        minus_p.negate();
+        minus_p.normalize();
+
+        let mut q2 = q.mul([42u64]);
+        q2.normalize();
+
+        let ans = BW6_761_PAIRING_ENGINE.pair(&[p, minus_p], &[q2, q]).unwrap();
+
+        assert!(ans == Fp6::one(&BW6_761_EXTENSION_6_FIELD));
+    }
+}
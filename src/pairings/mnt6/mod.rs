@@ -8,6 +8,7 @@ use crate::extension_towers::fp3::{Fp3, Extension3};
 use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
 use crate::pairings::PairingEngine;
 use crate::pairings::{calculate_bits, calculate_hamming_weight, calculate_naf_hamming_weight, into_ternary_wnaf};
+use crate::pairings::{batch_inverse, prefer_affine_miller_loop};
 use crate::weierstrass::Group;
 
 #[derive(Clone)]
@@ -52,7 +53,10 @@ pub struct MNT6Instance<
     pub fp3_extension: &'a Extension3<'a, FE, F>,
     pub fp6_extension: &'a Extension2Over3<'a, FE, F>,
     pub prefer_naf: bool,
-    pub x_naf: Vec<i8>
+    pub x_naf: Vec<i8>,
+    /// Twist points passed the prime order subgroup check, so the affine Miller loop can not meet
+    /// an undefined slope. It's off by default and only enabled by `for_subgroup_twist_points`
+    pub twist_points_in_subgroup: bool
 }
 
 
@@ -100,9 +104,20 @@ impl<
             fp3_extension: params.fp3_extension,
             fp6_extension: params.fp6_extension,
             prefer_naf: prefer_naf,
-            x_naf: naf
+            x_naf: naf,
+            twist_points_in_subgroup: false
         }
     }
+
+    /// Engine for twist points that passed the check against a prime subgroup order. Affine Miller
+    /// loop is only used for such points, as a small order point can make a slope undefined and redoing
+    /// the loop in projective coordinates would double the work that is not paid for. Order supplied to
+    /// the generic ABI is not checked to be a prime, so it's only enabled for the fixed curves
+    pub fn for_subgroup_twist_points(mut self) -> Self {
+        self.twist_points_in_subgroup = true;
+
+        self
+    }
 }
 
 struct PrecomputedG1<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> {
@@ -162,6 +177,133 @@ impl<
         Ok(f)
     }
 
+    fn prefer_affine_miller_loop(&self, num_pairs: usize) -> bool {
+        prefer_affine_miller_loop(3, calculate_bits(self.x), calculate_hamming_weight(self.x), num_pairs)
+    }
+
+    // Keeps all the twist points in affine coordinates and makes one inversion per step for all
+    // the pairs. Lines are the projective ones up to the factors from Fp3 and powers of the twist
+    // that are killed by the final exponentiation. Fails if some slope is not defined
+    fn affine_miller_loop<'b, I>(&self, i: I) -> Result<Fp6<'a, FE, F>, ()>
+    where 'a: 'b,
+        I: IntoIterator<
+            Item = &'b (&'b CurvePoint<'a, CB>, 
+                &'b CurvePoint<'a, CTW>)
+        >
+    {
+        let mut g1_points = vec![];
+        let mut g2_points = vec![];
+        let mut r = vec![];
+        for (p, q) in i.into_iter() {
+            debug_assert!(p.is_normalized());
+            debug_assert!(q.is_normalized());
+            g1_points.push(self.precompute_g1(p));
+            g2_points.push(*q);
+            r.push((q.x, q.y));
+        }
+
+        let mut f = Fp6::one(self.fp6_extension);
+        let mut inverses = Vec::with_capacity(r.len());
+
+        for bit in MsbBitIterator::new(self.x).skip(1) {
+            f.square();
+
+            // tangent slope is (3 * x_R^2 + a) / (2 * y_R)
+            inverses.clear();
+            for (_, y) in r.iter() {
+                let mut t = *y;
+                t.double();
+                inverses.push(t);
+            }
+            batch_inverse(&mut inverses)?;
+
+            for (((x, y), p), inv) in r.iter_mut().zip(g1_points.iter()).zip(inverses.iter()) {
+                let mut lambda = *x;
+                lambda.square();
+                let t = lambda;
+                lambda.double();
+                lambda.add_assign(&t);
+                lambda.add_assign(&self.curve_twist.a);
+                lambda.mul_assign(inv);
+
+                f.mul_assign(&self.affine_line_at_p(&lambda, x, y, p));
+
+                let mut new_x = lambda;
+                new_x.square();
+                new_x.sub_assign(x);
+                new_x.sub_assign(x);
+
+                let mut new_y = *x;
+                new_y.sub_assign(&new_x);
+                new_y.mul_assign(&lambda);
+                new_y.sub_assign(y);
+
+                *x = new_x;
+                *y = new_y;
+            }
+
+            if bit {
+                // chord slope is (y_Q - y_R) / (x_Q - x_R)
+                inverses.clear();
+                for ((x, _), q) in r.iter().zip(g2_points.iter()) {
+                    let mut t = q.x;
+                    t.sub_assign(x);
+                    inverses.push(t);
+                }
+                batch_inverse(&mut inverses)?;
+
+                for ((((x, y), p), q), inv) in r.iter_mut().zip(g1_points.iter()).zip(g2_points.iter()).zip(inverses.iter()) {
+                    let mut lambda = q.y;
+                    lambda.sub_assign(y);
+                    lambda.mul_assign(inv);
+
+                    f.mul_assign(&self.affine_line_at_p(&lambda, x, y, p));
+
+                    let mut new_x = lambda;
+                    new_x.square();
+                    new_x.sub_assign(x);
+                    new_x.sub_assign(&q.x);
+
+                    let mut new_y = *x;
+                    new_y.sub_assign(&new_x);
+                    new_y.mul_assign(&lambda);
+                    new_y.sub_assign(y);
+
+                    *x = new_x;
+                    *y = new_y;
+                }
+            }
+        }
+
+        if self.x_is_negative {
+            // vertical line through R and -R is in Fp3
+            f = f.inverse().ok_or(())?;
+        }
+
+        Ok(f)
+    }
+
+    // line through (x, y) on the twist with a given slope evaluated at P:
+    // lambda * (x - x_P * twist) - y + y_P * twist * w
+    fn affine_line_at_p(
+        &self,
+        lambda: &Fp3<'a, FE, F>,
+        x: &Fp3<'a, FE, F>,
+        y: &Fp3<'a, FE, F>,
+        p: &PrecomputedG1<'a, FE, F>
+    ) -> Fp6<'a, FE, F> {
+        let mut c0 = *x;
+        c0.sub_assign(&p.x_by_twist);
+        c0.mul_assign(lambda);
+        c0.sub_assign(y);
+
+        let mut line = Fp6::zero(self.fp6_extension);
+        line.c0 = c0;
+        line.c1 = p.y_by_twist;
+
+        line
+    }
+
     fn precompute_g1(&self, g1_point: &CurvePoint<'a, CB>) -> PrecomputedG1<'a, FE, F> {
         // not asserting normalization, it will be asserted in the loop
         let mut x_twist = self.twist.clone();
@@ -491,6 +633,15 @@ impl<
         Ok(f)
     }
 
+    fn multi_miller_loop(&self, pairs: &[(&CurvePoint<'a, CB>, &CurvePoint<'a, CTW>)]) -> Result<Fp6<'a, FE, F>, ()> {
+        // undefined slope means that the twist point is not in the subgroup of the given order
+        if self.twist_points_in_subgroup && self.prefer_affine_miller_loop(pairs.len()) {
+            return self.affine_miller_loop(pairs);
+        }
+
+        self.miller_loop(pairs)
+    }
+
     fn final_exponentiation(&self, f: &Fp6<'a, FE, F>) -> Option<Fp6<'a, FE, F>> {
         let value_inv = f.inverse();
         if value_inv.is_none() {
@@ -578,7 +729,7 @@ impl<
             //     self.miller_loop(&pairs[..])
             // };  
 
-            let loop_result = self.miller_loop(&pairs[..]);
+            let loop_result = self.multi_miller_loop(&pairs[..]);
 
             if loop_result.is_err() {
                 return None;
@@ -588,6 +739,33 @@ impl<
 
             self.final_exponentiation(&loop_result)
         }   
+
+    #[cfg(feature = "parallel_pairings")]
+    fn pair_parallel<'b>
+        (&self, points: &'b [CurvePoint<'a, CB>], twists: &'b [CurvePoint<'a, CTW>], num_threads: usize) -> Option<Self::PairingResult> {
+            crate::pairings::pair_in_parallel(self, points, twists, num_threads)
+        }
+}
+
+#[cfg(feature = "parallel_pairings")]
+impl<
+    'a, 
+        FE: ElementRepr, 
+        F: SizedPrimeField<Repr = FE>, 
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp3<'a, FE, F>>
+    > crate::pairings::MillerLoopEngine for MNT6Instance<'a, FE, F, CB, CTW> {
+    fn miller_loop_on_pairs(&self, pairs: &[(&CurvePoint<'a, CB>, &CurvePoint<'a, CTW>)]) -> Option<Self::PairingResult> {
+        self.multi_miller_loop(pairs).ok()
+    }
+
+    fn final_exponentiation_on_product(&self, f: &Self::PairingResult) -> Option<Self::PairingResult> {
+        self.final_exponentiation(f)
+    }
+
+    fn pairing_result_one(&self) -> Self::PairingResult {
+        Fp6::one(self.fp6_extension)
+    }
 }
 
 #[cfg(test)]
@@ -1342,4 +1520,39 @@ mod tests {
         assert!(ans1 == ans2);
         assert!(ans1 == ans3);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_affine_miller_loop_on_mnt6_753() {
+        use crate::engines::mnt6_753::*;
+        use crate::weierstrass::Group;
+
+        let mut points = vec![];
+        for k in 1..4u64 {
+            let mut p = MNT6_753_G1_GENERATOR.mul(vec![k * 12345]);
+            p.normalize();
+            let mut q = MNT6_753_G2_GENERATOR.mul(vec![k * 67890 + 1]);
+            q.normalize();
+            points.push((p, q));
+        }
+        let pairs: Vec<_> = points.iter().map(|(p, q)| (p, q)).collect();
+
+        let mut engine = MNT6_753_PAIRING_ENGINE;
+        assert!(!engine.prefer_affine_miller_loop(1));
+        assert!(engine.prefer_affine_miller_loop(32));
+
+        // only an engine for subgroup checked twist points takes the affine loop
+        assert!(!engine.twist_points_in_subgroup);
+        let subgroup_engine = engine.clone().for_subgroup_twist_points();
+        assert!(subgroup_engine.twist_points_in_subgroup);
+        let g1_points: Vec<_> = points.iter().cycle().take(32).map(|(p, _)| p.clone()).collect();
+        let g2_points: Vec<_> = points.iter().cycle().take(32).map(|(_, q)| q.clone()).collect();
+        assert!(subgroup_engine.pair(&g1_points, &g2_points).unwrap() == engine.pair(&g1_points, &g2_points).unwrap());
+
+        for &x_is_negative in [false, true].iter() {
+            engine.x_is_negative = x_is_negative;
+            let expected = engine.final_exponentiation(&engine.miller_loop(&pairs[..]).unwrap()).unwrap();
+            let affine = engine.final_exponentiation(&engine.affine_miller_loop(&pairs[..]).unwrap()).unwrap();
+            assert!(affine == expected);
+        }
+    }
+}
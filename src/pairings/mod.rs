@@ -3,11 +3,20 @@ use crate::traits::{FieldElement};
 use crate::weierstrass::Group;
 
 pub mod bls12;
+pub mod bls24;
 pub mod bn;
 pub mod mnt6;
 pub mod mnt4;
+pub mod bw6;
+
+#[cfg(feature = "parallel_pairings")]
+mod parallel;
+
+#[cfg(feature = "parallel_pairings")]
+pub(crate) use self::parallel::{MillerLoopEngine, pair_in_parallel};
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize, serde::Deserialize))]
 pub enum TwistType {
     D,
     M
@@ -19,6 +28,15 @@ pub trait PairingEngine: Sized + Clone + Send + Sync {
     type G2: Group;
 
     fn pair<'b> (&self, points: &'b [Self::G1], twists: &'b [Self::G2]) -> Option<Self::PairingResult>;
+
+    /// Same as `pair`, but Miller loops are split between up to `num_threads` threads and a single
+    /// final exponentiation is done on the product. Not used by the precompiles. Engines without
+    /// a parallel implementation fall back to `pair`
+    #[cfg(feature = "parallel_pairings")]
+    fn pair_parallel<'b> (&self, points: &'b [Self::G1], twists: &'b [Self::G2], num_threads: usize) -> Option<Self::PairingResult> {
+        let _ = num_threads;
+        self.pair(points, twists)
+    }
 }
 
 pub(crate) fn calculate_hamming_weight(representation: &[u64]) -> u32 {
@@ -30,6 +48,71 @@ pub(crate) fn calculate_hamming_weight(representation: &[u64]) -> u32 {
     weight
 }
 
+// compressed squarings pay off if the exponent has long runs of zeroes, e.g. BLS12 `x`
+pub(crate) fn prefer_compressed_cyclotomic_exp(exp: &[u64]) -> bool {
+    calculate_hamming_weight(exp) * 4 <= calculate_bits(exp)
+}
+
+// binary Montgomery inversion was measured at 120-160 multiplications for 4 to 12 limbs
+const INVERSION_COST_IN_MULTIPLICATIONS: u64 = 150;
+
+// MNT4/6 Miller loop can keep the twist point in affine coordinates and invert the slope denominators
+// of all the pairs at once on every step instead of using the extended Jacobian formulas. Both are
+// counted in base field multiplications from the loop bit length and Hamming weight, same as the gas model
+pub(crate) fn prefer_affine_miller_loop(twist_degree: usize, x_bits: u32, x_hamming: u32, num_pairs: usize) -> bool {
+    // multiplication, squaring and inversion on top of the base field one
+    let (mul, sqr, inv) = match twist_degree {
+        2 => (3u64, 2u64, 4u64),
+        3 => (6u64, 5u64, 12u64),
+        _ => {
+            return false;
+        }
+    };
+
+    if num_pairs == 0 {
+        return false;
+    }
+
+    let doublings = x_bits.saturating_sub(1) as u64;
+    let additions = x_hamming.saturating_sub(1) as u64;
+    let num_pairs = num_pairs as u64;
+
+    let projective = num_pairs * (doublings * (12 * sqr + 4 * mul) + additions * (6 * sqr + 9 * mul));
+
+    // Montgomery's trick takes 3 multiplications for every pair except the first one
+    let batch_inversion = 3 * (num_pairs - 1) * mul + INVERSION_COST_IN_MULTIPLICATIONS + inv;
+    let affine = num_pairs * (doublings * (2 * sqr + 3 * mul) + additions * (sqr + 3 * mul))
+        + (doublings + additions) * batch_inversion;
+
+    affine < projective
+}
+
+// Montgomery's trick, fails if any of the elements is zero
+pub(crate) fn batch_inverse<F: FieldElement>(elements: &mut [F]) -> Result<(), ()> {
+    if elements.is_empty() {
+        return Ok(());
+    }
+
+    let mut prefix_products = Vec::with_capacity(elements.len());
+    let mut accumulator = elements[0].clone();
+    prefix_products.push(accumulator.clone());
+    for el in elements[1..].iter() {
+        accumulator.mul_assign(el);
+        prefix_products.push(accumulator.clone());
+    }
+
+    let mut inverse = accumulator.inverse().ok_or(())?;
+    for i in (1..elements.len()).rev() {
+        let mut el_inverse = inverse.clone();
+        el_inverse.mul_assign(&prefix_products[i - 1]);
+        inverse.mul_assign(&elements[i]);
+        elements[i] = el_inverse;
+    }
+    elements[0] = inverse;
+
+    Ok(())
+}
+
 pub(crate) fn calculate_bits(representation: &[u64]) -> u32 {
     let mut b = (representation.len() * 64) as u32;
 
@@ -427,6 +510,24 @@ mod test {
         // assert!(extension_6.frobenius_coeffs_c1 == extension_6_fast.frobenius_coeffs_c1);
     }
 
+    #[test]
+    fn test_batch_inverse() {
+        let base_field = new_field::<U256Repr>("21888242871839275222246405745257275088696311157297823662689037894645226208583", 10).unwrap();
+        let mut elements = vec![];
+        for i in 1..6u64 {
+            elements.push(Fp::from_repr(&base_field, U256Repr::from(i * i + 7)).unwrap());
+        }
+
+        let mut inverses = elements.clone();
+        super::batch_inverse(&mut inverses).unwrap();
+        for (el, inv) in elements.iter().zip(inverses.iter()) {
+            assert!(el.inverse().unwrap() == *inv);
+        }
+
+        elements[2] = Fp::zero(&base_field);
+        assert!(super::batch_inverse(&mut elements).is_err());
+    }
+
     #[test]
     fn test_ternary_wnaf_for_bn254_loop_len() {
         use num_bigint::BigUint;
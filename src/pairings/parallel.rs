@@ -0,0 +1,125 @@
+use crate::weierstrass::Group;
+use super::PairingEngine;
+
+// Engines that can run the Miller loop over a part of the pairs and the final exponentiation
+// of a product of such partial results
+pub(crate) trait MillerLoopEngine: PairingEngine {
+    fn miller_loop_on_pairs(&self, pairs: &[(&Self::G1, &Self::G2)]) -> Option<Self::PairingResult>;
+    fn final_exponentiation_on_product(&self, f: &Self::PairingResult) -> Option<Self::PairingResult>;
+    fn pairing_result_one(&self) -> Self::PairingResult;
+}
+
+// Same validation and result as `PairingEngine::pair`, but pairs are split in chunks between
+// at most `num_threads` threads and only one final exponentiation is done
+pub(crate) fn pair_in_parallel<E: MillerLoopEngine>(
+    engine: &E,
+    points: &[E::G1],
+    twists: &[E::G2],
+    num_threads: usize
+) -> Option<E::PairingResult> 
+where E::G1: Sync, E::G2: Sync
+{
+    use crate::traits::FieldElement;
+
+    if points.len() != twists.len() {
+        return None;
+    }
+
+    if !crate::features::in_gas_metering() && points.is_empty() {
+        return None;
+    }
+
+    let mut pairs = Vec::with_capacity(points.len());
+    for (p, q) in points.iter().zip(twists.iter()) {
+        if !p.is_zero() && !q.is_zero() {
+            pairs.push((p, q));
+        }
+    }
+
+    if pairs.is_empty() {
+        return Some(engine.pairing_result_one());
+    }
+
+    let num_threads = num_threads.max(1).min(pairs.len());
+    let chunk_size = pairs.len().div_ceil(num_threads);
+
+    let partial_results: Vec<Option<E::PairingResult>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = pairs.chunks(chunk_size).map(|chunk| {
+            scope.spawn(move || engine.miller_loop_on_pairs(chunk))
+        }).collect();
+
+        handles.into_iter().map(|handle| {
+            handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        }).collect()
+    });
+
+    let mut f = engine.pairing_result_one();
+    for partial in partial_results.into_iter() {
+        f.mul_assign(&partial?);
+    }
+
+    engine.final_exponentiation_on_product(&f)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pairings::PairingEngine;
+    use crate::weierstrass::Group;
+    use crate::weierstrass::CurveParameters;
+    use crate::weierstrass::curve::CurvePoint;
+
+    fn multiples<'a, C: CurveParameters>(generator: &CurvePoint<'a, C>, num_points: u64) -> Vec<CurvePoint<'a, C>> {
+        let mut points = vec![];
+        for i in 1..=num_points {
+            let mut p = generator.mul([i * 1234567]);
+            p.normalize();
+            points.push(p);
+        }
+        // zero pairs are skipped in both modes
+        points[1] = generator.zero_like();
+
+        points
+    }
+
+    fn assert_same_as_sequential<E: PairingEngine>(engine: &E, points: &[E::G1], twists: &[E::G2]) {
+        let expected = engine.pair(points, twists).unwrap();
+        for &num_threads in [1, 3, 100].iter() {
+            let result = engine.pair_parallel(points, twists, num_threads).unwrap();
+            assert!(result == expected);
+        }
+
+        assert!(engine.pair_parallel(&points[1..], twists, 3).is_none());
+    }
+
+    #[test]
+    fn test_parallel_pairing_bls12_381() {
+        use crate::engines::bls12_381::*;
+        let points = multiples(&BLS12_381_G1_GENERATOR, 8);
+        let twists = multiples(&BLS12_381_G2_GENERATOR, 8);
+        assert_same_as_sequential(&BLS12_381_PAIRING_ENGINE, &points, &twists);
+    }
+
+    #[test]
+    fn test_parallel_pairing_bn254() {
+        use crate::engines::bn254::*;
+        let points = multiples(&BN254_G1_GENERATOR, 8);
+        let twists = multiples(&BN254_G2_GENERATOR, 8);
+        assert_same_as_sequential(&BN254_PAIRING_ENGINE, &points, &twists);
+    }
+
+    #[test]
+    fn test_parallel_pairing_mnt4_753() {
+        use crate::engines::mnt4_753::*;
+        let points = multiples(&MNT4_753_G1_GENERATOR, 16);
+        let twists = multiples(&MNT4_753_G2_GENERATOR, 16);
+        assert_same_as_sequential(&MNT4_753_PAIRING_ENGINE, &points, &twists);
+    }
+
+    #[test]
+    fn test_parallel_pairing_mnt6_753() {
+        use crate::engines::mnt6_753::*;
+        let points = multiples(&MNT6_753_G1_GENERATOR, 8);
+        let twists = multiples(&MNT6_753_G2_GENERATOR, 8);
+        assert_same_as_sequential(&MNT6_753_PAIRING_ENGINE, &points, &twists);
+    }
+}
@@ -127,4 +127,90 @@ pub extern "C" fn c_meter_operation(
             return 1u32;
         }
     }
-} 
\ No newline at end of file
+} 
+// this is C interface for metered execution. Returns 0 on success, 1 on error
+// and 2 if gas limit is exceeded, in which case `gas_used` contains the required gas
+#[cfg(feature = "gas_metering")]
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn c_execute_with_gas_limit(
+    op: ::std::os::raw::c_char,
+    i: *const ::std::os::raw::c_char,
+    i_len: u32,
+    gas_limit: u64,
+    o: *mut ::std::os::raw::c_char,
+    o_len: *mut u32,
+    gas_used: *mut u64,
+    err: *mut ::std::os::raw::c_char,
+    char_len: *mut u32) -> u32 
+{            
+    use crate::gas_meter::execute_with_gas_limit;
+    use crate::errors::ApiError;
+    use std::io::Write;
+
+    let op_u8: u8 = unsafe { std::mem::transmute(op) };
+    let err_out_i8: &mut [i8] = unsafe { std::slice::from_raw_parts_mut(err, PREALLOCATE_FOR_ERROR_BYTES) };
+    let mut err_out: &mut [u8] = unsafe { std::mem::transmute(err_out_i8) };
+
+    let operation = OperationType::from_u8(op_u8);
+
+    if operation.is_none() {
+        let written = err_out.write(b"Unknown operation type\0");
+        if let Ok(bytes_written) = written {
+            unsafe { *char_len = bytes_written as u32 };
+        } else {
+            unsafe { *char_len = 0u32 };
+        }
+
+        return 1u32;
+    }
+
+    let operation = operation.expect("is some");
+    
+    let input_i8: & [i8] = unsafe { std::slice::from_raw_parts(i, i_len as usize) };
+    let input: &[u8] = unsafe { std::mem::transmute(input_i8) };
+
+    let raw_out_i8: &mut [i8] = unsafe { std::slice::from_raw_parts_mut(o, PREALLOCATE_FOR_RESULT_BYTES) };
+    let mut raw_out: &mut [u8] = unsafe { std::mem::transmute(raw_out_i8) };
+
+    let result = execute_with_gas_limit(operation, input, gas_limit);
+
+    match result {
+        Ok((result, gas)) => {
+            unsafe { *gas_used = gas };
+            let written = raw_out.write(result.as_ref());
+            if let Ok(bytes_written) = written {
+                unsafe { *o_len = bytes_written as u32 };
+                return 0u32;
+            }
+
+            let written = err_out.write(b"Failed to write the result\0");
+            if let Ok(bytes_written) = written {
+                unsafe { *char_len = bytes_written as u32 };
+            } else {
+                unsafe { *char_len = 0u32 };
+            }
+
+            return 1u32;
+        },
+        Err(error) => {
+            let return_code = if let ApiError::OutOfGas { gas_required, .. } = error {
+                unsafe { *gas_used = gas_required };
+
+                2u32
+            } else {
+                1u32
+            };
+
+            let err_description = error.to_string();
+            let written = err_out.write(err_description.as_bytes());
+            if let Ok(bytes_written) = written {
+                unsafe { *char_len = bytes_written as u32 };
+            } else {
+                unsafe { *char_len = 0u32 };
+            }
+
+            return return_code;
+        }
+    }
+} 
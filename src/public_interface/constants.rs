@@ -5,6 +5,8 @@ pub const BLS12: u8 = 0x01;
 pub const BN: u8 = 0x02;
 pub const MNT4: u8 = 0x03;
 pub const MNT6: u8 = 0x04;
+pub const BW6: u8 = 0x05;
+pub const BLS24: u8 = 0x06;
 
 pub const TWIST_TYPE_LENGTH: usize = 1;
 pub const TWIST_TYPE_M: u8 = 0x01;
@@ -34,6 +36,16 @@ pub const OPERATION_G2_MULTIEXP: u8 = 0x06;
 
 pub const OPERATION_PAIRING: u8 = 0x07;
 
+pub const OPERATION_EDWARDS_ADD: u8 = 0x08;
+pub const OPERATION_EDWARDS_MUL: u8 = 0x09;
+pub const OPERATION_EDWARDS_MULTIEXP: u8 = 0x0a;
+
+pub const OPERATION_G1_DECOMPRESS: u8 = 0x0b;
+pub const OPERATION_G2_DECOMPRESS: u8 = 0x0c;
+
+pub const OPERATION_G1_MAP: u8 = 0x0d;
+pub const OPERATION_G2_MAP: u8 = 0x0e;
+
 pub const NUM_LIMBS_MIN: usize = 4;
 pub const NUM_LIMBS_MAX: usize = 16;
 pub const NUM_GROUP_LIMBS_MIN: usize = 1;
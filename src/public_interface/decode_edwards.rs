@@ -0,0 +1,51 @@
+use crate::edwards::curve::{EdwardsCurve, EdwardsPoint};
+use crate::field::{SizedPrimeField};
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::weierstrass::CurveParameters;
+
+use super::decode_fp::*;
+
+use crate::errors::ApiError;
+
+/// Serializes a point as big-endian affine X and Y. Neutral element is encoded
+/// as its affine coordinates (0, 1), so no special encoding is required for it
+pub fn serialize_edwards_point<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    >
+    (
+        encoding_length: usize,
+        point: &EdwardsPoint<'a, C>
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let (x, y) = point.into_xy();
+    let mut result = Vec::with_capacity(2*encoding_length);
+    result.extend(serialize_fp_fixed_len(encoding_length, &x)?);
+    result.extend(serialize_fp_fixed_len(encoding_length, &y)?);
+
+    Ok(result)
+}
+
+pub fn decode_edwards_point_from_xy<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'b, FE, F>>
+    >
+    (
+        bytes: &'a [u8],
+        field_byte_len: usize,
+        curve: &'b EdwardsCurve<'b, C>
+    ) -> Result<(EdwardsPoint<'b, C>, &'a [u8]), ApiError>
+{
+    let (x, rest) = decode_fp(bytes, field_byte_len, curve.params.params())?;
+    let (y, rest) = decode_fp(rest, field_byte_len, curve.params.params())?;
+
+    let p: EdwardsPoint<'b, C> = EdwardsPoint::point_from_xy(curve, x, y);
+
+    Ok((p, rest))
+}
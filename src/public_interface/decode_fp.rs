@@ -2,6 +2,7 @@ use crate::field::{SizedPrimeField};
 use crate::fp::Fp;
 use crate::extension_towers::fp2;
 use crate::extension_towers::fp3;
+use crate::extension_towers::fp4_as_2_over_2;
 use crate::representation::ElementRepr;
 use crate::traits::ZeroAndOne;
 use crate::integers::MaxFieldUint;
@@ -9,6 +10,7 @@ use crate::field::PrimeField;
 
 use crate::errors::ApiError;
 use super::decode_utils::*;
+use super::parameters_cache::*;
 use crate::field::field_from_modulus;
 
 pub fn parse_base_field_from_encoding<
@@ -17,9 +19,19 @@ pub fn parse_base_field_from_encoding<
     >(encoding: &'a [u8]) -> Result<(PrimeField<FE>, usize, MaxFieldUint, &'a [u8]), ApiError>
 {
     let ((modulus, modulus_len), rest) = get_base_field_params(&encoding)?;
-    let field = field_from_modulus::<FE>(&modulus).map_err(|_| {
-        ApiError::InputError("Failed to create prime field from modulus".to_owned())
-    })?;
+    let mut cached = CachedParameters::lookup(CACHE_TAG_BASE_FIELD, modulus.as_ref(), consumed_prefix(encoding, rest));
+    let field = match cached.restore_field::<FE>()? {
+        Some(field) => field,
+        None => {
+            let field = field_from_modulus::<FE>(&modulus).map_err(|_| {
+                ApiError::InputError("Failed to create prime field from modulus".to_owned())
+            })?;
+            cached.record(&field);
+            cached.store();
+
+            field
+        }
+    };
     if rest.len() < modulus_len {
         return Err(ApiError::InputError("Input is not long enough".to_owned()));
     }
@@ -160,6 +172,63 @@ pub fn decode_fp3<
     Ok((x, rest))
 }
 
+pub fn decode_fp3_oversized<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+(
+    bytes: &'b [u8], 
+    encoding_length: usize,
+    extension_field: &'a fp3::Extension3<'a, FE, F>
+) -> Result<(fp3::Fp3<'a, FE, F>, &'b [u8]), ApiError>
+{
+    let (c0_encoding, rest) = split(bytes, encoding_length, "Input is not long enough to Fp3_c0")?;
+    let c0 = Fp::from_be_bytes_with_padding(extension_field.field, c0_encoding, false, true).map_err(|e| {
+        ApiError::InputError(format!("Failed to parse Fp3.c0 element, {}", e))
+    })?;
+
+    let (c1_encoding, rest) = split(rest, encoding_length, "Input is not long enough to Fp3_c1")?;
+    let c1 = Fp::from_be_bytes_with_padding(extension_field.field, c1_encoding, false, true).map_err(|e| {
+        ApiError::InputError(format!("Failed to parse Fp3.c1 element, {}", e))
+    })?;
+
+    let (c2_encoding, rest) = split(rest, encoding_length, "Input is not long enough to Fp3_c2")?;
+    let c2 = Fp::from_be_bytes_with_padding(extension_field.field, c2_encoding, false, true).map_err(|e| {
+        ApiError::InputError(format!("Failed to parse Fp3.c2 element, {}", e))
+    })?;
+
+    let mut x = fp3::Fp3::zero(extension_field);
+    x.c0 = c0;
+    x.c1 = c1;
+    x.c2 = c2;
+
+    Ok((x, rest))
+}
+
+pub fn decode_fp4<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+(
+    bytes: &'b [u8], 
+    field_byte_len: usize,
+    extension_field: &'a fp4_as_2_over_2::Extension2Over2<'a, FE, F>
+) -> Result<(fp4_as_2_over_2::Fp4<'a, FE, F>, &'b [u8]), ApiError>
+{
+    let (c0, rest) = decode_fp2(bytes, field_byte_len, extension_field.field)?;
+    let (c1, rest) = decode_fp2(rest, field_byte_len, extension_field.field)?;
+
+    let mut x = fp4_as_2_over_2::Fp4::zero(extension_field);
+    x.c0 = c0;
+    x.c1 = c1;
+
+    Ok((x, rest))
+}
+
 pub fn serialize_fp_fixed_len<
     'a,
     FE: ElementRepr,
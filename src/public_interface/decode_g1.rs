@@ -7,7 +7,8 @@ use crate::integers::{MaxGroupSizeUint};
 
 use super::decode_fp::*;
 
-use super::decode_utils::{split, decode_group_order_with_length};
+use super::decode_utils::{split, decode_group_order_with_length, decode_sign_is_negative, select_root_by_sign, check_modulus_two_adicity};
+use crate::square_root::{sqrt, sign_of_fp};
 
 use crate::errors::ApiError;
 
@@ -79,6 +80,57 @@ pub fn decode_g1_point_from_xy<
     Ok((p, rest))
 }
 
+/// Decodes a point from the sign of Y followed by X and recovers Y from the curve equation
+pub fn decode_g1_point_from_compressed_x<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'b, FE, F>>
+    >
+    (
+        bytes: &'a [u8], 
+        field_byte_len: usize,
+        curve: &'b WeierstrassCurve<'b, C>
+    ) -> Result<(CurvePoint<'b, C>, &'a [u8]), ApiError>
+{
+    check_modulus_two_adicity(curve.params.params().modulus())?;
+    let (y_is_negative, rest) = decode_sign_is_negative(bytes)?;
+    let (x, rest) = decode_fp(rest, field_byte_len, curve.params.params())?;
+
+    let rhs = curve.evaluate_rhs(&x);
+    let y = sqrt(&rhs).ok_or_else(|| {
+        ApiError::InputError(format!("X is not a coordinate of a point on curve, file {}, line {}", file!(), line!()))
+    })?;
+    let y = select_root_by_sign(y, sign_of_fp(&y), y_is_negative)?;
+
+    let p: CurvePoint<'b, C> = CurvePoint::point_from_xy(curve, x, y);
+
+    Ok((p, rest))
+}
+
+/// Decodes a point that is either encoded as X and Y or compressed
+pub fn decode_g1_point<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'b, FE, F>>
+    >
+    (
+        bytes: &'a [u8], 
+        field_byte_len: usize,
+        curve: &'b WeierstrassCurve<'b, C>,
+        compressed: bool
+    ) -> Result<(CurvePoint<'b, C>, &'a [u8]), ApiError>
+{
+    if compressed {
+        decode_g1_point_from_compressed_x(bytes, field_byte_len, curve)
+    } else {
+        decode_g1_point_from_xy(bytes, field_byte_len, curve)
+    }
+}
+
 pub fn decode_g1_point_from_xy_oversized<
     'a,
     'b,
@@ -3,6 +3,7 @@ use crate::fp::Fp;
 use crate::extension_towers::*;
 use crate::extension_towers::fp2;
 use crate::extension_towers::fp3;
+use crate::extension_towers::fp4_as_2_over_2;
 use crate::representation::{ElementRepr};
 use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
 use crate::traits::FieldElement;
@@ -11,7 +12,9 @@ use crate::integers::MaxFieldUint;
 
 use super::decode_fp::*;
 use super::constants::*;
-use super::decode_utils::split;
+use super::decode_utils::{split, decode_sign_is_negative, select_root_by_sign, check_modulus_two_adicity};
+use super::parameters_cache::*;
+use crate::square_root::{sqrt_ext2, sqrt_ext3, sign_of_fp2, sign_of_fp3};
 
 use crate::errors::ApiError;
 
@@ -35,11 +38,17 @@ pub fn create_fp2_extension<
     }
 
     let (fp_non_residue, rest): (Fp<'a, FE, F>, _) = decode_fp(&rest, field_byte_len, base_field)?;
-    if fp_non_residue.is_zero() {
-        return Err(ApiError::UnexpectedZero("Fp2 non-residue can not be zero".to_owned()));
-    }
+    let tag = if need_frobenius {
+        CACHE_TAG_FP2_EXTENSION_WITH_FROBENIUS
+    } else {
+        CACHE_TAG_FP2_EXTENSION
+    };
+    let mut cached = CachedParameters::lookup(tag, modulus.as_ref(), consumed_prefix(bytes, rest));
+    if !cached.is_hit() {
+        if fp_non_residue.is_zero() {
+            return Err(ApiError::UnexpectedZero("Fp2 non-residue can not be zero".to_owned()));
+        }
 
-    {
         let not_a_square = is_non_nth_root(&fp_non_residue, modulus, 2);
         if !not_a_square {
             if !crate::features::in_fuzzing_or_gas_metering() {
@@ -49,12 +58,14 @@ pub fn create_fp2_extension<
     }
 
     let mut extension_2 = fp2::Extension2::new(fp_non_residue);
-    if need_frobenius {
+    if need_frobenius && !cached.restore(&mut extension_2)? {
         extension_2.calculate_frobenius_coeffs(modulus).map_err(|_| {
             ApiError::UnknownParameter("Failed to calculate Frobenius coeffs for Fp2".to_owned())
         })?;
+        cached.record(&extension_2);
     }
-    
+    cached.store();
+
     Ok((extension_2, rest))
 }
 
@@ -78,11 +89,17 @@ pub fn create_fp3_extension<
     }
 
     let (fp_non_residue, rest): (Fp<'a, FE, F>, _) = decode_fp(&rest, field_byte_len, base_field)?;
-    if fp_non_residue.is_zero() {
-        return Err(ApiError::UnexpectedZero("Fp3 non-residue can not be zero".to_owned()));
-    }
+    let tag = if need_frobenius {
+        CACHE_TAG_FP3_EXTENSION_WITH_FROBENIUS
+    } else {
+        CACHE_TAG_FP3_EXTENSION
+    };
+    let mut cached = CachedParameters::lookup(tag, modulus.as_ref(), consumed_prefix(bytes, rest));
+    if !cached.is_hit() {
+        if fp_non_residue.is_zero() {
+            return Err(ApiError::UnexpectedZero("Fp3 non-residue can not be zero".to_owned()));
+        }
 
-    {
         let not_a_cube = is_non_nth_root(&fp_non_residue, modulus, 3);
         if !not_a_cube {
             if !crate::features::in_fuzzing_or_gas_metering() {
@@ -92,12 +109,14 @@ pub fn create_fp3_extension<
     }
 
     let mut extension_3 = fp3::Extension3::new(fp_non_residue);
-    if need_frobenius {
+    if need_frobenius && !cached.restore(&mut extension_3)? {
         extension_3.calculate_frobenius_coeffs_optimized(modulus).map_err(|_| {
             ApiError::UnknownParameter("Failed to calculate Frobenius coeffs for Fp3".to_owned())
         })?;
+        cached.record(&extension_3);
     }
-    
+    cached.store();
+
     Ok((extension_3, rest))
 }
 
@@ -122,6 +141,57 @@ pub fn decode_g2_point_from_xy_in_fp2<
     Ok((p, rest))
 }
 
+/// Decodes a point from the sign of Y followed by X and recovers Y from the curve equation
+pub fn decode_g2_point_from_compressed_x_in_fp2<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = fp2::Fp2<'a, FE, F>>
+    >
+    (
+        bytes: &'b [u8], 
+        field_byte_len: usize,
+        curve: &'a WeierstrassCurve<'a, C>
+    ) -> Result<(CurvePoint<'a, C>, &'b [u8]), ApiError>
+{
+    check_modulus_two_adicity(curve.params.params().field.modulus())?;
+    let (y_is_negative, rest) = decode_sign_is_negative(bytes)?;
+    let (x, rest) = decode_fp2(rest, field_byte_len, curve.params.params())?;
+
+    let rhs = curve.evaluate_rhs(&x);
+    let y = sqrt_ext2(&rhs).ok_or_else(|| {
+        ApiError::InputError(format!("X is not a coordinate of a point on curve, file {}, line {}", file!(), line!()))
+    })?;
+    let y = select_root_by_sign(y, sign_of_fp2(&y), y_is_negative)?;
+
+    let p: CurvePoint<'a, C> = CurvePoint::point_from_xy(curve, x, y);
+
+    Ok((p, rest))
+}
+
+/// Decodes a point that is either encoded as X and Y or compressed
+pub fn decode_g2_point_in_fp2<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = fp2::Fp2<'a, FE, F>>
+    >
+    (
+        bytes: &'b [u8], 
+        field_byte_len: usize,
+        curve: &'a WeierstrassCurve<'a, C>,
+        compressed: bool
+    ) -> Result<(CurvePoint<'a, C>, &'b [u8]), ApiError>
+{
+    if compressed {
+        decode_g2_point_from_compressed_x_in_fp2(bytes, field_byte_len, curve)
+    } else {
+        decode_g2_point_from_xy_in_fp2(bytes, field_byte_len, curve)
+    }
+}
+
 pub fn decode_g2_point_from_xy_in_fp2_oversized<
     'a,
     'b,
@@ -164,6 +234,99 @@ pub fn decode_g2_point_from_xy_in_fp3<
     Ok((p, rest))
 }
 
+/// Decodes a point from the sign of Y followed by X and recovers Y from the curve equation
+pub fn decode_g2_point_from_compressed_x_in_fp3<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = fp3::Fp3<'a, FE, F>>
+    >
+    (
+        bytes: &'b [u8], 
+        field_byte_len: usize,
+        curve: &'a WeierstrassCurve<'a, C>
+    ) -> Result<(CurvePoint<'a, C>, &'b [u8]), ApiError>
+{
+    check_modulus_two_adicity(curve.params.params().field.modulus())?;
+    let (y_is_negative, rest) = decode_sign_is_negative(bytes)?;
+    let (x, rest) = decode_fp3(rest, field_byte_len, curve.params.params())?;
+
+    let rhs = curve.evaluate_rhs(&x);
+    let y = sqrt_ext3(&rhs).ok_or_else(|| {
+        ApiError::InputError(format!("X is not a coordinate of a point on curve, file {}, line {}", file!(), line!()))
+    })?;
+    let y = select_root_by_sign(y, sign_of_fp3(&y), y_is_negative)?;
+
+    let p: CurvePoint<'a, C> = CurvePoint::point_from_xy(curve, x, y);
+
+    Ok((p, rest))
+}
+
+/// Decodes a point that is either encoded as X and Y or compressed
+pub fn decode_g2_point_in_fp3<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = fp3::Fp3<'a, FE, F>>
+    >
+    (
+        bytes: &'b [u8], 
+        field_byte_len: usize,
+        curve: &'a WeierstrassCurve<'a, C>,
+        compressed: bool
+    ) -> Result<(CurvePoint<'a, C>, &'b [u8]), ApiError>
+{
+    if compressed {
+        decode_g2_point_from_compressed_x_in_fp3(bytes, field_byte_len, curve)
+    } else {
+        decode_g2_point_from_xy_in_fp3(bytes, field_byte_len, curve)
+    }
+}
+
+pub fn decode_g2_point_from_xy_in_fp3_oversized<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = fp3::Fp3<'a, FE, F>>
+    >
+    (
+        bytes: &'b [u8], 
+        encoding_length: usize,
+        curve: &'a WeierstrassCurve<'a, C>
+    ) -> Result<(CurvePoint<'a, C>, &'b [u8]), ApiError>
+{
+    let (x, rest) = decode_fp3_oversized(bytes, encoding_length, curve.params.params())?;
+    let (y, rest) = decode_fp3_oversized(rest, encoding_length, curve.params.params())?;
+    
+    let p: CurvePoint<'a, C> = CurvePoint::point_from_xy(curve, x, y);
+    
+    Ok((p, rest))
+}
+
+pub fn decode_g2_point_from_xy_in_fp4<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = fp4_as_2_over_2::Fp4<'a, FE, F>>
+    >
+    (
+        bytes: &'b [u8], 
+        field_byte_len: usize,
+        curve: &'a WeierstrassCurve<'a, C>
+    ) -> Result<(CurvePoint<'a, C>, &'b [u8]), ApiError>
+{
+    let (x, rest) = decode_fp4(bytes, field_byte_len, curve.params.params())?;
+    let (y, rest) = decode_fp4(rest, field_byte_len, curve.params.params())?;
+    
+    let p: CurvePoint<'a, C> = CurvePoint::point_from_xy(curve, x, y);
+    
+    Ok((p, rest))
+}
+
 pub fn serialize_g2_point_in_fp2<
     'a,
     FE: ElementRepr,
@@ -1,4 +1,6 @@
 use crate::integers::{MaxFieldUint, MaxGroupSizeUint, MaxLoopParametersUint};
+use crate::traits::FieldElement;
+use crate::square_root::Sign;
 
 use crate::public_interface::constants::*;
 
@@ -64,6 +66,53 @@ pub(crate) fn decode_sign_is_negative<
     Ok((x_is_negative, rest))
 }
 
+/// Length of a point encoding. Points are encoded as X and Y, or compressed as the sign of Y
+/// followed by X. All the points of one call use the same encoding, so the compressed one is
+/// recognized by the input length (lengths never coincide as coordinates are longer than a byte)
+pub(crate) fn point_encoding_length(coordinate_len: usize, compressed: bool) -> usize {
+    if compressed {
+        SIGN_ENCODING_LENGTH + coordinate_len
+    } else {
+        2 * coordinate_len
+    }
+}
+
+/// Checks if the pairing input contains `num_pairs` pairs of compressed G1 and G2
+/// points, each preceded by the subgroup check flag
+pub(crate) fn pairing_points_are_compressed(
+    bytes: &[u8],
+    num_pairs: usize,
+    g1_coordinate_len: usize,
+    g2_coordinate_len: usize
+) -> bool {
+    let pair_len = 2 * BOOLEAN_ENCODING_LENGTH
+        + point_encoding_length(g1_coordinate_len, true)
+        + point_encoding_length(g2_coordinate_len, true);
+
+    num_pairs != 0 && bytes.len() == num_pairs * pair_len
+}
+
+/// Picks the root with a requested sign from the root and its negation.
+/// Zero root has no negative counterpart, so only positive sign is accepted for it
+pub(crate) fn select_root_by_sign<FE: FieldElement>(
+    mut root: FE,
+    root_sign: Sign,
+    need_negative: bool
+) -> Result<FE, ApiError>
+{
+    match (root_sign, need_negative) {
+        (Sign::Zero, true) => {
+            return Err(ApiError::InputError(format!("Zero coordinate can not have negative sign, file {}, line {}", file!(), line!())));
+        },
+        (Sign::SignPlus, true) | (Sign::SignMinus, false) => {
+            root.negate();
+        },
+        _ => {}
+    }
+
+    Ok(root)
+}
+
 use crate::pairings::TwistType;
 
 pub(crate) fn decode_twist_type<
@@ -189,6 +238,45 @@ pub(crate) fn get_base_field_params(bytes: &[u8]) -> Result<((MaxFieldUint, usiz
     Ok(((modulus, modulus_len), rest))
 }
 
+/// Operation input with the leading base field modulus decoded and validated. Metering
+/// and execution of the same input share it, so the modulus and the size specialization
+/// for it are decoded only once
+pub(crate) struct DecodedInput<'a> {
+    pub(crate) modulus: MaxFieldUint,
+    pub(crate) modulus_len: usize,
+    pub(crate) modulus_limbs: usize,
+    /// full input, starting from the modulus length encoding
+    pub(crate) bytes: &'a [u8],
+    /// input after the modulus encoding
+    pub(crate) rest: &'a [u8],
+}
+
+impl<'a> DecodedInput<'a> {
+    pub(crate) fn decode(bytes: &'a [u8]) -> Result<Self, ApiError> {
+        let ((modulus, modulus_len), rest) = get_base_field_params(bytes)?;
+        let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+        Ok(Self {
+            modulus,
+            modulus_len,
+            modulus_limbs,
+            bytes,
+            rest
+        })
+    }
+
+    /// Extension degree of G2 inputs, that immediately follows the modulus
+    pub(crate) fn extension_degree(&self) -> Result<u8, ApiError> {
+        let (extension_degree, _) = split(self.rest, EXTENSION_DEGREE_ENCODING_LENGTH, "Input is not long enough to get extension degree")?;
+        let extension_degree = extension_degree[0];
+        if !(extension_degree == EXTENSION_DEGREE_2 || extension_degree == EXTENSION_DEGREE_3) {
+            return Err(ApiError::InputError("Extension degree must be 2 or 3".to_owned()));
+        }
+
+        Ok(extension_degree)
+    }
+}
+
 pub(crate) fn num_limbs_for_modulus(modulus: &MaxFieldUint) -> Result<usize, ApiError> {
     use crate::field::calculate_num_limbs;
 
@@ -198,6 +286,29 @@ pub(crate) fn num_limbs_for_modulus(modulus: &MaxFieldUint) -> Result<usize, Api
     Ok(modulus_limbs)
 }
 
+/// Tonelli-Shanks square root takes up to s^2 / 2 squarings for modulus - 1 = 2^s * t,
+/// so operations that take square roots in the base field reject moduli with large s
+pub(crate) fn check_modulus_two_adicity<M: AsRef<[u64]>>(modulus: &M) -> Result<(), ApiError> {
+    use crate::public_interface::sane_limits::MAX_MODULUS_TWO_ADICITY;
+
+    // modulus is odd, so modulus - 1 only differs in the lowest bit
+    let mut two_adicity = 0u32;
+    for (i, limb) in modulus.as_ref().iter().enumerate() {
+        let limb = if i == 0 { *limb & !1u64 } else { *limb };
+        if limb != 0 {
+            two_adicity += limb.trailing_zeros();
+            break;
+        }
+        two_adicity += 64;
+    }
+
+    if two_adicity > MAX_MODULUS_TWO_ADICITY {
+        return Err(ApiError::InputError(format!("Modulus - 1 is divisible by a too large power of two, file {}, line {}", file!(), line!())));
+    }
+
+    Ok(())
+}
+
 // pub(crate) fn num_units_for_group_order(order: &MaxGroupSizeUint) -> Result<usize, ApiError> {
 //     use crate::public_interface::constants::*;
 
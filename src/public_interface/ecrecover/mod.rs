@@ -0,0 +1,224 @@
+use crate::engines::secp256k1::*;
+use crate::public_interface::ApiError;
+use crate::public_interface::decode_fp;
+use crate::public_interface::decode_utils::split;
+
+use crate::field::{U320Repr, PrimeField, SizedPrimeField};
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::FieldElement;
+use crate::weierstrass::Group;
+use crate::weierstrass::curve::CurvePoint;
+
+pub const HASH_BYTE_LENGTH: usize = 32;
+pub const SCALAR_BYTE_LENGTH: usize = 32;
+pub const SERIALIZED_FP_BYTE_LENGTH: usize = 32;
+
+pub const EXPECTED_INPUT_LEN: usize = HASH_BYTE_LENGTH + SCALAR_BYTE_LENGTH * 3;
+pub const SERIALIZED_RESULT_BYTE_LENGTH: usize = 32;
+
+const ADDRESS_BYTE_LENGTH: usize = 20;
+
+/// Fixed price of the ecrecover precompile as defined in the Ethereum yellow paper
+pub const ECRECOVER_GAS: u64 = 3000;
+
+fn bufferize_input(input: &[u8]) -> [u8; EXPECTED_INPUT_LEN] {
+    // following the precompile semantics we either pad it with zeroes or ignore the rest
+
+    let mut buffer = [0u8; EXPECTED_INPUT_LEN];
+    let l = input.len();
+    if l <= EXPECTED_INPUT_LEN {
+        buffer[0..l].copy_from_slice(input);
+    } else {
+        buffer.copy_from_slice(&input[0..EXPECTED_INPUT_LEN]);
+    }
+
+    buffer
+}
+
+fn keccak256(input: &[u8]) -> [u8; 32] {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let mut hasher = Keccak::v256();
+    hasher.update(input);
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+
+    output
+}
+
+fn decode_signature_scalar<'a>(encoding: &[u8], name: &str) -> Result<Fp<'a, U320Repr, PrimeField<U320Repr>>, ApiError> {
+    let scalar = Fp::from_be_bytes(&SECP256K1_SCALAR_FIELD, encoding, true).map_err(|_| {
+        ApiError::InputError(format!("Signature {} is not below the group order", name))
+    })?;
+    if scalar.is_zero() {
+        return Err(ApiError::InputError(format!("Signature {} is zero", name)));
+    }
+
+    Ok(scalar)
+}
+
+fn hash_into_scalar<'a>(hash: &[u8]) -> Result<Fp<'a, U320Repr, PrimeField<U320Repr>>, ApiError> {
+    let mut padded = [0u8; 40];
+    padded[(40 - HASH_BYTE_LENGTH)..].copy_from_slice(hash);
+
+    let mut repr = U320Repr::default();
+    repr.read_be(&padded[..]).map_err(|_| {
+        ApiError::InputError("Failed to read message hash".to_owned())
+    })?;
+
+    // hash is below 2^256 < 2*n, so single subtraction is enough
+    if &repr >= SECP256K1_SCALAR_FIELD.modulus() {
+        repr.sub_noborrow(SECP256K1_SCALAR_FIELD.modulus());
+    }
+
+    Fp::from_repr(&SECP256K1_SCALAR_FIELD, repr).map_err(|_| {
+        ApiError::InputError("Failed to reduce message hash".to_owned())
+    })
+}
+
+/// Implements the `ecrecover` precompile (address 0x01) over the static secp256k1 engine.
+/// Any malformed signature is reported as an error, that the caller should map
+/// into an empty output as the precompile does
+pub struct ECRecoverExecutor;
+
+impl ECRecoverExecutor {
+    pub fn recover(input: &[u8]) -> Result<[u8; SERIALIZED_RESULT_BYTE_LENGTH], ApiError> {
+        let input_buffered = bufferize_input(input);
+
+        let (hash, rest) = split(&input_buffered, HASH_BYTE_LENGTH, "Input is not long enough to get message hash")?;
+        let (v, rest) = split(rest, SCALAR_BYTE_LENGTH, "Input is not long enough to get recovery id")?;
+        let (r_encoding, rest) = split(rest, SCALAR_BYTE_LENGTH, "Input is not long enough to get signature R")?;
+        let (s_encoding, _) = split(rest, SCALAR_BYTE_LENGTH, "Input is not long enough to get signature S")?;
+
+        if v[..(SCALAR_BYTE_LENGTH - 1)].iter().any(|&b| b != 0u8) {
+            return Err(ApiError::InputError("Invalid recovery id".to_owned()));
+        }
+        let y_is_odd = match v[SCALAR_BYTE_LENGTH - 1] {
+            27 => false,
+            28 => true,
+            _ => {
+                return Err(ApiError::InputError("Invalid recovery id".to_owned()));
+            }
+        };
+
+        let r = decode_signature_scalar(r_encoding, "R")?;
+        let s = decode_signature_scalar(s_encoding, "S")?;
+        let z = hash_into_scalar(hash)?;
+
+        // R is below the group order, so it's also a valid base field element
+        let x = Fp::from_be_bytes(&SECP256K1_FIELD, r_encoding, true).map_err(|_| {
+            ApiError::InputError("Signature R is not a valid x coordinate".to_owned())
+        })?;
+
+        let mut rhs = x;
+        rhs.square();
+        rhs.mul_assign(&x);
+        rhs.add_assign(&SECP256K1_G1_CURVE.b);
+
+        let mut y = crate::square_root::sqrt(&rhs).ok_or_else(|| {
+            ApiError::InputError("Signature R is not an x coordinate of a curve point".to_owned())
+        })?;
+        if y.into_repr().is_odd() != y_is_odd {
+            y.negate();
+        }
+
+        let r_point = CurvePoint::point_from_xy(&SECP256K1_G1_CURVE, x, y);
+
+        let r_inv = r.inverse().ok_or_else(|| {
+            ApiError::InputError("Signature R is not invertible".to_owned())
+        })?;
+
+        // Q = r^-1 * (s*R - z*G)
+        let mut u1 = z;
+        u1.mul_assign(&r_inv);
+        u1.negate();
+
+        let mut u2 = s;
+        u2.mul_assign(&r_inv);
+
+        let mut public_key = SECP256K1_G1_GENERATOR.mul(u1.into_repr());
+        public_key.add_assign(&r_point.mul(u2.into_repr()));
+
+        if public_key.is_zero() {
+            return Err(ApiError::InputError("Recovered public key is a point at infinity".to_owned()));
+        }
+
+        let (pk_x, pk_y) = public_key.into_xy();
+        let mut encoding = decode_fp::serialize_fp_fixed_len(SERIALIZED_FP_BYTE_LENGTH, &pk_x)?;
+        encoding.extend(decode_fp::serialize_fp_fixed_len(SERIALIZED_FP_BYTE_LENGTH, &pk_y)?);
+
+        let hash = keccak256(&encoding);
+
+        let mut output = [0u8; SERIALIZED_RESULT_BYTE_LENGTH];
+        output[(SERIALIZED_RESULT_BYTE_LENGTH - ADDRESS_BYTE_LENGTH)..].copy_from_slice(&hash[(32 - ADDRESS_BYTE_LENGTH)..]);
+
+        Ok(output)
+    }
+
+    pub fn meter(_input: &[u8]) -> Result<u64, ApiError> {
+        Ok(ECRECOVER_GAS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(input_hex: &str) -> Result<String, ApiError> {
+        let input = hex::decode(input_hex).unwrap();
+        ECRecoverExecutor::recover(&input).map(hex::encode)
+    }
+
+    #[test]
+    fn test_ecrecover_vectors() {
+        let vectors = [
+            (
+                "38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e000000000000000000000000000000000000000000000000000000000000001b38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e789d1dd423d25f0772d2748d60f7e4b81bb14d086eba8e8e8efb6dcff8a4ae02",
+                "000000000000000000000000ceaccac640adf55b2028469bd36ba501f28b699d"
+            ),
+            (
+                "47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad000000000000000000000000000000000000000000000000000000000000001b650acf9d3f5f0a2c799776a1254355d5f4061762a237396a99a0e0e3fc2bcd6729514a0dacb2e623ac4abd157cb18163ff942280db4d5caad66ddf941ba12e03",
+                "000000000000000000000000c08b5542d177ac6686946920409741463a15dddb"
+            ),
+            // message hash is above the group order
+            (
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff000000000000000000000000000000000000000000000000000000000000001c72040db6b4d81efb9704fd425fe13475d630b4ab1949b71135aa2420d5ce3ba869f961a2b2dca180266499ca3fbfcf3afb3fe6feb2adb49b8e53316575c774ab",
+                "0000000000000000000000001be31a94361a391bbafb2a4ccd704f57dc04d4bb"
+            ),
+        ];
+
+        for (input, expected) in vectors.iter() {
+            assert_eq!(&run(input).unwrap(), expected);
+
+            // trailing bytes are ignored
+            let extended = format!("{}{}", input, "00ff");
+            assert_eq!(&run(&extended).unwrap(), expected);
+
+            assert_eq!(ECRecoverExecutor::meter(&hex::decode(input).unwrap()).unwrap(), ECRECOVER_GAS);
+        }
+    }
+
+    #[test]
+    fn test_ecrecover_invalid_inputs() {
+        let valid = "47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad000000000000000000000000000000000000000000000000000000000000001b650acf9d3f5f0a2c799776a1254355d5f4061762a237396a99a0e0e3fc2bcd6729514a0dacb2e623ac4abd157cb18163ff942280db4d5caad66ddf941ba12e03";
+
+        // wrong recovery id
+        let invalid_v = valid.replacen("001b650a", "001d650a", 1);
+        assert!(run(&invalid_v).is_err());
+        let invalid_v = valid.replacen("0000000000000000000000000000001b", "0100000000000000000000000000001b", 1);
+        assert!(run(&invalid_v).is_err());
+
+        // zero R
+        let zero_r = format!("{}{}{}", &valid[..128], "00".repeat(32), &valid[192..]);
+        assert!(run(&zero_r).is_err());
+
+        // S equal to the group order
+        let s_is_order = format!("{}{}", &valid[..192], "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+        assert!(run(&s_is_order).is_err());
+
+        // truncated input is padded with zeroes, so S becomes zero
+        assert!(run(&valid[..192]).is_err());
+        assert!(run("").is_err());
+    }
+}
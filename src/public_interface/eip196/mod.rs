@@ -13,6 +13,7 @@ pub const SERIALIZED_G2_POINT_BYTE_LENGTH: usize = SERIALIZED_FP2_BYTE_LENGTH *
 
 pub const SERIALIZED_PAIRING_RESULT_BYTE_LENGTH: usize = 32;
 
+use crate::public_interface::decode_fp;
 use crate::public_interface::decode_g1;
 use crate::public_interface::decode_g2;
 
@@ -33,6 +34,14 @@ fn pairing_result_true() -> [u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH] {
     res
 }
 
+// Fp2 element c0 + v*c1 is encoded as (c1, c0) in EIP 197, so this swaps between it and the usual encoding
+fn swap_fp2_encodings(encoding: &mut [u8]) {
+    debug_assert!(encoding.len() % SERIALIZED_FP2_BYTE_LENGTH == 0);
+    for fp2_encoding in encoding.chunks_mut(SERIALIZED_FP2_BYTE_LENGTH) {
+        fp2_encoding.rotate_left(SERIALIZED_FP_BYTE_LENGTH);
+    }
+}
+
 const EXPECTED_ADDITION_INPUT_LEN: usize = SERIALIZED_G1_POINT_BYTE_LENGTH * 2;
 const EXPECTED_MULTIPLICATION_INPUT_LEN: usize = SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH;
 
@@ -68,8 +77,8 @@ impl EIP196Executor {
     pub fn add<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
         let input_buffered = bufferize_input_for_add(input);
 
-        let (mut p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(&input_buffered, SERIALIZED_FP_BYTE_LENGTH, &*BN254_G1_CURVE)?;
-        let (p_1, _) = decode_g1::decode_g1_point_from_xy_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &*BN254_G1_CURVE)?;
+        let (mut p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(&input_buffered, SERIALIZED_FP_BYTE_LENGTH, &BN254_G1_CURVE)?;
+        let (p_1, _) = decode_g1::decode_g1_point_from_xy_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &BN254_G1_CURVE)?;
 
         if !p_0.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
@@ -96,7 +105,7 @@ impl EIP196Executor {
     pub fn mul<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
         let input_buffered = bufferize_input_for_mul(input);
 
-        let (p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(&input_buffered, SERIALIZED_FP_BYTE_LENGTH, &*BN254_G1_CURVE)?;
+        let (p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(&input_buffered, SERIALIZED_FP_BYTE_LENGTH, &BN254_G1_CURVE)?;
         let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
 
         if !p_0.is_on_curve() {
@@ -133,7 +142,7 @@ impl EIP196Executor {
         let mut g2_points = Vec::with_capacity(num_pairs);
 
         for _ in 0..num_pairs {
-            let (g1, rest) = decode_g1::decode_g1_point_from_xy_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &*BN254_G1_CURVE)?;
+            let (g1, rest) = decode_g1::decode_g1_point_from_xy_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &BN254_G1_CURVE)?;
 
             // g2 encoding in EIP 196/197 is non-standard: Fp2 element c0 + v*c1 where v is non-residue is
             // encoded as (c1, c0) instead of usual (c0, c1)
@@ -152,7 +161,7 @@ impl EIP196Executor {
                 (&mut swapped_encoding[(SERIALIZED_FP_BYTE_LENGTH*3)..(SERIALIZED_FP_BYTE_LENGTH*4)]).copy_from_slice(&g2_encoding_bytes[(SERIALIZED_FP_BYTE_LENGTH*2)..(SERIALIZED_FP_BYTE_LENGTH*3)]);
 
 
-                let (g2, _) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(&swapped_encoding[..], SERIALIZED_FP_BYTE_LENGTH, &*BN254_G2_CURVE)?;
+                let (g2, _) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(&swapped_encoding[..], SERIALIZED_FP_BYTE_LENGTH, &BN254_G2_CURVE)?;
 
                 (g2, rest)
             };
@@ -194,7 +203,7 @@ impl EIP196Executor {
             return Ok(pairing_result_true());
         }
 
-        let engine = &*BN254_PAIRING_ENGINE;
+        let engine = BN254_PAIRING_ENGINE.for_pairing_checks();
 
         let pairing_result = engine.pair(&g1_points, &g2_points);
 
@@ -205,7 +214,7 @@ impl EIP196Executor {
         use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
         use crate::traits::ZeroAndOne;
 
-        let one_fp12 = Fp12::one(&*BN254_EXT12_FIELD);
+        let one_fp12 = Fp12::one(&BN254_EXT12_FIELD);
         let pairing_result = pairing_result.unwrap();
         let result = if pairing_result == one_fp12 {
             pairing_result_true()
@@ -215,4 +224,83 @@ impl EIP196Executor {
 
         Ok(result)
     }
-}
\ No newline at end of file
+
+    pub fn map_fp_to_g1<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_FP_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for Fp to G1 to curve mapping".to_owned()));
+        }
+        let (fe, _) = decode_fp::decode_fp_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &BN254_BASE_FIELD)?;
+        let point = mapping::fp_to_g1(&fe)?;
+
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &point)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    /// Both the input element and the resulting point use the EIP 197 encoding of Fp2 elements,
+    /// so the result can be passed to `pair` as is
+    pub fn map_fp2_to_g2<'a>(input: &'a [u8]) -> Result<[u8; SERIALIZED_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_FP2_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for Fp2 to G2 to curve mapping".to_owned()));
+        }
+        let mut swapped_encoding = [0u8; SERIALIZED_FP2_BYTE_LENGTH];
+        swapped_encoding.copy_from_slice(input);
+        swap_fp2_encodings(&mut swapped_encoding[..]);
+
+        let (fe, _) = decode_fp::decode_fp2_oversized(&swapped_encoding[..], SERIALIZED_FP_BYTE_LENGTH, &BN254_EXT2_FIELD)?;
+        let point = mapping::fp2_to_g2(&fe)?;
+
+        let mut output = [0u8; SERIALIZED_G2_POINT_BYTE_LENGTH];
+        let as_vec = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &point)?;
+
+        output.copy_from_slice(&as_vec[..]);
+        swap_fp2_encodings(&mut output[..]);
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // r - 1
+    const MINUS_ONE_SCALAR: &str = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000";
+
+    #[test]
+    fn test_mapped_points_are_valid_pairing_inputs() {
+        let mut fp_encoding = [0u8; SERIALIZED_FP_BYTE_LENGTH];
+        fp_encoding[31] = 42;
+        let p = EIP196Executor::map_fp_to_g1(&fp_encoding[..]).unwrap();
+
+        let mut fp2_encoding = [0u8; SERIALIZED_FP2_BYTE_LENGTH];
+        fp2_encoding[31] = 1;
+        fp2_encoding[63] = 2;
+        let q = EIP196Executor::map_fp2_to_g2(&fp2_encoding[..]).unwrap();
+
+        let mut mul_input = p.to_vec();
+        mul_input.extend(hex::decode(MINUS_ONE_SCALAR).unwrap());
+        let minus_p = EIP196Executor::mul(&mul_input[..]).unwrap();
+
+        let mut pairing_input = p.to_vec();
+        pairing_input.extend_from_slice(&q[..]);
+        assert_eq!(EIP196Executor::pair(&pairing_input[..]).unwrap(), pairing_result_false());
+
+        pairing_input.extend_from_slice(&minus_p[..]);
+        pairing_input.extend_from_slice(&q[..]);
+        assert_eq!(EIP196Executor::pair(&pairing_input[..]).unwrap(), pairing_result_true());
+    }
+
+    #[test]
+    fn test_map_invalid_inputs() {
+        assert!(EIP196Executor::map_fp_to_g1(&[0u8; SERIALIZED_FP_BYTE_LENGTH - 1][..]).is_err());
+        assert!(EIP196Executor::map_fp2_to_g2(&[0u8; SERIALIZED_FP2_BYTE_LENGTH + 1][..]).is_err());
+
+        // not in the field
+        assert!(EIP196Executor::map_fp_to_g1(&[0xffu8; SERIALIZED_FP_BYTE_LENGTH][..]).is_err());
+        assert!(EIP196Executor::map_fp2_to_g2(&[0xffu8; SERIALIZED_FP2_BYTE_LENGTH][..]).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+// Compressed point encoding for BLS12-381 as defined by ZCash and used by
+// the consensus layer and EIP-4844: x coordinate in big endian with three
+// top bits of the first byte used as flags (compression, infinity, sign of y)
+
+use crate::field::*;
+use crate::fp::Fp;
+use crate::extension_towers::fp2::Fp2;
+use crate::weierstrass::Group;
+use crate::weierstrass::curve::CurvePoint;
+use crate::weierstrass::{CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::traits::{FieldElement, ZeroAndOne};
+use crate::square_root::{sqrt, sqrt_ext2};
+use crate::engines::bls12_381::*;
+use crate::public_interface::decode_fp::serialize_fp_fixed_len;
+use crate::errors::ApiError;
+
+pub type G1 = CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>;
+pub type G2 = CurvePoint<'static, CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>>;
+
+pub const SERIALIZED_FP_COMPRESSED_BYTE_LENGTH: usize = 48;
+pub const SERIALIZED_G1_POINT_COMPRESSED_BYTE_LENGTH: usize = SERIALIZED_FP_COMPRESSED_BYTE_LENGTH;
+pub const SERIALIZED_G2_POINT_COMPRESSED_BYTE_LENGTH: usize = SERIALIZED_FP_COMPRESSED_BYTE_LENGTH * 2;
+
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SIGN_FLAG: u8 = 0x20;
+const FLAGS_MASK: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG;
+
+// y is "lexicographically largest" if it's larger than -y in normal (non-Montgomery) form
+fn fp_is_lexicographically_largest(el: &Fp<'static, U384Repr, PrimeField<U384Repr>>) -> bool {
+    let mut negated = *el;
+    negated.negate();
+
+    *el > negated
+}
+
+fn fp2_is_lexicographically_largest(el: &Fp2<'static, U384Repr, PrimeField<U384Repr>>) -> bool {
+    if el.c1.is_zero() {
+        fp_is_lexicographically_largest(&el.c0)
+    } else {
+        fp_is_lexicographically_largest(&el.c1)
+    }
+}
+
+// returns flags and a copy of encoding with flags cleared
+fn split_flags(encoding: &[u8]) -> Result<(bool, bool, Vec<u8>), ApiError> {
+    let flags = encoding[0] & FLAGS_MASK;
+    if flags & COMPRESSION_FLAG == 0 {
+        return Err(ApiError::InputError("Encoding is not marked as compressed".to_owned()));
+    }
+    let is_infinity = flags & INFINITY_FLAG != 0;
+    let sign = flags & SIGN_FLAG != 0;
+
+    let mut cleared = encoding.to_vec();
+    cleared[0] &= !FLAGS_MASK;
+
+    if is_infinity && (sign || cleared.iter().any(|&b| b != 0)) {
+        return Err(ApiError::InputError("Non-canonical encoding of point at infinity".to_owned()));
+    }
+
+    Ok((is_infinity, sign, cleared))
+}
+
+fn decode_fp_compressed(encoding: &[u8]) -> Result<Fp<'static, U384Repr, PrimeField<U384Repr>>, ApiError> {
+    Fp::from_be_bytes(&BLS12_381_FIELD, encoding, false).map_err(|e| {
+        ApiError::InputError(format!("Failed to parse Fp element, {}", e))
+    })
+}
+
+/// Decodes 48 byte compressed G1 point. Point is checked to be in the main subgroup
+pub fn decode_g1_point_compressed(encoding: &[u8]) -> Result<G1, ApiError> {
+    if encoding.len() != SERIALIZED_G1_POINT_COMPRESSED_BYTE_LENGTH {
+        return Err(ApiError::InputError("invalid length for compressed G1 point".to_owned()));
+    }
+
+    let (is_infinity, sign, x_encoding) = split_flags(encoding)?;
+    if is_infinity {
+        return Ok(G1::zero(&BLS12_381_G1_CURVE));
+    }
+
+    let x = decode_fp_compressed(&x_encoding)?;
+
+    // y^2 = x^3 + b
+    let mut rhs = x;
+    rhs.square();
+    rhs.mul_assign(&x);
+    rhs.add_assign(&BLS12_381_B_FOR_G1);
+
+    let mut y = sqrt(&rhs).ok_or_else(|| {
+        ApiError::InputError("Compressed G1 point is not on curve".to_owned())
+    })?;
+
+    if fp_is_lexicographically_largest(&y) != sign {
+        y.negate();
+    }
+
+    let point = G1::point_from_xy(&BLS12_381_G1_CURVE, x, y);
+
+    if !point.check_correct_subgroup() {
+        return Err(ApiError::InputError("G1 point is not in the expected subgroup".to_owned()));
+    }
+
+    Ok(point)
+}
+
+/// Decodes 96 byte compressed G2 point, encoded as (x.c1, x.c0).
+/// Point is checked to be in the main subgroup
+pub fn decode_g2_point_compressed(encoding: &[u8]) -> Result<G2, ApiError> {
+    if encoding.len() != SERIALIZED_G2_POINT_COMPRESSED_BYTE_LENGTH {
+        return Err(ApiError::InputError("invalid length for compressed G2 point".to_owned()));
+    }
+
+    let (is_infinity, sign, x_encoding) = split_flags(encoding)?;
+    if is_infinity {
+        return Ok(G2::zero(&BLS12_381_G2_CURVE));
+    }
+
+    let mut x = Fp2::zero(&BLS12_381_EXTENSION_2_FIELD);
+    x.c1 = decode_fp_compressed(&x_encoding[..SERIALIZED_FP_COMPRESSED_BYTE_LENGTH])?;
+    x.c0 = decode_fp_compressed(&x_encoding[SERIALIZED_FP_COMPRESSED_BYTE_LENGTH..])?;
+
+    let mut rhs = x;
+    rhs.square();
+    rhs.mul_assign(&x);
+    rhs.add_assign(&BLS12_381_B_FOR_G2);
+
+    let mut y = sqrt_ext2(&rhs).ok_or_else(|| {
+        ApiError::InputError("Compressed G2 point is not on curve".to_owned())
+    })?;
+
+    if fp2_is_lexicographically_largest(&y) != sign {
+        y.negate();
+    }
+
+    let point = G2::point_from_xy(&BLS12_381_G2_CURVE, x, y);
+
+    if !point.check_correct_subgroup() {
+        return Err(ApiError::InputError("G2 point is not in the expected subgroup".to_owned()));
+    }
+
+    Ok(point)
+}
+
+pub fn serialize_g1_point_compressed(point: &G1) -> Result<[u8; SERIALIZED_G1_POINT_COMPRESSED_BYTE_LENGTH], ApiError> {
+    let mut output = [0u8; SERIALIZED_G1_POINT_COMPRESSED_BYTE_LENGTH];
+    if point.is_zero() {
+        output[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+
+        return Ok(output);
+    }
+
+    let (x, y) = point.into_xy();
+    let as_vec = serialize_fp_fixed_len(SERIALIZED_FP_COMPRESSED_BYTE_LENGTH, &x)?;
+    output.copy_from_slice(&as_vec[..]);
+
+    output[0] |= COMPRESSION_FLAG;
+    if fp_is_lexicographically_largest(&y) {
+        output[0] |= SIGN_FLAG;
+    }
+
+    Ok(output)
+}
+
+pub fn serialize_g2_point_compressed(point: &G2) -> Result<[u8; SERIALIZED_G2_POINT_COMPRESSED_BYTE_LENGTH], ApiError> {
+    let mut output = [0u8; SERIALIZED_G2_POINT_COMPRESSED_BYTE_LENGTH];
+    if point.is_zero() {
+        output[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+
+        return Ok(output);
+    }
+
+    let (x, y) = point.into_xy();
+    let c1 = serialize_fp_fixed_len(SERIALIZED_FP_COMPRESSED_BYTE_LENGTH, &x.c1)?;
+    let c0 = serialize_fp_fixed_len(SERIALIZED_FP_COMPRESSED_BYTE_LENGTH, &x.c0)?;
+    output[..SERIALIZED_FP_COMPRESSED_BYTE_LENGTH].copy_from_slice(&c1[..]);
+    output[SERIALIZED_FP_COMPRESSED_BYTE_LENGTH..].copy_from_slice(&c0[..]);
+
+    output[0] |= COMPRESSION_FLAG;
+    if fp2_is_lexicographically_largest(&y) {
+        output[0] |= SIGN_FLAG;
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,307 @@
+use crate::engines::bls12_381;
+use crate::public_interface::ApiError;
+use crate::public_interface::decode_g1;
+
+use crate::weierstrass::Group;
+use crate::pairings::PairingEngine;
+
+pub mod decode_compressed;
+pub mod trusted_setup;
+
+use self::decode_compressed::*;
+pub use self::trusted_setup::TrustedSetup;
+
+pub const SCALAR_BYTE_LENGTH: usize = 32;
+pub const VERSIONED_HASH_BYTE_LENGTH: usize = 32;
+
+pub const SERIALIZED_POINT_EVALUATION_INPUT_BYTE_LENGTH: usize = VERSIONED_HASH_BYTE_LENGTH 
+    + SCALAR_BYTE_LENGTH * 2 
+    + SERIALIZED_G1_POINT_COMPRESSED_BYTE_LENGTH * 2;
+
+pub const SERIALIZED_POINT_EVALUATION_RESULT_BYTE_LENGTH: usize = 64;
+
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+pub const FIELD_ELEMENTS_PER_BLOB: u64 = 4096;
+
+// BLS12-381 main subgroup order in big endian
+pub const BLS_MODULUS_BYTES: [u8; SCALAR_BYTE_LENGTH] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01
+];
+
+pub fn kzg_to_versioned_hash(commitment: &[u8]) -> [u8; VERSIONED_HASH_BYTE_LENGTH] {
+    use sha2::{Sha256, Digest};
+
+    let mut hash = [0u8; VERSIONED_HASH_BYTE_LENGTH];
+    hash.copy_from_slice(&Sha256::digest(commitment)[..]);
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+
+    hash
+}
+
+fn point_evaluation_result() -> [u8; SERIALIZED_POINT_EVALUATION_RESULT_BYTE_LENGTH] {
+    let mut res = [0u8; SERIALIZED_POINT_EVALUATION_RESULT_BYTE_LENGTH];
+    res[24..32].copy_from_slice(&FIELD_ELEMENTS_PER_BLOB.to_be_bytes());
+    res[32..].copy_from_slice(&BLS_MODULUS_BYTES);
+
+    res
+}
+
+/// Implements the point evaluation precompile of EIP-4844 (address 0x0a)
+/// over the static BLS12-381 engine. Only [tau]_2 from the trusted setup is used
+pub struct KZGPointEvaluationExecutor {
+    setup: TrustedSetup
+}
+
+impl KZGPointEvaluationExecutor {
+    pub fn new(setup: TrustedSetup) -> Self {
+        Self {
+            setup
+        }
+    }
+
+    pub fn from_trusted_setup_file(path: &std::path::Path) -> Result<Self, ApiError> {
+        let setup = TrustedSetup::load(path)?;
+
+        Ok(Self::new(setup))
+    }
+
+    pub fn trusted_setup(&self) -> &TrustedSetup {
+        &self.setup
+    }
+
+    /// Input is versioned_hash | z | y | commitment | proof. On success returns
+    /// FIELD_ELEMENTS_PER_BLOB and BLS_MODULUS as 32 byte big endian integers
+    pub fn point_evaluation(&self, input: &[u8]) -> Result<[u8; SERIALIZED_POINT_EVALUATION_RESULT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_POINT_EVALUATION_INPUT_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for point evaluation".to_owned()));
+        }
+
+        let (versioned_hash, rest) = input.split_at(VERSIONED_HASH_BYTE_LENGTH);
+        let (z_encoding, rest) = rest.split_at(SCALAR_BYTE_LENGTH);
+        let (y_encoding, rest) = rest.split_at(SCALAR_BYTE_LENGTH);
+        let (commitment_encoding, proof_encoding) = rest.split_at(SERIALIZED_G1_POINT_COMPRESSED_BYTE_LENGTH);
+
+        if kzg_to_versioned_hash(commitment_encoding)[..] != versioned_hash[..] {
+            return Err(ApiError::InputError("Versioned hash does not match the commitment".to_owned()));
+        }
+
+        // both encodings have the same length, so big endian bytes compare as integers
+        if z_encoding >= &BLS_MODULUS_BYTES[..] {
+            return Err(ApiError::InputError("Evaluation point is not in the scalar field".to_owned()));
+        }
+        if y_encoding >= &BLS_MODULUS_BYTES[..] {
+            return Err(ApiError::InputError("Claimed value is not in the scalar field".to_owned()));
+        }
+
+        let (z, _) = decode_g1::decode_scalar_representation(z_encoding, SCALAR_BYTE_LENGTH)?;
+        let (y, _) = decode_g1::decode_scalar_representation(y_encoding, SCALAR_BYTE_LENGTH)?;
+
+        let commitment = decode_g1_point_compressed(commitment_encoding)?;
+        let proof = decode_g1_point_compressed(proof_encoding)?;
+
+        // e(C - [y]_1, -[1]_2) * e(proof, [tau]_2 - [z]_2) == 1
+        let mut p_minus_y = commitment;
+        p_minus_y.sub_assign(&bls12_381::BLS12_381_G1_GENERATOR.mul(y));
+        p_minus_y.normalize();
+
+        let mut x_minus_z = self.setup.tau_g2().clone();
+        x_minus_z.sub_assign(&bls12_381::BLS12_381_G2_GENERATOR.mul(z));
+        x_minus_z.normalize();
+
+        let mut minus_g2 = bls12_381::BLS12_381_G2_GENERATOR;
+        minus_g2.negate();
+
+        let engine = &bls12_381::BLS12_381_PAIRING_ENGINE;
+
+        let pairing_result = engine.pair(&[p_minus_y, proof], &[minus_g2, x_minus_z]);
+
+        if pairing_result.is_none() {
+            return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
+        }
+
+        use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+        use crate::traits::ZeroAndOne;
+
+        let one_fp12 = Fp12::one(&bls12_381::BLS12_381_EXTENSION_12_FIELD);
+        if pairing_result.unwrap() != one_fp12 {
+            return Err(ApiError::InputError("KZG proof is invalid".to_owned()));
+        }
+
+        Ok(point_evaluation_result())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::decode_compressed::{G1, G2};
+
+    // insecure setup with known tau, only suitable for tests
+    const TAU: u64 = 0x1234_5678_9abc;
+    const NUM_G1: usize = 4;
+    const NUM_G2: usize = 3;
+
+    fn powers_of_tau<P: Group + Clone>(generator: &P, num: usize) -> Vec<P> {
+        let mut result = Vec::with_capacity(num);
+        let mut current = generator.clone();
+        for _ in 0..num {
+            result.push(current.clone());
+            current = current.mul([TAU]);
+        }
+
+        result
+    }
+
+    fn make_insecure_setup_encoding() -> String {
+        // point evaluation does not use G1 part of the setup, so monomial
+        // basis is good enough instead of a Lagrange one
+        let g1 = powers_of_tau::<G1>(&bls12_381::BLS12_381_G1_GENERATOR, NUM_G1);
+        let g2 = powers_of_tau::<G2>(&bls12_381::BLS12_381_G2_GENERATOR, NUM_G2);
+
+        let mut encoding = format!("{}\n{}\n", NUM_G1, NUM_G2);
+        for p in g1.iter() {
+            encoding.push_str(&hex::encode(&serialize_g1_point_compressed(p).unwrap()[..]));
+            encoding.push('\n');
+        }
+        for p in g2.iter() {
+            encoding.push_str(&hex::encode(&serialize_g2_point_compressed(p).unwrap()[..]));
+            encoding.push('\n');
+        }
+
+        encoding
+    }
+
+    fn make_executor() -> KZGPointEvaluationExecutor {
+        let setup = TrustedSetup::parse(&make_insecure_setup_encoding()).unwrap();
+        assert_eq!(setup.g1_lagrange.len(), NUM_G1);
+        assert_eq!(setup.g2_monomial.len(), NUM_G2);
+
+        KZGPointEvaluationExecutor::new(setup)
+    }
+
+    fn scalar_encoding(value: u64) -> [u8; SCALAR_BYTE_LENGTH] {
+        let mut encoding = [0u8; SCALAR_BYTE_LENGTH];
+        encoding[24..].copy_from_slice(&value.to_be_bytes());
+
+        encoding
+    }
+
+    fn linear_combination(scalars: &[u64], points: &[G1]) -> G1 {
+        let mut result = G1::zero(&bls12_381::BLS12_381_G1_CURVE);
+        for (s, p) in scalars.iter().zip(points.iter()) {
+            result.add_assign(&p.mul([*s]));
+        }
+
+        result
+    }
+
+    // f(X) = a + b*X + c*X^2, so the quotient (f(X) - f(z))/(X - z) = (b + c*z) + c*X
+    fn make_input(a: u64, b: u64, c: u64, z: u64) -> Vec<u8> {
+        let powers = powers_of_tau::<G1>(&bls12_381::BLS12_381_G1_GENERATOR, 3);
+        let commitment = linear_combination(&[a, b, c], &powers);
+        let proof = linear_combination(&[b + c * z, c], &powers);
+        let y = a + b * z + c * z * z;
+
+        let commitment = serialize_g1_point_compressed(&commitment).unwrap();
+        let proof = serialize_g1_point_compressed(&proof).unwrap();
+
+        let mut input = vec![];
+        input.extend_from_slice(&kzg_to_versioned_hash(&commitment[..]));
+        input.extend_from_slice(&scalar_encoding(z));
+        input.extend_from_slice(&scalar_encoding(y));
+        input.extend_from_slice(&commitment[..]);
+        input.extend_from_slice(&proof[..]);
+
+        input
+    }
+
+    #[test]
+    fn test_generators_compression() {
+        let g1 = hex::decode("97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb").unwrap();
+        let g2 = hex::decode("93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8").unwrap();
+
+        assert_eq!(&serialize_g1_point_compressed(&bls12_381::BLS12_381_G1_GENERATOR).unwrap()[..], &g1[..]);
+        assert_eq!(&serialize_g2_point_compressed(&bls12_381::BLS12_381_G2_GENERATOR).unwrap()[..], &g2[..]);
+
+        assert!(decode_g1_point_compressed(&g1).unwrap().into_xy() == bls12_381::BLS12_381_G1_GENERATOR.into_xy());
+        assert!(decode_g2_point_compressed(&g2).unwrap().into_xy() == bls12_381::BLS12_381_G2_GENERATOR.into_xy());
+
+        let mut minus_g1 = bls12_381::BLS12_381_G1_GENERATOR;
+        minus_g1.negate();
+        let encoding = serialize_g1_point_compressed(&minus_g1).unwrap();
+        assert!(decode_g1_point_compressed(&encoding[..]).unwrap().into_xy() == minus_g1.into_xy());
+    }
+
+    #[test]
+    fn test_valid_point_evaluation() {
+        let executor = make_executor();
+        let input = make_input(7, 11, 13, 0x1_0001);
+        let output = executor.point_evaluation(&input).unwrap();
+
+        assert_eq!(&output[..32], &scalar_encoding(FIELD_ELEMENTS_PER_BLOB)[..]);
+        assert_eq!(&output[32..], &BLS_MODULUS_BYTES[..]);
+    }
+
+    #[test]
+    fn test_zero_polynomial_evaluation() {
+        let executor = make_executor();
+        let input = make_input(0, 0, 0, 12345);
+
+        assert!(executor.point_evaluation(&input).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_claimed_value() {
+        let executor = make_executor();
+        let mut input = make_input(7, 11, 13, 5);
+        input[VERSIONED_HASH_BYTE_LENGTH + SCALAR_BYTE_LENGTH * 2 - 1] ^= 1;
+
+        assert!(executor.point_evaluation(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_versioned_hash() {
+        let executor = make_executor();
+        let mut input = make_input(7, 11, 13, 5);
+        input[VERSIONED_HASH_BYTE_LENGTH - 1] ^= 1;
+
+        assert!(executor.point_evaluation(&input).is_err());
+
+        let mut input = make_input(7, 11, 13, 5);
+        input[0] = 0x00;
+
+        assert!(executor.point_evaluation(&input).is_err());
+    }
+
+    #[test]
+    fn test_scalar_out_of_field() {
+        let executor = make_executor();
+        let mut input = make_input(7, 11, 13, 5);
+        input[VERSIONED_HASH_BYTE_LENGTH..(VERSIONED_HASH_BYTE_LENGTH + SCALAR_BYTE_LENGTH)].copy_from_slice(&BLS_MODULUS_BYTES);
+
+        assert!(executor.point_evaluation(&input).is_err());
+    }
+
+    #[test]
+    fn test_invalid_input_length() {
+        let executor = make_executor();
+        let input = make_input(7, 11, 13, 5);
+
+        assert!(executor.point_evaluation(&input[1..]).is_err());
+    }
+
+    #[test]
+    fn test_malformed_setup() {
+        let encoding = make_insecure_setup_encoding();
+        let truncated: Vec<&str> = encoding.lines().take(2 + NUM_G1 + 1).collect();
+
+        assert!(TrustedSetup::parse(&truncated.join("\n")).is_err());
+
+        // two-byte character after the first digit would be split by a two-byte slice
+        let mut lines: Vec<String> = encoding.lines().map(|l| l.to_owned()).collect();
+        lines[2].replace_range(0..3, "aé");
+        assert!(TrustedSetup::parse(&lines.join("\n")).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+// Loader for the KZG trusted setup in the text format used by the c-kzg
+// library and the consensus specs: number of G1 points, number of G2 points,
+// then one hex encoded compressed point per line (G1 in Lagrange form first, 
+// G2 in monomial form after). Any lines following G2 points are ignored
+
+use super::decode_compressed::*;
+use crate::errors::ApiError;
+
+pub struct TrustedSetup {
+    pub g1_lagrange: Vec<G1>,
+    pub g2_monomial: Vec<G2>,
+}
+
+fn decode_hex(line: &str) -> Result<Vec<u8>, ApiError> {
+    let line = line.trim();
+    let line = line.strip_prefix("0x").unwrap_or(line);
+    // slicing by two bytes below can split a multi-byte character
+    if !line.is_ascii() {
+        return Err(ApiError::InputError(format!("Invalid hex encoding: {}", line)));
+    }
+    if line.len() & 1 != 0 {
+        return Err(ApiError::InputError("Hex encoding has odd length".to_owned()));
+    }
+
+    let mut result = Vec::with_capacity(line.len() / 2);
+    for i in (0..line.len()).step_by(2) {
+        let byte = u8::from_str_radix(&line[i..i+2], 16).map_err(|_| {
+            ApiError::InputError(format!("Invalid hex encoding: {}", line))
+        })?;
+        result.push(byte);
+    }
+
+    Ok(result)
+}
+
+fn parse_count<'a, I: Iterator<Item = &'a str>>(lines: &mut I) -> Result<usize, ApiError> {
+    let line = lines.next().ok_or_else(|| {
+        ApiError::InputError("Trusted setup is too short".to_owned())
+    })?;
+
+    line.trim().parse::<usize>().map_err(|_| {
+        ApiError::InputError(format!("Invalid number of points in trusted setup: {}", line))
+    })
+}
+
+impl TrustedSetup {
+    pub fn parse(encoding: &str) -> Result<Self, ApiError> {
+        let mut lines = encoding.lines().filter(|l| !l.trim().is_empty());
+
+        let num_g1 = parse_count(&mut lines)?;
+        let num_g2 = parse_count(&mut lines)?;
+
+        if num_g2 < 2 {
+            return Err(ApiError::InputError("Trusted setup should contain at least two G2 points".to_owned()));
+        }
+
+        let mut g1_lagrange = Vec::with_capacity(num_g1);
+        for _ in 0..num_g1 {
+            let line = lines.next().ok_or_else(|| {
+                ApiError::InputError("Trusted setup is too short".to_owned())
+            })?;
+            let encoding = decode_hex(line)?;
+            g1_lagrange.push(decode_g1_point_compressed(&encoding)?);
+        }
+
+        let mut g2_monomial = Vec::with_capacity(num_g2);
+        for _ in 0..num_g2 {
+            let line = lines.next().ok_or_else(|| {
+                ApiError::InputError("Trusted setup is too short".to_owned())
+            })?;
+            let encoding = decode_hex(line)?;
+            g2_monomial.push(decode_g2_point_compressed(&encoding)?);
+        }
+
+        Ok(Self {
+            g1_lagrange,
+            g2_monomial
+        })
+    }
+
+    pub fn load(path: &std::path::Path) -> Result<Self, ApiError> {
+        let encoding = std::fs::read_to_string(path).map_err(|e| {
+            ApiError::InputError(format!("Failed to read trusted setup file, {}", e))
+        })?;
+
+        Self::parse(&encoding)
+    }
+
+    /// [tau]_2, the only element of the setup used for point evaluation
+    pub fn tau_g2(&self) -> &G2 {
+        &self.g2_monomial[1]
+    }
+}
@@ -14,10 +14,12 @@
 ///
 /// Assumptions:
 /// - one byte for length encoding
+/// - points are encoded as X and Y, or all the points of the call are compressed
 
-use crate::weierstrass::{Group, CurveOverFpParameters};
+use crate::weierstrass::{Group, CurveParameters, CurveOverFpParameters};
 use crate::weierstrass::curve::{CurvePoint, WeierstrassCurve};
 use crate::representation::ElementRepr;
+use crate::fp::Fp;
 use crate::multiexp::peppinger;
 use crate::field::*;
 use super::constants::*;
@@ -25,10 +27,12 @@ use super::constants::*;
 use super::decode_g1::*;
 use super::decode_utils::*;
 use super::decode_fp::*;
+use super::known_curves::recognize_g1_curve;
 
 use crate::errors::ApiError;
 
 pub trait G1Api {
+    fn decompress_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
     fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
     fn mul_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
     fn multiexp(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
@@ -39,44 +43,59 @@ pub struct G1ApiImplementation<FE: ElementRepr> {
 }
 
 impl<FE: ElementRepr> G1Api for G1ApiImplementation<FE> {
-    fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
-        let (field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
-        let (a, b, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &field)?;
-        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+    fn decompress_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        if let Some((known_curve, modulus_len, _order_len, rest)) = recognize_g1_curve(bytes) {
+            return expand_for_known_g1_curve!(known_curve, decompress_point_on_curve, modulus_len, rest);
+        }
 
-        let fp_params = CurveOverFpParameters::new(&field);
+        Self::decompress_point_on_encoded_curve(bytes)
+    }
 
-        let curve = WeierstrassCurve::new(&order.as_ref(), a, b, &fp_params).map_err(|_| {
-            ApiError::InputError("Curve shape is not supported".to_owned())
-        })?;
+    fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        if let Some((known_curve, modulus_len, _order_len, rest)) = recognize_g1_curve(bytes) {
+            return expand_for_known_g1_curve!(known_curve, add_points_on_curve, modulus_len, rest);
+        }
 
-        let (mut p_0, rest) = decode_g1_point_from_xy(rest, modulus_len, &curve)?;
-        let (p_1, rest) = decode_g1_point_from_xy(rest, modulus_len, &curve)?;
+        Self::add_points_on_encoded_curve(bytes)
+    }
 
-        if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    fn mul_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        if let Some((known_curve, modulus_len, order_len, rest)) = recognize_g1_curve(bytes) {
+            return expand_for_known_g1_curve!(known_curve, mul_point_on_curve, modulus_len, order_len, rest);
         }
 
-        if !p_0.is_on_curve() {
-            if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
-            }
-        }
-        if !p_1.is_on_curve() {
-            if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
-            }
+        Self::mul_point_on_encoded_curve(bytes)
+    }
+
+    fn multiexp(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        if let Some((known_curve, modulus_len, order_len, rest)) = recognize_g1_curve(bytes) {
+            return expand_for_known_g1_curve!(known_curve, multiexp_on_curve, modulus_len, order_len, rest);
         }
 
-        p_0.add_assign(&p_1);
+        Self::multiexp_on_encoded_curve(bytes)
+    }
+}
+
+/// Operations on a curve that is created from the encoding, skipping recognition of known curves
+impl<FE: ElementRepr> G1ApiImplementation<FE> {
+    pub(crate) fn decompress_point_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(bytes)?;
+        let (a, b, rest) = parse_ab_in_base_field_from_encoding(rest, modulus_len, &field)?;
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+
+        let fp_params = CurveOverFpParameters::new(&field);
 
-        serialize_g1_point(modulus_len, &p_0)   
+        let curve = WeierstrassCurve::new(order.as_ref(), a, b, &fp_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        decompress_point_on_curve(&curve, modulus_len, rest)
     }
 
-    fn mul_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    pub(crate) fn add_points_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         let (field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (a, b, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &field)?;
-        let (order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
 
         let fp_params = CurveOverFpParameters::new(&field);
 
@@ -84,25 +103,24 @@ impl<FE: ElementRepr> G1Api for G1ApiImplementation<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (p_0, rest) = decode_g1_point_from_xy(rest, modulus_len, &curve)?;
-        let (scalar, rest) = decode_scalar_representation(rest, order_len)?;
+        add_points_on_curve(&curve, modulus_len, rest)
+    }
 
-        if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
-        }
+    pub(crate) fn mul_point_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
+        let (a, b, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &field)?;
+        let (order_len, order, rest) = parse_group_order_from_encoding(rest)?;
 
-        if !p_0.is_on_curve() {
-            if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
-            }
-        }
+        let fp_params = CurveOverFpParameters::new(&field);
 
-        let p = p_0.mul(&scalar);
+        let curve = WeierstrassCurve::new(&order.as_ref(), a, b, &fp_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
 
-        serialize_g1_point(modulus_len, &p)   
+        mul_point_on_curve(&curve, modulus_len, order_len, rest)
     }
 
-    fn multiexp(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    pub(crate) fn multiexp_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         let (field, modulus_len, _, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (a, b, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &field)?;
         let (order_len, order, rest) = parse_group_order_from_encoding(rest)?;
@@ -113,57 +131,172 @@ impl<FE: ElementRepr> G1Api for G1ApiImplementation<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
-        let num_pairs = num_pairs_encoding[0] as usize;
+        multiexp_on_curve(&curve, modulus_len, order_len, rest)
+    }
+}
+
+fn decompress_point_on_curve<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let (p, rest) = decode_g1_point_from_compressed_x(bytes, modulus_len, curve)?;
+
+    if !rest.is_empty() {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
+
+    serialize_g1_point(modulus_len, &p)
+}
+
+fn add_points_on_curve<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let compressed = bytes.len() == 2 * point_encoding_length(modulus_len, true);
+    let (mut p_0, rest) = decode_g1_point(bytes, modulus_len, curve, compressed)?;
+    let (p_1, rest) = decode_g1_point(rest, modulus_len, curve, compressed)?;
+
+    if rest.len() != 0 {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
 
-        if num_pairs == 0 {
-            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+    if !p_0.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+        }
+    }
+    if !p_1.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
         }
+    }
+
+    p_0.add_assign(&p_1);
 
-        let expected_pair_len = 2*modulus_len + order_len;
-        if rest.len() != expected_pair_len * num_pairs {
-            return Err(ApiError::InputError("Input length is invalid for number of pairs".to_owned()));
+    serialize_g1_point(modulus_len, &p_0)   
+}
+
+fn mul_point_on_curve<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        order_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let compressed = bytes.len() == point_encoding_length(modulus_len, true) + order_len;
+    let (p_0, rest) = decode_g1_point(bytes, modulus_len, curve, compressed)?;
+    let (scalar, rest) = decode_scalar_representation(rest, order_len)?;
+
+    if rest.len() != 0 {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
+
+    if !p_0.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
         }
+    }
+
+    let p = p_0.mul(&scalar);
 
-        let mut global_rest = rest;
-        let mut bases = Vec::with_capacity(num_pairs);
-        let mut scalars = Vec::with_capacity(num_pairs);
-
-        for _ in 0..num_pairs {
-            let (p, local_rest) = decode_g1_point_from_xy(global_rest, modulus_len, &curve)?;
-            let (scalar, local_rest) = decode_scalar_representation(local_rest, order_len)?;
-            if !p.is_on_curve() {
-                if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
-                }
+    serialize_g1_point(modulus_len, &p)   
+}
+
+fn multiexp_on_curve<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        order_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let (num_pairs_encoding, rest) = split(bytes, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+    let num_pairs = num_pairs_encoding[0] as usize;
+
+    if num_pairs == 0 {
+        return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+    }
+
+    let compressed = rest.len() == (point_encoding_length(modulus_len, true) + order_len) * num_pairs;
+    let expected_pair_len = point_encoding_length(modulus_len, compressed) + order_len;
+    if rest.len() != expected_pair_len * num_pairs {
+        return Err(ApiError::InputError("Input length is invalid for number of pairs".to_owned()));
+    }
+
+    let mut global_rest = rest;
+    let mut bases = Vec::with_capacity(num_pairs);
+    let mut scalars = Vec::with_capacity(num_pairs);
+
+    for _ in 0..num_pairs {
+        let (p, local_rest) = decode_g1_point(global_rest, modulus_len, curve, compressed)?;
+        let (scalar, local_rest) = decode_scalar_representation(local_rest, order_len)?;
+        if !p.is_on_curve() {
+            if !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
             }
-            bases.push(p);
-            scalars.push(scalar);
-            global_rest = local_rest;
         }
+        bases.push(p);
+        scalars.push(scalar);
+        global_rest = local_rest;
+    }
 
-        if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
-        }
+    if global_rest.len() != 0 {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
 
-        if bases.len() != scalars.len() || bases.len() == 0 {
-            if !crate::features::in_gas_metering() {
-                return Err(ApiError::InputError(format!("Multiexp with empty input pairs, file {}, line {}", file!(), line!())));
-            } else {
-                let result = CurvePoint::zero(&curve);
-                return serialize_g1_point(modulus_len, &result);
-            }
-        } 
+    if bases.len() != scalars.len() || bases.len() == 0 {
+        if !crate::features::in_gas_metering() {
+            return Err(ApiError::InputError(format!("Multiexp with empty input pairs, file {}, line {}", file!(), line!())));
+        } else {
+            let result = CurvePoint::zero(curve);
+            return serialize_g1_point(modulus_len, &result);
+        }
+    } 
 
-        let result = peppinger(&bases, scalars);
+    let result = peppinger(&bases, scalars);
 
-        serialize_g1_point(modulus_len, &result)   
-    }
+    serialize_g1_point(modulus_len, &result)   
 }
 
 pub struct PublicG1Api;
 
 impl G1Api for PublicG1Api {
+    fn decompress_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (_, modulus, _) = parse_modulus_and_length(bytes)?;
+        let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+        let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, bytes, decompress_point);
+
+        result
+    }
+
     fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         let (_, modulus, _) = parse_modulus_and_length(&bytes)?;
         let modulus_limbs = num_limbs_for_modulus(&modulus)?;
@@ -1,6 +1,7 @@
-use crate::weierstrass::{Group, CurveOverFp2Parameters, CurveOverFp3Parameters};
+use crate::weierstrass::{Group, CurveParameters, CurveOverFp2Parameters, CurveOverFp3Parameters};
 use crate::weierstrass::curve::{CurvePoint, WeierstrassCurve};
 use crate::representation::ElementRepr;
+use crate::extension_towers::fp2::Fp2;
 use crate::multiexp::peppinger;
 
 use crate::field::*;
@@ -10,6 +11,7 @@ use super::decode_g2::*;
 use super::decode_g1::*;
 use super::constants::*;
 use super::decode_fp::*;
+use super::known_curves::recognize_g2_curve_in_fp2;
 
 use crate::errors::ApiError;
 
@@ -24,6 +26,7 @@ use crate::errors::ApiError;
 /// - Curve order
 
 pub trait G2Api {
+    fn decompress_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
     fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
     fn mul_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
     fn multiexp(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
@@ -34,42 +37,72 @@ pub struct G2ApiImplementationFp2<FE: ElementRepr> {
 }
 
 impl<FE: ElementRepr> G2Api for G2ApiImplementationFp2<FE> {
+    fn decompress_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        if let Some((known_curve, modulus_len, _order_len, rest)) = recognize_g2_curve_in_fp2(bytes) {
+            return expand_for_known_g2_curve!(known_curve, decompress_point_on_curve_in_fp2, modulus_len, rest);
+        }
+
+        Self::decompress_point_on_encoded_curve(bytes)
+    }
+
     fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
-        let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
+        if let Some((known_curve, modulus_len, _order_len, rest)) = recognize_g2_curve_in_fp2(bytes) {
+            return expand_for_known_g2_curve!(known_curve, add_points_on_curve_in_fp2, modulus_len, rest);
+        }
+
+        Self::add_points_on_encoded_curve(bytes)
+    }
+
+    fn mul_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        if let Some((known_curve, modulus_len, order_len, rest)) = recognize_g2_curve_in_fp2(bytes) {
+            return expand_for_known_g2_curve!(known_curve, mul_point_on_curve_in_fp2, modulus_len, order_len, rest);
+        }
+
+        Self::mul_point_on_encoded_curve(bytes)
+    }
+
+    fn multiexp(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        if let Some((known_curve, modulus_len, order_len, rest)) = recognize_g2_curve_in_fp2(bytes) {
+            return expand_for_known_g2_curve!(known_curve, multiexp_on_curve_in_fp2, modulus_len, order_len, rest);
+        }
+
+        Self::multiexp_on_encoded_curve(bytes)
+    }
+}
+
+/// Operations on a curve that is created from the encoding, skipping recognition of known curves
+impl<FE: ElementRepr> G2ApiImplementationFp2<FE> {
+    pub(crate) fn decompress_point_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(bytes)?;
         let (extension_2, rest) = create_fp2_extension(rest, &modulus, modulus_len, &field, false)?;
-        let (a, b, rest) = parse_ab_in_fp2_from_encoding(&rest, modulus_len, &extension_2)?;
+        let (a, b, rest) = parse_ab_in_fp2_from_encoding(rest, modulus_len, &extension_2)?;
         let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
 
         let fp2_params = CurveOverFp2Parameters::new(&extension_2);
 
-        let curve = WeierstrassCurve::new(&order.as_ref(), a, b, &fp2_params).map_err(|_| {
+        let curve = WeierstrassCurve::new(order.as_ref(), a, b, &fp2_params).map_err(|_| {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (mut p_0, rest) = decode_g2_point_from_xy_in_fp2(rest, modulus_len, &curve)?;
-        let (p_1, rest) = decode_g2_point_from_xy_in_fp2(rest, modulus_len, &curve)?;
+        decompress_point_on_curve_in_fp2(&curve, modulus_len, rest)
+    }
 
-        if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
-        }
+    pub(crate) fn add_points_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
+        let (extension_2, rest) = create_fp2_extension(rest, &modulus, modulus_len, &field, false)?;
+        let (a, b, rest) = parse_ab_in_fp2_from_encoding(&rest, modulus_len, &extension_2)?;
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
 
-        if !p_0.is_on_curve() {
-            if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
-            }
-        }
-        if !p_1.is_on_curve() {
-            if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
-            }
-        }
+        let fp2_params = CurveOverFp2Parameters::new(&extension_2);
 
-        p_0.add_assign(&p_1);
+        let curve = WeierstrassCurve::new(&order.as_ref(), a, b, &fp2_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
 
-        serialize_g2_point_in_fp2(modulus_len, &p_0)   
+        add_points_on_curve_in_fp2(&curve, modulus_len, rest)
     }
 
-    fn mul_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    pub(crate) fn mul_point_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (extension_2, rest) = create_fp2_extension(rest, &modulus, modulus_len, &field, false)?;
         let (a, b, rest) = parse_ab_in_fp2_from_encoding(&rest, modulus_len, &extension_2)?;
@@ -81,25 +114,10 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp2<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (p_0, rest) = decode_g2_point_from_xy_in_fp2(rest, modulus_len, &curve)?;
-        let (scalar, rest) = decode_scalar_representation(rest, order_len)?;
-
-        if rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
-        }
-
-        if !p_0.is_on_curve() {
-            if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
-            }
-        }
-
-        let p = p_0.mul(&scalar);
-
-        serialize_g2_point_in_fp2(modulus_len, &p)   
+        mul_point_on_curve_in_fp2(&curve, modulus_len, order_len, rest)
     }
 
-    fn multiexp(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    pub(crate) fn multiexp_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (extension_2, rest) = create_fp2_extension(&rest, &modulus, modulus_len, &field, false)?;
         let (a, b, rest) = parse_ab_in_fp2_from_encoding(&rest, modulus_len, &extension_2)?;
@@ -111,52 +129,158 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp2<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
-        let num_pairs = num_pairs_encoding[0] as usize;
+        multiexp_on_curve_in_fp2(&curve, modulus_len, order_len, rest)
+    }
+}
 
-        if num_pairs == 0 {
-            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
-        }
+fn decompress_point_on_curve_in_fp2<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let (p, rest) = decode_g2_point_from_compressed_x_in_fp2(bytes, modulus_len, curve)?;
+
+    if !rest.is_empty() {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
 
-        let expected_pair_len = 4*modulus_len + order_len;
-        if rest.len() != expected_pair_len * num_pairs {
-            return Err(ApiError::InputError("Input length is invalid for number of pairs".to_owned()));
-        }
+    serialize_g2_point_in_fp2(modulus_len, &p)
+}
 
-        let mut global_rest = rest;
-        let mut bases = Vec::with_capacity(num_pairs);
-        let mut scalars = Vec::with_capacity(num_pairs);
+fn add_points_on_curve_in_fp2<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let compressed = bytes.len() == 2 * point_encoding_length(2*modulus_len, true);
+    let (mut p_0, rest) = decode_g2_point_in_fp2(bytes, modulus_len, curve, compressed)?;
+    let (p_1, rest) = decode_g2_point_in_fp2(rest, modulus_len, curve, compressed)?;
+
+    if rest.len() != 0 {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
 
-        for _ in 0..num_pairs {
-            let (p, local_rest) = decode_g2_point_from_xy_in_fp2(global_rest, modulus_len, &curve)?;
-            if !p.is_on_curve() {
-                if !crate::features::in_fuzzing_or_gas_metering() {
-                    return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
-                }
-            }
-            let (scalar, local_rest) = decode_scalar_representation(local_rest, order_len)?;
-            bases.push(p);
-            scalars.push(scalar);
-            global_rest = local_rest;
+    if !p_0.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
         }
+    }
+    if !p_1.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+        }
+    }
 
-        if global_rest.len() != 0 {
-            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    p_0.add_assign(&p_1);
+
+    serialize_g2_point_in_fp2(modulus_len, &p_0)   
+}
+
+fn mul_point_on_curve_in_fp2<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        order_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let compressed = bytes.len() == point_encoding_length(2*modulus_len, true) + order_len;
+    let (p_0, rest) = decode_g2_point_in_fp2(bytes, modulus_len, curve, compressed)?;
+    let (scalar, rest) = decode_scalar_representation(rest, order_len)?;
+
+    if rest.len() != 0 {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
+
+    if !p_0.is_on_curve() {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
         }
+    }
 
-        if bases.len() != scalars.len() || bases.len() == 0 {
+    let p = p_0.mul(&scalar);
+
+    serialize_g2_point_in_fp2(modulus_len, &p)   
+}
+
+fn multiexp_on_curve_in_fp2<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        order_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let (num_pairs_encoding, rest) = split(bytes, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+    let num_pairs = num_pairs_encoding[0] as usize;
+
+    if num_pairs == 0 {
+        return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+    }
+
+    let compressed = rest.len() == (point_encoding_length(2*modulus_len, true) + order_len) * num_pairs;
+    let expected_pair_len = point_encoding_length(2*modulus_len, compressed) + order_len;
+    if rest.len() != expected_pair_len * num_pairs {
+        return Err(ApiError::InputError("Input length is invalid for number of pairs".to_owned()));
+    }
+
+    let mut global_rest = rest;
+    let mut bases = Vec::with_capacity(num_pairs);
+    let mut scalars = Vec::with_capacity(num_pairs);
+
+    for _ in 0..num_pairs {
+        let (p, local_rest) = decode_g2_point_in_fp2(global_rest, modulus_len, curve, compressed)?;
+        if !p.is_on_curve() {
             if !crate::features::in_fuzzing_or_gas_metering() {
-                return Err(ApiError::InputError(format!("Multiexp with empty input pairs, file {}, line {}", file!(), line!())));
-            } else {
-                let result = CurvePoint::zero(&curve);
-                return serialize_g2_point_in_fp2(modulus_len, &result);
+                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
             }
-        } 
-
-        let result = peppinger(&bases, scalars);
+        }
+        let (scalar, local_rest) = decode_scalar_representation(local_rest, order_len)?;
+        bases.push(p);
+        scalars.push(scalar);
+        global_rest = local_rest;
+    }
 
-        serialize_g2_point_in_fp2(modulus_len, &result)   
+    if global_rest.len() != 0 {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
     }
+
+    if bases.len() != scalars.len() || bases.len() == 0 {
+        if !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Multiexp with empty input pairs, file {}, line {}", file!(), line!())));
+        } else {
+            let result = CurvePoint::zero(curve);
+            return serialize_g2_point_in_fp2(modulus_len, &result);
+        }
+    } 
+
+    let result = peppinger(&bases, scalars);
+
+    serialize_g2_point_in_fp2(modulus_len, &result)   
 }
 
 pub struct G2ApiImplementationFp3<FE: ElementRepr> {
@@ -164,6 +288,27 @@ pub struct G2ApiImplementationFp3<FE: ElementRepr> {
 }
 
 impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
+    fn decompress_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(bytes)?;
+        let (extension_3, rest) = create_fp3_extension(rest, &modulus, modulus_len, &field, false)?;
+        let (a, b, rest) = parse_ab_in_fp3_from_encoding(rest, modulus_len, &extension_3)?;
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+
+        let fp3_params = CurveOverFp3Parameters::new(&extension_3);
+
+        let curve = WeierstrassCurve::new(order.as_ref(), a, b, &fp3_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        let (p, rest) = decode_g2_point_from_compressed_x_in_fp3(rest, modulus_len, &curve)?;
+
+        if !rest.is_empty() {
+            return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+        }
+
+        serialize_g2_point_in_fp3(modulus_len, &p)
+    }
+
     fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (extension_3, rest) = create_fp3_extension(rest, &modulus, modulus_len, &field, false)?;
@@ -176,8 +321,9 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (mut p_0, rest) = decode_g2_point_from_xy_in_fp3(rest, modulus_len, &curve)?;
-        let (p_1, rest) = decode_g2_point_from_xy_in_fp3(rest, modulus_len, &curve)?;
+        let compressed = rest.len() == 2 * point_encoding_length(3*modulus_len, true);
+        let (mut p_0, rest) = decode_g2_point_in_fp3(rest, modulus_len, &curve, compressed)?;
+        let (p_1, rest) = decode_g2_point_in_fp3(rest, modulus_len, &curve, compressed)?;
 
         if rest.len() != 0 {
             return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
@@ -211,7 +357,8 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
             ApiError::InputError("Curve shape is not supported".to_owned())
         })?;
 
-        let (p_0, rest) = decode_g2_point_from_xy_in_fp3(rest, modulus_len, &curve)?;
+        let compressed = rest.len() == point_encoding_length(3*modulus_len, true) + order_len;
+        let (p_0, rest) = decode_g2_point_in_fp3(rest, modulus_len, &curve, compressed)?;
         let (scalar, rest) = decode_scalar_representation(rest, order_len)?;
 
         if rest.len() != 0 {
@@ -248,7 +395,8 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
             return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
         }
 
-        let expected_pair_len = 6*modulus_len + order_len;
+        let compressed = rest.len() == (point_encoding_length(3*modulus_len, true) + order_len) * num_pairs;
+        let expected_pair_len = point_encoding_length(3*modulus_len, compressed) + order_len;
         if rest.len() != expected_pair_len * num_pairs {
             return Err(ApiError::InputError("Input length is invalid for number of pairs".to_owned()));
         }
@@ -258,7 +406,7 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
         let mut scalars = Vec::with_capacity(num_pairs);
 
         for _ in 0..num_pairs {
-            let (p, local_rest) = decode_g2_point_from_xy_in_fp3(global_rest, modulus_len, &curve)?;
+            let (p, local_rest) = decode_g2_point_in_fp3(global_rest, modulus_len, &curve, compressed)?;
             if !p.is_on_curve() {
                 if !crate::features::in_fuzzing_or_gas_metering() {
                     return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
@@ -292,6 +440,29 @@ impl<FE: ElementRepr> G2Api for G2ApiImplementationFp3<FE> {
 pub struct PublicG2Api;
 
 impl G2Api for PublicG2Api {
+    fn decompress_point(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (modulus, _, extension_degree, _, _) = parse_modulus_and_extension_degree(bytes)?;
+        let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+        let result: Result<Vec<u8>, ApiError> = match extension_degree {
+            EXTENSION_DEGREE_2 => {
+                let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, bytes, decompress_point);
+
+                result
+            },
+            EXTENSION_DEGREE_3 => {
+                let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp3, bytes, decompress_point);
+
+                result
+            },
+            _ => {
+                return Err(ApiError::InputError("Invalid extension degree".to_owned()));
+            }
+        };
+
+        result
+    }
+
     fn add_points(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         let (modulus, _, extension_degree, _, _) = parse_modulus_and_extension_degree(&bytes)?;
         let modulus_limbs = num_limbs_for_modulus(&modulus)?;
@@ -0,0 +1,273 @@
+//! Recognition of well-known curves in the generic ABI.
+//!
+//! If an encoded curve is exactly BLS12-381, BLS12-377 or BN254 then operations run
+//! on the precomputed static curves and pairing engines instead of deriving fields,
+//! Frobenius coefficients and the twist from the encoding. Only decoded values are compared,
+//! so any input that does not match (or fails to decode) goes through the generic path
+//! and gets the same validation and error messages as before.
+
+use crate::field::{SizedPrimeField, PrimeField, U384Repr};
+use crate::fp::Fp;
+use crate::extension_towers::fp2::Extension2;
+use crate::extension_towers::fp6_as_3_over_2::Extension3Over2;
+use crate::representation::ElementRepr;
+use crate::weierstrass::{CurveParameters, CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::weierstrass::curve::WeierstrassCurve;
+use crate::pairings::TwistType;
+use crate::pairings::bls12::Bls12Instance;
+use crate::integers::MaxFieldUint;
+
+use crate::engines::bls12_381::*;
+use crate::engines::bls12_377::*;
+
+use super::decode_utils::*;
+use super::decode_fp::*;
+use super::decode_g1::parse_group_order_from_encoding;
+use super::constants::*;
+use super::sane_limits::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum KnownCurve {
+    Bls12_381,
+    Bls12_377,
+    #[cfg(feature = "eip_196")]
+    Bn254,
+}
+
+pub(crate) type KnownBls12Engine = Bls12Instance<
+    'static,
+    U384Repr,
+    PrimeField<U384Repr>,
+    CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>,
+    CurveOverFp2Parameters<'static, U384Repr, PrimeField<U384Repr>>
+>;
+
+#[cfg(feature = "eip_196")]
+pub(crate) type KnownBnEngine = crate::pairings::bn::BnInstance<
+    'static,
+    crate::field::U256Repr,
+    PrimeField<crate::field::U256Repr>,
+    CurveOverFpParameters<'static, crate::field::U256Repr, PrimeField<crate::field::U256Repr>>,
+    CurveOverFp2Parameters<'static, crate::field::U256Repr, PrimeField<crate::field::U256Repr>>
+>;
+
+/// Calls `$func` with the static G1 curve of a known curve followed by other arguments
+macro_rules! expand_for_known_g1_curve {
+    ($known_curve: expr, $func: path, $($argument: expr),*) => {
+        match $known_curve {
+            $crate::public_interface::known_curves::KnownCurve::Bls12_381 => {
+                $func(&$crate::engines::bls12_381::BLS12_381_G1_CURVE, $($argument),*)
+            },
+            $crate::public_interface::known_curves::KnownCurve::Bls12_377 => {
+                $func(&$crate::engines::bls12_377::BLS12_377_G1_CURVE, $($argument),*)
+            },
+            #[cfg(feature = "eip_196")]
+            $crate::public_interface::known_curves::KnownCurve::Bn254 => {
+                $func(&$crate::engines::bn254::BN254_G1_CURVE, $($argument),*)
+            },
+        }
+    }
+}
+
+/// Calls `$func` with the static G2 curve of a known curve followed by other arguments
+macro_rules! expand_for_known_g2_curve {
+    ($known_curve: expr, $func: path, $($argument: expr),*) => {
+        match $known_curve {
+            $crate::public_interface::known_curves::KnownCurve::Bls12_381 => {
+                $func(&$crate::engines::bls12_381::BLS12_381_G2_CURVE, $($argument),*)
+            },
+            $crate::public_interface::known_curves::KnownCurve::Bls12_377 => {
+                $func(&$crate::engines::bls12_377::BLS12_377_G2_CURVE, $($argument),*)
+            },
+            #[cfg(feature = "eip_196")]
+            $crate::public_interface::known_curves::KnownCurve::Bn254 => {
+                $func(&$crate::engines::bn254::BN254_G2_CURVE, $($argument),*)
+            },
+        }
+    }
+}
+
+fn known_curve_for_modulus(modulus: &MaxFieldUint) -> Option<KnownCurve> {
+    if *modulus == BLS12_381_MODULUS_UINT {
+        return Some(KnownCurve::Bls12_381);
+    }
+    if *modulus == BLS12_377_MODULUS_UINT {
+        return Some(KnownCurve::Bls12_377);
+    }
+    #[cfg(feature = "eip_196")]
+    {
+        if *modulus == crate::engines::bn254::BN254_MODULUS {
+            return Some(KnownCurve::Bn254);
+        }
+    }
+
+    None
+}
+
+fn trim_high_zero_limbs(limbs: &[u64]) -> &[u64] {
+    let len = limbs.iter().rposition(|limb| *limb != 0).map(|i| i + 1).unwrap_or(0);
+
+    &limbs[..len]
+}
+
+/// Group order and loop parameters can be encoded with leading zero bytes,
+/// so limbs are compared as integers
+fn is_same_integer(a: &[u64], b: &[u64]) -> bool {
+    trim_high_zero_limbs(a) == trim_high_zero_limbs(b)
+}
+
+/// Decodes A, B and the group order and checks that they match the curve.
+/// Returns the encoded length of the group order (scalars are encoded with it)
+fn match_curve_over_fp<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        bytes: &'b [u8]
+    ) -> Option<(usize, &'b [u8])>
+{
+    let (a, rest) = decode_fp(bytes, modulus_len, curve.params.params()).ok()?;
+    let (b, rest) = decode_fp(rest, modulus_len, curve.params.params()).ok()?;
+    let (order_len, order, rest) = parse_group_order_from_encoding(rest).ok()?;
+
+    if a != curve.a || b != curve.b || !is_same_integer(order.as_ref(), curve.subgroup_order_repr) {
+        return None;
+    }
+
+    Some((order_len, rest))
+}
+
+/// Same as `match_curve_over_fp`, but also checks the extension degree and Fp2 non-residue
+fn match_curve_over_fp2<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = crate::extension_towers::fp2::Fp2<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        bytes: &'b [u8]
+    ) -> Option<(usize, &'b [u8])>
+{
+    let extension_2 = curve.params.params();
+    let (extension_degree, rest) = split(bytes, EXTENSION_DEGREE_ENCODING_LENGTH, "Input is not long enough to get extension degree").ok()?;
+    if extension_degree[0] != EXTENSION_DEGREE_2 {
+        return None;
+    }
+    let (fp_non_residue, rest) = decode_fp(rest, modulus_len, extension_2.field).ok()?;
+    let (a, rest) = decode_fp2(rest, modulus_len, extension_2).ok()?;
+    let (b, rest) = decode_fp2(rest, modulus_len, extension_2).ok()?;
+    let (order_len, order, rest) = parse_group_order_from_encoding(rest).ok()?;
+
+    if fp_non_residue != extension_2.non_residue || a != curve.a || b != curve.b || !is_same_integer(order.as_ref(), curve.subgroup_order_repr) {
+        return None;
+    }
+
+    Some((order_len, rest))
+}
+
+/// Decodes non-residues for Fp2 and Fp6 and the twist type of BLS12 and BN curves
+fn match_tower<
+    'a,
+    'b,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>
+    >
+    (
+        fp2_extension: &'a Extension2<'a, FE, F>,
+        fp6_extension: &'a Extension3Over2<'a, FE, F>,
+        twist_type: TwistType,
+        modulus_len: usize,
+        bytes: &'b [u8]
+    ) -> Option<&'b [u8]>
+{
+    let (fp_non_residue, rest) = decode_fp(bytes, modulus_len, fp2_extension.field).ok()?;
+    let (fp2_non_residue, rest) = decode_fp2(rest, modulus_len, fp2_extension).ok()?;
+    let (encoded_twist_type, rest) = decode_twist_type(rest).ok()?;
+
+    if fp_non_residue != fp2_extension.non_residue || fp2_non_residue != fp6_extension.non_residue || encoded_twist_type != twist_type {
+        return None;
+    }
+
+    Some(rest)
+}
+
+fn match_loop_parameter<'b>(
+    parameter: &[u64],
+    is_negative: bool,
+    max_bits: usize,
+    bytes: &'b [u8]
+) -> Option<&'b [u8]> {
+    let (encoded_parameter, rest) = decode_loop_parameter_scalar_with_bit_limit(bytes, max_bits).ok()?;
+    let (encoded_is_negative, rest) = decode_sign_is_negative(rest).ok()?;
+
+    if !is_same_integer(encoded_parameter.as_ref(), parameter) || encoded_is_negative != is_negative {
+        return None;
+    }
+
+    Some(rest)
+}
+
+/// Recognizes the curve in G1 operations encoding. Returns a curve, modulus length,
+/// group order length and the rest of the input after the curve parameters
+pub(crate) fn recognize_g1_curve(bytes: &[u8]) -> Option<(KnownCurve, usize, usize, &[u8])> {
+    let ((modulus, modulus_len), rest) = get_base_field_params(bytes).ok()?;
+    let known_curve = known_curve_for_modulus(&modulus)?;
+    let (order_len, rest) = expand_for_known_g1_curve!(known_curve, match_curve_over_fp, modulus_len, rest)?;
+
+    Some((known_curve, modulus_len, order_len, rest))
+}
+
+/// Recognizes the curve in G2 (over Fp2) operations encoding. Returns a curve, modulus length,
+/// group order length and the rest of the input after the curve parameters
+pub(crate) fn recognize_g2_curve_in_fp2(bytes: &[u8]) -> Option<(KnownCurve, usize, usize, &[u8])> {
+    let ((modulus, modulus_len), rest) = get_base_field_params(bytes).ok()?;
+    let known_curve = known_curve_for_modulus(&modulus)?;
+    let (order_len, rest) = expand_for_known_g2_curve!(known_curve, match_curve_over_fp2, modulus_len, rest)?;
+
+    Some((known_curve, modulus_len, order_len, rest))
+}
+
+/// Recognizes the curve in BLS12 pairing encoding (without a curve type).
+/// Returns an engine, modulus length and encoded pairs
+pub(crate) fn recognize_bls12_engine(bytes: &[u8]) -> Option<(KnownBls12Engine, usize, &[u8])> {
+    let ((modulus, modulus_len), rest) = get_base_field_params(bytes).ok()?;
+    let engine = match known_curve_for_modulus(&modulus)? {
+        KnownCurve::Bls12_381 => BLS12_381_PAIRING_ENGINE,
+        KnownCurve::Bls12_377 => BLS12_377_PAIRING_ENGINE,
+        #[cfg(feature = "eip_196")]
+        KnownCurve::Bn254 => {
+            return None;
+        },
+    };
+
+    let (_, rest) = match_curve_over_fp(engine.curve, modulus_len, rest)?;
+    let rest = match_tower(engine.fp2_extension, engine.fp6_extension, engine.twist_type, modulus_len, rest)?;
+    let rest = match_loop_parameter(engine.x, engine.x_is_negative, MAX_BLS12_X_BIT_LENGTH, rest)?;
+
+    Some((engine, modulus_len, rest))
+}
+
+/// Recognizes the curve in BN pairing encoding (without a curve type).
+/// Returns an engine, modulus length and encoded pairs
+#[cfg(feature = "eip_196")]
+pub(crate) fn recognize_bn_engine(bytes: &[u8]) -> Option<(KnownBnEngine, usize, &[u8])> {
+    let ((modulus, modulus_len), rest) = get_base_field_params(bytes).ok()?;
+    if known_curve_for_modulus(&modulus)? != KnownCurve::Bn254 {
+        return None;
+    }
+    let engine = crate::engines::bn254::BN254_PAIRING_ENGINE;
+
+    let (_, rest) = match_curve_over_fp(engine.curve, modulus_len, rest)?;
+    let rest = match_tower(engine.fp2_extension, engine.fp6_extension, engine.twist_type, modulus_len, rest)?;
+    let rest = match_loop_parameter(engine.u, engine.u_is_negative, MAX_BN_U_BIT_LENGTH, rest)?;
+
+    Some((engine, modulus_len, rest))
+}
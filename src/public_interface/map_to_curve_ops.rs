@@ -0,0 +1,267 @@
+//! Generic map-to-curve with simplified SWU and an optional isogeny map.
+//!
+//! Every call starts with the common curve parameters of `G1Api` or `G2Api`
+//! (only quadratic extension is supported for G2), followed by:
+//! - SWU Z
+//! - Isogeny flag (boolean)
+//! - If isogeny is used:
+//!   - A of the isogenous curve that simplified SWU is applied to
+//!   - B of the isogenous curve that simplified SWU is applied to
+//!   - Isogeny map degree (one byte)
+//!   - x numerator, x denominator, y numerator and y denominator coefficients,
+//!     `degree + 1` each, starting from the lowest power
+//! - Field element to map
+//!
+//! Output is a point on the encoded curve, the cofactor is not cleared
+
+use crate::weierstrass::{CurveParameters, CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::weierstrass::curve::{CurvePoint, WeierstrassCurve};
+use crate::representation::ElementRepr;
+use crate::fp::Fp;
+use crate::extension_towers::fp2::Fp2;
+use crate::traits::FieldElement;
+use crate::field::*;
+use crate::mapping::simple_swu::*;
+use crate::mapping::isogeny::*;
+use super::constants::*;
+use super::sane_limits::*;
+
+use super::decode_g1::*;
+use super::decode_g2::*;
+use super::decode_utils::*;
+use super::decode_fp::*;
+
+use crate::errors::ApiError;
+
+pub trait MapToCurveApi {
+    fn map_to_g1(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
+    fn map_to_g2(bytes: &[u8]) -> Result<Vec<u8>, ApiError>;
+}
+
+pub struct MapToCurveApiImplementation<FE: ElementRepr> {
+    _marker_fe: std::marker::PhantomData<FE>,
+}
+
+impl<FE: ElementRepr> MapToCurveApi for MapToCurveApiImplementation<FE> {
+    fn map_to_g1(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(bytes)?;
+        check_modulus_two_adicity(&modulus)?;
+        let (a, b, rest) = parse_ab_in_base_field_from_encoding(rest, modulus_len, &field)?;
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+
+        let fp_params = CurveOverFpParameters::new(&field);
+
+        let curve = WeierstrassCurve::new(order.as_ref(), a, b, &fp_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        map_to_curve_in_fp(&curve, modulus_len, rest)
+    }
+
+    fn map_to_g2(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(bytes)?;
+        check_modulus_two_adicity(&modulus)?;
+        let (extension_2, rest) = create_fp2_extension(rest, &modulus, modulus_len, &field, false)?;
+        let (a, b, rest) = parse_ab_in_fp2_from_encoding(rest, modulus_len, &extension_2)?;
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+
+        let fp2_params = CurveOverFp2Parameters::new(&extension_2);
+
+        let curve = WeierstrassCurve::new(order.as_ref(), a, b, &fp2_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        map_to_curve_in_fp2(&curve, modulus_len, rest)
+    }
+}
+
+type DecodedIsogeny<'a, 'b, C> = (Option<(WeierstrassCurve<'a, C>, IsogenyParameters<C>)>, &'b [u8]);
+
+/// Decodes the isogeny flag and, if it's set, the isogenous curve and the isogeny map
+fn decode_isogeny<
+    'a,
+    'b,
+    C: CurveParameters,
+    D: Fn(&'b [u8]) -> Result<(C::BaseFieldElement, &'b [u8]), ApiError>
+    >
+    (
+        bytes: &'b [u8],
+        curve: &WeierstrassCurve<'a, C>,
+        decode_element: D
+    ) -> Result<DecodedIsogeny<'a, 'b, C>, ApiError>
+{
+    let (use_isogeny, rest) = decode_boolean(bytes)?;
+    if !use_isogeny {
+        return Ok((None, rest));
+    }
+
+    let (a, rest) = decode_element(rest)?;
+    let (b, rest) = decode_element(rest)?;
+    if a.is_zero() || b.is_zero() {
+        return Err(ApiError::InputError("Simplified SWU requires isogenous curve with non-zero A and B".to_owned()));
+    }
+
+    let swu_curve = WeierstrassCurve::new(curve.subgroup_order_repr, a, b, curve.params).map_err(|_| {
+        ApiError::InputError("Isogenous curve shape is not supported".to_owned())
+    })?;
+
+    let (map_degree_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get isogeny map degree")?;
+    let map_degree = map_degree_encoding[0] as usize;
+    if map_degree == 0 {
+        return Err(ApiError::InputError("Isogeny map degree can not be zero".to_owned()));
+    }
+    if map_degree > MAX_ISOGENY_MAP_DEGREE {
+        return Err(ApiError::InputError(format!("Isogeny map degree is too large, file {}, line {}", file!(), line!())));
+    }
+
+    let mut global_rest = rest;
+    let mut coefficients = Vec::with_capacity(4);
+    for _ in 0..4 {
+        let mut k = Vec::with_capacity(map_degree + 1);
+        for _ in 0..=map_degree {
+            let (c, local_rest) = decode_element(global_rest)?;
+            k.push(c);
+            global_rest = local_rest;
+        }
+        coefficients.push(k);
+    }
+
+    let k4 = coefficients.pop().expect("is present");
+    let k3 = coefficients.pop().expect("is present");
+    let k2 = coefficients.pop().expect("is present");
+    let k1 = coefficients.pop().expect("is present");
+
+    if k2.iter().all(|c| c.is_zero()) || k4.iter().all(|c| c.is_zero()) {
+        return Err(ApiError::InputError("Isogeny map denominator can not be zero".to_owned()));
+    }
+
+    let isogeny = IsogenyParameters::<C> {
+        map_degree,
+        k1,
+        k2,
+        k3,
+        k4
+    };
+
+    Ok((Some((swu_curve, isogeny)), global_rest))
+}
+
+/// Applies the isogeny if any and checks that the result is on the curve, so
+/// invalid isogeny coefficients are rejected
+fn finalize_mapping<'a, C: CurveParameters>(
+    x: C::BaseFieldElement,
+    y: C::BaseFieldElement,
+    isogeny: Option<&IsogenyParameters<C>>,
+    curve: &'a WeierstrassCurve<'a, C>
+) -> Result<CurvePoint<'a, C>, ApiError> {
+    let (x, y) = match isogeny {
+        Some(isogeny) => apply_isogeny_map(&x, &y, isogeny, curve.params),
+        None => (x, y)
+    };
+
+    // zero denominator of the isogeny map gives (0, 0) that is the point at infinity
+    let p = CurvePoint::point_from_xy(curve, x, y);
+    if !p.is_on_curve() {
+        return Err(ApiError::InputError(format!("Mapped point is not on curve, isogeny map is invalid, file {}, line {}", file!(), line!())));
+    }
+
+    Ok(p)
+}
+
+fn map_to_curve_in_fp<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let field = curve.params.params();
+    let (z, rest) = decode_fp(bytes, modulus_len, field)?;
+    let (isogeny, rest) = decode_isogeny(rest, curve, |b| decode_fp(b, modulus_len, field))?;
+    let (u, rest) = decode_fp(rest, modulus_len, field)?;
+
+    if !rest.is_empty() {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
+
+    let (swu_curve, isogeny) = match isogeny.as_ref() {
+        Some((swu_curve, isogeny)) => (swu_curve, Some(isogeny)),
+        None => (curve, None)
+    };
+
+    let swu = swu_parameters_fp(&z, swu_curve).ok_or_else(|| {
+        ApiError::InputError(format!("SWU Z is not suitable for the curve, file {}, line {}", file!(), line!()))
+    })?;
+
+    let (x, y) = simplified_swu_fp(&u, &swu, swu_curve).ok_or_else(|| {
+        ApiError::InputError(format!("Mapped value has no square root, modulus is not a prime, file {}, line {}", file!(), line!()))
+    })?;
+    let p = finalize_mapping(x, y, isogeny, curve)?;
+
+    serialize_g1_point(modulus_len, &p)
+}
+
+fn map_to_curve_in_fp2<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    C: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    >
+    (
+        curve: &'a WeierstrassCurve<'a, C>,
+        modulus_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let extension = curve.params.params();
+    let (z, rest) = decode_fp2(bytes, modulus_len, extension)?;
+    let (isogeny, rest) = decode_isogeny(rest, curve, |b| decode_fp2(b, modulus_len, extension))?;
+    let (u, rest) = decode_fp2(rest, modulus_len, extension)?;
+
+    if !rest.is_empty() {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
+
+    let (swu_curve, isogeny) = match isogeny.as_ref() {
+        Some((swu_curve, isogeny)) => (swu_curve, Some(isogeny)),
+        None => (curve, None)
+    };
+
+    let swu = swu_parameters_fp2(&z, swu_curve).ok_or_else(|| {
+        ApiError::InputError(format!("SWU Z is not suitable for the curve, file {}, line {}", file!(), line!()))
+    })?;
+
+    let (x, y) = simplified_swu_fp2(&u, &swu, swu_curve).ok_or_else(|| {
+        ApiError::InputError(format!("Mapped value has no square root, modulus is not a prime, file {}, line {}", file!(), line!()))
+    })?;
+    let p = finalize_mapping(x, y, isogeny, curve)?;
+
+    serialize_g2_point_in_fp2(modulus_len, &p)
+}
+
+pub struct PublicMapToCurveApi;
+
+impl MapToCurveApi for PublicMapToCurveApi {
+    fn map_to_g1(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (_, modulus, _) = parse_modulus_and_length(bytes)?;
+        let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+        let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, MapToCurveApiImplementation, bytes, map_to_g1);
+
+        result
+    }
+
+    fn map_to_g2(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        let (_, modulus, _) = parse_modulus_and_length(bytes)?;
+        let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+        let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, MapToCurveApiImplementation, bytes, map_to_g2);
+
+        result
+    }
+}
@@ -0,0 +1,194 @@
+// For C style API caller has to preallocate some buffers for results
+pub const MNT_753_PREALLOCATE_FOR_ERROR_BYTES: usize = 256;
+pub const MNT_753_PREALLOCATE_FOR_RESULT_BYTES: usize = 96 * 3 * 2; // maximum for MNT6 G2 point
+
+use static_assertions::const_assert;
+const_assert!(MNT_753_PREALLOCATE_FOR_RESULT_BYTES == super::SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH);
+const_assert!(MNT_753_PREALLOCATE_FOR_RESULT_BYTES >= super::SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH);
+
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mnt753OperationType {
+    MNT_G1ADD = 1,
+    MNT_G1MUL = 2,
+    MNT_G1MULTIEXP = 3,
+    MNT_G2ADD = 4,
+    MNT_G2MUL = 5,
+    MNT_G2MULTIEXP = 6,
+    MNT_PAIR = 7,
+}
+
+impl Mnt753OperationType {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            MNT_G1ADD_OPERATION_RAW_VALUE => {
+                Some(Mnt753OperationType::MNT_G1ADD)
+            },
+            MNT_G1MUL_OPERATION_RAW_VALUE => {
+                Some(Mnt753OperationType::MNT_G1MUL)
+            },
+            MNT_G1MULTIEXP_OPERATION_RAW_VALUE => {
+                Some(Mnt753OperationType::MNT_G1MULTIEXP)
+            },
+            MNT_G2ADD_OPERATION_RAW_VALUE => {
+                Some(Mnt753OperationType::MNT_G2ADD)
+            },
+            MNT_G2MUL_OPERATION_RAW_VALUE => {
+                Some(Mnt753OperationType::MNT_G2MUL)
+            },
+            MNT_G2MULTIEXP_OPERATION_RAW_VALUE => {
+                Some(Mnt753OperationType::MNT_G2MULTIEXP)
+            },
+            MNT_PAIR_OPERATION_RAW_VALUE => {
+                Some(Mnt753OperationType::MNT_PAIR)
+            },
+            _ => {
+                None
+            }
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+pub const MNT_G1ADD_OPERATION_RAW_VALUE: u8 = Mnt753OperationType::MNT_G1ADD as u8;
+pub const MNT_G1MUL_OPERATION_RAW_VALUE: u8 = Mnt753OperationType::MNT_G1MUL as u8;
+pub const MNT_G1MULTIEXP_OPERATION_RAW_VALUE: u8 = Mnt753OperationType::MNT_G1MULTIEXP as u8;
+
+pub const MNT_G2ADD_OPERATION_RAW_VALUE: u8 = Mnt753OperationType::MNT_G2ADD as u8;
+pub const MNT_G2MUL_OPERATION_RAW_VALUE: u8 = Mnt753OperationType::MNT_G2MUL as u8;
+pub const MNT_G2MULTIEXP_OPERATION_RAW_VALUE: u8 = Mnt753OperationType::MNT_G2MULTIEXP as u8;
+
+pub const MNT_PAIR_OPERATION_RAW_VALUE: u8 = Mnt753OperationType::MNT_PAIR as u8;
+
+fn write_result(
+    result: Result<Vec<u8>, crate::public_interface::ApiError>,
+    o: *mut ::std::os::raw::c_char,
+    o_len: *mut u32,
+    err: *mut ::std::os::raw::c_char,
+    char_len: *mut u32) -> u32
+{
+    use std::io::Write;
+
+    let err_out_i8: &mut [i8] = unsafe { std::slice::from_raw_parts_mut(err, MNT_753_PREALLOCATE_FOR_ERROR_BYTES) };
+    let mut err_out: &mut [u8] = unsafe { &mut *(err_out_i8 as *mut [i8] as *mut [u8]) };
+
+    let raw_out_i8: &mut [i8] = unsafe { std::slice::from_raw_parts_mut(o, MNT_753_PREALLOCATE_FOR_RESULT_BYTES) };
+    let mut raw_out: &mut [u8] = unsafe { &mut *(raw_out_i8 as *mut [i8] as *mut [u8]) };
+
+    match result {
+        Ok(result) => {
+            let written = raw_out.write(result.as_ref());
+            if let Ok(bytes_written) = written {
+                unsafe { *o_len = bytes_written as u32 };
+                return 0u32;
+            }
+
+            let written = err_out.write(b"Failed to write the result\0");
+            if let Ok(bytes_written) = written {
+                unsafe { *char_len = bytes_written as u32 };
+            } else {
+                unsafe { *char_len = 0u32 };
+            }
+
+            1u32
+        },
+        Err(error) => {
+            let err_description = error.to_string();
+            let written = err_out.write(err_description.as_bytes());
+            if let Ok(bytes_written) = written {
+                unsafe { *char_len = bytes_written as u32 };
+            } else {
+                unsafe { *char_len = 0u32 };
+            }
+
+            1u32
+        }
+    }
+}
+
+fn write_unknown_operation(
+    err: *mut ::std::os::raw::c_char,
+    char_len: *mut u32) -> u32
+{
+    use std::io::Write;
+
+    let err_out_i8: &mut [i8] = unsafe { std::slice::from_raw_parts_mut(err, MNT_753_PREALLOCATE_FOR_ERROR_BYTES) };
+    let mut err_out: &mut [u8] = unsafe { &mut *(err_out_i8 as *mut [i8] as *mut [u8]) };
+
+    let written = err_out.write(b"Unknown operation type\0");
+    if let Ok(bytes_written) = written {
+        unsafe { *char_len = bytes_written as u32 };
+    } else {
+        unsafe { *char_len = 0u32 };
+    }
+
+    1u32
+}
+
+// this is C interface for MNT4-753
+#[no_mangle]
+pub extern "C" fn mnt4_753_perform_operation(
+    op: ::std::os::raw::c_char,
+    i: *const ::std::os::raw::c_char,
+    i_len: u32,
+    o: *mut ::std::os::raw::c_char,
+    o_len: *mut u32,
+    err: *mut ::std::os::raw::c_char,
+    char_len: *mut u32) -> u32
+{
+    let operation = match Mnt753OperationType::from_u8(op as u8) {
+        Some(operation) => operation,
+        None => return write_unknown_operation(err, char_len)
+    };
+
+    let input_i8: &[i8] = unsafe { std::slice::from_raw_parts(i, i_len as usize) };
+    let input: &[u8] = unsafe { &*(input_i8 as *const [i8] as *const [u8]) };
+
+    let result = match operation {
+        Mnt753OperationType::MNT_G1ADD => super::MNT4753Executor::g1_add(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G1MUL => super::MNT4753Executor::g1_mul(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G1MULTIEXP => super::MNT4753Executor::g1_multiexp(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G2ADD => super::MNT4753Executor::g2_add(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G2MUL => super::MNT4753Executor::g2_mul(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G2MULTIEXP => super::MNT4753Executor::g2_multiexp(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_PAIR => super::MNT4753Executor::pair(input).map(|r| r[..].to_vec()),
+    };
+
+    write_result(result, o, o_len, err, char_len)
+}
+
+// this is C interface for MNT6-753
+#[no_mangle]
+pub extern "C" fn mnt6_753_perform_operation(
+    op: ::std::os::raw::c_char,
+    i: *const ::std::os::raw::c_char,
+    i_len: u32,
+    o: *mut ::std::os::raw::c_char,
+    o_len: *mut u32,
+    err: *mut ::std::os::raw::c_char,
+    char_len: *mut u32) -> u32
+{
+    let operation = match Mnt753OperationType::from_u8(op as u8) {
+        Some(operation) => operation,
+        None => return write_unknown_operation(err, char_len)
+    };
+
+    let input_i8: &[i8] = unsafe { std::slice::from_raw_parts(i, i_len as usize) };
+    let input: &[u8] = unsafe { &*(input_i8 as *const [i8] as *const [u8]) };
+
+    let result = match operation {
+        Mnt753OperationType::MNT_G1ADD => super::MNT6753Executor::g1_add(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G1MUL => super::MNT6753Executor::g1_mul(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G1MULTIEXP => super::MNT6753Executor::g1_multiexp(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G2ADD => super::MNT6753Executor::g2_add(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G2MUL => super::MNT6753Executor::g2_mul(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_G2MULTIEXP => super::MNT6753Executor::g2_multiexp(input).map(|r| r[..].to_vec()),
+        Mnt753OperationType::MNT_PAIR => super::MNT6753Executor::pair(input).map(|r| r[..].to_vec()),
+    };
+
+    write_result(result, o, o_len, err, char_len)
+}
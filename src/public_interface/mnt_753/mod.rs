@@ -0,0 +1,734 @@
+pub struct MNT4753Executor;
+pub struct MNT6753Executor;
+
+use crate::engines::mnt4_753;
+use crate::engines::mnt6_753;
+use crate::public_interface::ApiError;
+
+pub const SCALAR_BYTE_LENGTH: usize = 96;
+
+pub const SERIALIZED_FP_BYTE_LENGTH: usize = 96;
+pub const SERIALIZED_G1_POINT_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 2;
+
+pub const SERIALIZED_FP2_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 2;
+pub const SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH: usize = SERIALIZED_FP2_BYTE_LENGTH * 2;
+
+pub const SERIALIZED_FP3_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 3;
+pub const SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH: usize = SERIALIZED_FP3_BYTE_LENGTH * 2;
+
+pub const SERIALIZED_PAIRING_RESULT_BYTE_LENGTH: usize = 32;
+
+use crate::public_interface::decode_g1;
+use crate::public_interface::decode_g2;
+
+use crate::weierstrass::Group;
+use crate::multiexp::peppinger;
+use crate::pairings::PairingEngine;
+
+#[cfg(feature = "mnt_753_c_api")]
+pub mod c_api;
+
+fn pairing_result_false() -> [u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH] {
+    [0u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH]
+}
+
+fn pairing_result_true() -> [u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH] {
+    let mut res = [0u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH];
+    res[31] = 1u8;
+
+    res
+}
+
+impl MNT4753Executor {
+    pub fn g1_add(input: &[u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH * 2 {
+            return Err(ApiError::InputError("invalid input length for G1 addition".to_owned()));
+        }
+
+        let (mut p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G1_CURVE)?;
+        let (p_1, _) = decode_g1::decode_g1_point_from_xy_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G1_CURVE)?;
+
+        if !p_0.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+        }
+        if !p_1.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+        }
+
+        p_0.add_assign(&p_1);
+
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p_0)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g1_mul(input: &[u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for G1 multiplication".to_owned()));
+        }
+
+        let (p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G1_CURVE)?;
+        let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
+
+        if !p_0.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+        }
+
+        let p = p_0.mul(scalar);
+
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g1_multiexp(input: &[u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for G1 multiexp".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        let mut global_rest = input;
+        let mut bases = Vec::with_capacity(num_pairs);
+        let mut scalars = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (p, local_rest) = decode_g1::decode_g1_point_from_xy_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G1_CURVE)?;
+            let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
+            if !p.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+            }
+            bases.push(p);
+            scalars.push(scalar);
+            global_rest = local_rest;
+        }
+
+        let result = peppinger(&bases, scalars);
+
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &result)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g2_add(input: &[u8]) -> Result<[u8; SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH * 2 {
+            return Err(ApiError::InputError("invalid input length for G2 addition".to_owned()));
+        }
+
+        let (mut p_0, rest) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G2_CURVE)?;
+        let (p_1, _) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G2_CURVE)?;
+
+        if !p_0.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+        }
+        if !p_1.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+        }
+
+        p_0.add_assign(&p_1);
+
+        let mut output = [0u8; SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &p_0)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g2_mul(input: &[u8]) -> Result<[u8; SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for G2 multiplication".to_owned()));
+        }
+
+        let (p_0, rest) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G2_CURVE)?;
+        let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
+
+        if !p_0.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+        }
+
+        let p = p_0.mul(scalar);
+
+        let mut output = [0u8; SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &p)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g2_multiexp(input: &[u8]) -> Result<[u8; SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for G2 multiexp".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        let mut global_rest = input;
+        let mut bases = Vec::with_capacity(num_pairs);
+        let mut scalars = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (p, local_rest) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G2_CURVE)?;
+            let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
+            if !p.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+            }
+            bases.push(p);
+            scalars.push(scalar);
+            global_rest = local_rest;
+        }
+
+        let result = peppinger(&bases, scalars);
+
+        let mut output = [0u8; SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &result)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn pair(input: &[u8]) -> Result<[u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for pairing".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_MNT4_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        let mut global_rest = input;
+
+        let mut g1_points = Vec::with_capacity(num_pairs);
+        let mut g2_points = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (g1, rest) = decode_g1::decode_g1_point_from_xy_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G1_CURVE)?;
+            let (g2, rest) = decode_g2::decode_g2_point_from_xy_in_fp2_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &mnt4_753::MNT4_753_G2_CURVE)?;
+
+            global_rest = rest;
+
+            if !g1.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError("G1 point is not on curve".to_owned()));
+            }
+
+            if !g2.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError("G2 point is not on curve".to_owned()));
+            }
+
+            // G1 has a cofactor of one, so only G2 requires a subgroup check
+            if !g2.wnaf_mul_with_window_size(&mnt4_753::MNT4_753_SUBGROUP_ORDER[..], 5).is_zero() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError("G2 point is not in the expected subgroup".to_owned()));
+            }
+
+            if !g1.is_zero() && !g2.is_zero() {
+                g1_points.push(g1);
+                g2_points.push(g2);
+            }
+        }
+
+        debug_assert!(g1_points.len() == g2_points.len());
+
+        if g1_points.is_empty() {
+            return Ok(pairing_result_true());
+        }
+
+        // every G2 point passed the subgroup check above
+        let engine = mnt4_753::MNT4_753_PAIRING_ENGINE.for_subgroup_twist_points();
+
+        let pairing_result = engine.pair(&g1_points, &g2_points);
+
+        if pairing_result.is_none() {
+            return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
+        }
+
+        use crate::extension_towers::fp4_as_2_over_2::Fp4;
+        use crate::traits::ZeroAndOne;
+
+        let one_fp4 = Fp4::one(&mnt4_753::MNT4_753_EXTENSION_4_FIELD);
+        let pairing_result = pairing_result.unwrap();
+        let result = if pairing_result == one_fp4 {
+            pairing_result_true()
+        } else {
+            pairing_result_false()
+        };
+
+        Ok(result)
+    }
+}
+
+impl MNT6753Executor {
+    pub fn g1_add(input: &[u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH * 2 {
+            return Err(ApiError::InputError("invalid input length for G1 addition".to_owned()));
+        }
+
+        let (mut p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G1_CURVE)?;
+        let (p_1, _) = decode_g1::decode_g1_point_from_xy_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G1_CURVE)?;
+
+        if !p_0.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+        }
+        if !p_1.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+        }
+
+        p_0.add_assign(&p_1);
+
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p_0)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g1_mul(input: &[u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for G1 multiplication".to_owned()));
+        }
+
+        let (p_0, rest) = decode_g1::decode_g1_point_from_xy_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G1_CURVE)?;
+        let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
+
+        if !p_0.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+        }
+
+        let p = p_0.mul(scalar);
+
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g1_multiexp(input: &[u8]) -> Result<[u8; SERIALIZED_G1_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for G1 multiexp".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_G1_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        let mut global_rest = input;
+        let mut bases = Vec::with_capacity(num_pairs);
+        let mut scalars = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (p, local_rest) = decode_g1::decode_g1_point_from_xy_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G1_CURVE)?;
+            let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
+            if !p.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+            }
+            bases.push(p);
+            scalars.push(scalar);
+            global_rest = local_rest;
+        }
+
+        let result = peppinger(&bases, scalars);
+
+        let mut output = [0u8; SERIALIZED_G1_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &result)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g2_add(input: &[u8]) -> Result<[u8; SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH * 2 {
+            return Err(ApiError::InputError("invalid input length for G2 addition".to_owned()));
+        }
+
+        let (mut p_0, rest) = decode_g2::decode_g2_point_from_xy_in_fp3_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G2_CURVE)?;
+        let (p_1, _) = decode_g2::decode_g2_point_from_xy_in_fp3_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G2_CURVE)?;
+
+        if !p_0.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 0 is not on curve, file {}, line {}", file!(), line!())));
+        }
+        if !p_1.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point 1 is not on curve, file {}, line {}", file!(), line!())));
+        }
+
+        p_0.add_assign(&p_1);
+
+        let mut output = [0u8; SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g2::serialize_g2_point_in_fp3(SERIALIZED_FP_BYTE_LENGTH, &p_0)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g2_mul(input: &[u8]) -> Result<[u8; SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() != SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH {
+            return Err(ApiError::InputError("invalid input length for G2 multiplication".to_owned()));
+        }
+
+        let (p_0, rest) = decode_g2::decode_g2_point_from_xy_in_fp3_oversized(input, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G2_CURVE)?;
+        let (scalar, _) = decode_g1::decode_scalar_representation(rest, SCALAR_BYTE_LENGTH)?;
+
+        if !p_0.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+        }
+
+        let p = p_0.mul(scalar);
+
+        let mut output = [0u8; SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g2::serialize_g2_point_in_fp3(SERIALIZED_FP_BYTE_LENGTH, &p)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn g2_multiexp(input: &[u8]) -> Result<[u8; SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for G2 multiexp".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH + SCALAR_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        let mut global_rest = input;
+        let mut bases = Vec::with_capacity(num_pairs);
+        let mut scalars = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (p, local_rest) = decode_g2::decode_g2_point_from_xy_in_fp3_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G2_CURVE)?;
+            let (scalar, local_rest) = decode_g1::decode_scalar_representation(local_rest, SCALAR_BYTE_LENGTH)?;
+            if !p.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError(format!("Point is not on curve, file {}, line {}", file!(), line!())));
+            }
+            bases.push(p);
+            scalars.push(scalar);
+            global_rest = local_rest;
+        }
+
+        let result = peppinger(&bases, scalars);
+
+        let mut output = [0u8; SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH];
+
+        let as_vec = decode_g2::serialize_g2_point_in_fp3(SERIALIZED_FP_BYTE_LENGTH, &result)?;
+
+        output.copy_from_slice(&as_vec[..]);
+
+        Ok(output)
+    }
+
+    pub fn pair(input: &[u8]) -> Result<[u8; SERIALIZED_PAIRING_RESULT_BYTE_LENGTH], ApiError> {
+        if input.len() % (SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH) != 0 {
+            return Err(ApiError::InputError("invalid input length for pairing".to_owned()));
+        }
+        let num_pairs = input.len() / (SERIALIZED_MNT6_G2_POINT_BYTE_LENGTH + SERIALIZED_G1_POINT_BYTE_LENGTH);
+
+        if num_pairs == 0 {
+            return Err(ApiError::InputError("Invalid number of pairs".to_owned()));
+        }
+
+        let mut global_rest = input;
+
+        let mut g1_points = Vec::with_capacity(num_pairs);
+        let mut g2_points = Vec::with_capacity(num_pairs);
+
+        for _ in 0..num_pairs {
+            let (g1, rest) = decode_g1::decode_g1_point_from_xy_oversized(global_rest, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G1_CURVE)?;
+            let (g2, rest) = decode_g2::decode_g2_point_from_xy_in_fp3_oversized(rest, SERIALIZED_FP_BYTE_LENGTH, &mnt6_753::MNT6_753_G2_CURVE)?;
+
+            global_rest = rest;
+
+            if !g1.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError("G1 point is not on curve".to_owned()));
+            }
+
+            if !g2.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError("G2 point is not on curve".to_owned()));
+            }
+
+            // G1 has a cofactor of one, so only G2 requires a subgroup check
+            if !g2.wnaf_mul_with_window_size(&mnt6_753::MNT6_753_SUBGROUP_ORDER[..], 5).is_zero() && !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError("G2 point is not in the expected subgroup".to_owned()));
+            }
+
+            if !g1.is_zero() && !g2.is_zero() {
+                g1_points.push(g1);
+                g2_points.push(g2);
+            }
+        }
+
+        debug_assert!(g1_points.len() == g2_points.len());
+
+        if g1_points.is_empty() {
+            return Ok(pairing_result_true());
+        }
+
+        // every G2 point passed the subgroup check above
+        let engine = mnt6_753::MNT6_753_PAIRING_ENGINE.for_subgroup_twist_points();
+
+        let pairing_result = engine.pair(&g1_points, &g2_points);
+
+        if pairing_result.is_none() {
+            return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
+        }
+
+        use crate::extension_towers::fp6_as_2_over_3::Fp6;
+
+        let one_fp6 = Fp6::one(&mnt6_753::MNT6_753_EXTENSION_6_FIELD);
+        let pairing_result = pairing_result.unwrap();
+        let result = if pairing_result == one_fp6 {
+            pairing_result_true()
+        } else {
+            pairing_result_false()
+        };
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    type Scalar = crate::integers::MaxGroupSizeUint;
+
+    fn make_random_scalar_with_encoding<R: Rng>(rng: &mut R) -> (Scalar, Vec<u8>) {
+        let mut buff = vec![0u8; SCALAR_BYTE_LENGTH];
+        rng.fill_bytes(&mut buff);
+        // keep scalars below the group order bit length, as multiexp expects
+        buff[0] = 0;
+        buff[1] = 0;
+
+        let (scalar, _) = decode_g1::decode_scalar_representation(&buff, SCALAR_BYTE_LENGTH).unwrap();
+
+        (scalar, buff)
+    }
+
+    #[test]
+    fn test_mnt4_753_g1_ops() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        let (s0, s0_encoding) = make_random_scalar_with_encoding(&mut rng);
+        let (s1, s1_encoding) = make_random_scalar_with_encoding(&mut rng);
+
+        let g = mnt4_753::MNT4_753_G1_GENERATOR;
+        let g_encoding = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &g).unwrap();
+
+        let mut p0 = g.mul(s0);
+        p0.normalize();
+        let mut p1 = g.mul(s1);
+        p1.normalize();
+
+        let mut mul_input = g_encoding.clone();
+        mul_input.extend(s0_encoding.clone());
+        let p0_encoding = MNT4753Executor::g1_mul(&mul_input).unwrap();
+        assert_eq!(&p0_encoding[..], &decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p0).unwrap()[..]);
+
+        let mut expected = p0.clone();
+        expected.add_assign(&p1);
+
+        let mut add_input = p0_encoding.to_vec();
+        add_input.extend(decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p1).unwrap());
+        let sum = MNT4753Executor::g1_add(&add_input).unwrap();
+        assert_eq!(&sum[..], &decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &expected).unwrap()[..]);
+
+        let mut multiexp_input = g_encoding.clone();
+        multiexp_input.extend(s0_encoding);
+        multiexp_input.extend(g_encoding);
+        multiexp_input.extend(s1_encoding);
+        let multiexp = MNT4753Executor::g1_multiexp(&multiexp_input).unwrap();
+        assert_eq!(&multiexp[..], &sum[..]);
+
+        assert!(MNT4753Executor::g1_add(&add_input[1..]).is_err());
+    }
+
+    #[test]
+    fn test_mnt4_753_g2_ops() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        let (s0, s0_encoding) = make_random_scalar_with_encoding(&mut rng);
+
+        let g = mnt4_753::MNT4_753_G2_GENERATOR;
+        let g_encoding = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &g).unwrap();
+
+        let mut p0 = g.mul(s0);
+        p0.normalize();
+
+        let mut mul_input = g_encoding.clone();
+        mul_input.extend(s0_encoding.clone());
+        let p0_encoding = MNT4753Executor::g2_mul(&mul_input).unwrap();
+        assert_eq!(&p0_encoding[..], &decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &p0).unwrap()[..]);
+
+        let mut expected = p0.clone();
+        expected.add_assign(&g);
+
+        let mut add_input = p0_encoding.to_vec();
+        add_input.extend(g_encoding.clone());
+        let sum = MNT4753Executor::g2_add(&add_input).unwrap();
+        assert_eq!(&sum[..], &decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &expected).unwrap()[..]);
+
+        let mut one = vec![0u8; SCALAR_BYTE_LENGTH];
+        one[SCALAR_BYTE_LENGTH - 1] = 1;
+
+        let mut multiexp_input = g_encoding.clone();
+        multiexp_input.extend(s0_encoding);
+        multiexp_input.extend(g_encoding);
+        multiexp_input.extend(one);
+        let multiexp = MNT4753Executor::g2_multiexp(&multiexp_input).unwrap();
+        assert_eq!(&multiexp[..], &sum[..]);
+    }
+
+    #[test]
+    fn test_mnt4_753_pairing() {
+        let p = mnt4_753::MNT4_753_G1_GENERATOR;
+        let mut minus_p = p.clone();
+        minus_p.negate();
+        let q = mnt4_753::MNT4_753_G2_GENERATOR;
+
+        let q_encoding = decode_g2::serialize_g2_point_in_fp2(SERIALIZED_FP_BYTE_LENGTH, &q).unwrap();
+
+        let mut input = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p).unwrap();
+        input.extend(q_encoding.clone());
+
+        let result = MNT4753Executor::pair(&input).unwrap();
+        assert_eq!(result, pairing_result_false());
+
+        input.extend(decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &minus_p).unwrap());
+        input.extend(q_encoding);
+
+        let result = MNT4753Executor::pair(&input).unwrap();
+        assert_eq!(result, pairing_result_true());
+    }
+
+    #[test]
+    fn test_mnt6_753_g1_ops() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        let (s0, s0_encoding) = make_random_scalar_with_encoding(&mut rng);
+        let (s1, s1_encoding) = make_random_scalar_with_encoding(&mut rng);
+
+        let g = mnt6_753::MNT6_753_G1_GENERATOR;
+        let g_encoding = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &g).unwrap();
+
+        let mut p0 = g.mul(s0);
+        p0.normalize();
+        let mut p1 = g.mul(s1);
+        p1.normalize();
+
+        let mut mul_input = g_encoding.clone();
+        mul_input.extend(s0_encoding.clone());
+        let p0_encoding = MNT6753Executor::g1_mul(&mul_input).unwrap();
+        assert_eq!(&p0_encoding[..], &decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p0).unwrap()[..]);
+
+        let mut expected = p0.clone();
+        expected.add_assign(&p1);
+
+        let mut add_input = p0_encoding.to_vec();
+        add_input.extend(decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p1).unwrap());
+        let sum = MNT6753Executor::g1_add(&add_input).unwrap();
+        assert_eq!(&sum[..], &decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &expected).unwrap()[..]);
+
+        let mut multiexp_input = g_encoding.clone();
+        multiexp_input.extend(s0_encoding);
+        multiexp_input.extend(g_encoding);
+        multiexp_input.extend(s1_encoding);
+        let multiexp = MNT6753Executor::g1_multiexp(&multiexp_input).unwrap();
+        assert_eq!(&multiexp[..], &sum[..]);
+
+        assert!(MNT6753Executor::g1_add(&add_input[1..]).is_err());
+    }
+
+    #[test]
+    fn test_mnt6_753_g2_ops() {
+        let mut rng = XorShiftRng::from_seed([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+        let (s0, s0_encoding) = make_random_scalar_with_encoding(&mut rng);
+
+        let g = mnt6_753::MNT6_753_G2_GENERATOR;
+        let g_encoding = decode_g2::serialize_g2_point_in_fp3(SERIALIZED_FP_BYTE_LENGTH, &g).unwrap();
+
+        let mut p0 = g.mul(s0);
+        p0.normalize();
+
+        let mut mul_input = g_encoding.clone();
+        mul_input.extend(s0_encoding.clone());
+        let p0_encoding = MNT6753Executor::g2_mul(&mul_input).unwrap();
+        assert_eq!(&p0_encoding[..], &decode_g2::serialize_g2_point_in_fp3(SERIALIZED_FP_BYTE_LENGTH, &p0).unwrap()[..]);
+
+        let mut expected = p0.clone();
+        expected.add_assign(&g);
+
+        let mut add_input = p0_encoding.to_vec();
+        add_input.extend(g_encoding.clone());
+        let sum = MNT6753Executor::g2_add(&add_input).unwrap();
+        assert_eq!(&sum[..], &decode_g2::serialize_g2_point_in_fp3(SERIALIZED_FP_BYTE_LENGTH, &expected).unwrap()[..]);
+
+        let mut one = vec![0u8; SCALAR_BYTE_LENGTH];
+        one[SCALAR_BYTE_LENGTH - 1] = 1;
+
+        let mut multiexp_input = g_encoding.clone();
+        multiexp_input.extend(s0_encoding);
+        multiexp_input.extend(g_encoding);
+        multiexp_input.extend(one);
+        let multiexp = MNT6753Executor::g2_multiexp(&multiexp_input).unwrap();
+        assert_eq!(&multiexp[..], &sum[..]);
+    }
+
+    #[test]
+    fn test_mnt6_753_pairing() {
+        let p = mnt6_753::MNT6_753_G1_GENERATOR;
+        let mut minus_p = p.clone();
+        minus_p.negate();
+        let q = mnt6_753::MNT6_753_G2_GENERATOR;
+
+        let q_encoding = decode_g2::serialize_g2_point_in_fp3(SERIALIZED_FP_BYTE_LENGTH, &q).unwrap();
+
+        let mut input = decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &p).unwrap();
+        input.extend(q_encoding.clone());
+
+        let result = MNT6753Executor::pair(&input).unwrap();
+        assert_eq!(result, pairing_result_false());
+
+        input.extend(decode_g1::serialize_g1_point(SERIALIZED_FP_BYTE_LENGTH, &minus_p).unwrap());
+        input.extend(q_encoding);
+
+        let result = MNT6753Executor::pair(&input).unwrap();
+        assert_eq!(result, pairing_result_true());
+    }
+}
@@ -1,14 +1,23 @@
 pub mod decode_g1;
 pub mod decode_g2;
 pub mod decode_fp;
+pub mod decode_edwards;
 pub(crate) mod decode_utils;
 
 #[macro_use]
 pub(crate) mod api_specialization_macro;
 
-mod g1_ops;
-mod g2_ops;
-mod pairing_ops;
+#[macro_use]
+pub(crate) mod known_curves;
+
+pub mod parameters_cache;
+
+pub(crate) mod g1_ops;
+pub(crate) mod g2_ops;
+pub(crate) mod pairing_ops;
+mod edwards_ops;
+#[cfg(feature = "mappings")]
+pub(crate) mod map_to_curve_ops;
 
 pub mod sane_limits;
 pub mod constants;
@@ -16,15 +25,22 @@ pub mod constants;
 pub use pairing_ops::{PairingApi, PublicPairingApi};
 pub use g1_ops::{G1Api, PublicG1Api};
 pub use g2_ops::{G2Api, PublicG2Api};
+pub use edwards_ops::{EdwardsApi, PublicEdwardsApi};
+#[cfg(feature = "mappings")]
+pub use map_to_curve_ops::{MapToCurveApi, PublicMapToCurveApi};
 
 mod unified_api;
 pub use self::unified_api::{OperationType, perform_operation, PREALLOCATE_FOR_ERROR_BYTES, PREALLOCATE_FOR_RESULT_BYTES};
+#[cfg(feature = "gas_metering")]
+pub(crate) use self::unified_api::perform_decoded_operation;
 pub use crate::errors::ApiError;
 
 #[cfg(feature = "c_api")]
 mod c_api;
 #[cfg(feature = "c_api")]
 pub use self::c_api::{c_perform_operation};
+#[cfg(all(feature = "c_api", feature = "gas_metering"))]
+pub use self::c_api::{c_execute_with_gas_limit};
 
 #[cfg(feature = "eip_2537")]
 pub mod eip2537;
@@ -35,6 +51,18 @@ pub mod eip2539;
 #[cfg(feature = "eip_196")]
 pub mod eip196;
 
+#[cfg(feature = "eip_4844")]
+pub mod eip4844;
+
+#[cfg(feature = "mnt_753")]
+pub mod mnt_753;
+
+#[cfg(feature = "ecrecover")]
+pub mod ecrecover;
+
+#[cfg(feature = "rip_7212")]
+pub mod rip7212;
+
 pub struct API;
 
 impl API {
@@ -66,6 +94,29 @@ impl API {
             OPERATION_PAIRING => {
                 PublicPairingApi::pair(&rest)
             },
+            OPERATION_EDWARDS_ADD => {
+                PublicEdwardsApi::add_points(&rest)
+            },
+            OPERATION_EDWARDS_MUL => {
+                PublicEdwardsApi::mul_point(&rest)
+            },
+            OPERATION_EDWARDS_MULTIEXP => {
+                PublicEdwardsApi::multiexp(&rest)
+            },
+            OPERATION_G1_DECOMPRESS => {
+                PublicG1Api::decompress_point(rest)
+            },
+            OPERATION_G2_DECOMPRESS => {
+                PublicG2Api::decompress_point(rest)
+            },
+            #[cfg(feature = "mappings")]
+            OPERATION_G1_MAP => {
+                PublicMapToCurveApi::map_to_g1(rest)
+            },
+            #[cfg(feature = "mappings")]
+            OPERATION_G2_MAP => {
+                PublicMapToCurveApi::map_to_g2(rest)
+            },
             _ => {
                 return Err(ApiError::InputError("Unknown operation type".to_owned()));
             }
@@ -15,18 +15,21 @@
 /// 
 /// 
 
-use crate::weierstrass::curve::WeierstrassCurve;
-use crate::weierstrass::{Group, CurveOverFpParameters, CurveOverFp2Parameters, CurveOverFp3Parameters};
+use crate::weierstrass::curve::{CurvePoint, WeierstrassCurve};
+use crate::weierstrass::{Group, CurveParameters, CurveOverFpParameters, CurveOverFp2Parameters, CurveOverFp3Parameters, CurveOverFp4Parameters};
 use crate::pairings::*;
 use crate::pairings::bls12::{Bls12Instance, Bls12InstanceParams};
+use crate::pairings::bls24::{Bls24Instance, Bls24InstanceParams};
 use crate::pairings::bn::{BnInstance, BnInstanceParams};
 use crate::pairings::mnt4::{MNT4Instance, MNT4InstanceParams};
 use crate::pairings::mnt6::{MNT6Instance, MNT6InstanceParams};
+use crate::pairings::bw6::{Bw6Instance, Bw6InstanceParams};
 use crate::representation::{ElementRepr};
 use crate::traits::{FieldElement, ZeroAndOne};
 use crate::extension_towers::*;
 use crate::fp::Fp;
 use crate::integers::*;
+use crate::field::SizedPrimeField;
 
 use super::decode_g1::*;
 use super::decode_utils::*;
@@ -34,6 +37,8 @@ use super::decode_fp::*;
 use super::decode_g2::*;
 use super::constants::*;
 use super::sane_limits::*;
+use super::known_curves::*;
+use super::parameters_cache::*;
 
 use crate::errors::ApiError;
 
@@ -45,6 +50,108 @@ fn pairing_result_true() -> Vec<u8> {
     vec![1u8]
 }
 
+/// Decodes pairs of G1 and G2 points for BLS12 and BN curves (G2 is over Fp2),
+/// checks them and calls the engine. Pairs where one of the points is zero are skipped
+fn pair_with_g2_in_fp2<
+    'a,
+    FE: ElementRepr,
+    F: SizedPrimeField<Repr = FE>,
+    CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+    CTW: CurveParameters<BaseFieldElement = fp2::Fp2<'a, FE, F>>,
+    E: PairingEngine<G1 = CurvePoint<'a, CB>, G2 = CurvePoint<'a, CTW>, PairingResult = fp12_as_2_over3_over_2::Fp12<'a, FE, F>>
+    >
+    (
+        engine: &E,
+        g1_curve: &'a WeierstrassCurve<'a, CB>,
+        g2_curve: &'a WeierstrassCurve<'a, CTW>,
+        extension_12: &'a fp12_as_2_over3_over_2::Extension2Over3Over2<'a, FE, F>,
+        modulus_len: usize,
+        bytes: &[u8]
+    ) -> Result<Vec<u8>, ApiError>
+{
+    let (num_pairs_encoding, rest) = split(bytes, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
+    let num_pairs = num_pairs_encoding[0] as usize;
+
+    if num_pairs == 0 {
+        if !crate::features::in_gas_metering() {
+            return Err(ApiError::InputError("Zero pairs encoded".to_owned()));
+        }
+    }
+
+    let compressed = pairing_points_are_compressed(rest, num_pairs, modulus_len, 2*modulus_len);
+
+    let mut global_rest = rest;
+
+    let mut g1_points = vec![];
+    let mut g2_points = vec![];
+
+    for _ in 0..num_pairs {
+        let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
+        let (g1, rest) = decode_g1_point(&rest, modulus_len, g1_curve, compressed)?;
+        let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
+        let (g2, rest) = decode_g2_point_in_fp2(&rest, modulus_len, g2_curve, compressed)?;
+        global_rest = rest;
+
+        if !g1.is_on_curve() {
+            if !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError("G1 point is not on curve".to_owned()));
+            }
+        }
+
+        if !g2.is_on_curve() {
+            if !crate::features::in_fuzzing_or_gas_metering() {
+                return Err(ApiError::InputError("G2 point is not on curve".to_owned()));
+            }
+        }
+
+        if check_g1_subgroup {
+            if !g1.check_correct_subgroup() {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                }
+            }
+        }
+
+        if check_g2_subgroup {
+            if !g2.check_correct_subgroup() {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::InputError("G1 or G2 point is not in the expected subgroup".to_owned()));
+                }
+            }
+        }
+
+        if !g1.is_zero() && !g2.is_zero() {
+            g1_points.push(g1);
+            g2_points.push(g2);
+        }
+    }
+
+    if global_rest.len() != 0 {
+        return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
+    }
+
+    debug_assert!(g1_points.len() == g2_points.len());
+    if g1_points.len() == 0 {
+        return Ok(pairing_result_true());
+    }
+
+    let pairing_result = engine.pair(&g1_points, &g2_points);
+
+    if pairing_result.is_none() {
+        return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
+    }
+
+    let one_fp12 = fp12_as_2_over3_over_2::Fp12::one(extension_12);
+    let pairing_result = pairing_result.unwrap();
+    let result = if pairing_result == one_fp12 {
+        pairing_result_true()
+    } else {
+        pairing_result_false()
+    };
+
+    Ok(result)
+}
+
 pub struct PublicPairingApi;
 
 impl PairingApi for PublicPairingApi {
@@ -85,6 +192,12 @@ impl<FE: ElementRepr> PairingApi for PairingApiImplementation<FE> {
             MNT6 => {
                 PairingApiImplementation::<FE>::pair_mnt6(&rest)
             },
+            BW6 => {
+                PairingApiImplementation::<FE>::pair_bw6(&rest)
+            },
+            BLS24 => {
+                PairingApiImplementation::<FE>::pair_bls24(&rest)
+            },
             _ => {
                 return Err(ApiError::InputError("Unknown curve type".to_owned()));
             }
@@ -94,9 +207,17 @@ impl<FE: ElementRepr> PairingApi for PairingApiImplementation<FE> {
 
 impl<FE: ElementRepr>PairingApiImplementation<FE> {
     pub(crate) fn pair_bls12(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        if let Some((engine, modulus_len, rest)) = recognize_bls12_engine(bytes) {
+            return pair_with_g2_in_fp2(&engine, engine.curve, engine.curve_twist, engine.fp12_extension, modulus_len, rest);
+        }
+
+        Self::pair_bls12_on_encoded_curve(bytes)
+    }
+
+    pub(crate) fn pair_bls12_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         use crate::extension_towers::fp2::{Fp2, Extension2};
         use crate::extension_towers::fp6_as_3_over_2::{Fp6, Extension3Over2};
-        use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2};
+        use crate::extension_towers::fp12_as_2_over3_over_2::Extension2Over3Over2;
 
         let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &base_field)?;
@@ -120,8 +241,9 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         // - list of encoded pairs
 
         let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
+        let mut cached_fp2 = CachedParameters::lookup(CACHE_TAG_BLS12_FP2, modulus.as_ref(), consumed_prefix(bytes, rest));
 
-        {
+        if !cached_fp2.is_hit() {
             if fp_non_residue.is_zero() {
                 return Err(ApiError::InputError(format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!())));
             }
@@ -135,13 +257,18 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         // build an extension field
         let mut extension_2 = Extension2::new(fp_non_residue);
-        extension_2.calculate_frobenius_coeffs(&modulus).map_err(|_| {
-            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
-        })?;
+        if !cached_fp2.restore(&mut extension_2)? {
+            extension_2.calculate_frobenius_coeffs(&modulus).map_err(|_| {
+                ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
+            })?;
+            cached_fp2.record(&extension_2);
+        }
+        cached_fp2.store();
 
         let (fp2_non_residue, rest) = decode_fp2(&rest, modulus_len, &extension_2)?;
+        let mut cached_fp6_fp12 = CachedParameters::lookup(CACHE_TAG_BLS12_FP6_FP12, modulus.as_ref(), consumed_prefix(bytes, rest));
 
-        {
+        if !cached_fp6_fp12.is_hit() {
             if fp2_non_residue.is_zero() {
                 return Err(ApiError::InputError(format!("Non-residue for Fp6(12) is zero, file {}, line {}", file!(), line!())));
             }
@@ -155,26 +282,34 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         let (twist_type, rest) = decode_twist_type(rest)?;
 
-        let base_precomp = Fp6Fp12FrobeniusBaseElements::construct(
-            &modulus, 
-            &fp2_non_residue
-        ).map_err(|_| {
-            ApiError::UnknownParameter("Can not make base precomputations for Fp6/Fp12 frobenius".to_owned())
-        })?;
-
         let mut extension_6 = Extension3Over2::new(fp2_non_residue.clone());
-        {
+        let base_precomp = if cached_fp6_fp12.restore(&mut extension_6)? {
+            None
+        } else {
+            let base_precomp = Fp6Fp12FrobeniusBaseElements::construct(
+                &modulus, 
+                &fp2_non_residue
+            ).map_err(|_| {
+                ApiError::UnknownParameter("Can not make base precomputations for Fp6/Fp12 frobenius".to_owned())
+            })?;
             extension_6.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
                 ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp6".to_owned())
             })?;
-        }
+            cached_fp6_fp12.record(&extension_6);
+
+            Some(base_precomp)
+        };
 
         let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&extension_6));
-        {
+        if let Some(base_precomp) = base_precomp {
             extension_12.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
                 ApiError::InputError("Can not calculate Frobenius coefficients for Fp12".to_owned())
             })?;
+            cached_fp6_fp12.record(&extension_12);
+        } else {
+            cached_fp6_fp12.restore(&mut extension_12)?;
         }
+        cached_fp6_fp12.store();
 
         let fp2_non_residue_inv = fp2_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp2 non-residue must be invertible".to_owned()))?;
         let b_fp2 = match twist_type {
@@ -210,6 +345,154 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         let (x_is_negative, rest) = decode_sign_is_negative(rest)?;
 
+        let engine_params = Bls12InstanceParams {
+            x: &x.as_ref(),
+            x_is_negative: x_is_negative,
+            twist_type: twist_type,
+            base_field: &base_field,
+            curve: &g1_curve,
+            curve_twist: &g2_curve,
+            fp2_extension: &extension_2,
+            fp6_extension: &extension_6,
+            fp12_extension: &extension_12,
+            force_no_naf: true
+        };
+
+        let engine = Bls12Instance::from_params(engine_params);
+
+        pair_with_g2_in_fp2(&engine, &g1_curve, &g2_curve, &extension_12, modulus_len, rest)
+    }
+
+    pub(crate) fn pair_bls24(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        use crate::extension_towers::fp2::{Fp2, Extension2};
+        use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
+        use crate::extension_towers::fp12_as_3_over_4::Extension3Over4;
+        use crate::extension_towers::fp24_as_2_over3_over_4::{Fp24, Extension2Over3Over4};
+
+        let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(bytes)?;
+        let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(rest, modulus_len, &base_field)?;
+        if !a_fp.is_zero() {
+            return Err(ApiError::UnknownParameter("A parameter must be zero for BLS24 curve".to_owned()));
+        }
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let fp_params = CurveOverFpParameters::new(&base_field);
+        let g1_curve = WeierstrassCurve::new(order.as_ref(), a_fp, b_fp, &fp_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        // Now we need to expect:
+        // - non-residue for Fp2
+        // - twist type M/D
+        // - parameter X
+        // - sign of X
+        // - number of pairs
+        // - list of encoded pairs
+
+        // Tower is fixed as Fp4 = Fp2[v]/(v^2 - u), Fp12 = Fp4[w]/(w^3 - v)
+        // and Fp24 = Fp12[z]/(z^2 - w), so only non-residue for Fp2 is encoded
+
+        let (fp_non_residue, rest) = decode_fp(rest, modulus_len, &base_field)?;
+        let mut cached_tower = CachedParameters::lookup(CACHE_TAG_BLS24_TOWER, modulus.as_ref(), consumed_prefix(bytes, rest));
+
+        if !cached_tower.is_hit() {
+            if fp_non_residue.is_zero() {
+                return Err(ApiError::InputError(format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!())));
+            }
+            let is_not_a_square = is_non_nth_root(&fp_non_residue, &modulus, 2u64);
+            if !is_not_a_square {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::InputError(format!("Non-residue for Fp2 is actually a residue file {}, line {}", file!(), line!())));
+                }
+            }
+
+            // z^6 = v is irreducible iff v is neither a square nor a cube in Fp4. 
+            // Norm of v is -non_residue, so it's enough to check that it's not a cube in Fp
+            let mut v_norm = fp_non_residue;
+            v_norm.negate();
+            let is_not_a_cube = is_non_nth_root(&v_norm, &modulus, 3u64);
+            if !is_not_a_cube {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::InputError(format!("Non-residue for Fp24 is actually a residue file {}, line {}", file!(), line!())));
+                }
+            }
+        }
+
+        // build an extension field
+        let mut extension_2 = Extension2::new(fp_non_residue);
+        if !cached_tower.restore(&mut extension_2)? {
+            extension_2.calculate_frobenius_coeffs(&modulus).map_err(|_| {
+                ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
+            })?;
+            cached_tower.record(&extension_2);
+        }
+
+        let (twist_type, rest) = decode_twist_type(rest)?;
+
+        let mut fp2_non_residue = Fp2::zero(&extension_2);
+        fp2_non_residue.c1 = Fp::one(&base_field);
+
+        let mut extension_4 = Extension2Over2::new(fp2_non_residue);
+        if !cached_tower.restore(&mut extension_4)? {
+            extension_4.calculate_frobenius_coeffs_optimized(&modulus).map_err(|_| {
+                ApiError::InputError("Can not calculate Frobenius coefficients for Fp4".to_owned())
+            })?;
+            cached_tower.record(&extension_4);
+        }
+
+        let mut extension_12 = Extension3Over4::new(&extension_4);
+        if !cached_tower.restore(&mut extension_12)? {
+            extension_12.calculate_frobenius_coeffs(&modulus).map_err(|_| {
+                ApiError::InputError("Can not calculate Frobenius coefficients for Fp12".to_owned())
+            })?;
+            cached_tower.record(&extension_12);
+        }
+
+        let mut extension_24 = Extension2Over3Over4::new(&extension_12);
+        if !cached_tower.restore(&mut extension_24)? {
+            extension_24.calculate_frobenius_coeffs(&modulus).map_err(|_| {
+                ApiError::InputError("Can not calculate Frobenius coefficients for Fp24".to_owned())
+            })?;
+            cached_tower.record(&extension_24);
+        }
+        cached_tower.store();
+
+        let mut fp4_non_residue = Fp4::zero(&extension_4);
+        fp4_non_residue.c1 = Fp2::one(&extension_2);
+
+        let fp4_non_residue_inv = fp4_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp4 non-residue must be invertible".to_owned()))?;
+        let b_fp4 = match twist_type {
+            TwistType::D => {
+                let mut b_fp4 = fp4_non_residue_inv;
+                b_fp4.mul_by_fp(&b_fp);
+
+                b_fp4
+            },
+            TwistType::M => {
+                let mut b_fp4 = fp4_non_residue;
+                b_fp4.mul_by_fp(&b_fp);
+
+                b_fp4
+            },
+        };
+
+        let a_fp4 = Fp4::zero(&extension_4);
+
+        let fp4_params = CurveOverFp4Parameters::new(&extension_4);
+        let g2_curve = WeierstrassCurve::new(order.as_ref(), a_fp4, b_fp4, &fp4_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(rest, MAX_BLS24_X_BIT_LENGTH)?;
+        if x.is_zero() {
+            return Err(ApiError::InputError("Loop count parameters can not be zero".to_owned()));
+        }
+
+        if calculate_hamming_weight(x.as_ref()) > MAX_BLS24_X_HAMMING {
+            return Err(ApiError::InputError("X has too large hamming weight".to_owned()));
+        }
+
+        let (x_is_negative, rest) = decode_sign_is_negative(rest)?;
+
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
@@ -225,10 +508,10 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         let mut g2_points = vec![];
 
         for _ in 0..num_pairs {
-            let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
-            let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g1_curve)?;
-            let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
-            let (g2, rest) = decode_g2_point_from_xy_in_fp2(&rest, modulus_len, &g2_curve)?;
+            let (check_g1_subgroup, rest) = decode_boolean(global_rest)?;
+            let (g1, rest) = decode_g1_point_from_xy(rest, modulus_len, &g1_curve)?;
+            let (check_g2_subgroup, rest) = decode_boolean(rest)?;
+            let (g2, rest) = decode_g2_point_from_xy_in_fp4(rest, modulus_len, &g2_curve)?;
             global_rest = rest;
 
             if !g1.is_on_curve() {
@@ -265,29 +548,30 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             }
         }
 
-        if global_rest.len() != 0 {
+        if !global_rest.is_empty() {
             return Err(ApiError::InputError("Input contains garbage at the end".to_owned()));
         }
 
         debug_assert!(g1_points.len() == g2_points.len());
-        if g1_points.len() == 0 {
+        if g1_points.is_empty() {
             return Ok(pairing_result_true());
         }
 
-        let engine_params = Bls12InstanceParams {
-            x: &x.as_ref(),
-            x_is_negative: x_is_negative,
-            twist_type: twist_type,
+        let engine_params = Bls24InstanceParams {
+            x: x.as_ref(),
+            x_is_negative,
+            twist_type,
             base_field: &base_field,
             curve: &g1_curve,
             curve_twist: &g2_curve,
             fp2_extension: &extension_2,
-            fp6_extension: &extension_6,
+            fp4_extension: &extension_4,
             fp12_extension: &extension_12,
+            fp24_extension: &extension_24,
             force_no_naf: true
         };
 
-        let engine = Bls12Instance::from_params(engine_params);
+        let engine = Bls24Instance::from_params(engine_params);
 
         let pairing_result = engine.pair(&g1_points, &g2_points);
 
@@ -295,9 +579,9 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
         }
 
-        let one_fp12 = Fp12::one(&extension_12);
+        let one_fp24 = Fp24::one(&extension_24);
         let pairing_result = pairing_result.unwrap();
-        let result = if pairing_result == one_fp12 {
+        let result = if pairing_result == one_fp24 {
             pairing_result_true()
         } else {
             pairing_result_false()
@@ -307,9 +591,21 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
     }
 
     pub(crate) fn pair_bn(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        #[cfg(feature = "eip_196")]
+        {
+            if let Some((engine, modulus_len, rest)) = recognize_bn_engine(bytes) {
+                let engine = engine.for_pairing_checks();
+                return pair_with_g2_in_fp2(&engine, engine.curve, engine.curve_twist, engine.fp12_extension, modulus_len, rest);
+            }
+        }
+
+        Self::pair_bn_on_encoded_curve(bytes)
+    }
+
+    pub(crate) fn pair_bn_on_encoded_curve(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         use crate::extension_towers::fp2::{Fp2, Extension2};
         use crate::extension_towers::fp6_as_3_over_2::{Fp6, Extension3Over2};
-        use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2};
+        use crate::extension_towers::fp12_as_2_over3_over_2::Extension2Over3Over2;
 
         let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &base_field)?;
@@ -334,8 +630,9 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         // U is used instead of x for convention of go-ethereum people :)
 
         let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
+        let mut cached_fp2 = CachedParameters::lookup(CACHE_TAG_BN_FP2, modulus.as_ref(), consumed_prefix(bytes, rest));
 
-        {
+        if !cached_fp2.is_hit() {
             if fp_non_residue.is_zero() {
                 return Err(ApiError::InputError(format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!())));
             }
@@ -349,13 +646,18 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         // build an extension field
         let mut extension_2 = Extension2::new(fp_non_residue);
-        extension_2.calculate_frobenius_coeffs(&modulus).map_err(|_| {
-            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
-        })?;
+        if !cached_fp2.restore(&mut extension_2)? {
+            extension_2.calculate_frobenius_coeffs(&modulus).map_err(|_| {
+                ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
+            })?;
+            cached_fp2.record(&extension_2);
+        }
+        cached_fp2.store();
 
         let (fp2_non_residue, rest) = decode_fp2(&rest, modulus_len, &extension_2)?;
+        let mut cached_fp6_fp12 = CachedParameters::lookup(CACHE_TAG_BN_FP6_FP12, modulus.as_ref(), consumed_prefix(bytes, rest));
 
-        {
+        if !cached_fp6_fp12.is_hit() {
             if fp2_non_residue.is_zero() {
                 return Err(ApiError::InputError(format!("Non-residue for Fp6(12) is zero, file {}, line {}", file!(), line!())));
             }
@@ -369,26 +671,34 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         let (twist_type, rest) = decode_twist_type(&rest)?;
 
-        let base_precomp = Fp6Fp12FrobeniusBaseElements::construct(
-            &modulus, 
-            &fp2_non_residue
-        ).map_err(|_| {
-            ApiError::UnknownParameter("Can not make base precomputations for Fp6/Fp12 frobenius".to_owned())
-        })?;
-
         let mut extension_6 = Extension3Over2::new(fp2_non_residue.clone());
-        {
+        let base_precomp = if cached_fp6_fp12.restore(&mut extension_6)? {
+            None
+        } else {
+            let base_precomp = Fp6Fp12FrobeniusBaseElements::construct(
+                &modulus, 
+                &fp2_non_residue
+            ).map_err(|_| {
+                ApiError::UnknownParameter("Can not make base precomputations for Fp6/Fp12 frobenius".to_owned())
+            })?;
             extension_6.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
                 ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp6".to_owned())
             })?;
-        }
+            cached_fp6_fp12.record(&extension_6);
+
+            Some(base_precomp)
+        };
 
         let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&extension_6));
-        {
+        if let Some(base_precomp) = base_precomp {
             extension_12.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
                 ApiError::InputError("Can not calculate Frobenius coefficients for Fp12".to_owned())
             })?;
+            cached_fp6_fp12.record(&extension_12);
+        } else {
+            cached_fp6_fp12.restore(&mut extension_12)?;
         }
+        cached_fp6_fp12.store();
 
         let fp2_non_residue_inv = fp2_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp2 non-residue must be invertible".to_owned()))?;
 
@@ -443,6 +753,139 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         let fp2_non_residue_in_p_minus_one_over_2 = fp2_non_residue.pow(p_minus_one_over_2.as_ref());
 
+        let engine_params = BnInstanceParams {
+            u: &u.as_ref(),
+            six_u_plus_2: &six_u_plus_two.as_ref(),
+            u_is_negative: u_is_negative,
+            twist_type: twist_type,
+            base_field: &base_field,
+            curve: &g1_curve,
+            curve_twist: &g2_curve,
+            fp2_extension: &extension_2,
+            fp6_extension: &extension_6,
+            fp12_extension: &extension_12,
+            non_residue_in_p_minus_one_over_2: fp2_non_residue_in_p_minus_one_over_2,
+            force_no_naf: true
+        };
+
+        let engine = BnInstance::from_params(engine_params).for_pairing_checks();
+
+        pair_with_g2_in_fp2(&engine, &g1_curve, &g2_curve, &extension_12, modulus_len, rest)
+    }
+
+    pub(crate) fn pair_mnt6(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        use crate::extension_towers::fp3::{Fp3, Extension3};
+        use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
+
+        let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
+        let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &base_field)?;
+        let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
+        let fp_params = CurveOverFpParameters::new(&base_field);
+        let g1_curve = WeierstrassCurve::new(&order.as_ref(), a_fp.clone(), b_fp.clone(), &fp_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        // Now we need to expect:
+        // - non-residue for Fp3
+        // now separate Miller loop params
+        // - parameter X
+        // - sign of X 
+        // Final exp params
+        // - exp_w0
+        // - exp_w1
+        // - exp_w0_is_negative
+        // - number of pairs
+        // - list of encoded pairs
+
+        let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
+        let mut cached_tower = CachedParameters::lookup(CACHE_TAG_MNT6_TOWER, modulus.as_ref(), consumed_prefix(bytes, rest));
+
+        if !cached_tower.is_hit() {
+            if fp_non_residue.is_zero() {
+                return Err(ApiError::InputError(format!("Non-residue for Fp3 is zero file {}, line {}", file!(), line!())));
+            }
+            let is_not_a_root = is_non_nth_root(&fp_non_residue, &modulus, 6u64);
+            if !is_not_a_root {
+                if !crate::features::in_fuzzing_or_gas_metering() {
+                    return Err(ApiError::InputError(format!("Non-residue for Fp3 is actually a residue, file {}, line {}", file!(), line!())));
+                }
+            }
+        }
+
+        // build an extension field
+        let mut extension_3 = Extension3::new(fp_non_residue);
+        let base_precomp = if cached_tower.restore(&mut extension_3)? {
+            None
+        } else {
+            let base_precomp = Fp3Fp6FrobeniusBaseElements::construct(
+                &modulus, &fp_non_residue
+            ).map_err(|_| {
+                ApiError::UnknownParameter("Can not make base precomputations for Fp3/Fp6 frobenius".to_owned())
+            })?;
+            extension_3.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+                ApiError::InputError("Failed to calculate Frobenius coeffs for Fp3".to_owned())
+            })?;
+            cached_tower.record(&extension_3);
+
+            Some(base_precomp)
+        };
+
+        let mut extension_6 = Extension2Over3::new(Fp3::zero(&extension_3));
+        if let Some(base_precomp) = base_precomp {
+            extension_6.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+                ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp6".to_owned())
+            })?;
+            cached_tower.record(&extension_6);
+        } else {
+            cached_tower.restore(&mut extension_6)?;
+        }
+        cached_tower.store();
+
+        let one = Fp::one(&base_field);
+
+        let mut twist = Fp3::zero(&extension_3);
+        twist.c1 = one.clone();
+
+        let mut twist_squared = twist.clone();
+        twist_squared.square();
+
+        let mut twist_cubed = twist_squared.clone();
+        twist_cubed.mul_assign(&twist);
+
+        let mut a_fp3 = twist_squared.clone();
+        a_fp3.mul_by_fp(&a_fp);
+
+        let mut b_fp3 = twist_cubed.clone();
+        b_fp3.mul_by_fp(&b_fp);
+
+        let fp3_params = CurveOverFp3Parameters::new(&extension_3);
+        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_fp3, b_fp3, &fp3_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
+
+        let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+        if x.is_zero() {
+            return Err(ApiError::InputError("Ate loop count parameters can not be zero".to_owned()));
+        }
+
+        if calculate_hamming_weight(&x.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
+            return Err(ApiError::InputError("X has too large hamming weight".to_owned()));
+        }
+
+        let (x_is_negative, rest) = decode_sign_is_negative(rest)?;
+
+        let (exp_w0, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
+        if exp_w0.is_zero() {
+            return Err(ApiError::InputError("Final exp w0 loop count parameters can not be zero".to_owned()));
+        }
+
+        let (exp_w1, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W1_BIT_LENGTH)?;
+        if exp_w1.is_zero() {
+            return Err(ApiError::InputError("Final exp w1 loop count parameters can not be zero".to_owned()));
+        }
+
+        let (exp_w0_is_negative, rest) = decode_sign_is_negative(rest)?;
+
         let (num_pairs_encoding, rest) = split(rest, BYTES_FOR_LENGTH_ENCODING, "Input is not long enough to get number of pairs")?;
         let num_pairs = num_pairs_encoding[0] as usize;
 
@@ -452,6 +895,8 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             }
         }
 
+        let compressed = pairing_points_are_compressed(rest, num_pairs, modulus_len, 3*modulus_len);
+
         let mut global_rest = rest;
 
         let mut g1_points = vec![];
@@ -459,9 +904,9 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         for _ in 0..num_pairs {
             let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
-            let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g1_curve)?;
+            let (g1, rest) = decode_g1_point(&rest, modulus_len, &g1_curve, compressed)?;
             let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
-            let (g2, rest) = decode_g2_point_from_xy_in_fp2(&rest, modulus_len, &g2_curve)?;
+            let (g2, rest) = decode_g2_point_in_fp3(&rest, modulus_len, &g2_curve, compressed)?;
             global_rest = rest;
 
             if !g1.is_on_curve() {
@@ -507,22 +952,22 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             return Ok(pairing_result_true());
         }
 
-        let engine_params = BnInstanceParams {
-            u: &u.as_ref(),
-            six_u_plus_2: &six_u_plus_two.as_ref(),
-            u_is_negative: u_is_negative,
-            twist_type: twist_type,
+        let engine_params = MNT6InstanceParams {
+            x: &x.as_ref(),
+            x_is_negative: x_is_negative,
+            exp_w0: exp_w0.as_ref(),
+            exp_w1: exp_w1.as_ref(),
+            exp_w0_is_negative: exp_w0_is_negative,
             base_field: &base_field,
             curve: &g1_curve,
             curve_twist: &g2_curve,
-            fp2_extension: &extension_2,
+            twist: twist,
+            fp3_extension: &extension_3,
             fp6_extension: &extension_6,
-            fp12_extension: &extension_12,
-            non_residue_in_p_minus_one_over_2: fp2_non_residue_in_p_minus_one_over_2,
             force_no_naf: true
         };
 
-        let engine = BnInstance::from_params(engine_params);
+        let engine = MNT6Instance::from_params(engine_params);
 
         let pairing_result = engine.pair(&g1_points, &g2_points);
 
@@ -530,9 +975,9 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             return Err(ApiError::UnknownParameter("Pairing engine returned no value".to_owned()));
         }
 
-        let one_fp12 = Fp12::one(&extension_12);
+        let one_fp6 = Fp6::one(&extension_6);
         let pairing_result = pairing_result.unwrap();
-        let result = if pairing_result == one_fp12 {
+        let result = if pairing_result == one_fp6 {
             pairing_result_true()
         } else {
             pairing_result_false()
@@ -541,12 +986,15 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         Ok(result)
     }
 
-    pub(crate) fn pair_mnt6(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    pub(crate) fn pair_bw6(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
         use crate::extension_towers::fp3::{Fp3, Extension3};
         use crate::extension_towers::fp6_as_2_over_3::{Fp6, Extension2Over3};
 
         let (base_field, modulus_len, modulus, rest) = parse_base_field_from_encoding::<FE>(&bytes)?;
         let (a_fp, b_fp, rest) = parse_ab_in_base_field_from_encoding(&rest, modulus_len, &base_field)?;
+        if !a_fp.is_zero() {
+            return Err(ApiError::UnknownParameter("A parameter must be zero for BW6 curve".to_owned()));
+        }
         let (_order_len, order, rest) = parse_group_order_from_encoding(rest)?;
         let fp_params = CurveOverFpParameters::new(&base_field);
         let g1_curve = WeierstrassCurve::new(&order.as_ref(), a_fp.clone(), b_fp.clone(), &fp_params).map_err(|_| {
@@ -555,19 +1003,23 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         // Now we need to expect:
         // - non-residue for Fp3
-        // now separate Miller loop params
-        // - parameter X
-        // - sign of X 
+        // - twist type M/D
+        // now two separate Miller loop params
+        // - ate loop count 1
+        // - sign of ate loop count 1
+        // - ate loop count 2
+        // - sign of ate loop count 2
         // Final exp params
         // - exp_w0
         // - exp_w1
         // - exp_w0_is_negative
         // - number of pairs
-        // - list of encoded pairs
+        // - list of encoded pairs, where both G1 and G2 points are encoded as in G1
 
         let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
+        let mut cached_tower = CachedParameters::lookup(CACHE_TAG_BW6_TOWER, modulus.as_ref(), consumed_prefix(bytes, rest));
 
-        {
+        if !cached_tower.is_hit() {
             if fp_non_residue.is_zero() {
                 return Err(ApiError::InputError(format!("Non-residue for Fp3 is zero file {}, line {}", file!(), line!())));
             }
@@ -579,58 +1031,79 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             }
         }
 
-        let base_precomp = Fp3Fp6FrobeniusBaseElements::construct(
-            &modulus, &fp_non_residue
-        ).map_err(|_| {
-            ApiError::UnknownParameter("Can not make base precomputations for Fp3/Fp6 frobenius".to_owned())
-        })?;
+        let (twist_type, rest) = decode_twist_type(rest)?;
 
         // build an extension field
         let mut extension_3 = Extension3::new(fp_non_residue);
-        extension_3.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
-            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp3".to_owned())
-        })?;
+        let base_precomp = if cached_tower.restore(&mut extension_3)? {
+            None
+        } else {
+            let base_precomp = Fp3Fp6FrobeniusBaseElements::construct(
+                &modulus, &fp_non_residue
+            ).map_err(|_| {
+                ApiError::UnknownParameter("Can not make base precomputations for Fp3/Fp6 frobenius".to_owned())
+            })?;
+            extension_3.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+                ApiError::InputError("Failed to calculate Frobenius coeffs for Fp3".to_owned())
+            })?;
+            cached_tower.record(&extension_3);
 
-        let mut extension_6 = Extension2Over3::new(Fp3::zero(&extension_3));
+            Some(base_precomp)
+        };
 
-        {
+        let mut extension_6 = Extension2Over3::new(Fp3::zero(&extension_3));
+        if let Some(base_precomp) = base_precomp {
             extension_6.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
                 ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp6".to_owned())
             })?;
+            cached_tower.record(&extension_6);
+        } else {
+            cached_tower.restore(&mut extension_6)?;
         }
+        cached_tower.store();
 
-        let one = Fp::one(&base_field);
+        // sextic twist is defined over Fp itself
+        let fp_non_residue_inv = fp_non_residue.inverse().ok_or(ApiError::UnexpectedZero("Fp non-residue must be invertible".to_owned()))?;
+        let b_twist = match twist_type {
+            TwistType::D => {
+                let mut b_twist = fp_non_residue_inv;
+                b_twist.mul_assign(&b_fp);
 
-        let mut twist = Fp3::zero(&extension_3);
-        twist.c1 = one.clone();
+                b_twist
+            },
+            TwistType::M => {
+                let mut b_twist = fp_non_residue;
+                b_twist.mul_assign(&b_fp);
 
-        let mut twist_squared = twist.clone();
-        twist_squared.square();
+                b_twist
+            },
+        };
 
-        let mut twist_cubed = twist_squared.clone();
-        twist_cubed.mul_assign(&twist);
+        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_fp, b_twist, &fp_params).map_err(|_| {
+            ApiError::InputError("Curve shape is not supported".to_owned())
+        })?;
 
-        let mut a_fp3 = twist_squared.clone();
-        a_fp3.mul_by_fp(&a_fp);
+        let (ate_loop_count_1, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+        if ate_loop_count_1.is_zero() {
+            return Err(ApiError::InputError("Ate loop count parameters can not be zero".to_owned()));
+        }
 
-        let mut b_fp3 = twist_cubed.clone();
-        b_fp3.mul_by_fp(&b_fp);
+        if calculate_hamming_weight(&ate_loop_count_1.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
+            return Err(ApiError::InputError("Ate loop count has too large hamming weight".to_owned()));
+        }
 
-        let fp3_params = CurveOverFp3Parameters::new(&extension_3);
-        let g2_curve = WeierstrassCurve::new(&order.as_ref(), a_fp3, b_fp3, &fp3_params).map_err(|_| {
-            ApiError::InputError("Curve shape is not supported".to_owned())
-        })?;
+        let (ate_loop_count_1_is_negative, rest) = decode_sign_is_negative(rest)?;
 
-        let (x, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
-        if x.is_zero() {
+        let (ate_loop_count_2, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_ATE_LOOP_COUNT)?;
+        if ate_loop_count_2.is_zero() {
             return Err(ApiError::InputError("Ate loop count parameters can not be zero".to_owned()));
         }
 
-        if calculate_hamming_weight(&x.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
-            return Err(ApiError::InputError("X has too large hamming weight".to_owned()));
+        if calculate_hamming_weight(&ate_loop_count_2.as_ref()) > MAX_ATE_PAIRING_ATE_LOOP_COUNT_HAMMING {
+            return Err(ApiError::InputError("Ate loop count has too large hamming weight".to_owned()));
         }
 
-        let (x_is_negative, rest) = decode_sign_is_negative(rest)?;
+        let (ate_loop_count_2_is_negative, rest) = decode_sign_is_negative(rest)?;
 
         let (exp_w0, rest) = decode_loop_parameter_scalar_with_bit_limit(&rest, MAX_ATE_PAIRING_FINAL_EXP_W0_BIT_LENGTH)?;
         if exp_w0.is_zero() {
@@ -653,6 +1126,8 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             }
         }
 
+        let compressed = pairing_points_are_compressed(rest, num_pairs, modulus_len, modulus_len);
+
         let mut global_rest = rest;
 
         let mut g1_points = vec![];
@@ -660,9 +1135,9 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         for _ in 0..num_pairs {
             let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
-            let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g1_curve)?;
+            let (g1, rest) = decode_g1_point(&rest, modulus_len, &g1_curve, compressed)?;
             let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
-            let (g2, rest) = decode_g2_point_from_xy_in_fp3(&rest, modulus_len, &g2_curve)?;
+            let (g2, rest) = decode_g1_point(&rest, modulus_len, &g2_curve, compressed)?;
             global_rest = rest;
 
             if !g1.is_on_curve() {
@@ -708,22 +1183,24 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             return Ok(pairing_result_true());
         }
 
-        let engine_params = MNT6InstanceParams {
-            x: &x.as_ref(),
-            x_is_negative: x_is_negative,
+        let engine_params = Bw6InstanceParams {
+            ate_loop_count_1: &ate_loop_count_1.as_ref(),
+            ate_loop_count_1_is_negative: ate_loop_count_1_is_negative,
+            ate_loop_count_2: &ate_loop_count_2.as_ref(),
+            ate_loop_count_2_is_negative: ate_loop_count_2_is_negative,
             exp_w0: exp_w0.as_ref(),
             exp_w1: exp_w1.as_ref(),
             exp_w0_is_negative: exp_w0_is_negative,
+            twist_type: twist_type,
             base_field: &base_field,
             curve: &g1_curve,
             curve_twist: &g2_curve,
-            twist: twist,
             fp3_extension: &extension_3,
             fp6_extension: &extension_6,
             force_no_naf: true
         };
 
-        let engine = MNT6Instance::from_params(engine_params);
+        let engine = Bw6Instance::from_params(engine_params);
 
         let pairing_result = engine.pair(&g1_points, &g2_points);
 
@@ -767,8 +1244,9 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
         // - list of encoded pairs
 
         let (fp_non_residue, rest) = decode_fp(&rest, modulus_len, &base_field)?;
+        let mut cached_tower = CachedParameters::lookup(CACHE_TAG_MNT4_TOWER, modulus.as_ref(), consumed_prefix(bytes, rest));
 
-        {
+        if !cached_tower.is_hit() {
             if fp_non_residue.is_zero() {
                 return Err(ApiError::InputError(format!("Non-residue for Fp2 is zero file {}, line {}", file!(), line!())));
             }
@@ -780,25 +1258,34 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             }
         }
 
-        let base_precomp = Fp2Fp4FrobeniusBaseElements::construct(
-            &modulus, &fp_non_residue
-        ).map_err(|_| {
-            ApiError::UnknownParameter("Can not make base precomputations for Fp3/Fp6 frobenius".to_owned())
-        })?;
-
         // build an extension field
         let mut extension_2 = Extension2::new(fp_non_residue);
-        extension_2.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
-            ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
-        })?;
+        let base_precomp = if cached_tower.restore(&mut extension_2)? {
+            None
+        } else {
+            let base_precomp = Fp2Fp4FrobeniusBaseElements::construct(
+                &modulus, &fp_non_residue
+            ).map_err(|_| {
+                ApiError::UnknownParameter("Can not make base precomputations for Fp3/Fp6 frobenius".to_owned())
+            })?;
+            extension_2.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
+                ApiError::InputError("Failed to calculate Frobenius coeffs for Fp2".to_owned())
+            })?;
+            cached_tower.record(&extension_2);
 
-        let mut extension_4 = Extension2Over2::new(Fp2::zero(&extension_2));
+            Some(base_precomp)
+        };
 
-        {
+        let mut extension_4 = Extension2Over2::new(Fp2::zero(&extension_2));
+        if let Some(base_precomp) = base_precomp {
             extension_4.calculate_frobenius_coeffs_with_precomp(&base_precomp).map_err(|_| {
                 ApiError::UnknownParameter("Can not calculate Frobenius coefficients for Fp4".to_owned())
             })?;
+            cached_tower.record(&extension_4);
+        } else {
+            cached_tower.restore(&mut extension_4)?;
         }
+        cached_tower.store();
 
         // // build an extension field
 
@@ -855,6 +1342,8 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
             }
         }
 
+        let compressed = pairing_points_are_compressed(rest, num_pairs, modulus_len, 2*modulus_len);
+
         let mut global_rest = rest;
 
         let mut g1_points = vec![];
@@ -862,9 +1351,9 @@ impl<FE: ElementRepr>PairingApiImplementation<FE> {
 
         for _ in 0..num_pairs {
             let (check_g1_subgroup, rest) = decode_boolean(&global_rest)?;
-            let (g1, rest) = decode_g1_point_from_xy(&rest, modulus_len, &g1_curve)?;
+            let (g1, rest) = decode_g1_point(&rest, modulus_len, &g1_curve, compressed)?;
             let (check_g2_subgroup, rest) = decode_boolean(&rest)?;
-            let (g2, rest) = decode_g2_point_from_xy_in_fp2(&rest, modulus_len, &g2_curve)?;
+            let (g2, rest) = decode_g2_point_in_fp2(&rest, modulus_len, &g2_curve, compressed)?;
             global_rest = rest;
 
             if !g1.is_on_curve() {
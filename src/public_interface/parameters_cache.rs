@@ -0,0 +1,383 @@
+//! Optional cache of parsed curve parameters for the generic ABI.
+//!
+//! Repeated calls on the same custom curve would otherwise derive the prime field, check
+//! non-residues and calculate Frobenius coefficients of the extension towers on every call.
+//! With the `parameters_cache` feature successfully validated parameters are stored
+//! in a bounded LRU cache keyed by the encoded parameters prefix, so the next call with
+//! the same prefix skips validation and restores precomputed values.
+//!
+//! Entries contain only plain limbs (Montgomery form for field elements), so cached
+//! data never borrows from fields and the cache can be shared between threads.
+//! Without the feature all lookups miss and nothing is stored.
+
+use std::sync::Arc;
+
+use crate::field::{SizedPrimeField, PrimeField};
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::extension_towers::fp2::{Fp2, Extension2};
+use crate::extension_towers::fp3::Extension3;
+use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
+use crate::extension_towers::fp6_as_2_over_3::Extension2Over3;
+use crate::extension_towers::fp6_as_3_over_2::Extension3Over2;
+use crate::extension_towers::fp12_as_2_over3_over_2::Extension2Over3Over2;
+use crate::extension_towers::fp12_as_3_over_4::Extension3Over4;
+use crate::extension_towers::fp24_as_2_over3_over_4::Extension2Over3Over4;
+use crate::errors::ApiError;
+
+// Every place that caches parameters uses a separate tag, so prefixes of different encodings
+// (or the same encoding validated differently) never share an entry
+pub(crate) const CACHE_TAG_BASE_FIELD: u8 = 1;
+pub(crate) const CACHE_TAG_FP2_EXTENSION: u8 = 2;
+pub(crate) const CACHE_TAG_FP2_EXTENSION_WITH_FROBENIUS: u8 = 3;
+pub(crate) const CACHE_TAG_FP3_EXTENSION: u8 = 4;
+pub(crate) const CACHE_TAG_FP3_EXTENSION_WITH_FROBENIUS: u8 = 5;
+pub(crate) const CACHE_TAG_BLS12_FP2: u8 = 6;
+pub(crate) const CACHE_TAG_BLS12_FP6_FP12: u8 = 7;
+pub(crate) const CACHE_TAG_BN_FP2: u8 = 8;
+pub(crate) const CACHE_TAG_BN_FP6_FP12: u8 = 9;
+pub(crate) const CACHE_TAG_BLS24_TOWER: u8 = 10;
+pub(crate) const CACHE_TAG_MNT4_TOWER: u8 = 11;
+pub(crate) const CACHE_TAG_MNT6_TOWER: u8 = 12;
+pub(crate) const CACHE_TAG_BW6_TOWER: u8 = 13;
+
+/// Returns the part of `bytes` that was consumed before `rest`
+pub(crate) fn consumed_prefix<'a>(bytes: &'a [u8], rest: &[u8]) -> &'a [u8] {
+    debug_assert!(rest.len() <= bytes.len());
+
+    &bytes[..(bytes.len() - rest.len())]
+}
+
+/// Values that can be saved to the cache as limbs and restored into a freshly
+/// constructed value of the same shape
+pub(crate) trait CachedLimbs {
+    fn write_limbs(&self, limbs: &mut Vec<u64>);
+    fn read_limbs(&mut self, limbs: &mut &[u64]) -> bool;
+}
+
+fn read_repr<FE: ElementRepr>(repr: &mut FE, limbs: &mut &[u64]) -> bool {
+    if limbs.len() < FE::NUM_LIMBS {
+        return false;
+    }
+    let (head, tail) = limbs.split_at(FE::NUM_LIMBS);
+    repr.as_mut().copy_from_slice(head);
+    *limbs = tail;
+
+    true
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> CachedLimbs for Fp<'a, FE, F> {
+    fn write_limbs(&self, limbs: &mut Vec<u64>) {
+        limbs.extend_from_slice(self.repr.as_ref());
+    }
+
+    fn read_limbs(&mut self, limbs: &mut &[u64]) -> bool {
+        read_repr(&mut self.repr, limbs)
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> CachedLimbs for Fp2<'a, FE, F> {
+    fn write_limbs(&self, limbs: &mut Vec<u64>) {
+        self.c0.write_limbs(limbs);
+        self.c1.write_limbs(limbs);
+    }
+
+    fn read_limbs(&mut self, limbs: &mut &[u64]) -> bool {
+        self.c0.read_limbs(limbs) && self.c1.read_limbs(limbs)
+    }
+}
+
+impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> CachedLimbs for Fp4<'a, FE, F> {
+    fn write_limbs(&self, limbs: &mut Vec<u64>) {
+        self.c0.write_limbs(limbs);
+        self.c1.write_limbs(limbs);
+    }
+
+    fn read_limbs(&mut self, limbs: &mut &[u64]) -> bool {
+        self.c0.read_limbs(limbs) && self.c1.read_limbs(limbs)
+    }
+}
+
+impl<FE: ElementRepr> CachedLimbs for PrimeField<FE> {
+    fn write_limbs(&self, limbs: &mut Vec<u64>) {
+        limbs.extend_from_slice(&[self.mont_power, self.modulus_bits, self.mont_inv]);
+        limbs.extend_from_slice(self.modulus.as_ref());
+        limbs.extend_from_slice(self.mont_r.as_ref());
+        limbs.extend_from_slice(self.mont_r2.as_ref());
+    }
+
+    fn read_limbs(&mut self, limbs: &mut &[u64]) -> bool {
+        if limbs.len() < 3 {
+            return false;
+        }
+        let (header, tail) = limbs.split_at(3);
+        self.mont_power = header[0];
+        self.modulus_bits = header[1];
+        self.mont_inv = header[2];
+        *limbs = tail;
+
+        read_repr(&mut self.modulus, limbs) && read_repr(&mut self.mont_r, limbs) && read_repr(&mut self.mont_r2, limbs)
+    }
+}
+
+impl<T: CachedLimbs> CachedLimbs for [T] {
+    fn write_limbs(&self, limbs: &mut Vec<u64>) {
+        for el in self.iter() {
+            el.write_limbs(limbs);
+        }
+    }
+
+    fn read_limbs(&mut self, limbs: &mut &[u64]) -> bool {
+        self.iter_mut().all(|el| el.read_limbs(limbs))
+    }
+}
+
+/// Extensions are cached by their Frobenius coefficients, everything else
+/// is cheap to get from a non-residue in `new`
+macro_rules! impl_cached_limbs_for_extension {
+    ($extension: ident, $($coeffs: ident),+) => {
+        impl<'a, FE: ElementRepr, F: SizedPrimeField<Repr = FE>> CachedLimbs for $extension<'a, FE, F> {
+            fn write_limbs(&self, limbs: &mut Vec<u64>) {
+                debug_assert!(self.frobenius_coeffs_are_calculated);
+                $(
+                    self.$coeffs[..].write_limbs(limbs);
+                )+
+            }
+
+            fn read_limbs(&mut self, limbs: &mut &[u64]) -> bool {
+                $(
+                    if !self.$coeffs[..].read_limbs(limbs) {
+                        return false;
+                    }
+                )+
+                self.frobenius_coeffs_are_calculated = true;
+
+                true
+            }
+        }
+    }
+}
+
+impl_cached_limbs_for_extension!(Extension2, frobenius_coeffs_c1);
+impl_cached_limbs_for_extension!(Extension3, frobenius_coeffs_c1, frobenius_coeffs_c2);
+impl_cached_limbs_for_extension!(Extension2Over2, frobenius_coeffs_c1);
+impl_cached_limbs_for_extension!(Extension2Over3, frobenius_coeffs_c1);
+impl_cached_limbs_for_extension!(Extension3Over2, frobenius_coeffs_c1, frobenius_coeffs_c2);
+impl_cached_limbs_for_extension!(Extension2Over3Over2, frobenius_coeffs_c1);
+impl_cached_limbs_for_extension!(Extension3Over4, frobenius_coeffs_c1, frobenius_coeffs_c2);
+impl_cached_limbs_for_extension!(Extension2Over3Over4, frobenius_coeffs_c1);
+
+/// Lookup of one parameters set. On a hit values are restored in the same order as they
+/// were recorded on a miss. A set is stored only by `store`, so callers store it
+/// after all validations have passed
+pub(crate) struct CachedParameters {
+    key: Option<Vec<u8>>,
+    cached: Option<Arc<[u64]>>,
+    position: usize,
+    recorded: Vec<u64>,
+}
+
+impl CachedParameters {
+    /// Looks up parameters by the modulus and the encoded prefix that defines them
+    #[allow(unused_variables)]
+    pub(crate) fn lookup(tag: u8, modulus: &[u64], encoded_prefix: &[u8]) -> Self {
+        #[cfg(feature = "parameters_cache")]
+        {
+            if PARAMETERS_CACHE.capacity() != 0 {
+                let mut key = Vec::with_capacity(1 + modulus.len() * 8 + encoded_prefix.len());
+                key.push(tag);
+                for limb in modulus.iter() {
+                    key.extend_from_slice(&limb.to_le_bytes());
+                }
+                key.extend_from_slice(encoded_prefix);
+
+                let cached = PARAMETERS_CACHE.get(&key);
+
+                return Self {
+                    key: Some(key),
+                    cached,
+                    position: 0,
+                    recorded: vec![],
+                };
+            }
+        }
+
+        Self {
+            key: None,
+            cached: None,
+            position: 0,
+            recorded: vec![],
+        }
+    }
+
+    /// Parameters with this key were already validated
+    pub(crate) fn is_hit(&self) -> bool {
+        self.cached.is_some()
+    }
+
+    /// Restores the next value on a hit. Returns `false` on a miss,
+    /// then the caller should calculate the value and `record` it
+    pub(crate) fn restore<T: CachedLimbs + ?Sized>(&mut self, value: &mut T) -> Result<bool, ApiError> {
+        if let Some(cached) = self.cached.as_ref() {
+            let mut limbs = &cached[self.position..];
+            let available = limbs.len();
+            if !value.read_limbs(&mut limbs) {
+                // can not happen: modulus defines the number of limbs and every tag
+                // always records the same values
+                return Err(ApiError::UnknownParameter("Cached parameters are inconsistent".to_owned()));
+            }
+            self.position += available - limbs.len();
+
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    pub(crate) fn record<T: CachedLimbs + ?Sized>(&mut self, value: &T) {
+        if self.key.is_some() && self.cached.is_none() {
+            value.write_limbs(&mut self.recorded);
+        }
+    }
+
+    pub(crate) fn restore_field<FE: ElementRepr>(&mut self) -> Result<Option<PrimeField<FE>>, ApiError> {
+        let mut field = PrimeField {
+            mont_power: 0,
+            modulus_bits: 0,
+            modulus: FE::default(),
+            mont_r: FE::default(),
+            mont_r2: FE::default(),
+            mont_inv: 0
+        };
+        if !self.restore(&mut field)? {
+            return Ok(None);
+        }
+
+        Ok(Some(field))
+    }
+
+    /// Stores recorded values on a miss
+    pub(crate) fn store(self) {
+        #[cfg(feature = "parameters_cache")]
+        {
+            if let (Some(key), None) = (self.key, self.cached) {
+                PARAMETERS_CACHE.insert(key, self.recorded.into());
+            }
+        }
+    }
+}
+
+#[cfg(feature = "parameters_cache")]
+pub use self::lru::*;
+
+#[cfg(feature = "parameters_cache")]
+mod lru {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, MutexGuard};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use once_cell::sync::Lazy;
+
+    pub const DEFAULT_PARAMETERS_CACHE_CAPACITY: usize = 128;
+
+    pub(crate) static PARAMETERS_CACHE: Lazy<ParametersCache> = Lazy::new(|| {
+        ParametersCache::new(DEFAULT_PARAMETERS_CACHE_CAPACITY)
+    });
+
+    /// Sets the maximum number of cached parameter sets, least recently used sets are
+    /// dropped if there are more. Zero capacity disables the cache
+    pub fn set_parameters_cache_capacity(capacity: usize) {
+        PARAMETERS_CACHE.set_capacity(capacity);
+    }
+
+    pub fn clear_parameters_cache() {
+        PARAMETERS_CACHE.clear();
+    }
+
+    pub fn parameters_cache_len() -> usize {
+        PARAMETERS_CACHE.len()
+    }
+
+    struct Entries {
+        tick: u64,
+        map: HashMap<Vec<u8>, (u64, Arc<[u64]>)>,
+    }
+
+    impl Entries {
+        fn evict_until(&mut self, len: usize) {
+            while self.map.len() > len {
+                let oldest = self.map.iter()
+                    .min_by_key(|(_, (last_used, _))| *last_used)
+                    .map(|(key, _)| key.clone())
+                    .expect("map is not empty");
+                self.map.remove(&oldest);
+            }
+        }
+    }
+
+    /// Thread safe LRU map from parameters keys to cached limbs. Eviction scans
+    /// all entries, that is cheap compared to any operation for a reasonable capacity
+    pub(crate) struct ParametersCache {
+        capacity: AtomicUsize,
+        entries: Mutex<Entries>,
+    }
+
+    impl ParametersCache {
+        pub(crate) fn new(capacity: usize) -> Self {
+            Self {
+                capacity: AtomicUsize::new(capacity),
+                entries: Mutex::new(Entries {
+                    tick: 0,
+                    map: HashMap::new(),
+                }),
+            }
+        }
+
+        fn entries(&self) -> MutexGuard<'_, Entries> {
+            // entries are always consistent, so a panic in another thread doesn't matter
+            self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+
+        pub(crate) fn capacity(&self) -> usize {
+            self.capacity.load(Ordering::Relaxed)
+        }
+
+        pub(crate) fn set_capacity(&self, capacity: usize) {
+            let mut entries = self.entries();
+            self.capacity.store(capacity, Ordering::Relaxed);
+            entries.evict_until(capacity);
+        }
+
+        pub(crate) fn len(&self) -> usize {
+            self.entries().map.len()
+        }
+
+        pub(crate) fn clear(&self) {
+            self.entries().map.clear();
+        }
+
+        pub(crate) fn get(&self, key: &[u8]) -> Option<Arc<[u64]>> {
+            let mut entries = self.entries();
+            entries.tick += 1;
+            let tick = entries.tick;
+            let (last_used, value) = entries.map.get_mut(key)?;
+            *last_used = tick;
+
+            Some(value.clone())
+        }
+
+        pub(crate) fn insert(&self, key: Vec<u8>, value: Arc<[u64]>) {
+            let mut entries = self.entries();
+            let capacity = self.capacity();
+            if capacity == 0 {
+                return;
+            }
+            entries.tick += 1;
+            let tick = entries.tick;
+            if !entries.map.contains_key(&key) {
+                entries.evict_until(capacity - 1);
+            }
+            entries.map.insert(key, (tick, value));
+        }
+    }
+}
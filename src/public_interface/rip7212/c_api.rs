@@ -0,0 +1,130 @@
+// For C style API caller has to preallocate some buffers for results
+pub const RIP7212_PREALLOCATE_FOR_ERROR_BYTES: usize = 256;
+pub const RIP7212_PREALLOCATE_FOR_RESULT_BYTES: usize = 32;
+
+use static_assertions::const_assert;
+const_assert!(RIP7212_PREALLOCATE_FOR_RESULT_BYTES == super::SERIALIZED_RESULT_BYTE_LENGTH);
+
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rip7212OperationType {
+    P256VERIFY = 1,
+}
+
+impl Rip7212OperationType {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            RIP7212_P256VERIFY_OPERATION_RAW_VALUE => {
+                Some(Rip7212OperationType::P256VERIFY)
+            },
+            _ => {
+                None
+            }
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+pub const RIP7212_P256VERIFY_OPERATION_RAW_VALUE: u8 = Rip7212OperationType::P256VERIFY as u8;
+
+fn write_error(
+    description: &[u8],
+    err: *mut ::std::os::raw::c_char,
+    char_len: *mut u32) -> u32
+{
+    use std::io::Write;
+
+    let err_out_i8: &mut [i8] = unsafe { std::slice::from_raw_parts_mut(err, RIP7212_PREALLOCATE_FOR_ERROR_BYTES) };
+    let mut err_out: &mut [u8] = unsafe { &mut *(err_out_i8 as *mut [i8] as *mut [u8]) };
+
+    let written = err_out.write(description);
+    if let Ok(bytes_written) = written {
+        unsafe { *char_len = bytes_written as u32 };
+    } else {
+        unsafe { *char_len = 0u32 };
+    }
+
+    1u32
+}
+
+// this is C interface
+#[no_mangle]
+pub extern "C" fn rip7212_perform_operation(
+    op: ::std::os::raw::c_char,
+    i: *const ::std::os::raw::c_char,
+    i_len: u32,
+    o: *mut ::std::os::raw::c_char,
+    o_len: *mut u32,
+    err: *mut ::std::os::raw::c_char,
+    char_len: *mut u32) -> u32
+{
+    use std::io::Write;
+
+    let operation = match Rip7212OperationType::from_u8(op as u8) {
+        Some(operation) => operation,
+        None => return write_error(b"Unknown operation type\0", err, char_len)
+    };
+
+    let input_i8: &[i8] = unsafe { std::slice::from_raw_parts(i, i_len as usize) };
+    let input: &[u8] = unsafe { &*(input_i8 as *const [i8] as *const [u8]) };
+
+    let raw_out_i8: &mut [i8] = unsafe { std::slice::from_raw_parts_mut(o, RIP7212_PREALLOCATE_FOR_RESULT_BYTES) };
+    let mut raw_out: &mut [u8] = unsafe { &mut *(raw_out_i8 as *mut [i8] as *mut [u8]) };
+
+    let result = match operation {
+        Rip7212OperationType::P256VERIFY => super::P256VerifyExecutor::verify(input),
+    };
+
+    match result {
+        Ok(result) => {
+            let written = raw_out.write(result.as_ref());
+            if let Ok(bytes_written) = written {
+                unsafe { *o_len = bytes_written as u32 };
+                return 0u32;
+            }
+
+            write_error(b"Failed to write the result\0", err, char_len)
+        },
+        Err(error) => {
+            write_error(error.to_string().as_bytes(), err, char_len)
+        }
+    }
+}
+
+// this is C interface for gas metering
+#[no_mangle]
+pub extern "C" fn rip7212_meter_operation(
+    op: ::std::os::raw::c_char,
+    i: *const ::std::os::raw::c_char,
+    i_len: u32,
+    o: *mut u64,
+    err: *mut ::std::os::raw::c_char,
+    char_len: *mut u32) -> u32
+{
+    let operation = match Rip7212OperationType::from_u8(op as u8) {
+        Some(operation) => operation,
+        None => return write_error(b"Unknown operation type\0", err, char_len)
+    };
+
+    let input_i8: &[i8] = unsafe { std::slice::from_raw_parts(i, i_len as usize) };
+    let input: &[u8] = unsafe { &*(input_i8 as *const [i8] as *const [u8]) };
+
+    let result = match operation {
+        Rip7212OperationType::P256VERIFY => super::P256VerifyExecutor::meter(input),
+    };
+
+    match result {
+        Ok(result) => {
+            unsafe { *o = result };
+
+            0u32
+        },
+        Err(error) => {
+            write_error(error.to_string().as_bytes(), err, char_len)
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::engines::secp256r1::*;
+use crate::public_interface::ApiError;
+use crate::public_interface::decode_fp;
+use crate::public_interface::decode_utils::split;
+
+use crate::field::{U320Repr, PrimeField, SizedPrimeField};
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::FieldElement;
+use crate::weierstrass::Group;
+use crate::weierstrass::curve::CurvePoint;
+use crate::multiexp::shamir;
+
+pub const HASH_BYTE_LENGTH: usize = 32;
+pub const SCALAR_BYTE_LENGTH: usize = 32;
+pub const SERIALIZED_FP_BYTE_LENGTH: usize = 32;
+pub const SERIALIZED_G1_POINT_BYTE_LENGTH: usize = SERIALIZED_FP_BYTE_LENGTH * 2;
+
+pub const EXPECTED_INPUT_LEN: usize = HASH_BYTE_LENGTH + SCALAR_BYTE_LENGTH * 2 + SERIALIZED_G1_POINT_BYTE_LENGTH;
+pub const SERIALIZED_RESULT_BYTE_LENGTH: usize = 32;
+
+/// Fixed price of the P256VERIFY precompile as defined in RIP-7212
+pub const P256_VERIFY_GAS: u64 = 3450;
+
+#[cfg(feature = "rip_7212_c_api")]
+pub mod c_api;
+
+fn verification_result_false() -> [u8; SERIALIZED_RESULT_BYTE_LENGTH] {
+    [0u8; SERIALIZED_RESULT_BYTE_LENGTH]
+}
+
+fn verification_result_true() -> [u8; SERIALIZED_RESULT_BYTE_LENGTH] {
+    let mut res = [0u8; SERIALIZED_RESULT_BYTE_LENGTH];
+    res[31] = 1u8;
+
+    res
+}
+
+fn decode_signature_scalar<'a>(encoding: &[u8], name: &str) -> Result<Fp<'a, U320Repr, PrimeField<U320Repr>>, ApiError> {
+    let scalar = Fp::from_be_bytes(&SECP256R1_SCALAR_FIELD, encoding, true).map_err(|_| {
+        ApiError::InputError(format!("Signature {} is not below the group order", name))
+    })?;
+    if scalar.is_zero() {
+        return Err(ApiError::InputError(format!("Signature {} is zero", name)));
+    }
+
+    Ok(scalar)
+}
+
+fn reduce_into_scalar<'a>(encoding: &[u8]) -> Result<Fp<'a, U320Repr, PrimeField<U320Repr>>, ApiError> {
+    let mut padded = [0u8; 40];
+    padded[(40 - encoding.len())..].copy_from_slice(encoding);
+
+    let mut repr = U320Repr::default();
+    repr.read_be(&padded[..]).map_err(|_| {
+        ApiError::InputError("Failed to read scalar".to_owned())
+    })?;
+
+    // input is below 2^256 < 2*n, so single subtraction is enough
+    if &repr >= SECP256R1_SCALAR_FIELD.modulus() {
+        repr.sub_noborrow(SECP256R1_SCALAR_FIELD.modulus());
+    }
+
+    Fp::from_repr(&SECP256R1_SCALAR_FIELD, repr).map_err(|_| {
+        ApiError::InputError("Failed to reduce scalar".to_owned())
+    })
+}
+
+/// Implements the P256VERIFY precompile of RIP-7212 over the static secp256r1 engine.
+/// Malformed input is reported as an error and failed verification as a `false` result,
+/// both of which should be mapped into an empty output by a spec compliant caller
+pub struct P256VerifyExecutor;
+
+impl P256VerifyExecutor {
+    pub fn verify(input: &[u8]) -> Result<[u8; SERIALIZED_RESULT_BYTE_LENGTH], ApiError> {
+        if input.len() != EXPECTED_INPUT_LEN {
+            return Err(ApiError::InputError("invalid input length for P256 signature verification".to_owned()));
+        }
+
+        let (hash, rest) = split(input, HASH_BYTE_LENGTH, "Input is not long enough to get message hash")?;
+        let (r_encoding, rest) = split(rest, SCALAR_BYTE_LENGTH, "Input is not long enough to get signature R")?;
+        let (s_encoding, rest) = split(rest, SCALAR_BYTE_LENGTH, "Input is not long enough to get signature S")?;
+        let (x_encoding, rest) = split(rest, SERIALIZED_FP_BYTE_LENGTH, "Input is not long enough to get public key X")?;
+        let (y_encoding, _) = split(rest, SERIALIZED_FP_BYTE_LENGTH, "Input is not long enough to get public key Y")?;
+
+        let r = decode_signature_scalar(r_encoding, "R")?;
+        let s = decode_signature_scalar(s_encoding, "S")?;
+        let z = reduce_into_scalar(hash)?;
+
+        let x = Fp::from_be_bytes(&SECP256R1_FIELD, x_encoding, true).map_err(|e| {
+            ApiError::InputError(format!("Failed to parse public key X, {}", e))
+        })?;
+        let y = Fp::from_be_bytes(&SECP256R1_FIELD, y_encoding, true).map_err(|e| {
+            ApiError::InputError(format!("Failed to parse public key Y, {}", e))
+        })?;
+
+        let public_key = CurvePoint::point_from_xy(&SECP256R1_G1_CURVE, x, y);
+
+        // curve has a cofactor of one, so it's enough to check that the point is on curve
+        if public_key.is_zero() {
+            return Err(ApiError::InputError("Public key is a point at infinity".to_owned()));
+        }
+        if !public_key.is_on_curve() && !crate::features::in_fuzzing_or_gas_metering() {
+            return Err(ApiError::InputError("Public key is not on curve".to_owned()));
+        }
+
+        let s_inv = s.inverse().ok_or_else(|| {
+            ApiError::InputError("Signature S is not invertible".to_owned())
+        })?;
+
+        // R' = (z * s^-1) * G + (r * s^-1) * Q
+        let mut u1 = z;
+        u1.mul_assign(&s_inv);
+
+        let mut u2 = r;
+        u2.mul_assign(&s_inv);
+
+        let r_point = shamir(&SECP256R1_G1_GENERATOR, u1.into_repr(), &public_key, u2.into_repr());
+
+        if r_point.is_zero() {
+            return Ok(verification_result_false());
+        }
+
+        let (r_x, _) = r_point.into_xy();
+        let r_x_encoding = decode_fp::serialize_fp_fixed_len(SERIALIZED_FP_BYTE_LENGTH, &r_x)?;
+        let r_x = reduce_into_scalar(&r_x_encoding)?;
+
+        let result = if r_x == r {
+            verification_result_true()
+        } else {
+            verification_result_false()
+        };
+
+        Ok(result)
+    }
+
+    pub fn meter(_input: &[u8]) -> Result<u64, ApiError> {
+        Ok(P256_VERIFY_GAS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // RFC 6979, A.2.5, P-256 with SHA-256, message "sample"
+    const RFC6979_VECTOR: &str = "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bfefd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda860fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+
+    // message hash is above the group order
+    const LARGE_HASH_VECTOR: &str = "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6f93ac0ad9f288e41233df19a622fbe4eac8d0ca695630b2c1495dd216fed1c72029969e0d712f23005fc27ce3467670580c92f79c596e7b6e3b0d1d85c56563ed5784a75391dc43adcd42dbc4c938e80690c75b3f4309049d5076692f8dafe97ed5e8b3d94dd41f677d0e25f6ea5b332495bbdb74923eabbe9e7d2c1d09a08a";
+
+    fn run(input_hex: &str) -> Result<[u8; SERIALIZED_RESULT_BYTE_LENGTH], ApiError> {
+        let input = hex::decode(input_hex).unwrap();
+        P256VerifyExecutor::verify(&input)
+    }
+
+    #[test]
+    fn test_valid_signatures() {
+        assert_eq!(run(RFC6979_VECTOR).unwrap(), verification_result_true());
+        assert_eq!(run(LARGE_HASH_VECTOR).unwrap(), verification_result_true());
+        assert_eq!(P256VerifyExecutor::meter(&hex::decode(RFC6979_VECTOR).unwrap()).unwrap(), P256_VERIFY_GAS);
+    }
+
+    #[test]
+    fn test_invalid_signatures() {
+        // tampered message hash
+        let tampered = format!("{}{}", "bf", &RFC6979_VECTOR[2..]);
+        assert_eq!(run(&tampered).unwrap(), verification_result_false());
+
+        // public key of the other vector
+        let other_key = format!("{}{}", &RFC6979_VECTOR[..192], &LARGE_HASH_VECTOR[192..]);
+        assert_eq!(run(&other_key).unwrap(), verification_result_false());
+
+        // public key is not on curve
+        let not_on_curve = format!("{}{}", &RFC6979_VECTOR[..318], "98");
+        assert!(run(&not_on_curve).is_err());
+
+        // public key is a point at infinity
+        let infinity = format!("{}{}", &RFC6979_VECTOR[..192], "00".repeat(64));
+        assert!(run(&infinity).is_err());
+
+        // S equal to the group order
+        let s_is_order = format!("{}{}{}", &RFC6979_VECTOR[..128], "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551", &RFC6979_VECTOR[192..]);
+        assert!(run(&s_is_order).is_err());
+
+        // zero R
+        let zero_r = format!("{}{}{}", &RFC6979_VECTOR[..64], "00".repeat(32), &RFC6979_VECTOR[128..]);
+        assert!(run(&zero_r).is_err());
+
+        // input length must be exact
+        assert!(run(&RFC6979_VECTOR[..318]).is_err());
+        assert!(run(&format!("{}00", RFC6979_VECTOR)).is_err());
+    }
+
+    #[test]
+    fn test_shamir_against_separate_multiplications() {
+        let mut q = SECP256R1_G1_GENERATOR.mul(&[0x1234567890abcdef, 0x1, 0x0, 0x0][..]);
+        q.normalize();
+
+        let s_0 = [0xfedcba0987654321u64, 0xaaaaaaaaaaaaaaaa, 0x5555555555555555, 0x0000ffff0000ffff, 0];
+        let s_1 = [0x0123456789abcdefu64, 0x0f0f0f0f0f0f0f0f, 0xf0f0f0f0f0f0f0f0, 0xffff0000ffff0000, 0];
+
+        let mut expected = SECP256R1_G1_GENERATOR.mul(&s_0[..]);
+        expected.add_assign(&q.mul(&s_1[..]));
+
+        let mut result = shamir(&SECP256R1_G1_GENERATOR, &s_0[..], &q, &s_1[..]);
+
+        expected.normalize();
+        result.normalize();
+        assert_eq!(expected.into_xy(), result.into_xy());
+
+        // equal bases
+        let mut doubled = shamir(&SECP256R1_G1_GENERATOR, &s_0[..], &SECP256R1_G1_GENERATOR, &s_0[..]);
+        let mut expected = SECP256R1_G1_GENERATOR.mul(&s_0[..]);
+        expected.double();
+
+        doubled.normalize();
+        expected.normalize();
+        assert_eq!(expected.into_xy(), doubled.into_xy());
+    }
+}
@@ -1,6 +1,11 @@
 use crate::public_interface::pairing_ops::PairingApiImplementation;
-use crate::public_interface::g1_ops::{G1Api, PublicG1Api};
-use crate::public_interface::g2_ops::{G2Api, PublicG2Api};
+use crate::public_interface::g1_ops::{G1Api, G1ApiImplementation};
+use crate::public_interface::g2_ops::{G2Api, G2ApiImplementationFp2, G2ApiImplementationFp3};
+use crate::public_interface::edwards_ops::{EdwardsApi, EdwardsApiImplementation};
+#[cfg(feature = "mappings")]
+use crate::public_interface::map_to_curve_ops::{MapToCurveApi, MapToCurveApiImplementation};
+use crate::public_interface::decode_utils::{DecodedInput, split};
+use crate::public_interface::constants::*;
 
 use crate::errors::ApiError;
 
@@ -24,6 +29,17 @@ pub enum OperationType {
     BNPAIR = 8,
     MNT4PAIR = 9,
     MNT6PAIR = 10,
+    #[cfg(feature = "mappings")]
+    G1MAP = 11,
+    #[cfg(feature = "mappings")]
+    G2MAP = 12,
+    EDWARDSADD = 13,
+    EDWARDSMUL = 14,
+    EDWARDSMULTIEXP = 15,
+    G1DECOMPRESS = 16,
+    G2DECOMPRESS = 17,
+    BW6PAIR = 18,
+    BLS24PAIR = 19,
 }
 
 impl OperationType {
@@ -59,6 +75,35 @@ impl OperationType {
             MNT6PAIR_OPERATION_RAW_VALUE => {
                 Some(OperationType::MNT6PAIR)
             },
+            #[cfg(feature = "mappings")]
+            G1MAP_OPERATION_RAW_VALUE => {
+                Some(OperationType::G1MAP)
+            },
+            #[cfg(feature = "mappings")]
+            G2MAP_OPERATION_RAW_VALUE => {
+                Some(OperationType::G2MAP)
+            },
+            EDWARDSADD_OPERATION_RAW_VALUE => {
+                Some(OperationType::EDWARDSADD)
+            },
+            EDWARDSMUL_OPERATION_RAW_VALUE => {
+                Some(OperationType::EDWARDSMUL)
+            },
+            EDWARDSMULTIEXP_OPERATION_RAW_VALUE => {
+                Some(OperationType::EDWARDSMULTIEXP)
+            },
+            G1DECOMPRESS_OPERATION_RAW_VALUE => {
+                Some(OperationType::G1DECOMPRESS)
+            },
+            G2DECOMPRESS_OPERATION_RAW_VALUE => {
+                Some(OperationType::G2DECOMPRESS)
+            },
+            BW6PAIR_OPERATION_RAW_VALUE => {
+                Some(OperationType::BW6PAIR)
+            },
+            BLS24PAIR_OPERATION_RAW_VALUE => {
+                Some(OperationType::BLS24PAIR)
+            },
             _ => {
                 None
             }
@@ -68,6 +113,89 @@ impl OperationType {
     pub fn as_u8(&self) -> u8 {
         *self as u8
     }
+
+    /// Splits an input in the `API::run` format into the operation type and the input
+    /// for `perform_operation`. Pairing operations are selected by the curve type that
+    /// follows the operation encoding
+    pub fn from_prefixed_input(bytes: &[u8]) -> Result<(Self, &[u8]), ApiError> {
+        let (op_type, rest) = split(bytes, OPERATION_ENCODING_LENGTH , "Input should be longer than operation type encoding")?;
+        let operation = match op_type[0] {
+            OPERATION_G1_ADD => {
+                OperationType::G1ADD
+            },
+            OPERATION_G1_MUL => {
+                OperationType::G1MUL
+            },
+            OPERATION_G1_MULTIEXP => {
+                OperationType::G1MULTIEXP
+            },
+            OPERATION_G2_ADD => {
+                OperationType::G2ADD
+            },
+            OPERATION_G2_MUL => {
+                OperationType::G2MUL
+            },
+            OPERATION_G2_MULTIEXP => {
+                OperationType::G2MULTIEXP
+            },
+            OPERATION_EDWARDS_ADD => {
+                OperationType::EDWARDSADD
+            },
+            OPERATION_EDWARDS_MUL => {
+                OperationType::EDWARDSMUL
+            },
+            OPERATION_EDWARDS_MULTIEXP => {
+                OperationType::EDWARDSMULTIEXP
+            },
+            OPERATION_G1_DECOMPRESS => {
+                OperationType::G1DECOMPRESS
+            },
+            OPERATION_G2_DECOMPRESS => {
+                OperationType::G2DECOMPRESS
+            },
+            #[cfg(feature = "mappings")]
+            OPERATION_G1_MAP => {
+                OperationType::G1MAP
+            },
+            #[cfg(feature = "mappings")]
+            OPERATION_G2_MAP => {
+                OperationType::G2MAP
+            },
+            OPERATION_PAIRING => {
+                let (curve_type, rest) = split(rest, CURVE_TYPE_LENGTH, "Input should be longer than curve type encoding")?;
+                let operation = match curve_type[0] {
+                    BLS12 => {
+                        OperationType::BLS12PAIR
+                    },
+                    BN => {
+                        OperationType::BNPAIR
+                    },
+                    MNT4 => {
+                        OperationType::MNT4PAIR
+                    },
+                    MNT6 => {
+                        OperationType::MNT6PAIR
+                    },
+                    BW6 => {
+                        OperationType::BW6PAIR
+                    },
+                    BLS24 => {
+                        OperationType::BLS24PAIR
+                    },
+                    _ => {
+                        return Err(ApiError::InputError("Unknown curve type".to_owned()));
+                    }
+                };
+
+                return Ok((operation, rest));
+            },
+            _ => {
+                return Err(ApiError::InputError("Unknown operation type".to_owned()));
+            }
+        };
+
+        Ok((operation, rest))
+    }
 }
 
 pub const G1ADD_OPERATION_RAW_VALUE: u8 = OperationType::G1ADD as u8;
@@ -82,67 +210,159 @@ pub const BLS12PAIR_OPERATION_RAW_VALUE: u8 = OperationType::BLS12PAIR as u8;
 pub const BNPAIR_OPERATION_RAW_VALUE: u8 = OperationType::BNPAIR as u8;
 pub const MNT4PAIR_OPERATION_RAW_VALUE: u8 = OperationType::MNT4PAIR as u8;
 pub const MNT6PAIR_OPERATION_RAW_VALUE: u8 = OperationType::MNT6PAIR as u8;
+pub const BW6PAIR_OPERATION_RAW_VALUE: u8 = OperationType::BW6PAIR as u8;
+pub const BLS24PAIR_OPERATION_RAW_VALUE: u8 = OperationType::BLS24PAIR as u8;
+
+#[cfg(feature = "mappings")]
+pub const G1MAP_OPERATION_RAW_VALUE: u8 = OperationType::G1MAP as u8;
+#[cfg(feature = "mappings")]
+pub const G2MAP_OPERATION_RAW_VALUE: u8 = OperationType::G2MAP as u8;
+
+pub const EDWARDSADD_OPERATION_RAW_VALUE: u8 = OperationType::EDWARDSADD as u8;
+pub const EDWARDSMUL_OPERATION_RAW_VALUE: u8 = OperationType::EDWARDSMUL as u8;
+pub const EDWARDSMULTIEXP_OPERATION_RAW_VALUE: u8 = OperationType::EDWARDSMULTIEXP as u8;
+
+pub const G1DECOMPRESS_OPERATION_RAW_VALUE: u8 = OperationType::G1DECOMPRESS as u8;
+pub const G2DECOMPRESS_OPERATION_RAW_VALUE: u8 = OperationType::G2DECOMPRESS as u8;
 
 // This is pure rust API
 pub fn perform_operation(operation: OperationType, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    let input = DecodedInput::decode(input)?;
+
+    perform_decoded_operation(operation, &input)
+}
+
+/// Performs an operation on the input with already decoded modulus, so the size
+/// specialization is selected without decoding the modulus again
+pub(crate) fn perform_decoded_operation(operation: OperationType, input: &DecodedInput) -> Result<Vec<u8>, ApiError> {
+    use crate::field::*;
+
     assert!(!crate::features::in_gas_metering(), "must be run only in production mode");
 
+    let modulus_limbs = input.modulus_limbs;
+    let bytes = input.bytes;
+
     match operation {
         OperationType::G1ADD => {
-            PublicG1Api::add_points(&input)
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, bytes, add_points);
+
+            result
         },
         OperationType::G1MUL => {
-            PublicG1Api::mul_point(&input)
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, bytes, mul_point);
+
+            result
         },
         OperationType::G1MULTIEXP => {
-            PublicG1Api::multiexp(&input)
-        },
-        OperationType::G2ADD => {
-            PublicG2Api::add_points(&input)
-        },
-        OperationType::G2MUL => {
-            PublicG2Api::mul_point(&input)
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, bytes, multiexp);
+
+            result
         },
-        OperationType::G2MULTIEXP => {
-            PublicG2Api::multiexp(&input)
+        OperationType::G1DECOMPRESS => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, bytes, decompress_point);
+
+            result
         },
-        OperationType::BLS12PAIR | OperationType::BNPAIR | OperationType::MNT4PAIR | OperationType::MNT6PAIR => {
-            use crate::field::*;
-            use crate::public_interface::decode_utils::*;
+        OperationType::G2ADD | OperationType::G2MUL | OperationType::G2MULTIEXP | OperationType::G2DECOMPRESS => {
+            match (input.extension_degree()?, operation) {
+                (EXTENSION_DEGREE_2, OperationType::G2ADD) => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, bytes, add_points);
 
-            let modulus_limbs = {
-                let (_, modulus, _) = parse_modulus_and_length(&input)?;
-                let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+                    result
+                },
+                (EXTENSION_DEGREE_2, OperationType::G2MUL) => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, bytes, mul_point);
 
-                modulus_limbs
-            };
+                    result
+                },
+                (EXTENSION_DEGREE_2, OperationType::G2MULTIEXP) => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, bytes, multiexp);
 
-            match operation {
-                OperationType::BLS12PAIR => {
-                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, input, pair_bls12); 
+                    result
+                },
+                (EXTENSION_DEGREE_2, _) => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, bytes, decompress_point);
 
                     result
                 },
-                OperationType::BNPAIR => {
-                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, input, pair_bn); 
+                (_, OperationType::G2ADD) => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp3, bytes, add_points);
 
                     result
                 },
-                OperationType::MNT4PAIR => {
-                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, input, pair_mnt4); 
+                (_, OperationType::G2MUL) => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp3, bytes, mul_point);
 
                     result
                 },
-                OperationType::MNT6PAIR => {
-                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, input, pair_mnt6); 
+                (_, OperationType::G2MULTIEXP) => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp3, bytes, multiexp);
 
                     result
                 },
+                (_, _) => {
+                    let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp3, bytes, decompress_point);
 
-                _ => {
-                    unreachable!()
+                    result
                 }
             }
+        },
+        #[cfg(feature = "mappings")]
+        OperationType::G1MAP => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, MapToCurveApiImplementation, bytes, map_to_g1);
+
+            result
+        },
+        #[cfg(feature = "mappings")]
+        OperationType::G2MAP => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, MapToCurveApiImplementation, bytes, map_to_g2);
+
+            result
+        },
+        OperationType::EDWARDSADD => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, EdwardsApiImplementation, bytes, add_points);
+
+            result
+        },
+        OperationType::EDWARDSMUL => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, EdwardsApiImplementation, bytes, mul_point);
+
+            result
+        },
+        OperationType::EDWARDSMULTIEXP => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, EdwardsApiImplementation, bytes, multiexp);
+
+            result
+        },
+        OperationType::BLS12PAIR => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, bytes, pair_bls12);
+
+            result
+        },
+        OperationType::BNPAIR => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, bytes, pair_bn);
+
+            result
+        },
+        OperationType::MNT4PAIR => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, bytes, pair_mnt4);
+
+            result
+        },
+        OperationType::MNT6PAIR => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, bytes, pair_mnt6);
+
+            result
+        },
+        OperationType::BW6PAIR => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, bytes, pair_bw6);
+
+            result
+        },
+        OperationType::BLS24PAIR => {
+            let result: Result<Vec<u8>, ApiError> = expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, bytes, pair_bls24);
+
+            result
         }
     }
-}
\ No newline at end of file
+}
@@ -107,6 +107,11 @@ pub trait ElementRepr:
     fn mont_square(&mut self, modulus: &Self, mont_inv: u64);
     fn mont_mul_assign_with_partial_reduction(&mut self, other: &Self, modulus: &Self, mont_inv: u64);
     fn mont_square_with_partial_reduction(&mut self, modulus: &Self, mont_inv: u64);
+    /// Full product of two representations as (low, high) halves, without any reduction.
+    fn mul_wide(&self, other: &Self) -> (Self, Self);
+    /// Montgomery reduction of a double width number below `modulus * 2^(64 * NUM_LIMBS)`,
+    /// result is fully reduced.
+    fn mont_reduce_wide(low: &Self, high: &Self, modulus: &Self, mont_inv: u64) -> Self;
     fn into_normal_repr(&self, modulus: &Self, mont_inv: u64) -> Self;
     fn reduce(&mut self, modulus: &Self);
 }
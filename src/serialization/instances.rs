@@ -0,0 +1,347 @@
+//! Owned descriptions of pairing friendly curves. Descriptions are plain data and
+//! can be stored in JSON configs, extracted from an existing engine with `from_instance`
+//! and turned into the pairing ABI encoding (curve type and parameters, but not the
+//! number of pairs and pairs themselves) with `encode_pairing_parameters`
+
+use serde::{Serialize, Deserialize};
+
+use crate::field::SizedPrimeField;
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::integers::{MaxFieldUint, MaxGroupSizeUint, MaxLoopParametersUint};
+use crate::weierstrass::CurveParameters;
+use crate::extension_towers::fp2::Fp2;
+use crate::extension_towers::fp3::Fp3;
+use crate::extension_towers::fp4_as_2_over_2::Fp4;
+use crate::pairings::TwistType;
+use crate::pairings::bls12::Bls12Instance;
+use crate::pairings::bn::BnInstance;
+use crate::pairings::mnt4::MNT4Instance;
+use crate::pairings::mnt6::MNT6Instance;
+use crate::pairings::bw6::Bw6Instance;
+use crate::pairings::bls24::Bls24Instance;
+use crate::public_interface::constants::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bls12InstanceDescription {
+    pub modulus: MaxFieldUint,
+    pub b: MaxFieldUint,
+    pub group_order: MaxGroupSizeUint,
+    pub fp2_non_residue: MaxFieldUint,
+    pub fp6_non_residue: [MaxFieldUint; 2],
+    pub twist_type: TwistType,
+    pub x: MaxLoopParametersUint,
+    pub x_is_negative: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BnInstanceDescription {
+    pub modulus: MaxFieldUint,
+    pub b: MaxFieldUint,
+    pub group_order: MaxGroupSizeUint,
+    pub fp2_non_residue: MaxFieldUint,
+    pub fp6_non_residue: [MaxFieldUint; 2],
+    pub twist_type: TwistType,
+    pub u: MaxLoopParametersUint,
+    pub u_is_negative: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bls24InstanceDescription {
+    pub modulus: MaxFieldUint,
+    pub b: MaxFieldUint,
+    pub group_order: MaxGroupSizeUint,
+    pub fp2_non_residue: MaxFieldUint,
+    pub twist_type: TwistType,
+    pub x: MaxLoopParametersUint,
+    pub x_is_negative: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Mnt4InstanceDescription {
+    pub modulus: MaxFieldUint,
+    pub a: MaxFieldUint,
+    pub b: MaxFieldUint,
+    pub group_order: MaxGroupSizeUint,
+    pub fp2_non_residue: MaxFieldUint,
+    pub x: MaxLoopParametersUint,
+    pub x_is_negative: bool,
+    pub exp_w0: MaxLoopParametersUint,
+    pub exp_w1: MaxLoopParametersUint,
+    pub exp_w0_is_negative: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Mnt6InstanceDescription {
+    pub modulus: MaxFieldUint,
+    pub a: MaxFieldUint,
+    pub b: MaxFieldUint,
+    pub group_order: MaxGroupSizeUint,
+    pub fp3_non_residue: MaxFieldUint,
+    pub x: MaxLoopParametersUint,
+    pub x_is_negative: bool,
+    pub exp_w0: MaxLoopParametersUint,
+    pub exp_w1: MaxLoopParametersUint,
+    pub exp_w0_is_negative: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bw6InstanceDescription {
+    pub modulus: MaxFieldUint,
+    pub b: MaxFieldUint,
+    pub group_order: MaxGroupSizeUint,
+    pub fp3_non_residue: MaxFieldUint,
+    pub twist_type: TwistType,
+    pub ate_loop_count_1: MaxLoopParametersUint,
+    pub ate_loop_count_1_is_negative: bool,
+    pub ate_loop_count_2: MaxLoopParametersUint,
+    pub ate_loop_count_2_is_negative: bool,
+    pub exp_w0: MaxLoopParametersUint,
+    pub exp_w1: MaxLoopParametersUint,
+    pub exp_w0_is_negative: bool,
+}
+
+fn fp_into_uint<E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp<'_, E, F>) -> MaxFieldUint {
+    MaxFieldUint::from(element.into_repr().as_ref())
+}
+
+fn fp2_into_uints<E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp2<'_, E, F>) -> [MaxFieldUint; 2] {
+    [fp_into_uint(&element.c0), fp_into_uint(&element.c1)]
+}
+
+fn encode_fixed_len(value: &MaxFieldUint, len: usize) -> Vec<u8> {
+    let mut encoding = vec![0u8; value.as_ref().len() * 8];
+    value.to_big_endian(&mut encoding);
+
+    encoding.split_off(encoding.len() - len)
+}
+
+/// Encodes a length byte followed by a big-endian value without leading zeroes
+fn encode_with_length(limbs: &[u64]) -> Vec<u8> {
+    let mut encoding = vec![];
+    for limb in limbs.iter().rev() {
+        encoding.extend_from_slice(&limb.to_be_bytes());
+    }
+    let leading_zeroes = encoding.iter().take_while(|b| **b == 0).count();
+
+    let mut result = vec![(encoding.len() - leading_zeroes) as u8];
+    result.extend_from_slice(&encoding[leading_zeroes..]);
+
+    result
+}
+
+fn encode_sign(is_negative: bool) -> u8 {
+    if is_negative {
+        SIGN_MINUS
+    } else {
+        SIGN_PLUS
+    }
+}
+
+fn encode_twist_type(twist_type: TwistType) -> u8 {
+    match twist_type {
+        TwistType::M => TWIST_TYPE_M,
+        TwistType::D => TWIST_TYPE_D,
+    }
+}
+
+/// Encodes curve type, modulus, A, B and group order. Returns the encoding and the
+/// byte length of the modulus that should be used for the rest of field elements
+fn encode_common_parameters(
+    curve_type: u8,
+    modulus: &MaxFieldUint,
+    a: &MaxFieldUint,
+    b: &MaxFieldUint,
+    group_order: &MaxGroupSizeUint
+) -> (Vec<u8>, usize) {
+    let modulus_len = modulus.bits().div_ceil(8);
+
+    let mut encoding = vec![curve_type, modulus_len as u8];
+    encoding.extend(encode_fixed_len(modulus, modulus_len));
+    encoding.extend(encode_fixed_len(a, modulus_len));
+    encoding.extend(encode_fixed_len(b, modulus_len));
+    encoding.extend(encode_with_length(group_order.as_ref()));
+
+    (encoding, modulus_len)
+}
+
+impl Bls12InstanceDescription {
+    pub fn from_instance<
+        'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    >(instance: &Bls12Instance<'a, FE, F, CB, CTW>) -> Self {
+        Self {
+            modulus: MaxFieldUint::from(instance.base_field.modulus().as_ref()),
+            b: fp_into_uint(&instance.curve.b),
+            group_order: MaxGroupSizeUint::from(instance.curve.subgroup_order_repr),
+            fp2_non_residue: fp_into_uint(&instance.fp2_extension.non_residue),
+            fp6_non_residue: fp2_into_uints(&instance.fp6_extension.non_residue),
+            twist_type: instance.twist_type,
+            x: MaxLoopParametersUint::from(instance.x),
+            x_is_negative: instance.x_is_negative,
+        }
+    }
+
+    pub fn encode_pairing_parameters(&self) -> Vec<u8> {
+        let (mut encoding, modulus_len) = encode_common_parameters(BLS12, &self.modulus, &MaxFieldUint::zero(), &self.b, &self.group_order);
+        encoding.extend(encode_fixed_len(&self.fp2_non_residue, modulus_len));
+        for c in self.fp6_non_residue.iter() {
+            encoding.extend(encode_fixed_len(c, modulus_len));
+        }
+        encoding.push(encode_twist_type(self.twist_type));
+        encoding.extend(encode_with_length(self.x.as_ref()));
+        encoding.push(encode_sign(self.x_is_negative));
+
+        encoding
+    }
+}
+
+impl Bls24InstanceDescription {
+    pub fn from_instance<
+        'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp4<'a, FE, F>>
+    >(instance: &Bls24Instance<'a, FE, F, CB, CTW>) -> Self {
+        Self {
+            modulus: MaxFieldUint::from(instance.base_field.modulus().as_ref()),
+            b: fp_into_uint(&instance.curve.b),
+            group_order: MaxGroupSizeUint::from(instance.curve.subgroup_order_repr),
+            fp2_non_residue: fp_into_uint(&instance.fp2_extension.non_residue),
+            twist_type: instance.twist_type,
+            x: MaxLoopParametersUint::from(instance.x),
+            x_is_negative: instance.x_is_negative,
+        }
+    }
+
+    pub fn encode_pairing_parameters(&self) -> Vec<u8> {
+        let (mut encoding, modulus_len) = encode_common_parameters(BLS24, &self.modulus, &MaxFieldUint::zero(), &self.b, &self.group_order);
+        encoding.extend(encode_fixed_len(&self.fp2_non_residue, modulus_len));
+        encoding.push(encode_twist_type(self.twist_type));
+        encoding.extend(encode_with_length(self.x.as_ref()));
+        encoding.push(encode_sign(self.x_is_negative));
+
+        encoding
+    }
+}
+
+impl BnInstanceDescription {
+    pub fn from_instance<
+        'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp2<'a, FE, F>>
+    >(instance: &BnInstance<'a, FE, F, CB, CTW>) -> Self {
+        Self {
+            modulus: MaxFieldUint::from(instance.base_field.modulus().as_ref()),
+            b: fp_into_uint(&instance.curve.b),
+            group_order: MaxGroupSizeUint::from(instance.curve.subgroup_order_repr),
+            fp2_non_residue: fp_into_uint(&instance.fp2_extension.non_residue),
+            fp6_non_residue: fp2_into_uints(&instance.fp6_extension.non_residue),
+            twist_type: instance.twist_type,
+            u: MaxLoopParametersUint::from(instance.u),
+            u_is_negative: instance.u_is_negative,
+        }
+    }
+
+    pub fn encode_pairing_parameters(&self) -> Vec<u8> {
+        let (mut encoding, modulus_len) = encode_common_parameters(BN, &self.modulus, &MaxFieldUint::zero(), &self.b, &self.group_order);
+        encoding.extend(encode_fixed_len(&self.fp2_non_residue, modulus_len));
+        for c in self.fp6_non_residue.iter() {
+            encoding.extend(encode_fixed_len(c, modulus_len));
+        }
+        encoding.push(encode_twist_type(self.twist_type));
+        encoding.extend(encode_with_length(self.u.as_ref()));
+        encoding.push(encode_sign(self.u_is_negative));
+
+        encoding
+    }
+}
+
+/// MNT4 and MNT6 descriptions only differ by the extension that is used for a twist
+macro_rules! impl_mnt_description {
+    ($description: ident, $instance: ident, $curve_type: expr, $twist_element: ident, $non_residue: ident, $extension: ident) => {
+        impl $description {
+            pub fn from_instance<
+                'a,
+                FE: ElementRepr,
+                F: SizedPrimeField<Repr = FE>,
+                CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+                CTW: CurveParameters<BaseFieldElement = $twist_element<'a, FE, F>>
+            >(instance: &$instance<'a, FE, F, CB, CTW>) -> Self {
+                Self {
+                    modulus: MaxFieldUint::from(instance.base_field.modulus().as_ref()),
+                    a: fp_into_uint(&instance.curve.a),
+                    b: fp_into_uint(&instance.curve.b),
+                    group_order: MaxGroupSizeUint::from(instance.curve.subgroup_order_repr),
+                    $non_residue: fp_into_uint(&instance.$extension.non_residue),
+                    x: MaxLoopParametersUint::from(instance.x),
+                    x_is_negative: instance.x_is_negative,
+                    exp_w0: MaxLoopParametersUint::from(instance.exp_w0),
+                    exp_w1: MaxLoopParametersUint::from(instance.exp_w1),
+                    exp_w0_is_negative: instance.exp_w0_is_negative,
+                }
+            }
+
+            pub fn encode_pairing_parameters(&self) -> Vec<u8> {
+                let (mut encoding, modulus_len) = encode_common_parameters($curve_type, &self.modulus, &self.a, &self.b, &self.group_order);
+                encoding.extend(encode_fixed_len(&self.$non_residue, modulus_len));
+                encoding.extend(encode_with_length(self.x.as_ref()));
+                encoding.push(encode_sign(self.x_is_negative));
+                encoding.extend(encode_with_length(self.exp_w0.as_ref()));
+                encoding.extend(encode_with_length(self.exp_w1.as_ref()));
+                encoding.push(encode_sign(self.exp_w0_is_negative));
+
+                encoding
+            }
+        }
+    };
+}
+
+impl_mnt_description!(Mnt4InstanceDescription, MNT4Instance, MNT4, Fp2, fp2_non_residue, fp2_extension);
+impl_mnt_description!(Mnt6InstanceDescription, MNT6Instance, MNT6, Fp3, fp3_non_residue, fp3_extension);
+
+impl Bw6InstanceDescription {
+    pub fn from_instance<
+        'a,
+        FE: ElementRepr,
+        F: SizedPrimeField<Repr = FE>,
+        CB: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>,
+        CTW: CurveParameters<BaseFieldElement = Fp<'a, FE, F>>
+    >(instance: &Bw6Instance<'a, FE, F, CB, CTW>) -> Self {
+        Self {
+            modulus: MaxFieldUint::from(instance.base_field.modulus().as_ref()),
+            b: fp_into_uint(&instance.curve.b),
+            group_order: MaxGroupSizeUint::from(instance.curve.subgroup_order_repr),
+            fp3_non_residue: fp_into_uint(&instance.fp3_extension.non_residue),
+            twist_type: instance.twist_type,
+            ate_loop_count_1: MaxLoopParametersUint::from(instance.ate_loop_count_1),
+            ate_loop_count_1_is_negative: instance.ate_loop_count_1_is_negative,
+            ate_loop_count_2: MaxLoopParametersUint::from(instance.ate_loop_count_2),
+            ate_loop_count_2_is_negative: instance.ate_loop_count_2_is_negative,
+            exp_w0: MaxLoopParametersUint::from(instance.exp_w0),
+            exp_w1: MaxLoopParametersUint::from(instance.exp_w1),
+            exp_w0_is_negative: instance.exp_w0_is_negative,
+        }
+    }
+
+    pub fn encode_pairing_parameters(&self) -> Vec<u8> {
+        let (mut encoding, modulus_len) = encode_common_parameters(BW6, &self.modulus, &MaxFieldUint::zero(), &self.b, &self.group_order);
+        encoding.extend(encode_fixed_len(&self.fp3_non_residue, modulus_len));
+        encoding.push(encode_twist_type(self.twist_type));
+        encoding.extend(encode_with_length(self.ate_loop_count_1.as_ref()));
+        encoding.push(encode_sign(self.ate_loop_count_1_is_negative));
+        encoding.extend(encode_with_length(self.ate_loop_count_2.as_ref()));
+        encoding.push(encode_sign(self.ate_loop_count_2_is_negative));
+        encoding.extend(encode_with_length(self.exp_w0.as_ref()));
+        encoding.extend(encode_with_length(self.exp_w1.as_ref()));
+        encoding.push(encode_sign(self.exp_w0_is_negative));
+
+        encoding
+    }
+}
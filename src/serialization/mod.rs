@@ -0,0 +1,222 @@
+//! Serde support for field elements, curve points and pairing engines.
+//!
+//! Base field elements are serialized as `0x` prefixed hex strings of their canonical
+//! (non-Montgomery) value, extension field elements as sequences of their coefficients
+//! and curve points as affine `x` and `y` with the point at infinity encoded as (0, 0).
+//!
+//! Elements do not own their fields, so deserialization requires a field (or a curve
+//! for points) and is done through `DeserializeSeed`, e.g.
+//! `ParamsSeed::<Fp2<_, _>>::new(&extension).deserialize(&mut deserializer)`
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, SerializeTuple};
+
+use crate::field::SizedPrimeField;
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::ZeroAndOne;
+use crate::integers::MaxFieldUint;
+use crate::weierstrass::CurveParameters;
+use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
+use crate::extension_towers::fp2::{Fp2, Extension2};
+use crate::extension_towers::fp3::{Fp3, Extension3};
+use crate::extension_towers::fp4_as_2_over_2::{Fp4, Extension2Over2};
+use crate::extension_towers::fp6_as_2_over_3::{Fp6 as Fp6As2Over3, Extension2Over3};
+use crate::extension_towers::fp6_as_3_over_2::{Fp6 as Fp6As3Over2, Extension3Over2};
+use crate::extension_towers::fp12_as_2_over3_over_2::{Fp12, Extension2Over3Over2};
+
+pub mod instances;
+
+/// Values that can only be deserialized given some external parameters,
+/// such as a field for an element or a curve for a point
+pub trait DeserializeWithParams: Sized {
+    type Params: Copy;
+
+    fn deserialize_with_params<'de, D: Deserializer<'de>>(params: Self::Params, deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// `DeserializeSeed` that carries parameters for `DeserializeWithParams`
+pub struct ParamsSeed<T: DeserializeWithParams> {
+    params: T::Params,
+    _marker: std::marker::PhantomData<T>
+}
+
+impl<T: DeserializeWithParams> ParamsSeed<T> {
+    pub fn new(params: T::Params) -> Self {
+        Self {
+            params,
+            _marker: std::marker::PhantomData
+        }
+    }
+}
+
+impl<'de, T: DeserializeWithParams> DeserializeSeed<'de> for ParamsSeed<T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_with_params(self.params, deserializer)
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> Serialize for Fp<'a, E, F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MaxFieldUint::from(self.into_repr().as_ref()).serialize(serializer)
+    }
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> DeserializeWithParams for Fp<'a, E, F> {
+    type Params = &'a F;
+
+    fn deserialize_with_params<'de, D: Deserializer<'de>>(field: &'a F, deserializer: D) -> Result<Self, D::Error> {
+        let value = MaxFieldUint::deserialize(deserializer)?;
+        if value.bits() > E::NUM_LIMBS * 64 {
+            return Err(de::Error::custom("field element is too large"));
+        }
+        let mut repr = E::default();
+        repr.as_mut().copy_from_slice(&value.as_ref()[..E::NUM_LIMBS]);
+
+        Fp::from_repr(field, repr).map_err(|_| de::Error::custom("field element is not smaller than the modulus"))
+    }
+}
+
+struct CoefficientsVisitor<T: DeserializeWithParams> {
+    params: T::Params,
+    len: usize,
+}
+
+impl<'de, T: DeserializeWithParams> Visitor<'de> for CoefficientsVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a sequence of {} coefficients", self.len)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<T>, A::Error> {
+        let mut coeffs = Vec::with_capacity(self.len);
+        for i in 0..self.len {
+            let c = seq.next_element_seed(ParamsSeed::<T>::new(self.params))?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            coeffs.push(c);
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(self.len + 1, &self));
+        }
+
+        Ok(coeffs)
+    }
+}
+
+fn deserialize_coefficients<'de, D: Deserializer<'de>, T: DeserializeWithParams>(
+    params: T::Params,
+    len: usize,
+    deserializer: D
+) -> Result<Vec<T>, D::Error> {
+    deserializer.deserialize_tuple(len, CoefficientsVisitor { params, len })
+}
+
+macro_rules! impl_serde_for_extension {
+    ($element: ident, $extension: ident, $len: expr, [$($coeff: ident),+]) => {
+        impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> Serialize for $element<'a, E, F> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut tuple = serializer.serialize_tuple($len)?;
+                $(tuple.serialize_element(&self.$coeff)?;)+
+
+                tuple.end()
+            }
+        }
+
+        impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> DeserializeWithParams for $element<'a, E, F> {
+            type Params = &'a $extension<'a, E, F>;
+
+            fn deserialize_with_params<'de, D: Deserializer<'de>>(extension: Self::Params, deserializer: D) -> Result<Self, D::Error> {
+                let mut coeffs = deserialize_coefficients(extension.field, $len, deserializer)?.into_iter();
+                let mut result = Self::zero(extension);
+                $(result.$coeff = coeffs.next().expect("number of coefficients is checked");)+
+
+                Ok(result)
+            }
+        }
+    };
+}
+
+impl_serde_for_extension!(Fp2, Extension2, 2, [c0, c1]);
+impl_serde_for_extension!(Fp3, Extension3, 3, [c0, c1, c2]);
+impl_serde_for_extension!(Fp4, Extension2Over2, 2, [c0, c1]);
+impl_serde_for_extension!(Fp6As2Over3, Extension2Over3, 2, [c0, c1]);
+impl_serde_for_extension!(Fp6As3Over2, Extension3Over2, 3, [c0, c1, c2]);
+impl_serde_for_extension!(Fp12, Extension2Over3Over2, 2, [c0, c1]);
+
+const POINT_FIELDS: &[&str] = &["x", "y"];
+
+impl<'a, C: CurveParameters> Serialize for CurvePoint<'a, C> where C::BaseFieldElement: Serialize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (x, y) = self.into_xy();
+        let mut point = serializer.serialize_struct("CurvePoint", 2)?;
+        point.serialize_field("x", &x)?;
+        point.serialize_field("y", &y)?;
+
+        point.end()
+    }
+}
+
+struct PointVisitor<'a, C: CurveParameters> {
+    curve: &'a WeierstrassCurve<'a, C>
+}
+
+impl<'a, C: CurveParameters> PointVisitor<'a, C> {
+    fn point_from_xy<E: de::Error>(&self, x: C::BaseFieldElement, y: C::BaseFieldElement) -> Result<CurvePoint<'a, C>, E> {
+        let point = CurvePoint::point_from_xy(self.curve, x, y);
+        if !point.is_on_curve() {
+            return Err(de::Error::custom("point is not on curve"));
+        }
+
+        Ok(point)
+    }
+}
+
+impl<'de, 'a, C: CurveParameters> Visitor<'de> for PointVisitor<'a, C>
+    where C::BaseFieldElement: DeserializeWithParams<Params = <C::BaseFieldElement as ZeroAndOne>::Params>
+{
+    type Value = CurvePoint<'a, C>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an affine curve point")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let x = seq.next_element_seed(ParamsSeed::new(self.curve.params.params()))?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let y = seq.next_element_seed(ParamsSeed::new(self.curve.params.params()))?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        self.point_from_xy(x, y)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut x = None;
+        let mut y = None;
+        while let Some(key) = map.next_key::<String>()? {
+            let value = match key.as_str() {
+                "x" => &mut x,
+                "y" => &mut y,
+                _ => return Err(de::Error::unknown_field(&key, POINT_FIELDS)),
+            };
+            if value.is_some() {
+                return Err(de::Error::custom(format!("duplicate field `{}`", key)));
+            }
+            *value = Some(map.next_value_seed(ParamsSeed::new(self.curve.params.params()))?);
+        }
+        let x = x.ok_or_else(|| de::Error::missing_field("x"))?;
+        let y = y.ok_or_else(|| de::Error::missing_field("y"))?;
+
+        self.point_from_xy(x, y)
+    }
+}
+
+impl<'a, C: CurveParameters> DeserializeWithParams for CurvePoint<'a, C>
+    where C::BaseFieldElement: DeserializeWithParams<Params = <C::BaseFieldElement as ZeroAndOne>::Params>
+{
+    type Params = &'a WeierstrassCurve<'a, C>;
+
+    fn deserialize_with_params<'de, D: Deserializer<'de>>(curve: Self::Params, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("CurvePoint", POINT_FIELDS, PointVisitor { curve })
+    }
+}
@@ -2,6 +2,7 @@ use crate::fp::Fp;
 use crate::representation::ElementRepr;
 use crate::field::*;
 use crate::extension_towers::fp2::{Extension2, Fp2};
+use crate::extension_towers::fp3::Fp3;
 use crate::traits::FieldElement;
 use crate::traits::ZeroAndOne;
 
@@ -69,12 +70,135 @@ pub fn legendre_symbol_fp2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(ele
     legendre_symbol_fp(&a)
 }
 
-fn sqrt_for_one_mod_four<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(_element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
-    // TODO, or consider to slow
-    
+/// Decomposes `p - 1 = 2^s * t` for an odd `t`
+fn two_adic_decomposition<E: ElementRepr>(modulus: &E) -> (u32, E) {
+    let mut t = *modulus;
+    t.sub_noborrow(&E::from(1));
+
+    let mut s = 0u32;
+    while t.is_even() {
+        t.shr(1);
+        s += 1;
+    }
+
+    (s, t)
+}
+
+/// Candidates tried as a quadratic non-residue. Each of them is a non-residue with probability 1/2
+/// for a random prime modulus, so the search only fails for a composite modulus (that is not rejected
+/// by the ABI) or a handful of primes. The bound keeps the search within the price of one extra
+/// multiplication per decompressed point
+const NON_RESIDUE_CANDIDATES: [u64; 18] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61];
+
+fn find_quadratic_non_residue<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(field: &'a F) -> Option<Fp<'a, E, F>> {
+    // if all the primes below n are residues then all the integers below n are,
+    // so the smallest non-residue of a prime field is a prime
+    for candidate in NON_RESIDUE_CANDIDATES.iter() {
+        let z = Fp::from_repr(field, E::from(*candidate)).ok()?;
+        if legendre_symbol_fp(&z) == LegendreSymbol::QuadraticNonResidue {
+            return Some(z);
+        }
+    }
+
     None
 }
 
+/// Decomposition `p - 1 = 2^s * t` together with `c = z^t` for a quadratic non-residue `z`,
+/// found once and shared by all the square roots in the base field taken during a single call
+struct TonelliShanksParameters<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> {
+    s: u32,
+    t: E,
+    c: Fp<'a, E, F>,
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> TonelliShanksParameters<'a, E, F> {
+    fn new(field: &'a F) -> Option<Self> {
+        let (s, t) = two_adic_decomposition(field.modulus());
+        let z = find_quadratic_non_residue(field)?;
+        let c = z.pow(t.as_ref());
+
+        Some(Self { s, t, c })
+    }
+}
+
+/// Square root in the base field with the parameters that the modulus requires
+enum BaseFieldSqrt<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> {
+    ThreeModFour,
+    TonelliShanks(TonelliShanksParameters<'a, E, F>),
+}
+
+impl<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>> BaseFieldSqrt<'a, E, F> {
+    fn new(field: &'a F) -> Option<Self> {
+        if modulus_is_three_mod_four(field) {
+            Some(BaseFieldSqrt::ThreeModFour)
+        } else {
+            TonelliShanksParameters::new(field).map(BaseFieldSqrt::TonelliShanks)
+        }
+    }
+
+    fn sqrt(&self, element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
+        match self {
+            BaseFieldSqrt::ThreeModFour => sqrt_for_three_mod_four(element),
+            BaseFieldSqrt::TonelliShanks(parameters) => sqrt_for_one_mod_four(element, parameters),
+        }
+    }
+}
+
+/// Main loop of Tonelli-Shanks. Takes `x = a^((Q+1)/2)`, `b = a^Q` and `c = z^Q`
+/// for a quadratic non-residue `z`, where `q - 1 = 2^s * Q` for the order `q` of the field
+fn tonelli_shanks_loop<FE: FieldElement>(mut x: FE, mut b: FE, mut c: FE, s: u32, one: &FE) -> Option<FE> {
+    let mut m = s;
+    while b != *one {
+        // find the smallest i such that b^(2^i) == 1
+        let mut i = 0u32;
+        let mut tmp = b.clone();
+        while tmp != *one {
+            tmp.square();
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+
+        let mut t = c;
+        for _ in 0..(m - i - 1) {
+            t.square();
+        }
+
+        m = i;
+        c = t.clone();
+        c.square();
+        b.mul_assign(&c);
+        x.mul_assign(&t);
+    }
+
+    Some(x)
+}
+
+fn sqrt_for_one_mod_four<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(
+    element: &Fp<'a, E, F>,
+    parameters: &TonelliShanksParameters<'a, E, F>
+) -> Option<Fp<'a, E, F>> {
+    // general Tonelli-Shanks
+    if element.is_zero() {
+        return Some(*element);
+    }
+
+    // (t - 1)/2, t is odd
+    let mut t_minus_one_by_two = parameters.t;
+    t_minus_one_by_two.shr(1);
+
+    let w = element.pow(t_minus_one_by_two.as_ref());
+
+    let mut x = w;
+    x.mul_assign(element);
+
+    let mut b = x;
+    b.mul_assign(&w);
+
+    tonelli_shanks_loop(x, b, parameters.c, parameters.s, &Fp::one(element.field))
+}
+
 pub fn sqrt_for_three_mod_four<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
     // this is a simple case: we compute the power 
     // we know that it's 3 mod 4, so just bit shift
@@ -120,11 +244,11 @@ fn sqrt_for_one_mod_sixteen<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(el
 }
 
 pub fn sqrt<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp<'a, E, F>) -> Option<Fp<'a, E, F>> {
-    if modulus_is_three_mod_four(element.field) {
-        sqrt_for_three_mod_four(&element)
-    } else {
-        None
+    if element.is_zero() {
+        return Some(*element);
     }
+
+    BaseFieldSqrt::new(element.field)?.sqrt(element)
 }
 
 pub(crate) fn sqrt_for_three_mod_four_ext2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp2<'a, E, F>) -> Option<Fp2<'a, E, F>> {
@@ -176,9 +300,189 @@ pub(crate) fn sqrt_for_three_mod_four_ext2<'a, E: ElementRepr, F: SizedPrimeFiel
 }
 
 pub fn sqrt_ext2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp2<'a, E, F>) -> Option<Fp2<'a, E, F>> {
-    if modulus_is_three_mod_four_ext2(element.extension_field) {
-        sqrt_for_three_mod_four_ext2(&element)
+    // shortcut for 3 mod 4 requires Frobenius map, so extensions created without
+    // Frobenius coefficients (e.g. in the generic ABI) go through the general path
+    if modulus_is_three_mod_four_ext2(element.extension_field) && element.extension_field.frobenius_coeffs_are_calculated {
+        sqrt_for_three_mod_four_ext2(element)
     } else {
-        None
+        sqrt_ext2_by_norm(element)
+    }
+}
+
+fn half_of<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp<'a, E, F>) -> Fp<'a, E, F> {
+    let mut two = Fp::one(element.field);
+    two.double();
+    let mut result = two.inverse().expect("field characteristic is odd");
+    result.mul_assign(element);
+
+    result
+}
+
+/// Square root in `Fp2 = Fp[u]/(u^2 - non_residue)` for any odd characteristic.
+/// For `a = a0 + a1*u` with `a1 != 0` the root is `x0 + x1*u` where
+/// `x0^2 = (a0 +- sqrt(norm(a)))/2` and `x1 = a1/(2*x0)`
+pub(crate) fn sqrt_ext2_by_norm<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp2<'a, E, F>) -> Option<Fp2<'a, E, F>> {
+    if element.is_zero() {
+        return Some(*element);
+    }
+
+    let extension = element.extension_field;
+    let base = BaseFieldSqrt::new(extension.field)?;
+
+    if element.c1.is_zero() {
+        let mut result = Fp2::zero(extension);
+        if let Some(root) = base.sqrt(&element.c0) {
+            result.c0 = root;
+
+            return Some(result);
+        }
+
+        // then a0/non_residue must be a square, and the root is sqrt(a0/non_residue) * u
+        let mut tmp = extension.non_residue.inverse().expect("non-residue is not zero");
+        tmp.mul_assign(&element.c0);
+        result.c1 = base.sqrt(&tmp)?;
+
+        return Some(result);
+    }
+
+    let gamma = base.sqrt(&element.norm())?;
+
+    let mut delta = element.c0;
+    delta.add_assign(&gamma);
+    delta = half_of(&delta);
+
+    let x0 = match base.sqrt(&delta) {
+        Some(root) if !root.is_zero() => root,
+        _ => {
+            let mut delta = element.c0;
+            delta.sub_assign(&gamma);
+            delta = half_of(&delta);
+
+            base.sqrt(&delta)?
+        }
+    };
+
+    if x0.is_zero() {
+        return None;
+    }
+
+    let mut x1 = x0;
+    x1.double();
+    x1 = x1.inverse()?;
+    x1.mul_assign(&element.c1);
+
+    let mut result = Fp2::zero(extension);
+    result.c0 = x0;
+    result.c1 = x1;
+
+    Some(result)
+}
+
+/// Square root in a cubic extension. Uses Tonelli-Shanks with `p^3 - 1 = 2^s * t * (p^2 + p + 1)`,
+/// where `p - 1 = 2^s * t`, so all exponentiations are done with exponents not larger than `p`.
+/// Raising into the power `p` is a Frobenius map if coefficients are calculated and a full
+/// exponentiation otherwise
+pub fn sqrt_ext3<'a, E: ElementRepr, F: SizedPrimeField<Repr = E>>(element: &Fp3<'a, E, F>) -> Option<Fp3<'a, E, F>> {
+    if element.is_zero() {
+        return Some(*element);
+    }
+
+    let field = element.extension_field.field;
+    let modulus = *field.modulus();
+
+    let TonelliShanksParameters { s, t, c: z_pow_t } = TonelliShanksParameters::new(field)?;
+
+    // element of Fp is a square in Fp3 iff it's a square in Fp, and for
+    // any element of Fp raising into the (p^2 + p + 1) is just a cube
+    let mut c = Fp3::zero(element.extension_field);
+    c.c0 = z_pow_t;
+    c.c0.square();
+    c.c0.mul_assign(&z_pow_t);
+
+    let frobenius = |el: &Fp3<'a, E, F>| {
+        if element.extension_field.frobenius_coeffs_are_calculated {
+            let mut result = *el;
+            result.frobenius_map(1);
+
+            result
+        } else {
+            el.pow(modulus.as_ref())
+        }
+    };
+
+    // (Q - 1)/2 = (t - 1)/2 * (p^2 + p + 1) + p * (p + 1)/2
+    let mut t_minus_one_by_two = t;
+    t_minus_one_by_two.shr(1);
+
+    let mut p_plus_one_by_two = modulus;
+    p_plus_one_by_two.shr(1);
+    p_plus_one_by_two.add_nocarry(&E::from(1));
+
+    let h = element.pow(t_minus_one_by_two.as_ref());
+    let h_p = frobenius(&h);
+    let h_p2 = frobenius(&h_p);
+
+    let mut w = frobenius(&element.pow(p_plus_one_by_two.as_ref()));
+    w.mul_assign(&h);
+    w.mul_assign(&h_p);
+    w.mul_assign(&h_p2);
+
+    let mut x = w;
+    x.mul_assign(element);
+
+    let mut b = x;
+    b.mul_assign(&w);
+
+    tonelli_shanks_loop(x, b, c, s, &Fp3::one(element.extension_field))
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Sign {
+    Zero,
+    SignPlus,
+    SignMinus
+}
+
+/// Sign of the element is defined by the parity of its canonical representation:
+/// even elements are positive and odd ones are negative
+pub(crate) fn sign_of_fp<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> >(
+    el: &Fp<'a, E, F>
+) -> Sign {
+    if el.is_zero() {
+        return Sign::Zero;
+    }
+
+    let el_repr = el.into_repr();
+
+    if el_repr.as_ref()[0] & 1 == 0 {
+        // 0 mod 2
+        Sign::SignPlus
+    } else {
+        Sign::SignMinus
+    }
+}
+
+pub(crate) fn sign_of_fp2<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> >(
+    el: &Fp2<'a, E, F>
+) -> Sign {
+    // compare c_0 and then c_1
+    match sign_of_fp(&el.c0) {
+        Sign::Zero => sign_of_fp(&el.c1),
+        s => s
+    }
+}
+
+pub(crate) fn sign_of_fp3<'a, E: ElementRepr, F: SizedPrimeField<Repr = E> >(
+    el: &Fp3<'a, E, F>
+) -> Sign {
+    // compare c_0, then c_1 and then c_2
+    match sign_of_fp(&el.c0) {
+        Sign::Zero => {
+            match sign_of_fp(&el.c1) {
+                Sign::Zero => sign_of_fp(&el.c2),
+                s => s
+            }
+        },
+        s => s
     }
 }
\ No newline at end of file
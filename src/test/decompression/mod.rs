@@ -0,0 +1,460 @@
+use crate::public_interface::{G1Api, PublicG1Api, G2Api, PublicG2Api, PairingApi, PublicPairingApi, API};
+use crate::errors::ApiError;
+use crate::public_interface::constants::*;
+use crate::public_interface::decode_fp::*;
+use crate::public_interface::decode_g1::serialize_g1_point;
+use crate::public_interface::decode_g2::{serialize_g2_point_in_fp2, serialize_g2_point_in_fp3};
+
+use crate::field::{PrimeField, SizedPrimeField, U384Repr};
+use crate::fp::Fp;
+use crate::representation::ElementRepr;
+use crate::traits::{FieldElement, ZeroAndOne};
+use crate::weierstrass::{Group, CurveOverFpParameters, CurveOverFp2Parameters, CurveOverFp3Parameters};
+use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
+use crate::square_root::*;
+
+use crate::engines::bn254::*;
+use crate::engines::bls12_377::*;
+use crate::engines::mnt6_753::*;
+
+type G1Point<'a, FE> = CurvePoint<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>;
+type G2Fp2Point<'a, FE> = CurvePoint<'a, CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>;
+type G2Fp3Point<'a, FE> = CurvePoint<'a, CurveOverFp3Parameters<'a, FE, PrimeField<FE>>>;
+
+fn encode_modulus<FE: ElementRepr>(modulus: &FE) -> (Vec<u8>, usize) {
+    let modulus_len = (modulus.num_bits() as usize).div_ceil(8);
+    let mut encoding = vec![0u8; FE::NUM_LIMBS * 8];
+    modulus.write_be(&mut encoding[..]).unwrap();
+    let encoding = encoding[(FE::NUM_LIMBS * 8 - modulus_len)..].to_vec();
+
+    let mut result = vec![modulus_len as u8];
+    result.extend(encoding);
+
+    (result, modulus_len)
+}
+
+fn encode_order(order: &[u64]) -> Vec<u8> {
+    let mut encoding = vec![];
+    for limb in order.iter().rev() {
+        encoding.extend_from_slice(&limb.to_be_bytes());
+    }
+    let leading_zeroes = encoding.iter().take_while(|b| **b == 0).count();
+
+    let mut result = vec![(encoding.len() - leading_zeroes) as u8];
+    result.extend_from_slice(&encoding[leading_zeroes..]);
+
+    result
+}
+
+fn encode_sign(sign: Sign) -> u8 {
+    match sign {
+        Sign::SignMinus => SIGN_MINUS,
+        _ => SIGN_PLUS,
+    }
+}
+
+pub(crate) fn assemble_g1_curve_params<'a, FE: ElementRepr>(curve: &WeierstrassCurve<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>) -> (Vec<u8>, usize) {
+    // - Lengths of modulus (in bytes)
+    // - Field modulus
+    // - Curve A
+    // - Curve B
+    // - Length of a scalar field (curve order) (in bytes)
+    // - Curve order
+    let (mut calldata, modulus_len) = encode_modulus(curve.params.field.modulus());
+    calldata.extend(serialize_fp_fixed_len(modulus_len, &curve.a).unwrap());
+    calldata.extend(serialize_fp_fixed_len(modulus_len, &curve.b).unwrap());
+    calldata.extend(encode_order(curve.subgroup_order_repr));
+
+    (calldata, modulus_len)
+}
+
+pub(crate) fn assemble_g2_fp2_curve_params<'a, FE: ElementRepr>(curve: &WeierstrassCurve<'a, CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>) -> (Vec<u8>, usize) {
+    // - Lengths of modulus (in bytes)
+    // - Field modulus
+    // - Extension degree
+    // - Non-residue
+    // - Curve A in Fp2
+    // - Curve B in Fp2
+    // - Length of a scalar field (curve order) (in bytes)
+    // - Curve order
+    let extension = curve.params.field;
+    let (mut calldata, modulus_len) = encode_modulus(extension.field.modulus());
+    calldata.push(EXTENSION_DEGREE_2);
+    calldata.extend(serialize_fp_fixed_len(modulus_len, &extension.non_residue).unwrap());
+    calldata.extend(serialize_fp2_fixed_len(modulus_len, &curve.a).unwrap());
+    calldata.extend(serialize_fp2_fixed_len(modulus_len, &curve.b).unwrap());
+    calldata.extend(encode_order(curve.subgroup_order_repr));
+
+    (calldata, modulus_len)
+}
+
+pub(crate) fn assemble_g2_fp3_curve_params<'a, FE: ElementRepr>(curve: &WeierstrassCurve<'a, CurveOverFp3Parameters<'a, FE, PrimeField<FE>>>) -> (Vec<u8>, usize) {
+    let extension = curve.params.field;
+    let (mut calldata, modulus_len) = encode_modulus(extension.field.modulus());
+    calldata.push(EXTENSION_DEGREE_3);
+    calldata.extend(serialize_fp_fixed_len(modulus_len, &extension.non_residue).unwrap());
+    calldata.extend(serialize_fp3_fixed_len(modulus_len, &curve.a).unwrap());
+    calldata.extend(serialize_fp3_fixed_len(modulus_len, &curve.b).unwrap());
+    calldata.extend(encode_order(curve.subgroup_order_repr));
+
+    (calldata, modulus_len)
+}
+
+fn points_with_both_signs<G: Group + Clone>(generator: &G) -> Vec<G> {
+    let mut points = vec![];
+    let mut p = generator.clone();
+    for _ in 0..3 {
+        let mut negated = p.clone();
+        negated.negate();
+        points.push(p.clone());
+        points.push(negated);
+        p.add_assign(generator);
+    }
+
+    points
+}
+
+fn run_g1_decompression<'a, FE: ElementRepr>(generator: &G1Point<'a, FE>) {
+    let (curve_params, modulus_len) = assemble_g1_curve_params(generator.curve);
+
+    for p in points_with_both_signs(generator) {
+        let expected = serialize_g1_point(modulus_len, &p).unwrap();
+        let (x, y) = p.into_xy();
+
+        let mut input = curve_params.clone();
+        input.push(encode_sign(sign_of_fp(&y)));
+        input.extend(serialize_fp_fixed_len(modulus_len, &x).unwrap());
+        assert_eq!(PublicG1Api::decompress_point(&input).unwrap(), expected);
+
+        let mut api_input = vec![OPERATION_G1_DECOMPRESS];
+        api_input.extend(input);
+        assert_eq!(API::run(&api_input).unwrap(), expected);
+    }
+}
+
+fn run_g2_fp2_decompression<'a, FE: ElementRepr>(generator: &G2Fp2Point<'a, FE>) {
+    let (curve_params, modulus_len) = assemble_g2_fp2_curve_params(generator.curve);
+
+    for p in points_with_both_signs(generator) {
+        let expected = serialize_g2_point_in_fp2(modulus_len, &p).unwrap();
+        let (x, y) = p.into_xy();
+
+        let mut input = curve_params.clone();
+        input.push(encode_sign(sign_of_fp2(&y)));
+        input.extend(serialize_fp2_fixed_len(modulus_len, &x).unwrap());
+        assert_eq!(PublicG2Api::decompress_point(&input).unwrap(), expected);
+
+        let mut api_input = vec![OPERATION_G2_DECOMPRESS];
+        api_input.extend(input);
+        assert_eq!(API::run(&api_input).unwrap(), expected);
+    }
+}
+
+fn run_g2_fp3_decompression<'a, FE: ElementRepr>(generator: &G2Fp3Point<'a, FE>) {
+    let (curve_params, modulus_len) = assemble_g2_fp3_curve_params(generator.curve);
+
+    for p in points_with_both_signs(generator) {
+        let expected = serialize_g2_point_in_fp3(modulus_len, &p).unwrap();
+        let (x, y) = p.into_xy();
+
+        let mut input = curve_params.clone();
+        input.push(encode_sign(sign_of_fp3(&y)));
+        input.extend(serialize_fp3_fixed_len(modulus_len, &x).unwrap());
+        assert_eq!(PublicG2Api::decompress_point(&input).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_bn254_decompression() {
+    // p = 3 mod 4
+    run_g1_decompression(&BN254_G1_GENERATOR);
+    run_g2_fp2_decompression(&BN254_G2_GENERATOR);
+}
+
+#[test]
+fn test_bls12_377_decompression() {
+    // p = 1 mod 4 with large 2-adicity
+    run_g1_decompression(&BLS12_377_G1_GENERATOR);
+    run_g2_fp2_decompression(&BLS12_377_G2_GENERATOR);
+}
+
+#[test]
+fn test_mnt6_753_decompression() {
+    run_g1_decompression(&MNT6_753_G1_GENERATOR);
+    run_g2_fp3_decompression(&MNT6_753_G2_GENERATOR);
+}
+
+#[test]
+fn test_decompression_of_x_not_on_curve() {
+    let (curve_params, modulus_len) = assemble_g1_curve_params(&BLS12_377_G1_CURVE);
+    let field = BLS12_377_G1_CURVE.params.field;
+
+    let mut num_rejected = 0;
+    for i in 1..32u64 {
+        let x = Fp::from_repr(field, U384Repr::from(i)).unwrap();
+        let rhs = BLS12_377_G1_CURVE.evaluate_rhs(&x);
+
+        let mut input = curve_params.clone();
+        input.push(SIGN_PLUS);
+        input.extend(serialize_fp_fixed_len(modulus_len, &x).unwrap());
+        let result = PublicG1Api::decompress_point(&input);
+
+        if legendre_symbol_fp(&rhs) == LegendreSymbol::QuadraticNonResidue {
+            assert!(result.is_err());
+            num_rejected += 1;
+        } else {
+            let result = result.unwrap();
+            assert_eq!(&result[..modulus_len], &serialize_fp_fixed_len(modulus_len, &x).unwrap()[..]);
+            let y = decode_fp(&result[modulus_len..], modulus_len, field).unwrap().0;
+            let mut y_2 = y;
+            y_2.square();
+            assert!(y_2 == rhs);
+            assert!(sign_of_fp(&y) == Sign::SignPlus);
+        }
+    }
+
+    assert!(num_rejected > 0);
+}
+
+#[test]
+fn test_invalid_decompression_inputs() {
+    let (curve_params, modulus_len) = assemble_g1_curve_params(&BN254_G1_CURVE);
+    let (x, _) = BN254_G1_GENERATOR.into_xy();
+    let x_encoding = serialize_fp_fixed_len(modulus_len, &x).unwrap();
+
+    // invalid sign encoding
+    let mut input = curve_params.clone();
+    input.push(0x02);
+    input.extend(x_encoding.clone());
+    assert!(PublicG1Api::decompress_point(&input).is_err());
+
+    // garbage at the end
+    let mut input = curve_params.clone();
+    input.push(SIGN_PLUS);
+    input.extend(x_encoding.clone());
+    input.push(0u8);
+    assert!(PublicG1Api::decompress_point(&input).is_err());
+
+    // x is not in the field
+    let mut input = curve_params.clone();
+    input.push(SIGN_PLUS);
+    input.extend(vec![0xffu8; modulus_len]);
+    assert!(PublicG1Api::decompress_point(&input).is_err());
+
+    // x is truncated
+    let mut input = curve_params;
+    input.push(SIGN_PLUS);
+    input.extend_from_slice(&x_encoding[1..]);
+    assert!(PublicG1Api::decompress_point(&input).is_err());
+}
+
+#[test]
+fn test_zero_root_sign() {
+    use crate::public_interface::decode_utils::select_root_by_sign;
+
+    let zero = Fp::zero(&BLS12_377_FIELD);
+    assert!(select_root_by_sign(zero, sign_of_fp(&zero), false).unwrap().is_zero());
+    assert!(select_root_by_sign(zero, sign_of_fp(&zero), true).is_err());
+}
+
+/// Compresses serialized X and Y coordinates into the sign of Y followed by X
+pub(crate) fn compress_encoding(encoding: &[u8], modulus_len: usize) -> Vec<u8> {
+    let (x, y) = encoding.split_at(encoding.len() / 2);
+    // sign of the lowest non-zero coefficient is the parity of its canonical representation
+    let y_is_negative = y.chunks(modulus_len)
+        .find(|c| c.iter().any(|b| *b != 0))
+        .map(|c| c[modulus_len - 1] & 1 == 1)
+        .unwrap_or(false);
+
+    let mut result = vec![if y_is_negative { SIGN_MINUS } else { SIGN_PLUS }];
+    result.extend_from_slice(x);
+
+    result
+}
+
+type ApiFn = fn(&[u8]) -> Result<Vec<u8>, ApiError>;
+
+fn run_compressed_arithmetic(
+    curve_params: &[u8],
+    points: &[Vec<u8>],
+    modulus_len: usize,
+    order_len: usize,
+    (add, mul, multiexp): (ApiFn, ApiFn, ApiFn)
+) {
+    let scalar = vec![0x5au8; order_len];
+    let assemble = |compressed: bool, operands: &[(&Vec<u8>, Option<&Vec<u8>>)]| {
+        let mut input = curve_params.to_vec();
+        for (point, scalar) in operands.iter() {
+            if compressed {
+                input.extend(compress_encoding(point, modulus_len));
+            } else {
+                input.extend(point.iter());
+            }
+            if let Some(scalar) = scalar {
+                input.extend(scalar.iter());
+            }
+        }
+
+        input
+    };
+
+    for pair in points.windows(2) {
+        let operands = [(&pair[0], None), (&pair[1], None)];
+        let expected = add(&assemble(false, &operands)).unwrap();
+        assert_eq!(add(&assemble(true, &operands)).unwrap(), expected);
+
+        let operands = [(&pair[0], Some(&scalar))];
+        let expected = mul(&assemble(false, &operands)).unwrap();
+        assert_eq!(mul(&assemble(true, &operands)).unwrap(), expected);
+    }
+
+    let operands: Vec<_> = points.iter().map(|p| (p, Some(&scalar))).collect();
+    let with_num_pairs = |input: Vec<u8>| {
+        let mut result = curve_params.to_vec();
+        result.push(points.len() as u8);
+        result.extend_from_slice(&input[curve_params.len()..]);
+
+        result
+    };
+    let expected = multiexp(&with_num_pairs(assemble(false, &operands))).unwrap();
+    assert_eq!(multiexp(&with_num_pairs(assemble(true, &operands))).unwrap(), expected);
+
+    // all the points of the call must use the same encoding
+    let mut mixed = curve_params.to_vec();
+    mixed.extend(compress_encoding(&points[0], modulus_len));
+    mixed.extend(points[1].iter());
+    assert!(add(&mixed).is_err());
+}
+
+pub(crate) fn order_len(order: &[u64]) -> usize {
+    encode_order(order)[0] as usize
+}
+
+#[test]
+fn test_compressed_points_in_arithmetic_operations() {
+    let g1_ops: (ApiFn, ApiFn, ApiFn) = (PublicG1Api::add_points, PublicG1Api::mul_point, PublicG1Api::multiexp);
+    let g2_ops: (ApiFn, ApiFn, ApiFn) = (PublicG2Api::add_points, PublicG2Api::mul_point, PublicG2Api::multiexp);
+
+    let (curve_params, modulus_len) = assemble_g1_curve_params(&BN254_G1_CURVE);
+    let points: Vec<_> = points_with_both_signs(&BN254_G1_GENERATOR).iter().map(|p| serialize_g1_point(modulus_len, p).unwrap()).collect();
+    run_compressed_arithmetic(&curve_params, &points, modulus_len, order_len(BN254_G1_CURVE.subgroup_order_repr), g1_ops);
+
+    let (curve_params, modulus_len) = assemble_g2_fp2_curve_params(&BLS12_377_G2_CURVE);
+    let points: Vec<_> = points_with_both_signs(&BLS12_377_G2_GENERATOR).iter().map(|p| serialize_g2_point_in_fp2(modulus_len, p).unwrap()).collect();
+    run_compressed_arithmetic(&curve_params, &points, modulus_len, order_len(BLS12_377_G2_CURVE.subgroup_order_repr), g2_ops);
+
+    let (curve_params, modulus_len) = assemble_g2_fp3_curve_params(&MNT6_753_G2_CURVE);
+    let points: Vec<_> = points_with_both_signs(&MNT6_753_G2_GENERATOR).iter().map(|p| serialize_g2_point_in_fp3(modulus_len, p).unwrap()).collect();
+    run_compressed_arithmetic(&curve_params, &points, modulus_len, order_len(MNT6_753_G2_CURVE.subgroup_order_repr), g2_ops);
+}
+
+/// Replaces encoded pairs of the BN254 pairing input from `assemble_bn254` by compressed ones
+fn compress_bn254_pairs(calldata: &[u8], num_pairs: usize) -> Vec<u8> {
+    let modulus_len = 32;
+    let pair_len = 2 * BOOLEAN_ENCODING_LENGTH + 6 * modulus_len;
+    let (header, pairs) = calldata.split_at(calldata.len() - num_pairs * pair_len);
+
+    let mut result = header.to_vec();
+    for pair in pairs.chunks(pair_len) {
+        let (g1, g2) = pair.split_at(BOOLEAN_ENCODING_LENGTH + 2 * modulus_len);
+        result.push(g1[0]);
+        result.extend(compress_encoding(&g1[1..], modulus_len));
+        result.push(g2[0]);
+        result.extend(compress_encoding(&g2[1..], modulus_len));
+    }
+
+    result
+}
+
+#[test]
+fn test_compressed_points_in_pairing() {
+    use crate::test::pairings::bn::assemble_bn254;
+
+    let num_pairs = 2;
+    let calldata = assemble_bn254(num_pairs);
+    let expected = PublicPairingApi::pair(&calldata).unwrap();
+    assert_eq!(expected, vec![1u8]);
+
+    let compressed = compress_bn254_pairs(&calldata, num_pairs);
+    assert_eq!(PublicPairingApi::pair(&compressed).unwrap(), expected);
+
+    let mut api_input = vec![OPERATION_PAIRING];
+    api_input.extend(compressed.iter());
+    assert_eq!(API::run(&api_input).unwrap(), expected);
+
+    // pairs are (P, Q) and (-P, Q), so flipping the sign of -P breaks the equality
+    let mut flipped = compressed.clone();
+    let second_g1_sign = flipped.len() - (BOOLEAN_ENCODING_LENGTH + SIGN_ENCODING_LENGTH + 64) - (SIGN_ENCODING_LENGTH + 32);
+    flipped[second_g1_sign] ^= 1;
+    assert_eq!(PublicPairingApi::pair(&flipped).unwrap(), vec![0u8]);
+
+    // mixed encodings are rejected
+    let mut mixed = compressed[..(compressed.len() - 2 * BOOLEAN_ENCODING_LENGTH - 2 * SIGN_ENCODING_LENGTH - 96)].to_vec();
+    mixed.extend_from_slice(&calldata[(calldata.len() - 2 * BOOLEAN_ENCODING_LENGTH - 192)..]);
+    assert!(PublicPairingApi::pair(&mixed).is_err());
+}
+
+#[cfg(feature = "gas_metering")]
+#[test]
+fn test_decompression_metering() {
+    use crate::gas_meter::{GasMeter, meter_operation};
+    use crate::public_interface::{OperationType, perform_operation};
+    use crate::test::pairings::bn::assemble_bn254;
+
+    let (curve_params, modulus_len) = assemble_g1_curve_params(&BN254_G1_CURVE);
+    let p = serialize_g1_point(modulus_len, &BN254_G1_GENERATOR).unwrap();
+    let mut input = curve_params.clone();
+    input.extend(compress_encoding(&p, modulus_len));
+
+    let price = meter_operation(OperationType::G1DECOMPRESS, &input).unwrap();
+    let mut api_input = vec![OPERATION_G1_DECOMPRESS];
+    api_input.extend(input.iter());
+    assert_eq!(GasMeter::meter(&api_input).unwrap(), price);
+
+    assert_eq!(perform_operation(OperationType::G1DECOMPRESS, &input).unwrap(), p);
+
+    let mut garbage_input = input.clone();
+    garbage_input.push(0u8);
+    assert!(meter_operation(OperationType::G1DECOMPRESS, &garbage_input).is_err());
+
+    // compressed operands are charged for decompression
+    let mut uncompressed_add = curve_params.clone();
+    uncompressed_add.extend(p.iter());
+    uncompressed_add.extend(p.iter());
+    let mut compressed_add = curve_params.clone();
+    compressed_add.extend(compress_encoding(&p, modulus_len));
+    compressed_add.extend(compress_encoding(&p, modulus_len));
+    let uncompressed_price = meter_operation(OperationType::G1ADD, &uncompressed_add).unwrap();
+    assert_eq!(meter_operation(OperationType::G1ADD, &compressed_add).unwrap(), uncompressed_price + 2 * price);
+
+    let (curve_params, modulus_len) = assemble_g2_fp2_curve_params(&BN254_G2_CURVE);
+    let q = serialize_g2_point_in_fp2(modulus_len, &BN254_G2_GENERATOR).unwrap();
+    let mut input = curve_params;
+    input.extend(compress_encoding(&q, modulus_len));
+    let g2_price = meter_operation(OperationType::G2DECOMPRESS, &input).unwrap();
+    assert!(g2_price > price);
+    assert_eq!(perform_operation(OperationType::G2DECOMPRESS, &input).unwrap(), q);
+
+    let num_pairs = 2;
+    let calldata = assemble_bn254(num_pairs);
+    let mut uncompressed_pairing = vec![OPERATION_PAIRING];
+    uncompressed_pairing.extend(calldata.iter());
+    let mut compressed_pairing = vec![OPERATION_PAIRING];
+    compressed_pairing.extend(compress_bn254_pairs(&calldata, num_pairs));
+    let uncompressed_price = GasMeter::meter(&uncompressed_pairing).unwrap();
+    assert_eq!(GasMeter::meter(&compressed_pairing).unwrap(), uncompressed_price + 2 * (price + g2_price));
+
+    // square root cost is bounded by the limit on two-adicity of the modulus,
+    // lowest bytes of the modulus are replaced by 0x01 00 .. 00 01 or 0x02 00 .. 00 01
+    let max_two_adicity = crate::public_interface::sane_limits::MAX_MODULUS_TWO_ADICITY as usize;
+    assert_eq!(max_two_adicity, 64);
+    let with_two_adicity = |top: u8| {
+        let mut input = api_input.clone();
+        let modulus_end = 2 + modulus_len;
+        input[(modulus_end - 9)..modulus_end].copy_from_slice(&[top, 0, 0, 0, 0, 0, 0, 0, 1]);
+
+        input
+    };
+    assert!(GasMeter::meter(&with_two_adicity(0x01)).is_ok());
+    assert!(GasMeter::meter(&with_two_adicity(0x02)).is_err());
+}
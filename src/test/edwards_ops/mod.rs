@@ -0,0 +1,248 @@
+use crate::public_interface::{EdwardsApi, PublicEdwardsApi, API};
+use crate::public_interface::constants::*;
+use crate::public_interface::decode_fp::serialize_fp_fixed_len;
+use crate::public_interface::decode_edwards::serialize_edwards_point;
+use crate::errors::ApiError;
+
+use crate::field::{U256Repr, PrimeField, SizedPrimeField};
+use crate::representation::ElementRepr;
+use crate::weierstrass::{Group, CurveOverFpParameters};
+use crate::edwards::curve::{EdwardsCurve, EdwardsPoint};
+use crate::integers::MaxGroupSizeUint;
+
+use crate::engines::jubjub::*;
+use crate::engines::baby_jubjub::*;
+use crate::engines::bandersnatch::*;
+
+type StaticParameters = CurveOverFpParameters<'static, U256Repr, PrimeField<U256Repr>>;
+
+const MODULUS_LEN: usize = 32;
+const ORDER_LEN: usize = 32;
+
+pub(crate) fn call_edwards_engine_add(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    PublicEdwardsApi::add_points(bytes)
+}
+
+pub(crate) fn call_edwards_engine_mul(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    PublicEdwardsApi::mul_point(bytes)
+}
+
+pub(crate) fn call_edwards_engine_multiexp(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    PublicEdwardsApi::multiexp(bytes)
+}
+
+pub(crate) fn assemble_curve_params(curve: &EdwardsCurve<'static, StaticParameters>) -> Vec<u8> {
+    // - Lengths of modulus (in bytes)
+    // - Field modulus
+    // - Curve A
+    // - Curve D
+    // - Length of a scalar field (curve order) (in bytes)
+    // - Curve order
+
+    let mut modulus_encoded = vec![0u8; MODULUS_LEN];
+    curve.params.field.modulus().write_be(&mut modulus_encoded[..]).unwrap();
+
+    let mut order_encoded = vec![];
+    for limb in curve.subgroup_order_repr.iter().rev() {
+        order_encoded.extend_from_slice(&limb.to_be_bytes());
+    }
+
+    let mut calldata = vec![MODULUS_LEN as u8];
+    calldata.extend(modulus_encoded);
+    calldata.extend(serialize_fp_fixed_len(MODULUS_LEN, &curve.a).unwrap());
+    calldata.extend(serialize_fp_fixed_len(MODULUS_LEN, &curve.d).unwrap());
+    calldata.push(ORDER_LEN as u8);
+    calldata.extend(order_encoded);
+
+    calldata
+}
+
+pub(crate) fn encode_point(point: &EdwardsPoint<'static, StaticParameters>) -> Vec<u8> {
+    serialize_edwards_point(MODULUS_LEN, point).unwrap()
+}
+
+pub(crate) fn make_scalar(seed: u8) -> Vec<u8> {
+    // keep scalars below the group order bit length, as multiexp expects
+    let mut scalar = vec![0u8; ORDER_LEN];
+    for (i, b) in scalar.iter_mut().enumerate().skip(1) {
+        *b = seed.wrapping_mul(i as u8).wrapping_add(0x5a);
+    }
+
+    scalar
+}
+
+fn run_engine_ops(generator: &EdwardsPoint<'static, StaticParameters>) {
+    let curve_params = assemble_curve_params(generator.curve);
+
+    // addition
+    let mut doubled = generator.clone();
+    doubled.double();
+
+    let mut input = curve_params.clone();
+    input.extend(encode_point(generator));
+    input.extend(encode_point(generator));
+    let result = call_edwards_engine_add(&input).unwrap();
+    assert_eq!(result, encode_point(&doubled));
+
+    // addition of the neutral element
+    let mut input = curve_params.clone();
+    input.extend(encode_point(generator));
+    input.extend(encode_point(&EdwardsPoint::zero(generator.curve)));
+    let result = call_edwards_engine_add(&input).unwrap();
+    assert_eq!(result, encode_point(generator));
+
+    // multiplication
+    let scalar = make_scalar(7);
+    let expected = generator.mul(MaxGroupSizeUint::from_big_endian(&scalar));
+
+    let mut input = curve_params.clone();
+    input.extend(encode_point(generator));
+    input.extend(scalar.clone());
+    let result = call_edwards_engine_mul(&input).unwrap();
+    assert_eq!(result, encode_point(&expected));
+
+    // multiplication by the group order gives (0, 1)
+    let mut input = curve_params.clone();
+    input.extend(encode_point(generator));
+    input.extend_from_slice(&curve_params[(curve_params.len() - ORDER_LEN)..]);
+    let result = call_edwards_engine_mul(&input).unwrap();
+    assert_eq!(result, encode_point(&EdwardsPoint::zero(generator.curve)));
+    assert_eq!(result[2*MODULUS_LEN - 1], 1u8);
+
+    // multiexp against the naive sum
+    let num_pairs = 3;
+    let mut input = curve_params.clone();
+    input.push(num_pairs as u8);
+    let mut expected = EdwardsPoint::zero(generator.curve);
+    let mut base = doubled.clone();
+    for i in 0..num_pairs {
+        base.add_assign(generator);
+        let scalar = make_scalar(i as u8 + 1);
+        expected.add_assign(&base.mul(MaxGroupSizeUint::from_big_endian(&scalar)));
+
+        input.extend(encode_point(&base));
+        input.extend(scalar);
+    }
+    let result = call_edwards_engine_multiexp(&input).unwrap();
+    assert_eq!(result, encode_point(&expected));
+
+    // the same through the generic entry point
+    let mut api_input = vec![OPERATION_EDWARDS_MULTIEXP];
+    api_input.extend(input);
+    assert_eq!(API::run(&api_input).unwrap(), result);
+}
+
+#[test]
+fn test_jubjub_ops() {
+    run_engine_ops(&JUBJUB_GENERATOR);
+}
+
+#[test]
+fn test_baby_jubjub_ops() {
+    run_engine_ops(&BABY_JUBJUB_GENERATOR);
+}
+
+#[test]
+fn test_bandersnatch_ops() {
+    run_engine_ops(&BANDERSNATCH_GENERATOR);
+}
+
+#[test]
+fn test_baby_jubjub_addition_vector() {
+    // EIP-2494 test vector
+    let p_0 = "274dbce8d15179969bc0d49fa725bddf9de555e0ba6a693c6adb52fc9ee7a82c05ce98c61b05f47fe2eae9a542bd99f6b2e78246231640b54595febfd51eb853";
+    let p_1 = "2491aba8d3a191a76e35bc47bd9afe6cc88fee14d607cbe779f2349047d5c1572e07297f8d3c3d7818dbddfd24c35583f9a9d4ed0cb0c1d1348dd8f7f99152d7";
+    let expected = "11805510440a3488b3b811eaacd0ec7c72dded51978190e19067a2afaebaf3611f07aa1b3c598e2ff9ff77744a39298a0a89a9027777af9fa100dd448e072c13";
+
+    let mut input = vec![OPERATION_EDWARDS_ADD];
+    input.extend(assemble_curve_params(&BABY_JUBJUB_CURVE));
+    input.extend(hex::decode(p_0).unwrap());
+    input.extend(hex::decode(p_1).unwrap());
+
+    let result = API::run(&input).unwrap();
+    assert_eq!(hex::encode(result), expected);
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let curve_params = assemble_curve_params(&JUBJUB_CURVE);
+
+    // point is not on curve
+    let mut point = encode_point(&JUBJUB_GENERATOR);
+    point[2*MODULUS_LEN - 1] ^= 1u8;
+    let mut input = curve_params.clone();
+    input.extend(point);
+    input.extend(make_scalar(1));
+    assert!(call_edwards_engine_mul(&input).is_err());
+
+    // garbage at the end
+    let mut input = curve_params.clone();
+    input.extend(encode_point(&JUBJUB_GENERATOR));
+    input.extend(encode_point(&JUBJUB_GENERATOR));
+    input.push(0u8);
+    assert!(call_edwards_engine_add(&input).is_err());
+
+    // singular curve with a = d
+    let mut singular = curve_params.clone();
+    let a_encoding = singular[(1 + MODULUS_LEN)..(1 + 2*MODULUS_LEN)].to_vec();
+    singular[(1 + 2*MODULUS_LEN)..(1 + 3*MODULUS_LEN)].copy_from_slice(&a_encoding);
+    singular.extend(encode_point(&JUBJUB_GENERATOR));
+    singular.extend(encode_point(&JUBJUB_GENERATOR));
+    assert!(call_edwards_engine_add(&singular).is_err());
+
+    // zero pairs in multiexp
+    let mut input = curve_params.clone();
+    input.push(0u8);
+    assert!(call_edwards_engine_multiexp(&input).is_err());
+}
+
+#[cfg(feature = "gas_metering")]
+#[test]
+fn test_edwards_metering() {
+    use crate::public_interface::{OperationType, perform_operation};
+    use crate::gas_meter::{GasMeter, meter_operation};
+
+    let curve_params = assemble_curve_params(&BABY_JUBJUB_CURVE);
+
+    let mut add_input = curve_params.clone();
+    add_input.extend(encode_point(&BABY_JUBJUB_GENERATOR));
+    add_input.extend(encode_point(&BABY_JUBJUB_GENERATOR));
+
+    let mut mul_input = curve_params.clone();
+    mul_input.extend(encode_point(&BABY_JUBJUB_GENERATOR));
+    mul_input.extend(make_scalar(7));
+
+    let mut multiexp_input = curve_params.clone();
+    multiexp_input.push(2u8);
+    for i in 0..2 {
+        multiexp_input.extend(encode_point(&BABY_JUBJUB_GENERATOR));
+        multiexp_input.extend(make_scalar(i + 1));
+    }
+
+    let cases = [
+        (OperationType::EDWARDSADD, OPERATION_EDWARDS_ADD, add_input),
+        (OperationType::EDWARDSMUL, OPERATION_EDWARDS_MUL, mul_input),
+        (OperationType::EDWARDSMULTIEXP, OPERATION_EDWARDS_MULTIEXP, multiexp_input),
+    ];
+
+    let mut prices = vec![];
+    for (operation, op_byte, input) in cases.iter() {
+        assert_eq!(OperationType::from_u8(operation.as_u8()), Some(*operation));
+
+        let price = meter_operation(*operation, input).unwrap();
+        let mut api_input = vec![*op_byte];
+        api_input.extend(input.iter());
+        assert_eq!(GasMeter::meter(&api_input).unwrap(), price);
+
+        assert_eq!(API::run(&api_input).unwrap(), perform_operation(*operation, input).unwrap());
+
+        let mut garbage_input = input.clone();
+        garbage_input.push(0u8);
+        assert!(meter_operation(*operation, &garbage_input).is_err());
+
+        prices.push(price);
+    }
+
+    assert!(prices[0] < prices[1]);
+    assert!(prices[1] < prices[2]);
+}
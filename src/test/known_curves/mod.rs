@@ -0,0 +1,636 @@
+// Differential tests: inputs that describe BLS12-381, BLS12-377 and BN254 in the generic ABI
+// run on static curves and pairing engines, and must give the same results and errors
+// as the generic path that creates fields and curves from the encoding
+
+use crate::public_interface::{G1Api, PublicG1Api, G2Api, PublicG2Api, PairingApi, PublicPairingApi, API};
+use crate::public_interface::g1_ops::G1ApiImplementation;
+use crate::public_interface::g2_ops::G2ApiImplementationFp2;
+use crate::public_interface::pairing_ops::PairingApiImplementation;
+use crate::public_interface::known_curves::*;
+use crate::public_interface::constants::*;
+use crate::public_interface::decode_fp::*;
+use crate::public_interface::decode_g1::serialize_g1_point;
+use crate::public_interface::decode_g2::serialize_g2_point_in_fp2;
+use crate::public_interface::decode_utils::{get_base_field_params, num_limbs_for_modulus};
+use crate::errors::ApiError;
+
+use crate::field::*;
+use crate::representation::ElementRepr;
+use crate::extension_towers::fp2::Extension2;
+use crate::extension_towers::fp6_as_3_over_2::Extension3Over2;
+use crate::weierstrass::{Group, CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::weierstrass::curve::{WeierstrassCurve, CurvePoint};
+use crate::pairings::TwistType;
+use crate::square_root::*;
+
+use crate::engines::bls12_381::*;
+use crate::engines::bls12_377::*;
+use crate::engines::bn254::*;
+
+type G1Point<'a, FE> = CurvePoint<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>;
+type G2Point<'a, FE> = CurvePoint<'a, CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>;
+
+struct PairingCurve<'a, FE: ElementRepr> {
+    curve_type: u8,
+    g1_generator: G1Point<'a, FE>,
+    g2_generator: G2Point<'a, FE>,
+    fp2_extension: &'a Extension2<'a, FE, PrimeField<FE>>,
+    fp6_extension: &'a Extension3Over2<'a, FE, PrimeField<FE>>,
+    twist_type: TwistType,
+    loop_parameter: &'a [u64],
+    loop_parameter_is_negative: bool,
+}
+
+fn bls12_381() -> PairingCurve<'static, U384Repr> {
+    let engine = BLS12_381_PAIRING_ENGINE;
+
+    PairingCurve {
+        curve_type: BLS12,
+        g1_generator: BLS12_381_G1_GENERATOR,
+        g2_generator: BLS12_381_G2_GENERATOR,
+        fp2_extension: engine.fp2_extension,
+        fp6_extension: engine.fp6_extension,
+        twist_type: engine.twist_type,
+        loop_parameter: engine.x,
+        loop_parameter_is_negative: engine.x_is_negative,
+    }
+}
+
+fn bls12_377() -> PairingCurve<'static, U384Repr> {
+    let engine = BLS12_377_PAIRING_ENGINE;
+
+    PairingCurve {
+        curve_type: BLS12,
+        g1_generator: BLS12_377_G1_GENERATOR,
+        g2_generator: BLS12_377_G2_GENERATOR,
+        fp2_extension: engine.fp2_extension,
+        fp6_extension: engine.fp6_extension,
+        twist_type: engine.twist_type,
+        loop_parameter: engine.x,
+        loop_parameter_is_negative: engine.x_is_negative,
+    }
+}
+
+fn bn254() -> PairingCurve<'static, U256Repr> {
+    let engine = &BN254_PAIRING_ENGINE;
+
+    PairingCurve {
+        curve_type: BN,
+        g1_generator: BN254_G1_GENERATOR.clone(),
+        g2_generator: BN254_G2_GENERATOR.clone(),
+        fp2_extension: engine.fp2_extension,
+        fp6_extension: engine.fp6_extension,
+        twist_type: engine.twist_type,
+        loop_parameter: engine.u,
+        loop_parameter_is_negative: engine.u_is_negative,
+    }
+}
+
+fn encode_modulus<FE: ElementRepr>(modulus: &FE) -> (Vec<u8>, usize) {
+    let modulus_len = (modulus.num_bits() as usize).div_ceil(8);
+    let mut encoding = vec![0u8; FE::NUM_LIMBS * 8];
+    modulus.write_be(&mut encoding[..]).unwrap();
+    let encoding = encoding[(FE::NUM_LIMBS * 8 - modulus_len)..].to_vec();
+
+    let mut result = vec![modulus_len as u8];
+    result.extend(encoding);
+
+    (result, modulus_len)
+}
+
+/// Big endian encoding with a length, `padding` zero bytes are added on top
+fn encode_integer(limbs: &[u64], padding: usize) -> Vec<u8> {
+    let mut encoding = vec![];
+    for limb in limbs.iter().rev() {
+        encoding.extend_from_slice(&limb.to_be_bytes());
+    }
+    let leading_zeroes = encoding.iter().take_while(|b| **b == 0).count();
+
+    let mut result = vec![(encoding.len() - leading_zeroes + padding) as u8];
+    result.extend(vec![0u8; padding]);
+    result.extend_from_slice(&encoding[leading_zeroes..]);
+
+    result
+}
+
+fn encode_twist_type(twist_type: TwistType) -> u8 {
+    match twist_type {
+        TwistType::M => TWIST_TYPE_M,
+        TwistType::D => TWIST_TYPE_D,
+    }
+}
+
+fn encode_sign(is_negative: bool) -> u8 {
+    if is_negative { SIGN_MINUS } else { SIGN_PLUS }
+}
+
+/// Returns G1 curve encoding, modulus length and group order length
+fn encode_g1_curve<'a, FE: ElementRepr>(curve: &WeierstrassCurve<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>, order_padding: usize) -> (Vec<u8>, usize, usize) {
+    let (mut encoding, modulus_len) = encode_modulus(curve.params.field.modulus());
+    encoding.extend(serialize_fp_fixed_len(modulus_len, &curve.a).unwrap());
+    encoding.extend(serialize_fp_fixed_len(modulus_len, &curve.b).unwrap());
+    let order = encode_integer(curve.subgroup_order_repr, order_padding);
+    let order_len = order[0] as usize;
+    encoding.extend(order);
+
+    (encoding, modulus_len, order_len)
+}
+
+/// Returns G2 curve encoding, modulus length and group order length
+fn encode_g2_curve<'a, FE: ElementRepr>(curve: &WeierstrassCurve<'a, CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>, order_padding: usize) -> (Vec<u8>, usize, usize) {
+    let extension = curve.params.field;
+    let (mut encoding, modulus_len) = encode_modulus(extension.field.modulus());
+    encoding.push(EXTENSION_DEGREE_2);
+    encoding.extend(serialize_fp_fixed_len(modulus_len, &extension.non_residue).unwrap());
+    encoding.extend(serialize_fp2_fixed_len(modulus_len, &curve.a).unwrap());
+    encoding.extend(serialize_fp2_fixed_len(modulus_len, &curve.b).unwrap());
+    let order = encode_integer(curve.subgroup_order_repr, order_padding);
+    let order_len = order[0] as usize;
+    encoding.extend(order);
+
+    (encoding, modulus_len, order_len)
+}
+
+/// Pairing encoding up to the number of pairs (without it)
+fn encode_pairing_curve<FE: ElementRepr>(curve: &PairingCurve<FE>) -> (Vec<u8>, usize) {
+    let (g1_encoding, modulus_len, _) = encode_g1_curve(curve.g1_generator.curve, 0);
+
+    let mut encoding = vec![curve.curve_type];
+    encoding.extend(g1_encoding);
+    encoding.extend(serialize_fp_fixed_len(modulus_len, &curve.fp2_extension.non_residue).unwrap());
+    encoding.extend(serialize_fp2_fixed_len(modulus_len, &curve.fp6_extension.non_residue).unwrap());
+    encoding.push(encode_twist_type(curve.twist_type));
+    encoding.extend(encode_integer(curve.loop_parameter, 0));
+    encoding.push(encode_sign(curve.loop_parameter_is_negative));
+
+    (encoding, modulus_len)
+}
+
+fn encode_scalar(scalar: &[u8], order_len: usize) -> Vec<u8> {
+    let mut encoding = vec![0u8; order_len - scalar.len()];
+    encoding.extend_from_slice(scalar);
+
+    encoding
+}
+
+fn scalars(order_len: usize) -> Vec<Vec<u8>> {
+    vec![
+        encode_scalar(&[], order_len),
+        encode_scalar(&[1], order_len),
+        encode_scalar(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x11, 0x22], order_len),
+        vec![0xffu8; order_len],
+    ]
+}
+
+fn g1_points<'a, FE: ElementRepr>(generator: &G1Point<'a, FE>) -> Vec<G1Point<'a, FE>> {
+    let mut double = generator.clone();
+    double.double();
+    let mut negated = generator.clone();
+    negated.negate();
+
+    vec![generator.clone(), double, negated, CurvePoint::zero(generator.curve)]
+}
+
+fn g2_points<'a, FE: ElementRepr>(generator: &G2Point<'a, FE>) -> Vec<G2Point<'a, FE>> {
+    let mut double = generator.clone();
+    double.double();
+    let mut negated = generator.clone();
+    negated.negate();
+
+    vec![generator.clone(), double, negated, CurvePoint::zero(generator.curve)]
+}
+
+/// Y is not on curve for X of the generator
+fn g1_point_not_on_curve<'a, FE: ElementRepr>(generator: &G1Point<'a, FE>, modulus_len: usize) -> Vec<u8> {
+    let (x, _) = generator.into_xy();
+    let mut encoding = serialize_fp_fixed_len(modulus_len, &x).unwrap();
+    encoding.extend(serialize_fp_fixed_len(modulus_len, &x).unwrap());
+
+    encoding
+}
+
+fn g2_point_not_on_curve<'a, FE: ElementRepr>(generator: &G2Point<'a, FE>, modulus_len: usize) -> Vec<u8> {
+    let (x, _) = generator.into_xy();
+    let mut encoding = serialize_fp2_fixed_len(modulus_len, &x).unwrap();
+    encoding.extend(serialize_fp2_fixed_len(modulus_len, &x).unwrap());
+
+    encoding
+}
+
+/// Finds a point on curve by decompressing small X, such points are not in the
+/// main subgroup for curves with a cofactor
+fn find_g1_point_by_x(curve_encoding: &[u8], modulus_len: usize) -> Vec<u8> {
+    for x in 1..64u8 {
+        let mut input = curve_encoding.to_vec();
+        input.push(SIGN_PLUS);
+        input.extend(encode_scalar(&[x], modulus_len));
+        if let Ok(point) = PublicG1Api::decompress_point(&input) {
+            return point;
+        }
+    }
+
+    unreachable!("failed to find a point with small X");
+}
+
+fn find_g2_point_by_x(curve_encoding: &[u8], modulus_len: usize) -> Vec<u8> {
+    for x in 1..64u8 {
+        let mut input = curve_encoding.to_vec();
+        input.push(SIGN_PLUS);
+        input.extend(encode_scalar(&[x], modulus_len));
+        input.extend(encode_scalar(&[1], modulus_len));
+        if let Ok(point) = PublicG2Api::decompress_point(&input) {
+            return point;
+        }
+    }
+
+    unreachable!("failed to find a point with small X");
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    Decompress,
+    Add,
+    Mul,
+    Multiexp,
+}
+
+fn g1_on_encoded_curve(operation: Operation, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    let ((modulus, _), _) = get_base_field_params(input)?;
+    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+    match operation {
+        Operation::Decompress => crate::expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, input, decompress_point_on_encoded_curve),
+        Operation::Add => crate::expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, input, add_points_on_encoded_curve),
+        Operation::Mul => crate::expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, input, mul_point_on_encoded_curve),
+        Operation::Multiexp => crate::expand_for_modulus_limbs!(modulus_limbs, G1ApiImplementation, input, multiexp_on_encoded_curve),
+    }
+}
+
+fn g2_on_encoded_curve(operation: Operation, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    let ((modulus, _), _) = get_base_field_params(input)?;
+    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+    match operation {
+        Operation::Decompress => crate::expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, input, decompress_point_on_encoded_curve),
+        Operation::Add => crate::expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, input, add_points_on_encoded_curve),
+        Operation::Mul => crate::expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, input, mul_point_on_encoded_curve),
+        Operation::Multiexp => crate::expand_for_modulus_limbs!(modulus_limbs, G2ApiImplementationFp2, input, multiexp_on_encoded_curve),
+    }
+}
+
+fn pairing_on_encoded_curve(input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    let (curve_type, rest) = (input[0], &input[1..]);
+    let ((modulus, _), _) = get_base_field_params(rest)?;
+    let modulus_limbs = num_limbs_for_modulus(&modulus)?;
+
+    match curve_type {
+        BLS12 => crate::expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, rest, pair_bls12_on_encoded_curve),
+        BN => crate::expand_for_modulus_limbs!(modulus_limbs, PairingApiImplementation, rest, pair_bn_on_encoded_curve),
+        _ => unreachable!(),
+    }
+}
+
+fn g1_operation_code(operation: Operation) -> u8 {
+    match operation {
+        Operation::Decompress => OPERATION_G1_DECOMPRESS,
+        Operation::Add => OPERATION_G1_ADD,
+        Operation::Mul => OPERATION_G1_MUL,
+        Operation::Multiexp => OPERATION_G1_MULTIEXP,
+    }
+}
+
+fn g2_operation_code(operation: Operation) -> u8 {
+    match operation {
+        Operation::Decompress => OPERATION_G2_DECOMPRESS,
+        Operation::Add => OPERATION_G2_ADD,
+        Operation::Mul => OPERATION_G2_MUL,
+        Operation::Multiexp => OPERATION_G2_MULTIEXP,
+    }
+}
+
+fn run_g1(operation: Operation, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    match operation {
+        Operation::Decompress => PublicG1Api::decompress_point(input),
+        Operation::Add => PublicG1Api::add_points(input),
+        Operation::Mul => PublicG1Api::mul_point(input),
+        Operation::Multiexp => PublicG1Api::multiexp(input),
+    }
+}
+
+fn run_g2(operation: Operation, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    match operation {
+        Operation::Decompress => PublicG2Api::decompress_point(input),
+        Operation::Add => PublicG2Api::add_points(input),
+        Operation::Mul => PublicG2Api::mul_point(input),
+        Operation::Multiexp => PublicG2Api::multiexp(input),
+    }
+}
+
+/// Known curve must be recognized and give the same result as the generic path,
+/// both through the G1 API and the top level API
+fn assert_same_g1(operation: Operation, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    assert!(recognize_g1_curve(input).is_some());
+    let expected = g1_on_encoded_curve(operation, input);
+    assert_eq!(run_g1(operation, input), expected, "{:?}", operation);
+
+    let mut api_input = vec![g1_operation_code(operation)];
+    api_input.extend_from_slice(input);
+    assert_eq!(API::run(&api_input), expected, "{:?}", operation);
+
+    expected
+}
+
+fn assert_same_g2(operation: Operation, input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    assert!(recognize_g2_curve_in_fp2(input).is_some());
+    let expected = g2_on_encoded_curve(operation, input);
+    assert_eq!(run_g2(operation, input), expected, "{:?}", operation);
+
+    let mut api_input = vec![g2_operation_code(operation)];
+    api_input.extend_from_slice(input);
+    assert_eq!(API::run(&api_input), expected, "{:?}", operation);
+
+    expected
+}
+
+fn assert_same_pairing(input: &[u8]) -> Result<Vec<u8>, ApiError> {
+    match input[0] {
+        BLS12 => assert!(recognize_bls12_engine(&input[1..]).is_some()),
+        BN => assert!(recognize_bn_engine(&input[1..]).is_some()),
+        _ => unreachable!(),
+    }
+    let expected = pairing_on_encoded_curve(input);
+    assert_eq!(PublicPairingApi::pair(input), expected);
+
+    let mut api_input = vec![OPERATION_PAIRING];
+    api_input.extend_from_slice(input);
+    assert_eq!(API::run(&api_input), expected);
+
+    expected
+}
+
+fn run_g1_differential<FE: ElementRepr>(generator: &G1Point<FE>, order_padding: usize) {
+    let (curve_encoding, modulus_len, order_len) = encode_g1_curve(generator.curve, order_padding);
+    let points = g1_points(generator);
+    let encoded_points: Vec<Vec<u8>> = points.iter().map(|p| serialize_g1_point(modulus_len, p).unwrap()).collect();
+
+    for p in points.iter() {
+        let (x, y) = p.into_xy();
+        let mut input = curve_encoding.clone();
+        input.push(if sign_of_fp(&y) == Sign::SignMinus { SIGN_MINUS } else { SIGN_PLUS });
+        input.extend(serialize_fp_fixed_len(modulus_len, &x).unwrap());
+        let _ = assert_same_g1(Operation::Decompress, &input);
+    }
+
+    for p_0 in encoded_points.iter() {
+        for p_1 in encoded_points.iter() {
+            let mut input = curve_encoding.clone();
+            input.extend_from_slice(p_0);
+            input.extend_from_slice(p_1);
+            assert!(assert_same_g1(Operation::Add, &input).is_ok());
+        }
+        for scalar in scalars(order_len) {
+            let mut input = curve_encoding.clone();
+            input.extend_from_slice(p_0);
+            input.extend(scalar);
+            assert!(assert_same_g1(Operation::Mul, &input).is_ok());
+        }
+    }
+
+    let mut input = curve_encoding.clone();
+    input.push(encoded_points.len() as u8);
+    for (p, scalar) in encoded_points.iter().zip(scalars(order_len)) {
+        input.extend_from_slice(p);
+        input.extend(scalar);
+    }
+    assert!(assert_same_g1(Operation::Multiexp, &input).is_ok());
+
+    // errors
+    let not_on_curve = g1_point_not_on_curve(generator, modulus_len);
+    let mut input = curve_encoding.clone();
+    input.extend_from_slice(&encoded_points[0]);
+    input.extend_from_slice(&not_on_curve);
+    assert!(assert_same_g1(Operation::Add, &input).is_err());
+    input.push(0);
+    assert!(assert_same_g1(Operation::Add, &input).is_err());
+
+    let mut input = curve_encoding.clone();
+    input.extend_from_slice(&not_on_curve);
+    input.extend(encode_scalar(&[1], order_len));
+    assert!(assert_same_g1(Operation::Mul, &input).is_err());
+
+    let mut input = curve_encoding.clone();
+    input.extend_from_slice(&encoded_points[0]);
+    input.extend(encode_scalar(&[1], order_len - 1));
+    assert!(assert_same_g1(Operation::Mul, &input).is_err());
+
+    let mut input = curve_encoding.clone();
+    input.push(0);
+    assert!(assert_same_g1(Operation::Multiexp, &input).is_err());
+
+    let mut input = curve_encoding.clone();
+    input.push(SIGN_PLUS);
+    input.extend(vec![0xffu8; modulus_len]);
+    assert!(assert_same_g1(Operation::Decompress, &input).is_err());
+}
+
+fn run_g2_differential<FE: ElementRepr>(generator: &G2Point<FE>, order_padding: usize) {
+    let (curve_encoding, modulus_len, order_len) = encode_g2_curve(generator.curve, order_padding);
+    let points = g2_points(generator);
+    let encoded_points: Vec<Vec<u8>> = points.iter().map(|p| serialize_g2_point_in_fp2(modulus_len, p).unwrap()).collect();
+
+    for p in points.iter() {
+        let (x, y) = p.into_xy();
+        let mut input = curve_encoding.clone();
+        input.push(if sign_of_fp2(&y) == Sign::SignMinus { SIGN_MINUS } else { SIGN_PLUS });
+        input.extend(serialize_fp2_fixed_len(modulus_len, &x).unwrap());
+        let _ = assert_same_g2(Operation::Decompress, &input);
+    }
+
+    for p_0 in encoded_points.iter() {
+        for p_1 in encoded_points.iter() {
+            let mut input = curve_encoding.clone();
+            input.extend_from_slice(p_0);
+            input.extend_from_slice(p_1);
+            assert!(assert_same_g2(Operation::Add, &input).is_ok());
+        }
+        for scalar in scalars(order_len) {
+            let mut input = curve_encoding.clone();
+            input.extend_from_slice(p_0);
+            input.extend(scalar);
+            assert!(assert_same_g2(Operation::Mul, &input).is_ok());
+        }
+    }
+
+    let mut input = curve_encoding.clone();
+    input.push(encoded_points.len() as u8);
+    for (p, scalar) in encoded_points.iter().zip(scalars(order_len)) {
+        input.extend_from_slice(p);
+        input.extend(scalar);
+    }
+    assert!(assert_same_g2(Operation::Multiexp, &input).is_ok());
+
+    // errors
+    let not_on_curve = g2_point_not_on_curve(generator, modulus_len);
+    let mut input = curve_encoding.clone();
+    input.extend_from_slice(&encoded_points[0]);
+    input.extend_from_slice(&not_on_curve);
+    assert!(assert_same_g2(Operation::Add, &input).is_err());
+    input.push(0);
+    assert!(assert_same_g2(Operation::Add, &input).is_err());
+
+    let mut input = curve_encoding.clone();
+    input.extend_from_slice(&not_on_curve);
+    input.extend(encode_scalar(&[1], order_len));
+    assert!(assert_same_g2(Operation::Mul, &input).is_err());
+
+    let mut input = curve_encoding.clone();
+    input.push(1);
+    input.extend_from_slice(&not_on_curve);
+    input.extend(encode_scalar(&[1], order_len));
+    assert!(assert_same_g2(Operation::Multiexp, &input).is_err());
+}
+
+fn encode_pairs(pairs: &[(u8, &[u8], u8, &[u8])]) -> Vec<u8> {
+    let mut encoding = vec![pairs.len() as u8];
+    for (check_g1, g1, check_g2, g2) in pairs.iter() {
+        encoding.push(*check_g1);
+        encoding.extend_from_slice(g1);
+        encoding.push(*check_g2);
+        encoding.extend_from_slice(g2);
+    }
+
+    encoding
+}
+
+fn run_pairing_differential<FE: ElementRepr>(curve: &PairingCurve<FE>) {
+    let (curve_encoding, modulus_len) = encode_pairing_curve(curve);
+    let g1: Vec<Vec<u8>> = g1_points(&curve.g1_generator).iter().map(|p| serialize_g1_point(modulus_len, p).unwrap()).collect();
+    let g2: Vec<Vec<u8>> = g2_points(&curve.g2_generator).iter().map(|p| serialize_g2_point_in_fp2(modulus_len, p).unwrap()).collect();
+    let (p, p_double, p_negated, p_zero) = (&g1[0][..], &g1[1][..], &g1[2][..], &g1[3][..]);
+    let (q, q_double, q_zero) = (&g2[0][..], &g2[1][..], &g2[3][..]);
+
+    let (g1_curve_encoding, _, _) = encode_g1_curve(curve.g1_generator.curve, 0);
+    let (g2_curve_encoding, _, _) = encode_g2_curve(curve.g2_generator.curve, 0);
+    let g1_not_in_subgroup = find_g1_point_by_x(&g1_curve_encoding, modulus_len);
+    let g2_not_in_subgroup = find_g2_point_by_x(&g2_curve_encoding, modulus_len);
+    let g1_not_on_curve = g1_point_not_on_curve(&curve.g1_generator, modulus_len);
+    let g2_not_on_curve = g2_point_not_on_curve(&curve.g2_generator, modulus_len);
+
+    let run = |pairs: &[(u8, &[u8], u8, &[u8])]| {
+        let mut input = curve_encoding.clone();
+        input.extend(encode_pairs(pairs));
+
+        assert_same_pairing(&input)
+    };
+
+    assert_eq!(run(&[(1, p, 1, q), (1, p_negated, 1, q)]), Ok(vec![1u8]));
+    assert_eq!(run(&[(1, p_double, 0, q), (0, p_negated, 1, q_double)]), Ok(vec![1u8]));
+    assert_eq!(run(&[(1, p, 1, q)]), Ok(vec![0u8]));
+    assert_eq!(run(&[(1, p, 1, q), (1, p, 1, q_double)]), Ok(vec![0u8]));
+    assert_eq!(run(&[(1, p_zero, 1, q), (1, p, 1, q_zero)]), Ok(vec![1u8]));
+
+    assert!(run(&[(1, p, 0, &g2_not_in_subgroup)]).is_ok());
+    assert!(run(&[(1, p, 1, &g2_not_in_subgroup)]).is_err());
+    assert!(run(&[(1, &g1_not_on_curve, 1, q)]).is_err());
+    assert!(run(&[(1, p, 1, &g2_not_on_curve)]).is_err());
+    assert!(run(&[(2, p, 1, q)]).is_err());
+    assert!(run(&[]).is_err());
+    if curve.curve_type == BLS12 {
+        // BN254 has no cofactor in G1
+        assert!(run(&[(0, &g1_not_in_subgroup, 1, q)]).is_ok());
+        assert!(run(&[(1, &g1_not_in_subgroup, 1, q)]).is_err());
+    }
+
+    let mut input = curve_encoding.clone();
+    input.extend(encode_pairs(&[(1, p, 1, q)]));
+    input.push(0);
+    assert!(assert_same_pairing(&input).is_err());
+}
+
+#[test]
+fn test_bls12_381_known_curve_differential() {
+    run_g1_differential(&BLS12_381_G1_GENERATOR, 0);
+    run_g2_differential(&BLS12_381_G2_GENERATOR, 0);
+    run_pairing_differential(&bls12_381());
+}
+
+#[test]
+fn test_bls12_377_known_curve_differential() {
+    run_g1_differential(&BLS12_377_G1_GENERATOR, 0);
+    run_g2_differential(&BLS12_377_G2_GENERATOR, 0);
+    run_pairing_differential(&bls12_377());
+}
+
+#[test]
+fn test_bn254_known_curve_differential() {
+    run_g1_differential(&BN254_G1_GENERATOR, 0);
+    run_g2_differential(&BN254_G2_GENERATOR, 0);
+    run_pairing_differential(&bn254());
+}
+
+#[test]
+fn test_known_curve_with_padded_group_order() {
+    // group order length is free in the encoding and defines the scalar length
+    run_g1_differential(&BLS12_381_G1_GENERATOR, 3);
+    run_g2_differential(&BN254_G2_GENERATOR, 1);
+}
+
+#[test]
+fn test_existing_known_curve_vectors_are_recognized() {
+    use crate::test::pairings::bls12::{assemble_bls12_381, assemble_bls12_377};
+
+    for input in [assemble_bls12_381(2), assemble_bls12_377(2)].iter() {
+        assert_eq!(assert_same_pairing(input), Ok(vec![1u8]));
+    }
+}
+
+#[test]
+fn test_near_miss_curves_are_not_recognized() {
+    let (g1_encoding, modulus_len, _) = encode_g1_curve(&BLS12_381_G1_CURVE, 0);
+
+    // different B
+    let mut input = g1_encoding.clone();
+    let b_last_byte = 3 * modulus_len;
+    input[b_last_byte] ^= 1;
+    assert!(recognize_g1_curve(&input).is_none());
+
+    // different group order
+    let mut input = g1_encoding.clone();
+    *input.last_mut().unwrap() ^= 1;
+    assert!(recognize_g1_curve(&input).is_none());
+
+    // extension degree 3 with the same modulus is never a known G2 curve
+    let (mut input, _, _) = encode_g2_curve(&BLS12_381_G2_CURVE, 0);
+    input[1 + modulus_len] = EXTENSION_DEGREE_3;
+    assert!(recognize_g2_curve_in_fp2(&input).is_none());
+
+    // different Fp2 non-residue for G2
+    let (mut input, _, _) = encode_g2_curve(&BLS12_377_G2_CURVE, 0);
+    input[1 + 2 * modulus_len] ^= 1;
+    assert!(recognize_g2_curve_in_fp2(&input).is_none());
+
+    let mut curve = bls12_381();
+    curve.twist_type = TwistType::D;
+    let (input, _) = encode_pairing_curve(&curve);
+    assert!(recognize_bls12_engine(&input[1..]).is_none());
+
+    let mut curve = bls12_377();
+    curve.loop_parameter_is_negative = !curve.loop_parameter_is_negative;
+    let (input, _) = encode_pairing_curve(&curve);
+    assert!(recognize_bls12_engine(&input[1..]).is_none());
+
+    let mut curve = bls12_381();
+    curve.fp2_extension = &BLS12_377_EXTENSION_2_FIELD;
+    let (input, _) = encode_pairing_curve(&curve);
+    assert!(recognize_bls12_engine(&input[1..]).is_none());
+
+    // BN254 parameters with BLS12 curve type go through the generic path
+    let mut curve = bn254();
+    curve.curve_type = BLS12;
+    let (input, _) = encode_pairing_curve(&curve);
+    assert!(recognize_bls12_engine(&input[1..]).is_none());
+    let mut curve = bls12_381();
+    curve.curve_type = BN;
+    let (input, _) = encode_pairing_curve(&curve);
+    assert!(recognize_bn_engine(&input[1..]).is_none());
+}
@@ -0,0 +1,335 @@
+use crate::public_interface::{MapToCurveApi, PublicMapToCurveApi, API, OperationType, perform_operation};
+use crate::public_interface::constants::*;
+use crate::public_interface::decode_fp::*;
+use crate::public_interface::decode_g1::{decode_g1_point_from_xy, serialize_g1_point};
+use crate::public_interface::decode_g2::serialize_g2_point_in_fp2;
+
+use crate::field::{PrimeField, U320Repr, U384Repr};
+use crate::fp::Fp;
+use crate::extension_towers::fp2::Fp2;
+use crate::representation::ElementRepr;
+use crate::traits::{FieldElement, ZeroAndOne};
+use crate::weierstrass::{Group, CurveOverFpParameters, CurveOverFp2Parameters};
+use crate::weierstrass::curve::WeierstrassCurve;
+use crate::mapping::isogeny::IsogenyParameters;
+use crate::mapping::parameters::{BLS12_G1_MAPPING_PARAMS, BLS12_G2_MAPPING_PARAMS};
+
+use crate::engines::bls12_381::*;
+use crate::engines::secp256r1::*;
+use crate::engines::bn254::*;
+
+use super::decompression::{assemble_g1_curve_params, assemble_g2_fp2_curve_params};
+
+type G1Curve<'a, FE> = WeierstrassCurve<'a, CurveOverFpParameters<'a, FE, PrimeField<FE>>>;
+type G2Curve<'a, FE> = WeierstrassCurve<'a, CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>;
+type G1Isogeny<'a, FE> = IsogenyParameters<CurveOverFpParameters<'a, FE, PrimeField<FE>>>;
+type G2Isogeny<'a, FE> = IsogenyParameters<CurveOverFp2Parameters<'a, FE, PrimeField<FE>>>;
+
+fn encode_g1_map_input<'a, FE: ElementRepr>(
+    curve: &G1Curve<'a, FE>,
+    z: &Fp<'a, FE, PrimeField<FE>>,
+    isogeny: Option<(&G1Curve<'a, FE>, &G1Isogeny<'a, FE>)>,
+    u: &Fp<'a, FE, PrimeField<FE>>
+) -> (Vec<u8>, usize) {
+    let (mut input, modulus_len) = assemble_g1_curve_params(curve);
+    input.extend(serialize_fp_fixed_len(modulus_len, z).unwrap());
+    match isogeny {
+        Some((swu_curve, isogeny)) => {
+            input.push(BOOLEAN_TRUE);
+            input.extend(serialize_fp_fixed_len(modulus_len, &swu_curve.a).unwrap());
+            input.extend(serialize_fp_fixed_len(modulus_len, &swu_curve.b).unwrap());
+            input.push(isogeny.map_degree as u8);
+            for k in [&isogeny.k1, &isogeny.k2, &isogeny.k3, &isogeny.k4].iter() {
+                for c in k.iter() {
+                    input.extend(serialize_fp_fixed_len(modulus_len, c).unwrap());
+                }
+            }
+        },
+        None => {
+            input.push(BOOLEAN_FALSE);
+        }
+    }
+    input.extend(serialize_fp_fixed_len(modulus_len, u).unwrap());
+
+    (input, modulus_len)
+}
+
+fn encode_g2_map_input<'a, FE: ElementRepr>(
+    curve: &G2Curve<'a, FE>,
+    z: &Fp2<'a, FE, PrimeField<FE>>,
+    isogeny: Option<(&G2Curve<'a, FE>, &G2Isogeny<'a, FE>)>,
+    u: &Fp2<'a, FE, PrimeField<FE>>
+) -> (Vec<u8>, usize) {
+    let (mut input, modulus_len) = assemble_g2_fp2_curve_params(curve);
+    input.extend(serialize_fp2_fixed_len(modulus_len, z).unwrap());
+    match isogeny {
+        Some((swu_curve, isogeny)) => {
+            input.push(BOOLEAN_TRUE);
+            input.extend(serialize_fp2_fixed_len(modulus_len, &swu_curve.a).unwrap());
+            input.extend(serialize_fp2_fixed_len(modulus_len, &swu_curve.b).unwrap());
+            input.push(isogeny.map_degree as u8);
+            for k in [&isogeny.k1, &isogeny.k2, &isogeny.k3, &isogeny.k4].iter() {
+                for c in k.iter() {
+                    input.extend(serialize_fp2_fixed_len(modulus_len, c).unwrap());
+                }
+            }
+        },
+        None => {
+            input.push(BOOLEAN_FALSE);
+        }
+    }
+    input.extend(serialize_fp2_fixed_len(modulus_len, u).unwrap());
+
+    (input, modulus_len)
+}
+
+fn p256_z() -> Fp<'static, U320Repr, PrimeField<U320Repr>> {
+    let mut z = Fp::from_repr(&SECP256R1_FIELD, U320Repr::from(10)).unwrap();
+    z.negate();
+
+    z
+}
+
+pub(crate) fn bls12_381_g1_map_input(u: u64) -> (Vec<u8>, usize) {
+    let (_, iso) = &*BLS12_G1_MAPPING_PARAMS;
+    let u = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(u)).unwrap();
+
+    encode_g1_map_input(&BLS12_381_G1_CURVE, &BLS12_381_G1_SWU_Z, Some((&BLS12_381_G1_CURVE_ISOGENY, iso)), &u)
+}
+
+pub(crate) fn bls12_381_g2_map_input(u: u64) -> (Vec<u8>, usize) {
+    let (_, iso) = &*BLS12_G2_MAPPING_PARAMS;
+    let mut u_fp2 = Fp2::zero(&BLS12_381_EXTENSION_2_FIELD);
+    u_fp2.c0 = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(u)).unwrap();
+    u_fp2.c1 = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(u + 1)).unwrap();
+
+    encode_g2_map_input(&BLS12_381_G2_CURVE, &BLS12_381_G2_SWU_Z, Some((&BLS12_381_G2_CURVE_ISOGENY, iso)), &u_fp2)
+}
+
+#[test]
+fn test_bls12_381_g1_map_with_isogeny() {
+    for u in [0u64, 1, 42, 0xffffffff].iter() {
+        let (input, modulus_len) = bls12_381_g1_map_input(*u);
+        let output = PublicMapToCurveApi::map_to_g1(&input).unwrap();
+
+        // clearing the cofactor gives the EIP-2537 mapping
+        let (p, rest) = decode_g1_point_from_xy(&output, modulus_len, &BLS12_381_G1_CURVE).unwrap();
+        assert!(rest.is_empty());
+        assert!(p.is_on_curve());
+        let mut cofactor_cleared = p.mul(&BLS12_381_G1_MAPPING_H_EFF[..]);
+        cofactor_cleared.normalize();
+
+        let u = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(*u)).unwrap();
+        let expected = crate::engines::bls12_381::mapping::fp_to_g1(&u).unwrap();
+        assert_eq!(serialize_g1_point(modulus_len, &cofactor_cleared).unwrap(), serialize_g1_point(modulus_len, &expected).unwrap());
+
+        assert_eq!(perform_operation(OperationType::G1MAP, &input).unwrap(), output);
+
+        let mut api_input = vec![OPERATION_G1_MAP];
+        api_input.extend(input);
+        assert_eq!(API::run(&api_input).unwrap(), output);
+    }
+}
+
+#[test]
+fn test_bls12_381_g2_map_with_isogeny() {
+    for i in [0u64, 1, 42].iter() {
+        let mut u = Fp2::zero(&BLS12_381_EXTENSION_2_FIELD);
+        u.c0 = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(*i)).unwrap();
+        u.c1 = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(*i + 1)).unwrap();
+
+        let (input, modulus_len) = bls12_381_g2_map_input(*i);
+        let output = PublicMapToCurveApi::map_to_g2(&input).unwrap();
+
+        let expected = crate::engines::bls12_381::mapping::fp2_to_twist(&u).unwrap();
+        assert_eq!(output, serialize_g2_point_in_fp2(modulus_len, &expected).unwrap());
+
+        assert_eq!(perform_operation(OperationType::G2MAP, &input).unwrap(), output);
+
+        let mut api_input = vec![OPERATION_G2_MAP];
+        api_input.extend(input);
+        assert_eq!(API::run(&api_input).unwrap(), output);
+    }
+}
+
+#[test]
+fn test_p256_map_without_isogeny() {
+    // map_to_curve(u[0]) for the empty message from P256_XMD:SHA-256_SSWU_RO_ test vectors,
+    // https://www.rfc-editor.org/rfc/rfc9380#appendix-J.1.1
+    let u = hex::decode("ad5342c66a6dd0ff080df1da0ea1c04b96e0330dd89406465eeba11582515009").unwrap();
+    let u = Fp::from_be_bytes(&SECP256R1_FIELD, &u, true).unwrap();
+
+    let (input, _) = encode_g1_map_input(&SECP256R1_G1_CURVE, &p256_z(), None, &u);
+    let output = PublicMapToCurveApi::map_to_g1(&input).unwrap();
+
+    let expected = hex::decode(
+        "ab640a12220d3ff283510ff3f4b1953d09fad35795140b1c5d64f313967934d5\
+        dccb558863804a881d4fff3455716c836cef230e5209594ddd33d85c565b19b1"
+    ).unwrap();
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_invalid_map_parameters() {
+    let field = &SECP256R1_FIELD;
+    let u = Fp::from_repr(field, U320Repr::from(42)).unwrap();
+    let (valid_input, _) = encode_g1_map_input(&SECP256R1_G1_CURVE, &p256_z(), None, &u);
+    assert!(PublicMapToCurveApi::map_to_g1(&valid_input).is_ok());
+
+    // Z is a square
+    let z = Fp::from_repr(field, U320Repr::from(4)).unwrap();
+    let (input, _) = encode_g1_map_input(&SECP256R1_G1_CURVE, &z, None, &u);
+    assert!(PublicMapToCurveApi::map_to_g1(&input).is_err());
+
+    // Z = -1 is a non-square for p = 3 mod 4, but is not allowed
+    let mut z = Fp::one(field);
+    z.negate();
+    let (input, _) = encode_g1_map_input(&SECP256R1_G1_CURVE, &z, None, &u);
+    assert!(PublicMapToCurveApi::map_to_g1(&input).is_err());
+
+    // A = 0 requires an isogeny
+    let z = Fp::from_repr(BN254_G1_CURVE.params.field, crate::field::U256Repr::from(5)).unwrap();
+    let u_bn = Fp::from_repr(BN254_G1_CURVE.params.field, crate::field::U256Repr::from(42)).unwrap();
+    let (input, _) = encode_g1_map_input(&BN254_G1_CURVE, &z, None, &u_bn);
+    assert!(PublicMapToCurveApi::map_to_g1(&input).is_err());
+
+    // invalid isogeny flag
+    let (mut input, modulus_len) = encode_g1_map_input(&SECP256R1_G1_CURVE, &p256_z(), None, &u);
+    let flag_position = input.len() - modulus_len - 1;
+    input[flag_position] = 0x02;
+    assert!(PublicMapToCurveApi::map_to_g1(&input).is_err());
+
+    // garbage at the end
+    let mut input = valid_input.clone();
+    input.push(0u8);
+    assert!(PublicMapToCurveApi::map_to_g1(&input).is_err());
+
+    // truncated field element
+    assert!(PublicMapToCurveApi::map_to_g1(&valid_input[..valid_input.len() - 1]).is_err());
+
+    // modulus - 1 divisible by 2^65
+    let input = with_two_adic_modulus(&valid_input, 65);
+    assert!(PublicMapToCurveApi::map_to_g1(&input).is_err());
+}
+
+#[test]
+fn test_map_with_composite_modulus() {
+    // for n = 35 the Euler criterion accepts Z and rejects gx1, but gx2 that is taken
+    // instead has no square root, so the call fails instead of panicking
+    let input = vec![1u8, 35, 1, 32, 1, 7, 23, BOOLEAN_FALSE, 9];
+    match PublicMapToCurveApi::map_to_g1(&input).unwrap_err() {
+        crate::errors::ApiError::InputError(descr) => assert!(descr.contains("no square root"), "{}", descr),
+        err => panic!("unexpected error {}", err)
+    }
+
+    let mut api_input = vec![OPERATION_G1_MAP];
+    api_input.extend(input);
+    assert!(API::run(&api_input).is_err());
+}
+
+/// Replaces the modulus in the encoding with a larger one of the same length, such
+/// that modulus - 1 is divisible by exactly 2^two_adicity
+fn with_two_adic_modulus(input: &[u8], two_adicity: usize) -> Vec<u8> {
+    let modulus_len = input[0] as usize;
+    let mut input = input.to_vec();
+    let modulus = &mut input[1..(1 + modulus_len)];
+    for (i, byte) in modulus.iter_mut().rev().enumerate() {
+        *byte = if i * 8 + 8 <= two_adicity {
+            0x00
+        } else if i * 8 < two_adicity {
+            0xffu8 << (two_adicity - i * 8)
+        } else {
+            0xff
+        };
+    }
+    modulus[modulus_len - 1] |= 1;
+
+    input
+}
+
+#[test]
+fn test_invalid_isogeny_parameters() {
+    let (_, iso) = &*BLS12_G1_MAPPING_PARAMS;
+    let u = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(42)).unwrap();
+    let encode = |swu_curve: &G1Curve<'static, U384Repr>, iso: &G1Isogeny<'static, U384Repr>| {
+        encode_g1_map_input(&BLS12_381_G1_CURVE, &BLS12_381_G1_SWU_Z, Some((swu_curve, iso)), &u).0
+    };
+
+    // invalid coefficients do not map onto the curve
+    let mut invalid = iso.clone();
+    invalid.k1[0].add_assign(&Fp::one(&BLS12_381_FIELD));
+    assert!(PublicMapToCurveApi::map_to_g1(&encode(&BLS12_381_G1_CURVE_ISOGENY, &invalid)).is_err());
+
+    // zero denominator
+    let mut invalid = iso.clone();
+    for c in invalid.k4.iter_mut() {
+        *c = Fp::zero(&BLS12_381_FIELD);
+    }
+    assert!(PublicMapToCurveApi::map_to_g1(&encode(&BLS12_381_G1_CURVE_ISOGENY, &invalid)).is_err());
+
+    // zero degree
+    let mut invalid = iso.clone();
+    invalid.map_degree = 0;
+    for k in [&mut invalid.k1, &mut invalid.k2, &mut invalid.k3, &mut invalid.k4].iter_mut() {
+        k.truncate(1);
+    }
+    assert!(PublicMapToCurveApi::map_to_g1(&encode(&BLS12_381_G1_CURVE_ISOGENY, &invalid)).is_err());
+
+    // degree above the limit
+    let mut invalid = iso.clone();
+    invalid.map_degree = crate::public_interface::sane_limits::MAX_ISOGENY_MAP_DEGREE + 1;
+    for k in [&mut invalid.k1, &mut invalid.k2, &mut invalid.k3, &mut invalid.k4].iter_mut() {
+        k.resize(invalid.map_degree + 1, Fp::zero(&BLS12_381_FIELD));
+    }
+    assert!(PublicMapToCurveApi::map_to_g1(&encode(&BLS12_381_G1_CURVE_ISOGENY, &invalid)).is_err());
+
+    // simplified SWU is not applicable to the isogenous curve with A = 0
+    let mut swu_curve = BLS12_381_G1_CURVE_ISOGENY.clone();
+    swu_curve.a = Fp::zero(&BLS12_381_FIELD);
+    assert!(PublicMapToCurveApi::map_to_g1(&encode(&swu_curve, iso)).is_err());
+}
+
+#[cfg(feature = "gas_metering")]
+#[test]
+fn test_map_to_curve_metering() {
+    use crate::gas_meter::{GasMeter, meter_operation};
+
+    let (input, _) = bls12_381_g1_map_input(42);
+    let price = meter_operation(OperationType::G1MAP, &input).unwrap();
+
+    let mut api_input = vec![OPERATION_G1_MAP];
+    api_input.extend(input.iter());
+    assert_eq!(GasMeter::meter(&api_input).unwrap(), price);
+
+    // metering does not decode the parameters, but checks the length
+    let mut garbage_input = input.clone();
+    garbage_input.push(0u8);
+    assert!(meter_operation(OperationType::G1MAP, &garbage_input).is_err());
+    assert!(meter_operation(OperationType::G1MAP, &input[..input.len() - 1]).is_err());
+
+    // isogeny evaluation is charged
+    let u = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(42)).unwrap();
+    let z = Fp::from_repr(&BLS12_381_FIELD, U384Repr::from(11)).unwrap();
+    let (input_without_isogeny, _) = encode_g1_map_input(&BLS12_381_G1_CURVE_ISOGENY, &z, None, &u);
+    let price_without_isogeny = meter_operation(OperationType::G1MAP, &input_without_isogeny).unwrap();
+    assert!(price_without_isogeny < price);
+
+    let (_, iso) = &*BLS12_G2_MAPPING_PARAMS;
+    let u = Fp2::one(&BLS12_381_EXTENSION_2_FIELD);
+    let (mut g2_input, _) = encode_g2_map_input(&BLS12_381_G2_CURVE, &BLS12_381_G2_SWU_Z, Some((&BLS12_381_G2_CURVE_ISOGENY, iso)), &u);
+    let g2_price = meter_operation(OperationType::G2MAP, &g2_input).unwrap();
+    assert!(g2_price > price);
+
+    // Tonelli-Shanks cost is bounded by the limit on two-adicity of the modulus
+    let field = &SECP256R1_FIELD;
+    let u = Fp::from_repr(field, U320Repr::from(42)).unwrap();
+    let (p256_input, _) = encode_g1_map_input(&SECP256R1_G1_CURVE, &p256_z(), None, &u);
+    let max_two_adicity = crate::public_interface::sane_limits::MAX_MODULUS_TWO_ADICITY as usize;
+    assert!(meter_operation(OperationType::G1MAP, &with_two_adic_modulus(&p256_input, max_two_adicity)).is_ok());
+    assert!(meter_operation(OperationType::G1MAP, &with_two_adic_modulus(&p256_input, max_two_adicity + 1)).is_err());
+
+    // only quadratic extension is supported
+    let modulus_len = g2_input[0] as usize;
+    g2_input[1 + modulus_len] = EXTENSION_DEGREE_3;
+    assert!(meter_operation(OperationType::G2MAP, &g2_input).is_err());
+}
@@ -1,6 +1,13 @@
 pub(crate) mod pairings;
 pub(crate) mod g2_ops;
 pub(crate) mod g1_ops;
+pub(crate) mod edwards_ops;
+pub(crate) mod decompression;
+#[cfg(feature = "mappings")]
+pub(crate) mod map_to_curve;
+mod known_curves;
+#[cfg(feature = "serialization")]
+pub(crate) mod serialization;
 pub(crate) mod parsers;
 pub(crate) mod public_api;
 pub(crate) mod spec_generator;
@@ -9,6 +16,8 @@ pub(crate) mod arithmetic_tests;
 mod fields;
 // mod fuzzing;
 mod gas_meter;
+#[cfg(feature = "parameters_cache")]
+mod parameters_cache;
 
 use num_bigint::BigUint;
 use num_traits::Zero;
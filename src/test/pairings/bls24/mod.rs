@@ -0,0 +1,167 @@
+use crate::public_interface::constants::*;
+
+use num_bigint::BigUint;
+use num_traits::Num;
+
+use super::call_pairing_engine;
+
+pub(crate) const MODULUS_LENGTH: usize = 40;
+
+const MODULUS: &str = "4c23a02b586d650d3f7498be97c5eafdec1d01aa27a1ae0421ee5da52bde5026fe802ff40300001";
+pub(crate) const GROUP_ORDER: &str = "196deac24a9da12b25fc7ec9cf927a98c8c480ece644e36419d0c5fd00c00001";
+const X: &str = "bfcfffff";
+
+const G1_X: &str = "22d95bf37e1375823d8ca854048131bccaeacac9c58aa77d3b447f3ce77a01c64199491c0a9fb46";
+const G1_Y: &str = "7b61cdad90327e8e8eb84d4783628e5ee44b957c5561428ccd086a9aa0a4f995ac59f0bf1ff07f";
+const G1_Y_NEGATED: &str = "446d83507f6a3d24568913ea1f8fc217fdd84852624b99db551dd6fb81d4008da3ba90e81100f82";
+
+const G2_X: [&str; 4] = [
+    "1decf6bee3c0774257b7fababca9daaf41fa06e7f7ea26c0646f3cf9c7fa6f425ee3b560d0654e0",
+    "109429877f87d2ffa8b365333bf3f70b586d53b5ad198b911ac401ab66f18c464fbd670db4d4ae3",
+    "1f6de645a2fc183814f0b9eef01b83dfe9ca5d96a79c7e9785c610d28cf0d2453bed5bf0d7358d3",
+    "390b32c8aafbfa49fbaef0f861ad5cf95b6dad31c0d94bb39401829aaf3b6ed73e5d1bc5eb8cbb0"
+];
+const G2_Y: [&str; 4] = [
+    "1eaa7903774e574519e95fc0a99d2b7cc2e65bd4da83ba636d2bd2dc31a1331199d8c7353664803",
+    "14cbeff1538a8c1cb97f6ac11f170ce5fe2426afbec79a58cbf4249b732d2c3afee3ca81a429062",
+    "47899075de8541cdc745394ae9089ab2ceb7ef42ee5ff10fd9bc5f8b373b5b261ff17e6fa67b001",
+    "cf3fc8784598dcfaf41fa35efd39bbad559f9d6fac248b9343d3142e8337603bc11b12795fd555"
+];
+
+pub(crate) fn encode_fixed_len(value: &str, length: usize) -> Vec<u8> {
+    let bytes = BigUint::from_str_radix(value, 16).unwrap().to_bytes_be();
+    assert!(bytes.len() <= length);
+    let mut result = vec![0u8; length - bytes.len()];
+    result.extend(bytes);
+
+    result
+}
+
+fn encode_with_length(value: &str) -> Vec<u8> {
+    let bytes = BigUint::from_str_radix(value, 16).unwrap().to_bytes_be();
+    let mut result = vec![bytes.len() as u8];
+    result.extend(bytes);
+
+    result
+}
+
+// Encodes BLS24-315 parameters and pairs of generators where
+// G1 generator is optionally negated
+pub(crate) fn assemble_bls24_315(negate_g1: &[bool]) -> Vec<u8> {
+    let mut calldata = vec![BLS24];
+    calldata.push(MODULUS_LENGTH as u8);
+    calldata.extend(encode_fixed_len(MODULUS, MODULUS_LENGTH));
+    calldata.extend(encode_fixed_len("0", MODULUS_LENGTH));
+    calldata.extend(encode_fixed_len("1", MODULUS_LENGTH));
+    calldata.extend(encode_with_length(GROUP_ORDER));
+    // Fp2 non-residue is 13
+    calldata.extend(encode_fixed_len("d", MODULUS_LENGTH));
+    calldata.push(TWIST_TYPE_D);
+    calldata.extend(encode_with_length(X));
+    calldata.push(SIGN_MINUS);
+
+    calldata.push(negate_g1.len() as u8);
+    for &negate in negate_g1.iter() {
+        calldata.push(BOOLEAN_TRUE);
+        calldata.extend(encode_fixed_len(G1_X, MODULUS_LENGTH));
+        if negate {
+            calldata.extend(encode_fixed_len(G1_Y_NEGATED, MODULUS_LENGTH));
+        } else {
+            calldata.extend(encode_fixed_len(G1_Y, MODULUS_LENGTH));
+        }
+        calldata.push(BOOLEAN_TRUE);
+        for c in G2_X.iter().chain(G2_Y.iter()) {
+            calldata.extend(encode_fixed_len(c, MODULUS_LENGTH));
+        }
+    }
+
+    calldata
+}
+
+#[test]
+fn test_bls24_315_pairing_through_api() {
+    let calldata = assemble_bls24_315(&[false, true]);
+    let result = call_pairing_engine(&calldata[..]).unwrap();
+    assert_eq!(result, vec![1u8]);
+}
+
+#[test]
+fn test_bls24_315_pairing_through_api_negative() {
+    let calldata = assemble_bls24_315(&[false, false]);
+    let result = call_pairing_engine(&calldata[..]).unwrap();
+    assert_eq!(result, vec![0u8]);
+}
+
+#[test]
+fn test_bls24_315_pairing_through_api_rejects_garbage() {
+    let mut calldata = assemble_bls24_315(&[false, true]);
+    calldata.push(0u8);
+    assert!(call_pairing_engine(&calldata[..]).is_err());
+}
+
+#[test]
+fn test_bls24_315_pairing_rejects_square_non_residue() {
+    let mut calldata = assemble_bls24_315(&[false, true]);
+    // 4 is a square, so it can not be used to build Fp2
+    let offset = 1 + 1 + MODULUS_LENGTH * 3 + 1 + GROUP_ORDER.len() / 2;
+    calldata[offset..(offset + MODULUS_LENGTH)].copy_from_slice(&encode_fixed_len("4", MODULUS_LENGTH));
+    assert!(call_pairing_engine(&calldata[..]).is_err());
+}
+
+#[cfg(feature = "gas_metering")]
+#[test]
+fn test_bls24_315_pairing_is_metered() {
+    let mut input = vec![OPERATION_PAIRING];
+    input.extend(assemble_bls24_315(&[false, true]));
+    let price = crate::gas_meter::GasMeter::meter(&input[..]).unwrap();
+    assert!(price > 0);
+}
+
+#[cfg(feature = "gas_metering")]
+#[test]
+fn test_bls24_315_pairing_metering() {
+    use crate::gas_meter::{GasMeter, meter_operation, execute_with_gas_limit};
+    use crate::public_interface::{OperationType, perform_operation};
+    use crate::errors::ApiError;
+
+    let calldata = assemble_bls24_315(&[false, true]);
+    let input = &calldata[1..];
+    let price = meter_operation(OperationType::BLS24PAIR, input).unwrap();
+    assert!(price > 0);
+
+    let mut prefixed = vec![OPERATION_PAIRING];
+    prefixed.extend_from_slice(&calldata);
+    assert_eq!(GasMeter::meter(&prefixed).unwrap(), price);
+
+    assert_eq!(perform_operation(OperationType::BLS24PAIR, input).unwrap(), vec![1u8]);
+    let (output, gas_used) = execute_with_gas_limit(OperationType::BLS24PAIR, input, price).unwrap();
+    assert_eq!(output, vec![1u8]);
+    assert_eq!(gas_used, price);
+    let error = execute_with_gas_limit(OperationType::BLS24PAIR, input, price - 1).unwrap_err();
+    assert_eq!(error, ApiError::OutOfGas { gas_required: price, gas_limit: price - 1 });
+
+    // more pairs are more expensive
+    let one_pair_price = meter_operation(OperationType::BLS24PAIR, &assemble_bls24_315(&[false])[1..]).unwrap();
+    assert!(one_pair_price < price);
+}
+
+#[test]
+fn test_bls24_315_description_encoding() {
+    use crate::integers::{MaxFieldUint, MaxGroupSizeUint, MaxLoopParametersUint};
+    use crate::pairings::TwistType;
+    use crate::serialization::instances::Bls24InstanceDescription;
+
+    let description = Bls24InstanceDescription {
+        modulus: MaxFieldUint::from_big_endian(&encode_fixed_len(MODULUS, MODULUS_LENGTH)),
+        b: MaxFieldUint::from(1u64),
+        group_order: MaxGroupSizeUint::from_big_endian(&encode_fixed_len(GROUP_ORDER, GROUP_ORDER.len() / 2)),
+        fp2_non_residue: MaxFieldUint::from(13u64),
+        twist_type: TwistType::D,
+        x: MaxLoopParametersUint::from_big_endian(&encode_fixed_len(X, X.len() / 2)),
+        x_is_negative: true,
+    };
+    let encoding = description.encode_pairing_parameters();
+
+    let calldata = assemble_bls24_315(&[false, true]);
+    assert_eq!(&calldata[..encoding.len()], &encoding[..]);
+}
@@ -420,6 +420,33 @@ fn test_call_public_api_on_bn254() {
     assert!(result[0] == 1);
 }
 
+#[test]
+fn test_fuentes_final_exponentiation_on_bn254() {
+    use crate::engines::bn254::*;
+    use crate::pairings::PairingEngine;
+    use crate::traits::FieldElement;
+
+    let generic_engine = BN254_PAIRING_ENGINE;
+    assert!(!generic_engine.prefer_fuentes_final_exp);
+    let engine = generic_engine.clone().for_pairing_checks();
+    assert!(engine.prefer_fuentes_final_exp);
+
+    let p = BN254_G1_GENERATOR.clone();
+    let q = BN254_G2_GENERATOR.clone();
+    let result = engine.pair(std::slice::from_ref(&p), std::slice::from_ref(&q)).unwrap();
+    let generic = generic_engine.pair(&[p], &[q]).unwrap();
+
+    // result is the generic pairing in power 2u * (6u^2 + 3u + 1) = 12u^3 + 6u^2 + 2u
+    let generic_u = generic.pow([BN254_U]);
+    let generic_u2 = generic_u.pow([BN254_U]);
+    let generic_u3 = generic_u2.pow([BN254_U]);
+    let mut expected = generic_u3.pow([12u64]);
+    expected.mul_assign(&generic_u2.pow([6u64]));
+    expected.mul_assign(&generic_u.pow([2u64]));
+
+    assert!(result == expected);
+}
+
 fn strip_0x(string: &str) -> String {
     let string = string.trim();
     let mut string = string.to_ascii_lowercase().as_bytes().to_vec();
@@ -0,0 +1,138 @@
+use crate::public_interface::constants::*;
+use crate::public_interface::decode_g1::serialize_g1_point;
+use crate::public_interface::decode_fp::serialize_fp_fixed_len;
+use crate::engines::bw6_761::*;
+use crate::weierstrass::Group;
+
+use num_bigint::BigUint;
+
+use super::call_pairing_engine;
+
+const MODULUS_LENGTH: usize = 96;
+
+fn limbs_to_be_bytes(limbs: &[u64]) -> Vec<u8> {
+    let mut le_bytes = vec![];
+    for limb in limbs.iter() {
+        le_bytes.extend_from_slice(&limb.to_le_bytes());
+    }
+
+    BigUint::from_bytes_le(&le_bytes).to_bytes_be()
+}
+
+pub(crate) fn encode_with_length(limbs: &[u64]) -> Vec<u8> {
+    let encoding = limbs_to_be_bytes(limbs);
+    let mut result = vec![encoding.len() as u8];
+    result.extend(encoding);
+
+    result
+}
+
+pub(crate) fn encode_sign(is_negative: bool) -> u8 {
+    if is_negative { SIGN_MINUS } else { SIGN_PLUS }
+}
+
+// Encodes BW6-761 parameters from the static engine and pairs
+// (g1^a, g2) * (g1^(-a), g2) and (g1, g2^a) * (g1^(-1), g2^b)
+pub(crate) fn assemble_bw6_761(scalar_a: u64, scalar_b: u64) -> Vec<u8> {
+    let engine = &BW6_761_PAIRING_ENGINE;
+
+    let mut calldata = vec![BW6];
+    calldata.push(MODULUS_LENGTH as u8);
+    let modulus = limbs_to_be_bytes(&BW6_761_MODULUS.0);
+    assert_eq!(modulus.len(), MODULUS_LENGTH);
+    calldata.extend(modulus);
+    calldata.extend(serialize_fp_fixed_len(MODULUS_LENGTH, &BW6_761_FP_ZERO).unwrap());
+    calldata.extend(serialize_fp_fixed_len(MODULUS_LENGTH, &BW6_761_B_FOR_G1).unwrap());
+    calldata.extend(encode_with_length(&BW6_761_SUBGROUP_ORDER));
+    calldata.extend(serialize_fp_fixed_len(MODULUS_LENGTH, &BW6_761_EXTENSION_3_FIELD.non_residue).unwrap());
+    calldata.push(TWIST_TYPE_M);
+    calldata.extend(encode_with_length(engine.ate_loop_count_1));
+    calldata.push(encode_sign(engine.ate_loop_count_1_is_negative));
+    calldata.extend(encode_with_length(engine.ate_loop_count_2));
+    calldata.push(encode_sign(engine.ate_loop_count_2_is_negative));
+    calldata.extend(encode_with_length(engine.exp_w0));
+    calldata.extend(encode_with_length(engine.exp_w1));
+    calldata.push(encode_sign(engine.exp_w0_is_negative));
+
+    let g1 = BW6_761_G1_GENERATOR;
+    let g2 = BW6_761_G2_GENERATOR;
+
+    let mut g1_a = g1.mul([scalar_a]);
+    g1_a.normalize();
+    let mut g1_minus_a = g1_a.clone();
+    g1_minus_a.negate();
+    let mut g2_a = g2.mul([scalar_a]);
+    g2_a.normalize();
+    let mut g1_minus_one = g1.clone();
+    g1_minus_one.negate();
+    let mut g2_b = g2.mul([scalar_b]);
+    g2_b.normalize();
+
+    let pairs = [(g1_a, g2.clone()), (g1_minus_a, g2.clone()), (g1.clone(), g2_a), (g1_minus_one, g2_b)];
+    calldata.push(pairs.len() as u8);
+    for (p, q) in pairs.iter() {
+        calldata.push(BOOLEAN_TRUE);
+        calldata.extend(serialize_g1_point(MODULUS_LENGTH, p).unwrap());
+        calldata.push(BOOLEAN_TRUE);
+        calldata.extend(serialize_g1_point(MODULUS_LENGTH, q).unwrap());
+    }
+
+    calldata
+}
+
+#[test]
+fn test_bw6_761_pairing_through_api() {
+    let calldata = assemble_bw6_761(12345, 12345);
+    let result = call_pairing_engine(&calldata[..]).unwrap();
+    assert_eq!(result, vec![1u8]);
+}
+
+#[test]
+fn test_bw6_761_pairing_through_api_negative() {
+    let calldata = assemble_bw6_761(12345, 54321);
+    let result = call_pairing_engine(&calldata[..]).unwrap();
+    assert_eq!(result, vec![0u8]);
+}
+
+#[test]
+fn test_bw6_761_pairing_through_api_rejects_garbage() {
+    let mut calldata = assemble_bw6_761(12345, 12345);
+    calldata.push(0u8);
+    assert!(call_pairing_engine(&calldata[..]).is_err());
+}
+
+#[cfg(feature = "gas_metering")]
+#[test]
+fn test_bw6_761_pairing_metering() {
+    use crate::gas_meter::{GasMeter, meter_operation, execute_with_gas_limit};
+    use crate::public_interface::{OperationType, perform_operation};
+    use crate::errors::ApiError;
+
+    let calldata = assemble_bw6_761(12345, 12345);
+    let input = &calldata[1..];
+    let price = meter_operation(OperationType::BW6PAIR, input).unwrap();
+    assert!(price > 0);
+
+    let mut prefixed = vec![OPERATION_PAIRING];
+    prefixed.extend_from_slice(&calldata);
+    assert_eq!(GasMeter::meter(&prefixed).unwrap(), price);
+
+    assert_eq!(perform_operation(OperationType::BW6PAIR, input).unwrap(), vec![1u8]);
+    let (output, gas_used) = execute_with_gas_limit(OperationType::BW6PAIR, input, price).unwrap();
+    assert_eq!(output, vec![1u8]);
+    assert_eq!(gas_used, price);
+    let error = execute_with_gas_limit(OperationType::BW6PAIR, input, price - 1).unwrap_err();
+    assert_eq!(error, ApiError::OutOfGas { gas_required: price, gas_limit: price - 1 });
+
+    // more pairs are more expensive
+    let two_pairs_price = {
+        let mut calldata = calldata.clone();
+        let pair_len = 2 * (1 + 2 * MODULUS_LENGTH);
+        let num_pairs_position = calldata.len() - 4 * pair_len - 1;
+        calldata[num_pairs_position] = 2;
+        calldata.truncate(calldata.len() - 2 * pair_len);
+
+        meter_operation(OperationType::BW6PAIR, &calldata[1..]).unwrap()
+    };
+    assert!(two_pairs_price < price);
+}
@@ -226,6 +226,59 @@ pub(crate) fn assemble_single_curve_params(curve: JsonMnt6PairingCurveParameters
     Ok(calldata)
 }
 
+// Encodes MNT6-753 parameters from the static engine and pairs that alternate
+// between (g1, g2) and (g1^(-1), g2), so the result is one for an even number of pairs
+pub(crate) fn assemble_mnt6_753(num_point_pairs: usize) -> Vec<u8> {
+    use crate::engines::mnt6_753::*;
+    use crate::public_interface::decode_fp::serialize_fp_fixed_len;
+    use crate::public_interface::decode_g1::serialize_g1_point;
+    use crate::public_interface::decode_g2::serialize_g2_point_in_fp3;
+    use crate::test::decompression::assemble_g1_curve_params;
+    use crate::weierstrass::Group;
+    use super::bw6::{encode_with_length, encode_sign};
+
+    let engine = &MNT6_753_PAIRING_ENGINE;
+    let (curve_params, modulus_len) = assemble_g1_curve_params(&MNT6_753_G1_CURVE);
+
+    let mut calldata = vec![MNT6];
+    calldata.extend(curve_params);
+    calldata.extend(serialize_fp_fixed_len(modulus_len, &MNT6_753_EXTENSION_3_FIELD.non_residue).unwrap());
+    calldata.extend(encode_with_length(engine.x));
+    calldata.push(encode_sign(engine.x_is_negative));
+    calldata.extend(encode_with_length(engine.exp_w0));
+    calldata.extend(encode_with_length(engine.exp_w1));
+    calldata.push(encode_sign(engine.exp_w0_is_negative));
+
+    let g1 = serialize_g1_point(modulus_len, &MNT6_753_G1_GENERATOR).unwrap();
+    let mut g1_minus_one = MNT6_753_G1_GENERATOR.clone();
+    g1_minus_one.negate();
+    let g1_minus_one = serialize_g1_point(modulus_len, &g1_minus_one).unwrap();
+    let g2 = serialize_g2_point_in_fp3(modulus_len, &MNT6_753_G2_GENERATOR).unwrap();
+
+    calldata.push(num_point_pairs as u8);
+    for i in 0..num_point_pairs {
+        calldata.push(BOOLEAN_TRUE);
+        if i % 2 == 0 {
+            calldata.extend(g1.iter());
+        } else {
+            calldata.extend(g1_minus_one.iter());
+        }
+        calldata.push(BOOLEAN_TRUE);
+        calldata.extend(g2.iter());
+    }
+
+    calldata
+}
+
+#[test]
+fn test_mnt6_753_pairing() {
+    let calldata = assemble_mnt6_753(2);
+    assert_eq!(call_pairing_engine(&calldata[..]).unwrap(), vec![1u8]);
+
+    let calldata = assemble_mnt6_753(1);
+    assert_eq!(call_pairing_engine(&calldata[..]).unwrap(), vec![0u8]);
+}
+
 // #[test]
 // fn test_bn_pairings_from_vectors() {
 //     // let curves = read_dir_and_grab_curves::<JsonBnPairingCurveParameters>("src/test/test_vectors/bn/negative_u/");
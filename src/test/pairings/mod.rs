@@ -2,6 +2,8 @@ pub(crate) mod bls12;
 pub(crate) mod bn;
 pub(crate) mod mnt4;
 pub(crate) mod mnt6;
+pub(crate) mod bw6;
+pub(crate) mod bls24;
 
 use crate::public_interface::{PairingApi, PublicPairingApi};
 use crate::errors::ApiError;
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use crate::public_interface::parameters_cache::*;
+use crate::errors::ApiError;
+
+use crate::field::*;
+use crate::traits::ZeroAndOne;
+use crate::extension_towers::fp2::Extension2;
+use crate::extension_towers::fp6_as_3_over_2::{Fp6, Extension3Over2};
+use crate::extension_towers::fp12_as_2_over3_over_2::Extension2Over3Over2;
+use crate::engines::bls12_381::*;
+
+use crate::test::pairings::call_pairing_engine;
+use crate::test::pairings::bls24::{assemble_bls24_315, encode_fixed_len, MODULUS_LENGTH, GROUP_ORDER};
+use crate::test::pairings::bw6::assemble_bw6_761;
+use crate::test::pairings::mnt4::assemble_mnt4_753;
+
+fn limbs(values: &[u64]) -> Arc<[u64]> {
+    values.to_vec().into()
+}
+
+fn run_twice(calldata: &[u8]) -> Result<Vec<u8>, ApiError> {
+    let first = call_pairing_engine(calldata);
+    let second = call_pairing_engine(calldata);
+    assert_eq!(first, second);
+
+    first
+}
+
+#[test]
+fn test_cache_evicts_least_recently_used() {
+    let cache = ParametersCache::new(2);
+    cache.insert(vec![1], limbs(&[1]));
+    cache.insert(vec![2], limbs(&[2]));
+    assert!(cache.get(&[1]).is_some());
+
+    cache.insert(vec![3], limbs(&[3]));
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(&[2]).is_none());
+    assert_eq!(&cache.get(&[1]).unwrap()[..], &[1]);
+    assert_eq!(&cache.get(&[3]).unwrap()[..], &[3]);
+
+    // replacing a value doesn't evict anything
+    cache.insert(vec![3], limbs(&[4]));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(&cache.get(&[3]).unwrap()[..], &[4]);
+
+    cache.set_capacity(1);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get(&[3]).is_some());
+
+    cache.clear();
+    assert_eq!(cache.len(), 0);
+}
+
+#[test]
+fn test_zero_capacity_disables_cache() {
+    let cache = ParametersCache::new(0);
+    cache.insert(vec![1], limbs(&[1]));
+    assert_eq!(cache.len(), 0);
+    assert!(cache.get(&[1]).is_none());
+}
+
+#[test]
+fn test_restore_frobenius_coefficients_and_field() {
+    let mut limbs = vec![];
+    BLS12_381_FIELD.write_limbs(&mut limbs);
+    BLS12_381_EXTENSION_2_FIELD.write_limbs(&mut limbs);
+    BLS12_381_EXTENSION_6_FIELD.write_limbs(&mut limbs);
+    BLS12_381_EXTENSION_12_FIELD.write_limbs(&mut limbs);
+
+    let mut cursor = &limbs[..];
+    let mut field = PrimeField {
+        mont_power: 0,
+        modulus_bits: 0,
+        modulus: U384Repr::default(),
+        mont_r: U384Repr::default(),
+        mont_r2: U384Repr::default(),
+        mont_inv: 0
+    };
+    assert!(field.read_limbs(&mut cursor));
+    assert_eq!(field.modulus, BLS12_381_FIELD.modulus);
+    assert_eq!(field.mont_r, BLS12_381_FIELD.mont_r);
+    assert_eq!(field.mont_r2, BLS12_381_FIELD.mont_r2);
+    assert_eq!(field.mont_inv, BLS12_381_FIELD.mont_inv);
+    assert_eq!(field.mont_power, BLS12_381_FIELD.mont_power);
+    assert_eq!(field.modulus_bits, BLS12_381_FIELD.modulus_bits);
+
+    let mut extension_2 = Extension2::new(BLS12_381_EXTENSION_2_FIELD.non_residue);
+    assert!(extension_2.read_limbs(&mut cursor));
+    assert!(extension_2.frobenius_coeffs_are_calculated);
+    assert!(extension_2.frobenius_coeffs_c1 == BLS12_381_EXTENSION_2_FIELD.frobenius_coeffs_c1);
+
+    let mut extension_6 = Extension3Over2::new(BLS12_381_EXTENSION_6_FIELD.non_residue);
+    assert!(extension_6.read_limbs(&mut cursor));
+    assert!(extension_6.frobenius_coeffs_c1 == BLS12_381_EXTENSION_6_FIELD.frobenius_coeffs_c1);
+    assert!(extension_6.frobenius_coeffs_c2 == BLS12_381_EXTENSION_6_FIELD.frobenius_coeffs_c2);
+
+    let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&BLS12_381_EXTENSION_6_FIELD));
+    assert!(extension_12.read_limbs(&mut cursor));
+    assert!(extension_12.frobenius_coeffs_c1 == BLS12_381_EXTENSION_12_FIELD.frobenius_coeffs_c1);
+    assert!(cursor.is_empty());
+
+    // not enough limbs to restore the last extension
+    let mut cursor = &limbs[(limbs.len() - 1)..];
+    let mut extension_12 = Extension2Over3Over2::new(Fp6::zero(&BLS12_381_EXTENSION_6_FIELD));
+    assert!(!extension_12.read_limbs(&mut cursor));
+}
+
+#[test]
+fn test_repeated_pairings_on_custom_curves() {
+    assert_eq!(run_twice(&assemble_bls24_315(&[false, true])), Ok(vec![1u8]));
+    assert_eq!(run_twice(&assemble_bls24_315(&[false, false])), Ok(vec![0u8]));
+    assert_eq!(run_twice(&assemble_bw6_761(12345, 12345)), Ok(vec![1u8]));
+    assert_eq!(run_twice(&assemble_bw6_761(12345, 54321)), Ok(vec![0u8]));
+    assert_eq!(run_twice(&assemble_mnt4_753(2)), Ok(vec![1u8]));
+
+    assert!(parameters_cache_len() > 0);
+}
+
+#[test]
+fn test_cached_parameters_do_not_skip_other_checks() {
+    let calldata = assemble_bls24_315(&[false, true]);
+    assert_eq!(run_twice(&calldata), Ok(vec![1u8]));
+
+    // 4 is a square, so it can not be used to build Fp2
+    let mut with_square_non_residue = calldata.clone();
+    let offset = 1 + 1 + MODULUS_LENGTH * 3 + 1 + GROUP_ORDER.len() / 2;
+    with_square_non_residue[offset..(offset + MODULUS_LENGTH)].copy_from_slice(&encode_fixed_len("4", MODULUS_LENGTH));
+    assert!(run_twice(&with_square_non_residue).is_err());
+
+    let mut with_wrong_twist = calldata.clone();
+    with_wrong_twist[offset + MODULUS_LENGTH] = 0xff;
+    assert!(run_twice(&with_wrong_twist).is_err());
+
+    let mut with_garbage = calldata.clone();
+    with_garbage.push(0u8);
+    assert!(run_twice(&with_garbage).is_err());
+
+    // only parameters are cached, points are always decoded
+    assert!(run_twice(&calldata[..(calldata.len() - 1)]).is_err());
+    assert_eq!(call_pairing_engine(&calldata), Ok(vec![1u8]));
+}
@@ -0,0 +1,235 @@
+use serde::de::DeserializeSeed;
+
+use crate::public_interface::API;
+use crate::public_interface::constants::*;
+use crate::public_interface::decode_g1::serialize_g1_point;
+use crate::public_interface::decode_g2::{serialize_g2_point_in_fp2, serialize_g2_point_in_fp3};
+use crate::serialization::{ParamsSeed, DeserializeWithParams};
+use crate::serialization::instances::*;
+
+use crate::field::{PrimeField, SizedPrimeField, U384Repr};
+use crate::fp::Fp;
+use crate::extension_towers::fp2::Fp2;
+use crate::extension_towers::fp3::Fp3;
+use crate::extension_towers::fp12_as_2_over3_over_2::Fp12;
+use crate::integers::{MaxFieldUint, MaxGroupSizeUint};
+use crate::pairings::PairingEngine;
+use crate::representation::ElementRepr;
+use crate::weierstrass::{Group, CurveParameters, CurveOverFpParameters};
+use crate::weierstrass::curve::CurvePoint;
+
+use crate::engines::bls12_381::*;
+use crate::engines::bls12_377::*;
+use crate::engines::bn254::*;
+use crate::engines::mnt4_753::*;
+use crate::engines::mnt6_753::*;
+use crate::engines::bw6_761::*;
+
+fn from_json<T: DeserializeWithParams>(params: T::Params, json: &str) -> Result<T, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    ParamsSeed::<T>::new(params).deserialize(&mut deserializer)
+}
+
+fn pair_through_api(parameters: Vec<u8>, encoded_pairs: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut input = vec![OPERATION_PAIRING];
+    input.extend(parameters);
+    input.push(encoded_pairs.len() as u8);
+    for pair in encoded_pairs {
+        input.extend(pair);
+    }
+
+    API::run(&input).unwrap()
+}
+
+fn negated<G: Group + Clone>(p: &G) -> G {
+    let mut minus_p = p.clone();
+    minus_p.negate();
+
+    minus_p
+}
+
+/// Encodes pairs (P, Q) and (-P, Q), so pairing check must pass
+fn cancelling_pairs(g1: Vec<u8>, minus_g1: Vec<u8>, g2: Vec<u8>) -> Vec<Vec<u8>> {
+    let mut pairs = vec![];
+    for p in vec![g1, minus_g1].into_iter() {
+        let mut pair = vec![1u8];
+        pair.extend(p);
+        pair.push(1u8);
+        pair.extend(g2.clone());
+        pairs.push(pair);
+    }
+
+    pairs
+}
+
+#[test]
+fn test_uint_serialization() {
+    let modulus = MaxFieldUint::from(BLS12_381_MODULUS.as_ref());
+    let json = serde_json::to_string(&modulus).unwrap();
+    assert_eq!(json, "\"0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab\"");
+    assert_eq!(serde_json::from_str::<MaxFieldUint>(&json).unwrap(), modulus);
+
+    assert_eq!(serde_json::to_string(&MaxGroupSizeUint::zero()).unwrap(), "\"0x0\"");
+    assert!(serde_json::from_str::<MaxGroupSizeUint>("\"0x0\"").unwrap().is_zero());
+    assert_eq!(serde_json::from_str::<MaxGroupSizeUint>("\"0x00ff\"").unwrap(), MaxGroupSizeUint::from(255u64));
+
+    // no prefix, empty, invalid digit and too many digits
+    assert!(serde_json::from_str::<MaxFieldUint>("\"ff\"").is_err());
+    assert!(serde_json::from_str::<MaxFieldUint>("\"0x\"").is_err());
+    assert!(serde_json::from_str::<MaxFieldUint>("\"0xfg\"").is_err());
+    assert!(serde_json::from_str::<MaxFieldUint>(&format!("\"0x1{}\"", "0".repeat(256))).is_err());
+}
+
+#[test]
+fn test_field_elements_roundtrip() {
+    let (x, y) = BLS12_381_G1_GENERATOR.into_xy();
+    let json = serde_json::to_string(&x).unwrap();
+    let decoded: Fp<'_, U384Repr, PrimeField<U384Repr>> = from_json(&BLS12_381_FIELD, &json).unwrap();
+    assert!(decoded == x);
+    assert!(decoded != y);
+
+    let (x, _) = BLS12_381_G2_GENERATOR.into_xy();
+    let json = serde_json::to_string(&x).unwrap();
+    let decoded: Fp2<'_, U384Repr, PrimeField<U384Repr>> = from_json(&BLS12_381_EXTENSION_2_FIELD, &json).unwrap();
+    assert!(decoded == x);
+
+    let (x, _) = MNT6_753_G2_GENERATOR.into_xy();
+    let json = serde_json::to_string(&x).unwrap();
+    let decoded: Fp3<'_, _, _> = from_json(MNT6_753_G2_GENERATOR.curve.params.params(), &json).unwrap();
+    assert!(decoded == x);
+
+    let engine = BLS12_381_PAIRING_ENGINE;
+    let gt = engine.pair(&[BLS12_381_G1_GENERATOR], &[BLS12_381_G2_GENERATOR]).unwrap();
+    let json = serde_json::to_string(&gt).unwrap();
+    let decoded: Fp12<'_, U384Repr, PrimeField<U384Repr>> = from_json(&BLS12_381_EXTENSION_12_FIELD, &json).unwrap();
+    assert!(decoded == gt);
+
+    // wrong number of coefficients
+    let json = serde_json::to_string(&[x.c0, x.c1]).unwrap();
+    assert!(from_json::<Fp3<'_, _, _>>(MNT6_753_G2_GENERATOR.curve.params.params(), &json).is_err());
+}
+
+#[test]
+fn test_field_element_out_of_range() {
+    let modulus = MaxFieldUint::from(BLS12_381_FIELD.modulus().as_ref());
+    let json = serde_json::to_string(&modulus).unwrap();
+    assert!(from_json::<Fp<'_, U384Repr, PrimeField<U384Repr>>>(&BLS12_381_FIELD, &json).is_err());
+
+    // fits into MaxFieldUint, but not into the representation
+    let json = format!("\"0x1{}\"", "0".repeat(U384Repr::NUM_LIMBS * 16));
+    assert!(from_json::<Fp<'_, U384Repr, PrimeField<U384Repr>>>(&BLS12_381_FIELD, &json).is_err());
+}
+
+#[test]
+fn test_points_roundtrip() {
+    type G1 = CurvePoint<'static, CurveOverFpParameters<'static, U384Repr, PrimeField<U384Repr>>>;
+
+    let json = serde_json::to_string(&BLS12_381_G1_GENERATOR).unwrap();
+    let decoded: G1 = from_json(&BLS12_381_G1_CURVE, &json).unwrap();
+    assert!(decoded.into_xy() == BLS12_381_G1_GENERATOR.into_xy());
+
+    let mut p = BLS12_381_G2_GENERATOR;
+    p.double();
+    let json = serde_json::to_string(&p).unwrap();
+    let decoded: CurvePoint<'_, _> = from_json(&BLS12_381_G2_CURVE, &json).unwrap();
+    assert!(decoded.into_xy() == p.into_xy());
+
+    let json = serde_json::to_string(&MNT4_753_G2_GENERATOR).unwrap();
+    let decoded: CurvePoint<'_, _> = from_json(&MNT4_753_G2_CURVE, &json).unwrap();
+    assert!(decoded.into_xy() == MNT4_753_G2_GENERATOR.into_xy());
+
+    let zero = G1::zero(&BLS12_381_G1_CURVE);
+    let json = serde_json::to_string(&zero).unwrap();
+    assert_eq!(json, "{\"x\":\"0x0\",\"y\":\"0x0\"}");
+    let decoded: G1 = from_json(&BLS12_381_G1_CURVE, &json).unwrap();
+    assert!(decoded.is_zero());
+
+    // point is not on curve
+    let (x, _) = BLS12_381_G1_GENERATOR.into_xy();
+    let json = format!("{{\"x\":{},\"y\":\"0x1\"}}", serde_json::to_string(&x).unwrap());
+    assert!(from_json::<G1>(&BLS12_381_G1_CURVE, &json).is_err());
+
+    // missing and unknown fields
+    assert!(from_json::<G1>(&BLS12_381_G1_CURVE, "{\"x\":\"0x0\"}").is_err());
+    assert!(from_json::<G1>(&BLS12_381_G1_CURVE, "{\"x\":\"0x0\",\"y\":\"0x0\",\"z\":\"0x1\"}").is_err());
+}
+
+#[test]
+fn test_bls12_descriptions() {
+    let description = Bls12InstanceDescription::from_instance(&BLS12_381_PAIRING_ENGINE);
+    let json = serde_json::to_string_pretty(&description).unwrap();
+    let decoded: Bls12InstanceDescription = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, description);
+
+    let pairs = cancelling_pairs(
+        serialize_g1_point(48, &BLS12_381_G1_GENERATOR).unwrap(),
+        serialize_g1_point(48, &negated(&BLS12_381_G1_GENERATOR)).unwrap(),
+        serialize_g2_point_in_fp2(48, &BLS12_381_G2_GENERATOR).unwrap()
+    );
+    assert_eq!(pair_through_api(decoded.encode_pairing_parameters(), pairs), vec![1u8]);
+
+    let description = Bls12InstanceDescription::from_instance(&BLS12_377_PAIRING_ENGINE);
+    let decoded: Bls12InstanceDescription = serde_json::from_str(&serde_json::to_string(&description).unwrap()).unwrap();
+    assert_eq!(decoded, description);
+
+    let pairs = cancelling_pairs(
+        serialize_g1_point(48, &BLS12_377_G1_GENERATOR).unwrap(),
+        serialize_g1_point(48, &negated(&BLS12_377_G1_GENERATOR)).unwrap(),
+        serialize_g2_point_in_fp2(48, &BLS12_377_G2_GENERATOR).unwrap()
+    );
+    assert_eq!(pair_through_api(decoded.encode_pairing_parameters(), pairs), vec![1u8]);
+}
+
+#[test]
+fn test_bn_description() {
+    let description = BnInstanceDescription::from_instance(&BN254_PAIRING_ENGINE);
+    let decoded: BnInstanceDescription = serde_json::from_str(&serde_json::to_string(&description).unwrap()).unwrap();
+    assert_eq!(decoded, description);
+
+    let pairs = cancelling_pairs(
+        serialize_g1_point(32, &BN254_G1_GENERATOR).unwrap(),
+        serialize_g1_point(32, &negated(&BN254_G1_GENERATOR)).unwrap(),
+        serialize_g2_point_in_fp2(32, &BN254_G2_GENERATOR).unwrap()
+    );
+    assert_eq!(pair_through_api(decoded.encode_pairing_parameters(), pairs), vec![1u8]);
+}
+
+#[test]
+fn test_mnt_descriptions() {
+    let description = Mnt4InstanceDescription::from_instance(&MNT4_753_PAIRING_ENGINE);
+    let decoded: Mnt4InstanceDescription = serde_json::from_str(&serde_json::to_string(&description).unwrap()).unwrap();
+    assert_eq!(decoded, description);
+
+    let pairs = cancelling_pairs(
+        serialize_g1_point(95, &MNT4_753_G1_GENERATOR).unwrap(),
+        serialize_g1_point(95, &negated(&MNT4_753_G1_GENERATOR)).unwrap(),
+        serialize_g2_point_in_fp2(95, &MNT4_753_G2_GENERATOR).unwrap()
+    );
+    assert_eq!(pair_through_api(decoded.encode_pairing_parameters(), pairs), vec![1u8]);
+
+    let description = Mnt6InstanceDescription::from_instance(&MNT6_753_PAIRING_ENGINE);
+    let decoded: Mnt6InstanceDescription = serde_json::from_str(&serde_json::to_string(&description).unwrap()).unwrap();
+    assert_eq!(decoded, description);
+
+    let pairs = cancelling_pairs(
+        serialize_g1_point(95, &MNT6_753_G1_GENERATOR).unwrap(),
+        serialize_g1_point(95, &negated(&MNT6_753_G1_GENERATOR)).unwrap(),
+        serialize_g2_point_in_fp3(95, &MNT6_753_G2_GENERATOR).unwrap()
+    );
+    assert_eq!(pair_through_api(decoded.encode_pairing_parameters(), pairs), vec![1u8]);
+}
+
+#[test]
+fn test_bw6_description() {
+    let description = Bw6InstanceDescription::from_instance(&BW6_761_PAIRING_ENGINE);
+    let decoded: Bw6InstanceDescription = serde_json::from_str(&serde_json::to_string(&description).unwrap()).unwrap();
+    assert_eq!(decoded, description);
+
+    // both G1 and G2 points are encoded as points over the base field
+    let pairs = cancelling_pairs(
+        serialize_g1_point(96, &BW6_761_G1_GENERATOR).unwrap(),
+        serialize_g1_point(96, &negated(&BW6_761_G1_GENERATOR)).unwrap(),
+        serialize_g1_point(96, &BW6_761_G2_GENERATOR).unwrap()
+    );
+    assert_eq!(pair_through_api(decoded.encode_pairing_parameters(), pairs), vec![1u8]);
+}
@@ -49,6 +49,21 @@ impl<'a, C: CurveParameters> WeierstrassCurve<'a, C> {
             params: params
         })
     }
+
+    /// Evaluates the right hand side `x^3 + a*x + b` of the curve equation
+    pub(crate) fn evaluate_rhs(&self, x: &C::BaseFieldElement) -> C::BaseFieldElement {
+        let mut rhs = self.b.clone();
+        let mut ax = x.clone();
+        ax.mul_assign(&self.a);
+        rhs.add_assign(&ax);
+
+        let mut x_3 = x.clone();
+        x_3.square();
+        x_3.mul_assign(x);
+        rhs.add_assign(&x_3);
+
+        rhs
+    }
 }
 
 pub struct CurvePoint<'a, C: CurveParameters> {
@@ -135,20 +150,10 @@ impl<'a, C: CurveParameters> CurvePoint<'a, C> {
         
         debug_assert!(self.is_normalized());
 
-        let mut rhs = self.y.clone();
-        rhs.square();
+        let mut y_2 = self.y.clone();
+        y_2.square();
 
-        let mut lhs = self.curve.b.clone();
-        let mut ax = self.x.clone();
-        ax.mul_assign(&self.curve.a);
-        lhs.add_assign(&ax);
-
-        let mut x_3 = self.x.clone();
-        x_3.square();
-        x_3.mul_assign(&self.x);
-        lhs.add_assign(&x_3);
-
-        rhs == lhs
+        y_2 == self.curve.evaluate_rhs(&self.x)
     }
 
     pub fn point_from_xy(
@@ -477,7 +482,10 @@ impl<'a, C: CurveParameters> CurvePoint<'a, C> {
         self.wnaf_mul_with_window_size_impl(exp, WINDOW_SIZE)
     }
 
-    pub(crate) fn wnaf_mul_with_window_size_impl<S: crate::representation::IntoWnaf>(&self, exp: S, window_size: u32) -> Self {
+    /// Table of odd multiples `-(2^(w-1) - 1)*P, ..., -P, P, ..., (2^(w-1) - 1)*P` for wNAF
+    /// digits, a digit `w` is at `index_for_positive + (w >> 1)` if positive and at
+    /// `index_for_positive - 1 - ((-w) >> 1)` otherwise, where `index_for_positive = 2^(w-2)`
+    pub(crate) fn wnaf_precomputation_table(&self, window_size: u32) -> Vec<Self> {
         assert!(window_size >= 2u32);
         let mut precomp_table = vec![Self::zero(&self.curve); (1 << (window_size-1)) as usize];
 
@@ -502,6 +510,13 @@ impl<'a, C: CurveParameters> CurvePoint<'a, C> {
 
         // batch_normalize(&mut precomp_table);
 
+        precomp_table
+    }
+
+    pub(crate) fn wnaf_mul_with_window_size_impl<S: crate::representation::IntoWnaf>(&self, exp: S, window_size: u32) -> Self {
+        let precomp_table = self.wnaf_precomputation_table(window_size);
+        let index_for_positive = (1 << (window_size-2)) as usize;
+
         let wnaf = exp.wnaf(window_size);
 
         let mut res = Self::zero(&self.curve);
@@ -769,4 +784,12 @@ impl<'a, C: CurveParameters> Group for CurvePoint<'a, C> {
             },
         }
     }
+
+    fn zero_like(&self) -> Self {
+        Self::zero(self.curve)
+    }
+
+    fn subgroup_order(&self) -> &[u64] {
+        self.curve.subgroup_order_repr
+    }
 }